@@ -275,6 +275,8 @@ impl Checker {
         let processor = Processor::with_config(
             ProcessorConfig {
                 chunk_size: self.chunk_size,
+                module_cache: None,
+                message_history: false,
             },
             db.clone(),
         )
@@ -321,7 +323,9 @@ impl Checker {
                 states,
                 schedule,
                 program_creations: _,
+                child_program_creations,
                 committed_message_ids: _,
+                message_records: _,
             } = overlay
                 .as_mut()
                 .process_programs(executable, None)
@@ -340,6 +344,10 @@ impl Checker {
                 schedule == expected_schedule,
                 "MB {current_mb} (height {height}) schedule mismatch",
             );
+            ensure!(
+                child_program_creations == db.mb_program_creations(current_mb).unwrap_or_default(),
+                "MB {current_mb} (height {height}) program creations mismatch",
+            );
 
             if let Some(pb) = pb.as_ref() {
                 pb.inc(1);
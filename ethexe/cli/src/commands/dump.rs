@@ -2,12 +2,20 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::params::{MergeParams, Params};
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, ensure};
 use clap::{Parser, Subcommand};
-use ethexe_common::db::GlobalsStorageRO;
-use ethexe_db::{Database, RawDatabase, RocksDatabase, dump::StateDump};
-use gprimitives::H256;
-use std::path::{Path, PathBuf};
+use ethexe_common::db::{GlobalsStorageRO, MbStorageRO};
+use ethexe_db::{
+    CASDatabase, Database, InitConfig, RawDatabase, RocksDatabase,
+    dump::{DumpFilter, StateDump, StateDumpDiff},
+};
+use ethexe_service::GenesisInitializerFromFile;
+use gprimitives::{ActorId, H256};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
 
 /// State dump operations for re-genesis.
 #[derive(Debug, Parser)]
@@ -26,7 +34,8 @@ pub struct DumpCommand {
 #[derive(Debug, Subcommand)]
 pub enum DumpSubcommand {
     /// Create a state dump from the database and write it to a file.
-    /// Use `.blob` extension for binary format or `.json` for JSON format.
+    /// Use `.blob` extension for binary format, `.json` for JSON format
+    /// or `.dump` for streaming format, which is written incrementally.
     /// If --block-hash is not provided, uses the latest committed block.
     Create {
         /// Block hash (hex-encoded, with or without 0x prefix).
@@ -34,18 +43,45 @@ pub enum DumpSubcommand {
         #[arg(long)]
         block_hash: Option<H256>,
 
-        /// Output file path (.blob for binary, .json for JSON).
+        /// Only dump the given programs and the codes they reference.
+        /// May be repeated. If omitted, all programs are dumped.
+        #[arg(long = "program")]
+        programs: Vec<ActorId>,
+
+        /// Output file path (.blob for binary, .json for JSON, .dump for streaming).
         #[arg(long, short)]
         output: PathBuf,
     },
-    /// Read a state dump from a file (`.blob` or `.json`) and print it as JSON
+    /// Initialize an empty database from a state dump file (`.blob`, `.json` or `.dump`),
+    /// so the node starts from the dumped state as the genesis one.
+    /// Requires Ethereum RPC and Router address, the same as `ethexe run`.
+    /// A dump of another chain is imported with a warning, e.g. to fork production state.
+    Import {
+        /// Dump file path (.blob for binary, .json for JSON, .dump for streaming).
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Read a state dump from a file (`.blob`, `.json` or `.dump`) and print it as JSON
     /// to stdout. Useful for re-inspecting an exported dump.
     Json {
         /// Dump file path. Format is detected from the extension
-        /// (`.blob` for binary, `.json` for JSON).
+        /// (`.blob` for binary, `.json` for JSON, `.dump` for streaming).
         #[arg(long, short)]
         file: PathBuf,
     },
+    /// Compare two state dump files (`.blob`, `.json` or `.dump`) and print added and
+    /// removed programs and codes, and per-program state changes.
+    Diff {
+        /// Old dump file path.
+        old: PathBuf,
+
+        /// New dump file path.
+        new: PathBuf,
+
+        /// Print the diff as JSON instead of the human-readable form.
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 impl DumpCommand {
@@ -56,12 +92,23 @@ impl DumpCommand {
 
     pub fn exec(self) -> Result<()> {
         match &self.command {
-            DumpSubcommand::Create { block_hash, output } => self.exec_create(*block_hash, output),
+            DumpSubcommand::Create {
+                block_hash,
+                programs,
+                output,
+            } => self.exec_create(*block_hash, programs, output),
+            DumpSubcommand::Import { file } => self.exec_import(file),
             DumpSubcommand::Json { file } => Self::exec_json(file),
+            DumpSubcommand::Diff { old, new, json } => Self::exec_diff(old, new, *json),
         }
     }
 
-    fn exec_create(&self, block_hash: Option<H256>, output: &Path) -> Result<()> {
+    fn exec_create(
+        &self,
+        block_hash: Option<H256>,
+        programs: &[ActorId],
+        output: &Path,
+    ) -> Result<()> {
         crate::enable_logging("info")?;
 
         let rocks_db = RocksDatabase::open(
@@ -83,8 +130,31 @@ impl DumpCommand {
             latest_prepared_block
         });
 
+        let filter = if programs.is_empty() {
+            DumpFilter::All
+        } else {
+            DumpFilter::Programs(programs.iter().copied().collect())
+        };
+
+        if output.extension().is_some_and(|ext| ext == "dump") {
+            log::info!("Streaming state dump for block {block_hash:?}...");
+            let file = BufWriter::new(File::create(output)?);
+            let (header, writer) = StateDump::stream_from_storage(&db, block_hash, &filter, file)?;
+            let blobs_amount = writer.blobs_amount();
+            writer.finish()?.flush()?;
+
+            log::info!(
+                "Dump written to {}: {} codes, {} programs, {} blobs",
+                output.display(),
+                header.codes.len(),
+                header.programs.len(),
+                blobs_amount,
+            );
+            return Ok(());
+        }
+
         log::info!("Collecting state dump for block {block_hash:?}...");
-        let dump = StateDump::collect_from_storage(&db, block_hash)?;
+        let dump = StateDump::collect_from_storage(&db, block_hash, &filter)?;
 
         log::info!(
             "Dump collected: {} codes, {} programs, {} blobs",
@@ -98,14 +168,154 @@ impl DumpCommand {
         Ok(())
     }
 
+    fn exec_import(&self, file: &Path) -> Result<()> {
+        crate::enable_logging("info")?;
+
+        let ethereum_config = self
+            .params
+            .ethereum
+            .clone()
+            .context("missing Ethereum-related configuration")?
+            .into_config()?;
+
+        // Same location `ethexe run` opens the database at.
+        let db_path = self
+            .db
+            .clone()
+            .or_else(|| {
+                self.params.node.as_ref().map(|node| {
+                    node.db_dir()
+                        .join(ethereum_config.router_address.to_string())
+                })
+            })
+            .context("missing database path")?;
+
+        let rocks_db = RocksDatabase::open(db_path.clone()).context("failed to open database")?;
+        let raw_db = RawDatabase::from_one(&rocks_db);
+        ensure!(
+            raw_db.kv.is_empty(),
+            "database at {} is not empty, remove it before importing a dump",
+            db_path.display()
+        );
+
+        log::info!("Importing state dump {}...", file.display());
+        let db = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?
+            .block_on(async {
+                let genesis_initializer = GenesisInitializerFromFile::new(file.to_path_buf())?;
+                ethexe_db::initialize_db(
+                    InitConfig {
+                        ethereum_rpc: ethereum_config.rpc.clone(),
+                        router_address: ethereum_config.router_address,
+                        slot_duration_secs: ethereum_config.block_time.as_secs(),
+                        genesis_initializer: Some(Box::new(genesis_initializer)),
+                    },
+                    raw_db,
+                )
+                .await
+            })
+            .context("failed to initialize database from dump")?;
+
+        let program_states = db
+            .mb_program_states(db.globals().latest_computed_mb_hash)
+            .context("genesis program states are missing")?;
+        log::info!(
+            "Dump imported into {}: {} programs",
+            db_path.display(),
+            program_states.len()
+        );
+
+        Ok(())
+    }
+
     fn exec_json(file: &Path) -> Result<()> {
         // Auto-detect the format from the file extension so passing either a
         // `.blob` or a previously-exported `.json` produces a clear error
         // instead of a raw deflate/SCALE decode failure.
-        let dump = StateDump::read_from_file(file)
-            .with_context(|| format!("failed to read dump file {}", file.display()))?;
-        let json = serde_json::to_string_pretty(&dump)?;
-        println!("{json}");
+        StateDump::file_to_json(file, io::stdout().lock())
+            .with_context(|| format!("failed to read dump file {}", file.display()))
+    }
+
+    fn exec_diff(old: &Path, new: &Path, json: bool) -> Result<()> {
+        // Blobs are put in temporary databases, so dumps aren't loaded into memory.
+        let load = |file: &Path| -> Result<_> {
+            let (header, blobs) = StateDump::open_file(file)
+                .with_context(|| format!("failed to read dump file {}", file.display()))?;
+
+            let dir = tempfile::tempdir()?;
+            let db = RocksDatabase::open(dir.path().to_path_buf())?;
+            for blob in blobs {
+                let blob =
+                    blob.with_context(|| format!("failed to read dump file {}", file.display()))?;
+                CASDatabase::write(&db, &blob);
+            }
+
+            Ok((header, dir, db))
+        };
+
+        let (old_header, _old_dir, old_db) = load(old)?;
+        let (new_header, _new_dir, new_db) = load(new)?;
+        let diff = StateDumpDiff::new(&old_header, &old_db, &new_header, &new_db)?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&diff)?);
+        } else {
+            print!("{diff}");
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTER: &str = "0x0101010101010101010101010101010101010101";
+
+    #[test]
+    fn import_args_parse() {
+        let command = DumpCommand::try_parse_from([
+            "dump",
+            "--ethereum-router",
+            ROUTER,
+            "--db",
+            "/tmp/db",
+            "import",
+            "--file",
+            "state.dump",
+        ])
+        .unwrap();
+
+        assert_eq!(command.db, Some(PathBuf::from("/tmp/db")));
+        assert!(
+            matches!(command.command, DumpSubcommand::Import { file } if file == Path::new("state.dump"))
+        );
+    }
+
+    #[test]
+    fn import_into_non_empty_db_fails() {
+        let db_dir = tempfile::tempdir().unwrap();
+        {
+            let rocks_db = RocksDatabase::open(db_dir.path().to_path_buf()).unwrap();
+            RawDatabase::from_one(&rocks_db)
+                .kv
+                .put(b"key", b"value".to_vec());
+        }
+
+        let command = DumpCommand::try_parse_from([
+            "dump",
+            "--ethereum-router",
+            ROUTER,
+            "--db",
+            db_dir.path().to_str().unwrap(),
+            "import",
+            "--file",
+            "state.dump",
+        ])
+        .unwrap();
+
+        let err = command.exec().unwrap_err();
+        assert!(err.to_string().contains("is not empty"), "{err}");
+    }
+}
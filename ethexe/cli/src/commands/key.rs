@@ -62,6 +62,37 @@ fn apply_default_storage(command: SchemeSubcommand, default: PathBuf) -> SchemeS
             apply_default_storage_keyring(&mut command, &default);
             SchemeSubcommand::Keyring { command }
         }
+        #[cfg(unix)]
+        SchemeSubcommand::Server {
+            mut storage,
+            socket,
+            policy,
+        } => {
+            if storage.path.is_none() && !storage.memory {
+                storage.path = Some(default);
+            }
+            SchemeSubcommand::Server {
+                storage,
+                socket,
+                policy,
+            }
+        }
+        SchemeSubcommand::SignTyped {
+            public_key,
+            data,
+            file,
+            mut storage,
+        } => {
+            if storage.path.is_none() && !storage.memory {
+                storage.path = Some(default);
+            }
+            SchemeSubcommand::SignTyped {
+                public_key,
+                data,
+                file,
+                storage,
+            }
+        }
         other => other,
     }
 }
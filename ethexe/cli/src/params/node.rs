@@ -12,7 +12,7 @@ use ethexe_common::{
     consensus::{DEFAULT_BATCH_SIZE_LIMIT, MAX_BATCH_SIZE_LIMIT},
     gear::{CANONICAL_QUARANTINE, MAX_BLOCK_GAS_LIMIT},
 };
-use ethexe_processor::DEFAULT_CHUNK_SIZE;
+use ethexe_processor::{DEFAULT_CHUNK_SIZE, DiskCacheConfig};
 use ethexe_service::config::{ConfigPublicKey, NodeConfig};
 use serde::Deserialize;
 use std::{num::NonZero, path::PathBuf};
@@ -80,6 +80,13 @@ pub struct NodeParams {
     #[serde(rename = "chunk-processing-threads")]
     pub chunk_processing_threads: Option<NonZero<usize>>,
 
+    /// Size limit of the on-disk cache of compiled program modules, in MiB.
+    ///
+    /// The cache is disabled if not set.
+    #[arg(long)]
+    #[serde(rename = "module-cache-size")]
+    pub module_cache_size: Option<NonZero<u64>>,
+
     /// Block gas limit for the node.
     #[arg(long)]
     #[serde(rename = "block-gas-limit")]
@@ -153,6 +160,21 @@ pub struct NodeParams {
     #[arg(long)]
     #[serde(default, rename = "db-cleanup")]
     pub db_cleanup: bool,
+
+    /// Index processed messages, so their outcomes can be queried with
+    /// `program_messageHistory` and `program_recentMessages` RPC methods.
+    #[arg(long, default_value = "false")]
+    #[serde(default, rename = "message-history")]
+    pub message_history: bool,
+
+    /// Unix socket of a remote signer daemon (`gsigner <scheme> server`).
+    ///
+    /// Keys are requested from the daemon instead of being read from the keys
+    /// directory. Not supported together with `--validator`, since Malachite
+    /// consensus requires the validator private key.
+    #[arg(long)]
+    #[serde(default, rename = "signer-socket")]
+    pub signer_socket: Option<PathBuf>,
 }
 
 impl NodeParams {
@@ -189,6 +211,10 @@ impl NodeParams {
                 .chunk_processing_threads
                 .unwrap_or(DEFAULT_CHUNK_SIZE)
                 .get(),
+            module_cache: self.module_cache_size.map(|size| DiskCacheConfig {
+                directory: self.module_cache_dir(),
+                max_size: size.get().saturating_mul(1024 * 1024),
+            }),
             block_gas_limit: self
                 .block_gas_limit
                 .unwrap_or(DEFAULT_BLOCK_GAS_LIMIT)
@@ -222,6 +248,8 @@ impl NodeParams {
                 .unwrap_or(ethexe_common::DEFAULT_BATCH_COMMITMENT_PERIOD),
             genesis_state_dump: self.genesis_state_dump,
             db_cleanup: self.db_cleanup,
+            message_history: self.message_history,
+            signer_socket: self.signer_socket,
         })
     }
 
@@ -247,6 +275,11 @@ impl NodeParams {
         self.base().join("net")
     }
 
+    /// Returns the directory that stores compiled program modules.
+    pub fn module_cache_dir(&self) -> PathBuf {
+        self.base().join("module-cache")
+    }
+
     fn base(&self) -> PathBuf {
         self.base.clone().unwrap_or_else(Self::default_base)
     }
@@ -294,6 +327,7 @@ impl MergeParams for NodeParams {
             chunk_processing_threads: self
                 .chunk_processing_threads
                 .or(with.chunk_processing_threads),
+            module_cache_size: self.module_cache_size.or(with.module_cache_size),
 
             block_gas_limit: self.block_gas_limit.or(with.block_gas_limit),
             batch_size_limit: self.batch_size_limit.or(with.batch_size_limit),
@@ -319,6 +353,10 @@ impl MergeParams for NodeParams {
             genesis_state_dump: self.genesis_state_dump.or(with.genesis_state_dump),
 
             db_cleanup: self.db_cleanup || with.db_cleanup,
+
+            message_history: self.message_history || with.message_history,
+
+            signer_socket: self.signer_socket.or(with.signer_socket),
         }
     }
 }
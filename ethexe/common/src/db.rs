@@ -7,17 +7,19 @@ use crate::{
     Address, BlockHeader, CodeBlobInfo, Digest, HashOf, ProgramStates, ProtocolTimelines, Schedule,
     SimpleBlockData, ValidatorsVec,
     events::BlockEvent,
-    gear::StateTransition,
+    gear::{ProgramCreation, StateTransition},
     injected::{InjectedTransaction, Promise, SignedInjectedTransaction, SignedTxReceipt},
     malachite::Operations,
 };
 use alloc::{
     collections::{BTreeSet, VecDeque},
+    string::String,
     vec::Vec,
 };
 use gear_core::{
     code::{CodeMetadata, InstrumentedCode},
     ids::{ActorId, CodeId},
+    message::ReplyCode,
 };
 use gprimitives::{H256, MessageId};
 use parity_scale_codec::{Decode, Encode};
@@ -167,6 +169,10 @@ pub trait MbStorageRO {
     /// Written atomically with [`MbStorageRW::set_mb_outcome`] in `compute_one`.
     /// Absence means no message was committable (all are off-chain injected).
     fn mb_committed_message_ids(&self, mb_hash: H256) -> Option<BTreeSet<MessageId>>;
+    /// Programs created by programs during MB computation, in creation order.
+    ///
+    /// Written with [`MbStorageRW::set_mb_outcome`] in `compute_one` when non-empty.
+    fn mb_program_creations(&self, mb_hash: H256) -> Option<Vec<ProgramCreation>>;
     fn mb_schedule(&self, mb_hash: H256) -> Option<Schedule>;
     fn mb_meta(&self, mb_hash: H256) -> MbMeta;
 }
@@ -180,10 +186,80 @@ pub trait MbStorageRW: MbStorageRO {
     fn set_mb_program_states(&self, mb_hash: H256, program_states: ProgramStates);
     fn set_mb_outcome(&self, mb_hash: H256, outcome: Vec<StateTransition>);
     fn set_mb_committed_message_ids(&self, mb_hash: H256, ids: BTreeSet<MessageId>);
+    fn set_mb_program_creations(&self, mb_hash: H256, creations: Vec<ProgramCreation>);
     fn set_mb_schedule(&self, mb_hash: H256, schedule: Schedule);
     fn mutate_mb_meta(&self, mb_hash: H256, f: impl FnOnce(&mut MbMeta));
 }
 
+/// Outcome of a dispatch processed by a program.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageOutcome {
+    /// Dispatch was executed successfully.
+    Success,
+    /// Dispatch wasn't executed, e.g. because the program is terminated.
+    NoExecution,
+    /// Program exited while executing the dispatch.
+    Exit,
+    /// Program was initialized by the dispatch.
+    InitSuccess,
+    /// Program initialization failed.
+    InitFailure { reason: String },
+    /// Execution of the dispatch trapped.
+    Trap { reason: String },
+}
+
+/// Reply sent by a program to a processed dispatch.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageReply {
+    pub message_id: MessageId,
+    pub code: ReplyCode,
+    pub value: u128,
+}
+
+/// Record of a dispatch processed by a program.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageRecord {
+    pub message_id: MessageId,
+    pub program_id: ActorId,
+    pub source: ActorId,
+    /// Value attached to the dispatch.
+    pub value: u128,
+    pub outcome: MessageOutcome,
+    pub gas_burned: u64,
+    /// Total value of messages sent while executing the dispatch, including the reply.
+    pub value_sent: u128,
+    /// Dispatch was moved into the waitlist, so it's processed again once woken.
+    pub waited: bool,
+    pub reply: Option<MessageReply>,
+}
+
+/// [`MessageRecord`] indexed along with the MB it was processed in.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessedMessage {
+    pub mb_hash: H256,
+    pub record: MessageRecord,
+}
+
+/// Optional index of processed messages, filled in only when message history is enabled.
+#[auto_impl::auto_impl(&, Box)]
+pub trait MessageHistoryStorageRO {
+    /// Returns the latest processing of the message.
+    fn processed_message(&self, message_id: MessageId) -> Option<ProcessedMessage>;
+    /// Returns ids of messages processed by the program, most recent first,
+    /// skipping `offset` most recent ones and returning at most `limit`.
+    fn program_messages(&self, program_id: ActorId, offset: u64, limit: u64) -> Vec<MessageId>;
+}
+
+#[auto_impl::auto_impl(&)]
+pub trait MessageHistoryStorageRW: MessageHistoryStorageRO {
+    /// Indexes messages processed in the MB, in processing order.
+    fn add_processed_messages(&self, mb_hash: H256, records: Vec<MessageRecord>);
+}
+
 pub struct PreparedBlockData {
     pub header: BlockHeader,
     pub events: Vec<BlockEvent>,
@@ -47,10 +47,72 @@ pub struct AddressBook {
     pub wrapped_vara: ActorId,
 }
 
-/// Squashed chain commitment with state transitions, MB head, and the latest
-/// advanced Ethereum block hash, zero if no ethereum block has been advanced.
+/// Init code of the `CREATE3` proxy, used by `Mirror` to deploy programs
+/// created by programs, see `Create3.sol`.
+pub const CREATE3_PROXY_INIT_CODE: [u8; 16] = [
+    0x67, 0x36, 0x3d, 0x3d, 0x37, 0x36, 0x3d, 0x34, 0xf0, 0x3d, 0x52, 0x60, 0x08, 0x60, 0x18, 0xf3,
+];
+
+/// Computes id of the program created by `parent` program with the given `salt`.
+///
+/// Child `Mirror` is deployed by the parent `Mirror` via `CREATE3`, so its
+/// address depends only on parent address and salt, but not on the init code.
+pub fn program_creation_actor_id(parent: ActorId, salt: H256) -> ActorId {
+    let proxy_init_code_hash = sha3::Keccak256::digest(CREATE3_PROXY_INIT_CODE);
+
+    let proxy = sha3::Keccak256::new()
+        .chain_update([0xff])
+        .chain_update(parent.to_address_lossy())
+        .chain_update(salt)
+        .chain_update(proxy_init_code_hash)
+        .finalize();
+
+    // RLP encoding of `[proxy, 1]`, where `1` is the nonce of the proxy.
+    let actor = sha3::Keccak256::new()
+        .chain_update([0xd6, 0x94])
+        .chain_update(&proxy[12..])
+        .chain_update([0x01])
+        .finalize();
+
+    let mut actor_id = [0; 32];
+    actor_id[12..].copy_from_slice(&actor[12..]);
+
+    actor_id.into()
+}
+
+/// Program created by another program, which must be deployed by `Router`
+/// before state transitions of the chain commitment are performed.
+#[derive(Clone, Debug, Default, Encode, Decode, TypeInfo, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramCreation {
+    pub actor_id: ActorId,
+    pub parent: ActorId,
+    pub code_id: CodeId,
+    /// Salt of `CREATE3` deployment, which is the id of the init message.
+    pub salt: H256,
+}
+
+impl ToDigest for ProgramCreation {
+    fn update_hasher(&self, hasher: &mut sha3::Keccak256) {
+        let Self {
+            actor_id,
+            parent,
+            code_id,
+            salt,
+        } = self;
+
+        hasher.update(actor_id.to_address_lossy());
+        hasher.update(parent.to_address_lossy());
+        hasher.update(code_id.into_bytes());
+        hasher.update(salt);
+    }
+}
+
+/// Squashed chain commitment with programs created by programs, state transitions,
+/// MB head, and the latest advanced Ethereum block hash, zero if no ethereum block has been advanced.
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
 pub struct ChainCommitment {
+    pub program_creations: Vec<ProgramCreation>,
     pub transitions: Vec<StateTransition>,
     pub head: H256,
     pub last_advanced_eth_block: H256,
@@ -59,11 +121,13 @@ pub struct ChainCommitment {
 impl ToDigest for ChainCommitment {
     fn update_hasher(&self, hasher: &mut sha3::Keccak256) {
         let ChainCommitment {
+            program_creations,
             transitions,
             head,
             last_advanced_eth_block,
         } = self;
 
+        hasher.update(program_creations.to_digest());
         hasher.update(transitions.to_digest());
         hasher.update(head.0);
         hasher.update(last_advanced_eth_block.0);
@@ -453,6 +517,7 @@ pub struct GenesisBlockInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use gprimitives::H160;
 
     #[test]
     fn validators_commitment_accepts_raw_vss_commitment_bytes() {
@@ -466,4 +531,23 @@ mod tests {
 
         assert!(commitment.verifiable_secret_sharing_commitment.is_empty());
     }
+
+    #[test]
+    fn program_creation_actor_id_is_address_derived_from_parent_and_salt() {
+        let parent = ActorId::from(H160::repeat_byte(0x42));
+        let salt = H256::repeat_byte(0x01);
+
+        let actor_id = program_creation_actor_id(parent, salt);
+
+        assert_eq!(actor_id.into_bytes()[..12], [0; 12]);
+        assert_eq!(actor_id, program_creation_actor_id(parent, salt));
+        assert_ne!(
+            actor_id,
+            program_creation_actor_id(parent, H256::repeat_byte(0x02))
+        );
+        assert_ne!(
+            actor_id,
+            program_creation_actor_id(ActorId::from(H160::repeat_byte(0x43)), salt)
+        );
+    }
 }
@@ -297,6 +297,7 @@ impl Arbitrary for ChainCommitment {
             head,
         )
             .prop_map(|(first, second, head)| Self {
+                program_creations: Vec::new(),
                 transitions: vec![first, second],
                 head,
                 last_advanced_eth_block: H256::zero(),
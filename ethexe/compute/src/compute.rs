@@ -14,7 +14,7 @@ use ethexe_common::{
     PromiseEmissionMode, PromisePolicy, SimpleBlockData,
     db::{
         CodesStorageRW, CompactMb, ConfigStorageRO, GlobalsStorageRO, MbStorageRO, MbStorageRW,
-        OnChainStorageRO,
+        MessageHistoryStorageRW, OnChainStorageRO,
     },
     events::{BlockRequestEvent, RouterRequestEvent},
     injected::Promise,
@@ -82,6 +82,8 @@ pub struct ComputeSubService<P: ProcessorExt> {
     /// keeps predecessors silent (their promises were already gossiped
     /// by the producer at the time).
     promise_emission_mode: PromiseEmissionMode,
+    /// Whether processed messages are indexed for the message history RPC.
+    message_history: bool,
     metrics: Metrics,
 
     input: VecDeque<MbComputeRequest>,
@@ -111,6 +113,7 @@ impl<P: ProcessorExt> ComputeSubService<P> {
             db,
             processor,
             promise_emission_mode,
+            message_history: false,
             metrics: Metrics::default(),
             input: VecDeque::new(),
             finalized_events_input: VecDeque::new(),
@@ -122,6 +125,12 @@ impl<P: ProcessorExt> ComputeSubService<P> {
         }
     }
 
+    /// Enables or disables indexing of processed messages.
+    pub fn with_message_history(mut self, enabled: bool) -> Self {
+        self.message_history = enabled;
+        self
+    }
+
     pub fn receive_mb(&mut self, mb_hash: H256, promise_policy: PromisePolicy) {
         // Idempotent: skip if already computed, in flight, or queued —
         // otherwise BlockProposal+BlockFinalized for the same head emit
@@ -151,6 +160,7 @@ impl<P: ProcessorExt> ComputeSubService<P> {
         mut processor: P,
         req: MbComputeRequest,
         promise_emission_mode: PromiseEmissionMode,
+        message_history: bool,
         promise_tx: mpsc::UnboundedSender<(H256, Promise)>,
     ) -> Result<H256> {
         let MbComputeRequest {
@@ -185,7 +195,15 @@ impl<P: ProcessorExt> ComputeSubService<P> {
                 }
                 _ => None,
             };
-            Self::compute_one(&db, &mut processor, mb_hash, compact_mb, promise_sink).await?;
+            Self::compute_one(
+                &db,
+                &mut processor,
+                mb_hash,
+                compact_mb,
+                promise_sink,
+                message_history,
+            )
+            .await?;
         }
 
         Ok(head_mb_hash)
@@ -197,6 +215,7 @@ impl<P: ProcessorExt> ComputeSubService<P> {
         mb_hash: H256,
         compact_mb: CompactMb,
         promise_sink: Option<BoundPromiseSink>,
+        message_history: bool,
     ) -> Result<()> {
         log::debug!("compute one MB: hash {mb_hash} {compact_mb}");
 
@@ -208,7 +227,9 @@ impl<P: ProcessorExt> ComputeSubService<P> {
             states,
             schedule,
             program_creations,
+            child_program_creations,
             committed_message_ids,
+            message_records,
         } = processing_result;
 
         program_creations
@@ -220,8 +241,14 @@ impl<P: ProcessorExt> ComputeSubService<P> {
         db.set_mb_outcome(mb_hash, transitions);
         // Written atomically with mb_outcome so batch builders can filter messages.
         db.set_mb_committed_message_ids(mb_hash, committed_message_ids);
+        if !child_program_creations.is_empty() {
+            db.set_mb_program_creations(mb_hash, child_program_creations);
+        }
         db.set_mb_program_states(mb_hash, states);
         db.set_mb_schedule(mb_hash, schedule);
+        if message_history {
+            db.add_processed_messages(mb_hash, message_records);
+        }
         db.mutate_mb_meta(mb_hash, |meta| {
             meta.computed = true;
         });
@@ -283,16 +310,25 @@ pub fn prepare_executable_for_mb(
         .ok_or(ComputeError::ParentMbScheduleMissing(parent))?;
     let advanced_block = db.mb_meta(parent).last_advanced_eb;
 
-    build_executable_data(db, mb_payload, program_states, schedule, advanced_block)
+    build_executable_data(
+        db,
+        mb_hash,
+        mb_payload,
+        program_states,
+        schedule,
+        advanced_block,
+    )
 }
 
 /// Walk the MB's `Operations` list and prepare processor input.
 ///
 /// Synthetic block height/timestamp come from `last_advanced_eb` (the latest
 /// EB pinned by this MB or any ancestor); if none, fall back to the router's
-/// genesis block from [`ConfigStorageRO::config`].
+/// genesis block from [`ConfigStorageRO::config`]. The random seed mixes the
+/// hash of that EB with the MB hash, so it's the same on every validator.
 fn build_executable_data(
     db: &Database,
+    mb_hash: H256,
     operations: Operations,
     program_states: ethexe_common::ProgramStates,
     schedule: ethexe_common::Schedule,
@@ -363,20 +399,23 @@ fn build_executable_data(
         }
     }
 
-    let (height, timestamp) = if let Some(current_anchor) = current_anchor {
+    let (anchor_hash, height, timestamp) = if let Some(current_anchor) = current_anchor {
         (
+            current_anchor.hash,
             current_anchor.header.height,
             current_anchor.header.timestamp,
         )
     } else {
-        db.block_header(db.config().genesis_block_hash)
-            .map(|h| (h.height, h.timestamp))
+        let genesis_block_hash = db.config().genesis_block_hash;
+        db.block_header(genesis_block_hash)
+            .map(|h| (genesis_block_hash, h.height, h.timestamp))
             .ok_or(ComputeError::GenesisBlockMissing)?
     };
 
     Ok(ExecutableData {
         height,
         timestamp,
+        random_seed: ethexe_processor::derive_random_seed(anchor_hash, mb_hash),
         program_states,
         schedule,
         injected_transactions,
@@ -519,6 +558,7 @@ impl<P: ProcessorExt> SubService for ComputeSubService<P> {
                     self.processor.clone(),
                     req,
                     self.promise_emission_mode,
+                    self.message_history,
                     sender,
                 )
                 .boxed(),
@@ -49,6 +49,13 @@ impl<P: ProcessorExt> ComputeService<P> {
             codes_sub_service: CodesSubService::new(db, processor),
         }
     }
+
+    /// Enables or disables indexing of processed messages, see
+    /// [`ethexe_common::db::MessageHistoryStorageRO`].
+    pub fn with_message_history(mut self, enabled: bool) -> Self {
+        self.mb_compute_sub_service = self.mb_compute_sub_service.with_message_history(enabled);
+        self
+    }
 }
 
 #[cfg(test)]
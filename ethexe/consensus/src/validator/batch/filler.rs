@@ -142,6 +142,7 @@ mod tests {
     fn include_chain_commitment_keeps_checkpoint_with_no_transitions() {
         let mut filler = BatchFiller::new(BatchLimits::default());
         let checkpoint = ChainCommitment {
+            program_creations: Vec::new(),
             head: H256::from_low_u64_be(0xC0DE),
             transitions: Vec::new(),
             last_advanced_eth_block: H256::from_low_u64_be(0xEB),
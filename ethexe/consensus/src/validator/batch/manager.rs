@@ -290,6 +290,7 @@ impl BatchCommitmentManager {
             )?;
 
             let mut chain_commitment = ChainCommitment {
+                program_creations: Vec::new(),
                 transitions: Vec::new(),
                 head: head_mb,
                 last_advanced_eth_block: self.db.mb_meta(head_mb).last_advanced_eb,
@@ -299,6 +300,9 @@ impl BatchCommitmentManager {
                 else {
                     anyhow::bail!("Computed MB {mb_hash} outcome not found in db");
                 };
+                chain_commitment
+                    .program_creations
+                    .extend(self.db.mb_program_creations(mb_hash).unwrap_or_default());
                 chain_commitment.transitions.extend(mb_transitions);
             }
             chain_commitment.transitions = super::utils::squash_transitions_by_actor(
@@ -9,7 +9,8 @@ use ethexe_common::{
     SimpleBlockData,
     db::{BlockMetaStorageRO, CodesStorageRO, MbStorageRO, OnChainStorageRO},
     gear::{
-        BatchCommitment, ChainCommitment, CodeCommitment, Message, StateTransition, ValueClaim,
+        BatchCommitment, ChainCommitment, CodeCommitment, Message, ProgramCreation,
+        StateTransition, ValueClaim,
     },
 };
 use gprimitives::{ActorId, H256};
@@ -240,6 +241,7 @@ pub fn try_include_chain_commitment<DB: BlockMetaStorageRO + MbStorageRO>(
     }
 
     // Aggregate transitions incrementally; stop when the next MB blows the size budget.
+    let mut program_creations: Vec<ProgramCreation> = Vec::new();
     let mut transitions: Vec<StateTransition> = Vec::new();
     let mut last_included = last_committed_mb;
     for mb_hash in &pending {
@@ -248,17 +250,22 @@ pub fn try_include_chain_commitment<DB: BlockMetaStorageRO + MbStorageRO>(
         };
 
         // Trial-fit this MB; bail if it pushes us past the batch size budget.
+        let creations_len_before = program_creations.len();
         let len_before = transitions.len();
+        program_creations.extend(db.mb_program_creations(*mb_hash).unwrap_or_default());
         transitions.extend(mb_transitions);
         let trial_commitment = ChainCommitment {
+            program_creations,
             head: *mb_hash,
             transitions,
             last_advanced_eth_block: db.mb_meta(*mb_hash).last_advanced_eb,
         };
         let would_fit = batch_filler.would_fit_chain_commitment(&trial_commitment);
+        program_creations = trial_commitment.program_creations;
         transitions = trial_commitment.transitions;
 
         if !would_fit {
+            let _ = program_creations.split_off(creations_len_before);
             let _ = transitions.split_off(len_before);
             break;
         }
@@ -277,6 +284,7 @@ pub fn try_include_chain_commitment<DB: BlockMetaStorageRO + MbStorageRO>(
     }
 
     let commitment = ChainCommitment {
+        program_creations,
         head: last_included,
         transitions,
         last_advanced_eth_block: db.mb_meta(last_included).last_advanced_eb,
@@ -335,6 +343,7 @@ pub fn try_include_checkpoint_chain_commitment<
     }
 
     let commitment = ChainCommitment {
+        program_creations: Vec::new(),
         head: mb_head,
         transitions: Vec::new(),
         last_advanced_eth_block: advanced,
@@ -720,6 +729,7 @@ mod tests {
 
         let parts = BatchParts {
             chain_commitment: Some(ChainCommitment {
+                program_creations: vec![],
                 transitions: vec![StateTransition {
                     actor_id: gprimitives::ActorId::from([0xAB; 32]),
                     new_state_hash: H256::from_low_u64_be(0xDEAD_BEEF),
@@ -328,6 +328,21 @@ interface IMirror {
     function initialize(address initializer, address abiInterface, bool isSmall, uint128 initialExecutableBalance)
         external;
 
+    /**
+     * @dev Initializes the program created by another program.
+     *      The init message is already sent by the parent program, so the program accepts messages right away.
+     * @param parent The address of the parent program, which is set as the initializer.
+     * @param abiInterface The address of the ABI interface, see `initialize` for details.
+     */
+    function initializeCreatedProgram(address parent, address abiInterface) external;
+
+    /**
+     * @dev Creates new program (`Mirror`) on behalf of this program via `Create3`.
+     * @param salt The salt for the program creation, which is the ID of the init message.
+     * @return mirror The address of the created program (`Mirror`).
+     */
+    function createProgram(bytes32 salt) external returns (address mirror);
+
     /**
      * @dev Performs state transition for the `Mirror` contract.
      * @param transition The state transition data.
@@ -295,6 +295,8 @@ interface IRouter {
 
     error UnknownProgram();
 
+    error ProgramCreationAddressMismatch();
+
     error CodeValidationNotRequested();
 
     error TooManyRewardsCommitments();
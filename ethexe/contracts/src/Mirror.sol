@@ -10,6 +10,8 @@ import {ICallbacks} from "src/ICallbacks.sol";
 import {IMirror} from "src/IMirror.sol";
 import {IRouter} from "src/IRouter.sol";
 import {IWrappedVara} from "src/IWrappedVara.sol";
+import {ClonesSmall} from "src/libraries/ClonesSmall.sol";
+import {Create3} from "src/libraries/Create3.sol";
 import {Gear} from "src/libraries/Gear.sol";
 
 /**
@@ -366,22 +368,29 @@ contract Mirror is IMirror {
         external
         onlyRouter
     {
-        require(initializer == address(0), InitializerAlreadySet());
-
-        require(!isSmall, IsSmallAlreadySet());
-
-        StorageSlot.AddressSlot storage implementationSlot =
-            StorageSlot.getAddressSlot(ERC1967Utils.IMPLEMENTATION_SLOT);
-
-        require(implementationSlot.value == address(0), AbiInterfaceAlreadySet());
+        _initialize(_initializer, _abiInterface, _isSmall, _initialExecutableBalance);
+    }
 
-        initializer = _initializer;
-        isSmall = _isSmall;
-        implementationSlot.value = _abiInterface;
+    /**
+     * @dev Initializes the program created by another program.
+     *      The init message is already sent by the parent program on Vara.eth side,
+     *      so the `nonce` is bumped to allow sending messages to the program right away.
+     * @param _parent The address of the parent program, which is set as the initializer.
+     * @param _abiInterface The address of the ABI interface, see `initialize` for details.
+     */
+    function initializeCreatedProgram(address _parent, address _abiInterface) external onlyRouter {
+        _initialize(_parent, _abiInterface, true, 0);
+        nonce++;
+    }
 
-        if (_initialExecutableBalance != 0) {
-            emit ExecutableBalanceTopUpRequested(_initialExecutableBalance);
-        }
+    /**
+     * @dev Creates new program (`Mirror`) on behalf of this program, which is the parent.
+     *      The program is deployed via `Create3`, so its address depends only on this program address and salt.
+     * @param _salt The salt for the program creation, which is the ID of the init message.
+     * @return mirror The address of the created program (`Mirror`).
+     */
+    function createProgram(bytes32 _salt) external onlyRouter returns (address) {
+        return Create3.deploy(_salt, ClonesSmall.initCode(router));
     }
 
     /**
@@ -448,6 +457,32 @@ contract Mirror is IMirror {
         );
     }
 
+    /* # Private calls, related to initialization */
+
+    /**
+     * @dev Internal implementation of `initialize` function.
+     */
+    function _initialize(address _initializer, address _abiInterface, bool _isSmall, uint128 _initialExecutableBalance)
+        private
+    {
+        require(initializer == address(0), InitializerAlreadySet());
+
+        require(!isSmall, IsSmallAlreadySet());
+
+        StorageSlot.AddressSlot storage implementationSlot =
+            StorageSlot.getAddressSlot(ERC1967Utils.IMPLEMENTATION_SLOT);
+
+        require(implementationSlot.value == address(0), AbiInterfaceAlreadySet());
+
+        initializer = _initializer;
+        isSmall = _isSmall;
+        implementationSlot.value = _abiInterface;
+
+        if (_initialExecutableBalance != 0) {
+            emit ExecutableBalanceTopUpRequested(_initialExecutableBalance);
+        }
+    }
+
     /* # Private calls, related to primary Gear logic */
 
     /**
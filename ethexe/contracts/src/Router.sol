@@ -965,6 +965,7 @@ contract Router is
 
         Gear.ChainCommitment calldata _commitment = _batch.chainCommitment[0];
 
+        bytes32 _programCreationsHash = _commitProgramCreations(router, _commitment.programCreations);
         bytes32 _transitionsHash = _commitTransitions(router, _commitment.transitions);
 
         emit MBCommitted(_commitment.head);
@@ -972,7 +973,9 @@ contract Router is
             emit EBCommitted(_commitment.lastAdvancedEthBlock);
         }
 
-        return Gear.chainCommitmentHash(_transitionsHash, _commitment.head, _commitment.lastAdvancedEthBlock);
+        return Gear.chainCommitmentHash(
+            _programCreationsHash, _transitionsHash, _commitment.head, _commitment.lastAdvancedEthBlock
+        );
     }
 
     function _commitCodes(Storage storage router, Gear.BatchCommitment calldata _batch) private returns (bytes32) {
@@ -1090,6 +1093,44 @@ contract Router is
         return Gear.validatorsCommitmentHash(_commitment);
     }
 
+    function _commitProgramCreations(Storage storage router, Gear.ProgramCreation[] calldata _programCreations)
+        private
+        returns (bytes32)
+    {
+        uint256 programCreationsLen = _programCreations.length;
+        uint256 programCreationsHashSize = programCreationsLen * 32;
+        uint256 programCreationsHashesMemPtr = Memory.allocate(programCreationsHashSize);
+        uint256 offset = 0;
+
+        for (uint256 i = 0; i < programCreationsLen; i++) {
+            Gear.ProgramCreation calldata _creation = _programCreations[i];
+
+            require(router.protocolData.programs[_creation.parent] != 0, UnknownProgram());
+            require(router.protocolData.codes[_creation.codeId] == Gear.CodeState.Validated, CodeNotValidated());
+
+            // Check for duplicate isn't necessary, because `Create3.deploy`
+            // reverts execution in case of address is already taken.
+            address actorId = IMirror(_creation.parent).createProgram(_creation.salt);
+            require(actorId == _creation.actorId, ProgramCreationAddressMismatch());
+
+            router.protocolData.programs[actorId] = _creation.codeId;
+            router.protocolData.programsCount++;
+
+            emit ProgramCreated(actorId, _creation.codeId);
+
+            IMirror(actorId).initializeCreatedProgram(_creation.parent, mirrorImpl());
+
+            bytes32 programCreationHash =
+                Gear.programCreationHash(_creation.actorId, _creation.parent, _creation.codeId, _creation.salt);
+            Memory.writeWordAsBytes32(programCreationsHashesMemPtr, offset, programCreationHash);
+            unchecked {
+                offset += 32;
+            }
+        }
+
+        return Hashes.efficientKeccak256AsBytes32(programCreationsHashesMemPtr, 0, programCreationsHashSize);
+    }
+
     function _commitTransitions(Storage storage router, Gear.StateTransition[] calldata _transitions)
         private
         returns (bytes32)
@@ -34,6 +34,31 @@ library ClonesSmall {
         uint256 size = 0x5a;
         uint256 memPtr = Memory.allocate(size);
 
+        _writeInitCode(memPtr, router);
+
+        assembly ("memory-safe") {
+            instance := create2(value, memPtr, size, salt)
+            if iszero(instance) { revert(0x00, 0x00) }
+        }
+    }
+
+    /**
+     * @dev Returns the init code of clone that has the `MirrorProxySmall` behavior.
+     *      Used to deploy clones with other deployment schemes, e.g. `Create3`.
+     */
+    function initCode(address router) internal pure returns (bytes memory code) {
+        uint256 size = 0x5a;
+        code = new bytes(size);
+
+        uint256 memPtr;
+        assembly ("memory-safe") {
+            memPtr := add(code, 0x20)
+        }
+
+        _writeInitCode(memPtr, router);
+    }
+
+    function _writeInitCode(uint256 memPtr, address router) private pure {
         /**
          * @dev This bytecode is taken from `cat out/MirrorProxySmall.sol/MirrorProxySmall.json | jq -r ".deployedBytecode.object"`
          *      The bytecode "0x3d605080600a3d3981f3" is responsible for deploy and is modified version of ERC1167 from OpenZeppelin:
@@ -46,10 +71,5 @@ library ClonesSmall {
             (0x00000000000000000000000000000000000000005afa15604c575f8080513682) | (uint256(uint160(router)) << 0x60)
         );
         Memory.writeWord(memPtr, 0x40, 0x80378136915af43d5f803e156048573d5ff35b3d5ffd5b5f80fd000000000000);
-
-        assembly ("memory-safe") {
-            instance := create2(value, memPtr, size, salt)
-            if iszero(instance) { revert(0x00, 0x00) }
-        }
     }
 }
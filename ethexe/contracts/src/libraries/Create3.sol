@@ -0,0 +1,78 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+pragma solidity ^0.8.35;
+
+/**
+ * @dev `CREATE3` deployment, where the address of deployed contract depends only on
+ *      the deployer address and salt, but not on the init code.
+ *
+ *      First, the minimal proxy is deployed via `create2` with the given salt, and then
+ *      the proxy deploys the actual contract via `create` with its nonce equal to `1`.
+ *
+ *      It's used by `Mirror` to deploy programs created by programs, so that their
+ *      addresses can be computed by `ethexe` nodes without knowing the `Router` address,
+ *      see `ethexe_common::gear::program_creation_actor_id`.
+ * @dev https://github.com/Vectorized/solady/blob/main/src/utils/CREATE3.sol
+ */
+library Create3 {
+    /**
+     * @dev Init code of the proxy:
+     *      `0x67363d3d37363d34f03d5260086018f3`
+     *
+     *      Deploys runtime code `0x363d3d37363d34f0`, which copies calldata
+     *      into memory and deploys it via `create` with `callvalue`.
+     */
+    uint256 internal constant PROXY_INITCODE = 0x67363d3d37363d34f03d5260086018f3;
+
+    /**
+     * @dev Thrown when the proxy deployment fails.
+     */
+    error ProxyDeploymentFailed();
+
+    /**
+     * @dev Thrown when the deployment via proxy fails.
+     */
+    error DeploymentFailed();
+
+    /**
+     * @dev Deploys `initCode` deterministically with `salt` and returns the deployed address.
+     */
+    function deploy(bytes32 salt, bytes memory initCode) internal returns (address instance) {
+        address proxy;
+
+        assembly ("memory-safe") {
+            mstore(0x00, PROXY_INITCODE)
+            proxy := create2(0, 0x10, 0x10, salt)
+        }
+
+        require(proxy != address(0), ProxyDeploymentFailed());
+
+        instance = predictDeterministicAddress(salt, address(this));
+
+        (bool success,) = proxy.call(initCode);
+        require(success && instance.code.length != 0, DeploymentFailed());
+    }
+
+    /**
+     * @dev Returns the address of contract deployed by `deployer` with `salt`.
+     */
+    function predictDeterministicAddress(bytes32 salt, address deployer) internal pure returns (address instance) {
+        assembly ("memory-safe") {
+            let m := mload(0x40)
+            mstore(0x00, PROXY_INITCODE)
+            let proxyInitCodeHash := keccak256(0x10, 0x10)
+
+            mstore8(m, 0xff)
+            mstore(add(m, 0x01), shl(96, deployer))
+            mstore(add(m, 0x15), salt)
+            mstore(add(m, 0x35), proxyInitCodeHash)
+            let proxy := keccak256(m, 0x55)
+
+            // RLP encoding of `[proxy, 1]`: `0xd6 ++ 0x94 ++ proxy ++ 0x01`.
+            mstore(0x14, proxy)
+            mstore(0x00, 0xd694)
+            mstore8(0x34, 0x01)
+            instance := and(keccak256(0x1e, 0x17), 0xffffffffffffffffffffffffffffffffffffffff)
+        }
+    }
+}
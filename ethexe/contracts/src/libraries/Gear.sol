@@ -180,10 +180,36 @@ library Gear {
         bool valid;
     }
 
+    /**
+     * @dev Represents program created by another program.
+     */
+    struct ProgramCreation {
+        /**
+         * @dev Address of the created program (`Mirror`), which is deployed by the parent via `Create3`.
+         */
+        address actorId;
+        /**
+         * @dev Address of the parent program (`Mirror`).
+         */
+        address parent;
+        /**
+         * @dev Code ID of the created program. Must be in `CodeState.Validated` state.
+         */
+        bytes32 codeId;
+        /**
+         * @dev Salt of the `Create3` deployment, which is the ID of the init message.
+         */
+        bytes32 salt;
+    }
+
     /**
      * @dev Represents chain commitment.
      */
     struct ChainCommitment {
+        /**
+         * @dev Programs created by programs, deployed before transitions are performed.
+         */
+        ProgramCreation[] programCreations;
         /**
          * @dev Transitions of program states, value and messages.
          */
@@ -554,16 +580,33 @@ library Gear {
 
     /**
      * @dev Computes the hash of `ChainCommitment`.
+     * @param _programCreationsHash The hash of the program creations in the chain commitment.
      * @param _transitionsHash The hash of the transitions in the chain commitment.
      * @param _head The head of the chain commitment.
      * @param _lastAdvancedEthBlock The latest folded-in Ethereum block hash.
      */
-    function chainCommitmentHash(bytes32 _transitionsHash, bytes32 _head, bytes32 _lastAdvancedEthBlock)
+    function chainCommitmentHash(
+        bytes32 _programCreationsHash,
+        bytes32 _transitionsHash,
+        bytes32 _head,
+        bytes32 _lastAdvancedEthBlock
+    ) internal pure returns (bytes32) {
+        return keccak256(abi.encodePacked(_programCreationsHash, _transitionsHash, _head, _lastAdvancedEthBlock));
+    }
+
+    /**
+     * @dev Computes the hash of `ProgramCreation`.
+     * @param _actorId The address of the created program.
+     * @param _parent The address of the parent program.
+     * @param _codeId The code ID of the created program.
+     * @param _salt The salt of the program creation.
+     */
+    function programCreationHash(address _actorId, address _parent, bytes32 _codeId, bytes32 _salt)
         internal
         pure
         returns (bytes32)
     {
-        return keccak256(abi.encodePacked(_transitionsHash, _head, _lastAdvancedEthBlock));
+        return keccak256(abi.encodePacked(_actorId, _parent, _codeId, _salt));
     }
 
     /**
@@ -185,12 +185,20 @@ contract Base is POCBaseTest {
     }
 
     function commitBlock(uint256[] memory _privateKeys, Gear.StateTransition[] memory _transactions) internal {
+        commitBlock(_privateKeys, new Gear.ProgramCreation[](0), _transactions);
+    }
+
+    function commitBlock(
+        uint256[] memory _privateKeys,
+        Gear.ProgramCreation[] memory _programCreations,
+        Gear.StateTransition[] memory _transactions
+    ) internal {
         bytes32 _blockHash = blockHash(vm.getBlockNumber());
         uint48 _timestamp = uint48(vm.getBlockTimestamp());
 
         rollBlocks(1);
 
-        commitBlock(_privateKeys, _transactions, _blockHash, _timestamp, false);
+        commitBlock(_privateKeys, _programCreations, _transactions, _blockHash, _timestamp, false);
     }
 
     function commitBlock(
@@ -199,9 +207,25 @@ contract Base is POCBaseTest {
         bytes32 _blockHash,
         uint48 _timestamp,
         bool revertExpected
+    ) internal {
+        commitBlock(
+            _privateKeys, new Gear.ProgramCreation[](0), _transactions, _blockHash, _timestamp, revertExpected
+        );
+    }
+
+    function commitBlock(
+        uint256[] memory _privateKeys,
+        Gear.ProgramCreation[] memory _programCreations,
+        Gear.StateTransition[] memory _transactions,
+        bytes32 _blockHash,
+        uint48 _timestamp,
+        bool revertExpected
     ) internal {
         Gear.ChainCommitment memory _chainCommitment = Gear.ChainCommitment({
-            transitions: _transactions, head: keccak256("head"), lastAdvancedEthBlock: bytes32(0)
+            programCreations: _programCreations,
+            transitions: _transactions,
+            head: keccak256("head"),
+            lastAdvancedEthBlock: bytes32(0)
         });
 
         Gear.ChainCommitment[] memory _chainCommitments = new Gear.ChainCommitment[](1);
@@ -304,6 +328,13 @@ contract Base is POCBaseTest {
     }
 
     function chainCommitmentHash(Gear.ChainCommitment memory _commitment) internal pure returns (bytes32) {
+        bytes32[] memory _programCreationsHashes = new bytes32[](_commitment.programCreations.length);
+        for (uint256 i = 0; i < _commitment.programCreations.length; i++) {
+            Gear.ProgramCreation memory _creation = _commitment.programCreations[i];
+            _programCreationsHashes[i] =
+                Gear.programCreationHash(_creation.actorId, _creation.parent, _creation.codeId, _creation.salt);
+        }
+
         bytes32[] memory _transitionsHashes = new bytes32[](_commitment.transitions.length);
         for (uint256 i = 0; i < _commitment.transitions.length; i++) {
             Gear.StateTransition memory _transition = _commitment.transitions[i];
@@ -334,7 +365,10 @@ contract Base is POCBaseTest {
         }
 
         return Gear.chainCommitmentHash(
-            keccak256(abi.encodePacked(_transitionsHashes)), _commitment.head, _commitment.lastAdvancedEthBlock
+            keccak256(abi.encodePacked(_programCreationsHashes)),
+            keccak256(abi.encodePacked(_transitionsHashes)),
+            _commitment.head,
+            _commitment.lastAdvancedEthBlock
         );
     }
 
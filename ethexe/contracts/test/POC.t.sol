@@ -8,6 +8,7 @@ import {Vm} from "forge-std/Vm.sol";
 import {FROSTOffchain, SigningKey} from "frost-secp256k1-evm/FROSTOffchain.sol";
 import {IRouter} from "src/IRouter.sol";
 import {IMirror} from "src/Mirror.sol";
+import {Create3} from "src/libraries/Create3.sol";
 import {Gear} from "src/libraries/Gear.sol";
 import {Base} from "test/Base.t.sol";
 
@@ -115,6 +116,13 @@ contract POCTest is Base {
         assertEq(actor.stateHash(), bytes32(uint256(2)));
         assertEq(actor.nonce(), uint256(2));
 
+        address _child = createChild(_privateKeys, _ping, _codeId);
+        IMirror child = IMirror(_child);
+        assertEq(router.programCodeId(_child), _codeId);
+        assertEq(child.initializer(), _ping);
+        assertEq(child.nonce(), uint256(1));
+        assertEq(child.stateHash(), bytes32(uint256(3)));
+
         // Check that going to next era without re-election is ok and old validators are still valid.
         rollBlocks(eraDuration / blockDuration);
         doPingPong(_privateKeys, _ping);
@@ -250,6 +258,33 @@ contract POCTest is Base {
         commitBlock(_privateKeys, _transitions);
     }
 
+    function createChild(uint256[] memory _privateKeys, address _parent, bytes32 _codeId)
+        private
+        returns (address _child)
+    {
+        bytes32 _salt = keccak256("init message id");
+        _child = Create3.predictDeterministicAddress(_salt, _parent);
+
+        Gear.ProgramCreation[] memory _programCreations = new Gear.ProgramCreation[](1);
+        _programCreations[0] = Gear.ProgramCreation(_child, _parent, _codeId, _salt);
+
+        Gear.StateTransition[] memory _transitions = new Gear.StateTransition[](1);
+        _transitions[0] = Gear.StateTransition(
+            _child, // actor id
+            bytes32(uint256(3)), // new state hash
+            false, // exited
+            address(0), // inheritor
+            uint128(0), // value to receive
+            false, // value to receive negative sign
+            new Gear.ValueClaim[](0), // value claims
+            new Gear.Message[](0) // messages
+        );
+
+        vm.expectEmit(true, true, false, true);
+        emit IRouter.ProgramCreated(_child, _codeId);
+        commitBlock(_privateKeys, _programCreations, _transitions);
+    }
+
     function doPingPong(uint256[] memory _privateKeys, address _ping) internal {
         vm.startPrank(admin, admin);
         {
@@ -4,7 +4,7 @@
 //! Database for ethexe.
 
 use crate::{
-    CASDatabase, KVDatabase, VERSION,
+    CASDatabase, KVDatabase, VERSION, migrations,
     overlay::{CASOverlay, KVOverlay},
 };
 use anyhow::{Context, Result};
@@ -15,16 +15,17 @@ use ethexe_common::{
         BlockMeta, BlockMetaStorageRO, BlockMetaStorageRW, CodesStorageRO, CodesStorageRW,
         CompactMb, ConfigStorageRO, DBConfig, DBGlobals, GlobalsStorageRO, GlobalsStorageRW,
         HashStorageRO, InjectedStorageRO, InjectedStorageRW, MbMeta, MbStorageRO, MbStorageRW,
-        OnChainStorageRO, OnChainStorageRW,
+        MessageHistoryStorageRO, MessageHistoryStorageRW, MessageRecord, OnChainStorageRO,
+        OnChainStorageRW, ProcessedMessage,
     },
     events::BlockEvent,
-    gear::StateTransition,
+    gear::{ProgramCreation, StateTransition},
     injected::{InjectedTransaction, Promise, SignedInjectedTransaction, SignedTxReceipt},
     malachite::Operations,
 };
 use ethexe_runtime_common::state::{
-    Allocations, DispatchStash, Mailbox, MemoryPages, MemoryPagesRegion, MessageQueue,
-    ProgramState, Storage, UserMailbox, Waitlist,
+    Allocations, DispatchStash, GasReservations, Mailbox, MemoryPages, MemoryPagesRegion,
+    MessageQueue, ProgramState, Storage, UserMailbox, Waitlist,
 };
 use gear_core::{
     buffer::Payload,
@@ -36,7 +37,7 @@ use gprimitives::{H256, MessageId};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeSet, HashMap, HashSet},
     mem::size_of,
     sync::{Arc, RwLock, RwLockReadGuard},
 };
@@ -70,6 +71,11 @@ enum Key {
 
     // Key discriminant 28 (MbLocalOutcome) is retired — do not reuse.
     MbCommittedMessageIds(H256) = 29,
+    MbProgramCreations(H256) = 30,
+
+    ProcessedMessage(MessageId) = 31,
+    ProgramMessagesLen(ActorId) = 32,
+    ProgramMessage(ActorId, u64) = 33,
 }
 
 impl Key {
@@ -83,7 +89,7 @@ impl Key {
 
     fn to_bytes(&self) -> Vec<u8> {
         // Pre-allocate enough space for the largest possible key.
-        let mut bytes = Vec::with_capacity(2 * size_of::<H256>() + size_of::<u32>());
+        let mut bytes = Vec::with_capacity(2 * size_of::<H256>() + size_of::<u64>());
         bytes.extend(self.prefix());
 
         match self {
@@ -96,6 +102,7 @@ impl Key {
             Self::MbProgramStates(hash)
             | Self::MbOutcome(hash)
             | Self::MbCommittedMessageIds(hash)
+            | Self::MbProgramCreations(hash)
             | Self::MbSchedule(hash)
             | Self::MbMeta(hash)
             | Self::MbCompactBlock(hash) => bytes.extend(hash.as_ref()),
@@ -104,7 +111,17 @@ impl Key {
                 bytes.extend(hash.as_ref())
             }
 
-            Self::ProgramToCodeId(program_id) => bytes.extend(program_id.as_ref()),
+            Self::ProgramToCodeId(program_id) | Self::ProgramMessagesLen(program_id) => {
+                bytes.extend(program_id.as_ref())
+            }
+
+            Self::ProcessedMessage(message_id) => bytes.extend(message_id.as_ref()),
+
+            Self::ProgramMessage(program_id, index) => {
+                bytes.extend(program_id.as_ref());
+                // Big-endian, so messages of the program are ordered by index.
+                bytes.extend(index.to_be_bytes());
+            }
 
             Self::CodeMetadata(code_id)
             | Self::CodeUploadInfo(code_id)
@@ -125,7 +142,7 @@ impl Key {
             "Key must be longer than H256, to avoid collision with CAS keys"
         );
         debug_assert!(
-            bytes.len() <= 2 * size_of::<H256>() + size_of::<u32>(),
+            bytes.len() <= 2 * size_of::<H256>() + size_of::<u64>(),
             "Key must not be longer than maximum possible length"
         );
 
@@ -201,7 +218,7 @@ impl Storage for dyn CASDatabase + '_ {
 
         let data = self.read(hash)?;
 
-        let state = ProgramState::decode(&mut &data[..])
+        let state = migrations::decode_program_state(&data)
             .expect("Failed to decode data into `ProgramState`");
 
         Some(state)
@@ -301,6 +318,17 @@ impl Storage for dyn CASDatabase + '_ {
         unsafe { HashOf::new(self.write(&allocations.encode())) }
     }
 
+    fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations> {
+        self.read(hash.inner()).map(|data| {
+            GasReservations::decode(&mut &data[..])
+                .expect("Failed to decode data into `GasReservations`")
+        })
+    }
+
+    fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations> {
+        unsafe { HashOf::new(self.write(&reservations.encode())) }
+    }
+
     fn payload(&self, hash: HashOf<Payload>) -> Option<Payload> {
         self.read(hash.inner())
             .map(|data| Payload::try_from(data).expect("Failed to decode data into `Payload`"))
@@ -409,6 +437,15 @@ impl MbStorageRO for RawDatabase {
             })
     }
 
+    fn mb_program_creations(&self, mb_hash: H256) -> Option<Vec<ProgramCreation>> {
+        self.kv
+            .get(&Key::MbProgramCreations(mb_hash).to_bytes())
+            .map(|data| {
+                Vec::<ProgramCreation>::decode(&mut data.as_slice())
+                    .expect("Failed to decode data into `Vec<ProgramCreation>`")
+            })
+    }
+
     fn mb_schedule(&self, mb_hash: H256) -> Option<Schedule> {
         self.kv
             .get(&Key::MbSchedule(mb_hash).to_bytes())
@@ -461,6 +498,14 @@ impl MbStorageRW for RawDatabase {
         );
     }
 
+    fn set_mb_program_creations(&self, mb_hash: H256, creations: Vec<ProgramCreation>) {
+        tracing::trace!(mb_hash = %mb_hash, "Set MB program creations");
+        self.kv.put(
+            &Key::MbProgramCreations(mb_hash).to_bytes(),
+            creations.encode(),
+        );
+    }
+
     fn set_mb_schedule(&self, mb_hash: H256, schedule: Schedule) {
         tracing::trace!(mb_hash = %mb_hash, "Set MB schedule");
         self.kv
@@ -733,6 +778,85 @@ impl InjectedStorageRW for RawDatabase {
     }
 }
 
+impl RawDatabase {
+    fn program_messages_len(&self, program_id: ActorId) -> u64 {
+        self.kv
+            .get(&Key::ProgramMessagesLen(program_id).to_bytes())
+            .map(|data| {
+                u64::decode(&mut data.as_slice()).expect("Failed to decode data into `u64`")
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl MessageHistoryStorageRO for RawDatabase {
+    fn processed_message(&self, message_id: MessageId) -> Option<ProcessedMessage> {
+        self.kv
+            .get(&Key::ProcessedMessage(message_id).to_bytes())
+            .map(|data| {
+                ProcessedMessage::decode(&mut data.as_slice())
+                    .expect("Failed to decode data into `ProcessedMessage`")
+            })
+    }
+
+    fn program_messages(&self, program_id: ActorId, offset: u64, limit: u64) -> Vec<MessageId> {
+        let end = self.program_messages_len(program_id).saturating_sub(offset);
+        let start = end.saturating_sub(limit);
+
+        (start..end)
+            .rev()
+            .filter_map(|index| {
+                self.kv
+                    .get(&Key::ProgramMessage(program_id, index).to_bytes())
+                    .map(|data| {
+                        MessageId::decode(&mut data.as_slice())
+                            .expect("Failed to decode data into `MessageId`")
+                    })
+            })
+            .collect()
+    }
+}
+
+impl MessageHistoryStorageRW for RawDatabase {
+    fn add_processed_messages(&self, mb_hash: H256, records: Vec<MessageRecord>) {
+        tracing::trace!(mb_hash = %mb_hash, amount = records.len(), "Add processed messages");
+
+        // All records of the MB are written in one batch, so that program
+        // message indexes are never left partially updated.
+        let mut batch = Vec::new();
+        let mut indexed = HashSet::new();
+        let mut lens = HashMap::new();
+
+        for record in records {
+            let message_id = record.message_id;
+            let program_id = record.program_id;
+            let key = Key::ProcessedMessage(message_id).to_bytes();
+
+            // Waited messages are processed more than once: keep the latest
+            // processing, but list the message for the program only once.
+            let is_new = !self.kv.contains(&key) && indexed.insert(message_id);
+            batch.push((key, ProcessedMessage { mb_hash, record }.encode()));
+
+            if is_new {
+                let len = lens
+                    .entry(program_id)
+                    .or_insert_with(|| self.program_messages_len(program_id));
+                batch.push((
+                    Key::ProgramMessage(program_id, *len).to_bytes(),
+                    message_id.encode(),
+                ));
+                *len += 1;
+            }
+        }
+
+        batch.extend(lens.into_iter().map(|(program_id, len)| {
+            (Key::ProgramMessagesLen(program_id).to_bytes(), len.encode())
+        }));
+
+        self.kv.put_batch(batch);
+    }
+}
+
 #[derive(derive_more::Debug, Clone)]
 #[debug("Database(CAS + KV)")]
 pub struct Database {
@@ -813,6 +937,15 @@ impl Database {
         Self::try_from_raw(RawDatabase::from_one(&mem_db)).unwrap()
     }
 
+    /// Database reading the given CAS, with in-memory KV part initialized by default values.
+    ///
+    /// Intended for walking CAS blobs before the database is initialized.
+    pub(crate) fn with_memory_kv(cas: &dyn CASDatabase) -> Self {
+        let mut db = Self::memory_inner();
+        db.raw.cas = cas.clone_boxed();
+        db
+    }
+
     /// # Safety
     /// Not ready for using in prod. Intended to be for rpc calls only.
     pub unsafe fn overlaid(self) -> Self {
@@ -947,6 +1080,8 @@ impl Storage for Database {
             fn write_memory_pages_region(&self, pages_region: MemoryPagesRegion) -> HashOf<MemoryPagesRegion>;
             fn allocations(&self, hash: HashOf<Allocations>) -> Option<Allocations>;
             fn write_allocations(&self, allocations: Allocations) -> HashOf<Allocations>;
+            fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations>;
+            fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations>;
             fn payload(&self, hash: HashOf<Payload>) -> Option<Payload>;
             fn write_payload(&self, payload: Payload) -> HashOf<Payload>;
             fn page_data(&self, hash: HashOf<PageBuf>) -> Option<PageBuf>;
@@ -994,6 +1129,7 @@ impl MbStorageRO for Database {
         fn mb_program_states(&self, mb_hash: H256) -> Option<ProgramStates>;
         fn mb_outcome(&self, mb_hash: H256) -> Option<Vec<StateTransition>>;
         fn mb_committed_message_ids(&self, mb_hash: H256) -> Option<BTreeSet<MessageId>>;
+        fn mb_program_creations(&self, mb_hash: H256) -> Option<Vec<ProgramCreation>>;
         fn mb_schedule(&self, mb_hash: H256) -> Option<Schedule>;
         fn mb_meta(&self, mb_hash: H256) -> MbMeta;
     });
@@ -1006,11 +1142,25 @@ impl MbStorageRW for Database {
         fn set_mb_program_states(&self, mb_hash: H256, program_states: ProgramStates);
         fn set_mb_outcome(&self, mb_hash: H256, outcome: Vec<StateTransition>);
         fn set_mb_committed_message_ids(&self, mb_hash: H256, ids: BTreeSet<MessageId>);
+        fn set_mb_program_creations(&self, mb_hash: H256, creations: Vec<ProgramCreation>);
         fn set_mb_schedule(&self, mb_hash: H256, schedule: Schedule);
         fn mutate_mb_meta(&self, mb_hash: H256, f: impl FnOnce(&mut MbMeta));
     });
 }
 
+impl MessageHistoryStorageRO for Database {
+    delegate!(to self.raw {
+        fn processed_message(&self, message_id: MessageId) -> Option<ProcessedMessage>;
+        fn program_messages(&self, program_id: ActorId, offset: u64, limit: u64) -> Vec<MessageId>;
+    });
+}
+
+impl MessageHistoryStorageRW for Database {
+    delegate!(to self.raw {
+        fn add_processed_messages(&self, mb_hash: H256, records: Vec<MessageRecord>);
+    });
+}
+
 impl InjectedStorageRW for Database {
     delegate!(to self.raw {
         fn set_injected_transaction(&self, tx: SignedInjectedTransaction);
@@ -1419,6 +1569,15 @@ mod tests {
         assert_eq!(db.allocations(hash), Some(allocations));
     }
 
+    #[test]
+    fn test_gas_reservations() {
+        let db = Database::memory();
+
+        let reservations = GasReservations::default();
+        let hash = db.write_gas_reservations(reservations.clone());
+        assert_eq!(db.gas_reservations(hash), Some(reservations));
+    }
+
     #[test]
     fn test_payload() {
         let db = Database::memory();
@@ -1437,4 +1596,143 @@ mod tests {
         let hash = db.write_page_data(page_data.clone());
         assert_eq!(db.page_data(hash), Some(page_data));
     }
+
+    #[test]
+    fn test_message_history() {
+        use ethexe_common::db::MessageOutcome;
+
+        let db = Database::memory();
+
+        let program_id = ActorId::from(42);
+        let record = |message_id: u64| MessageRecord {
+            message_id: MessageId::from(message_id),
+            program_id,
+            source: ActorId::from(1),
+            value: 0,
+            outcome: MessageOutcome::Success,
+            gas_burned: 100,
+            value_sent: 0,
+            waited: false,
+            reply: None,
+        };
+
+        assert_eq!(db.processed_message(MessageId::from(1)), None);
+        assert!(db.program_messages(program_id, 0, 10).is_empty());
+
+        let mb1 = H256::random();
+        db.add_processed_messages(mb1, vec![record(1), record(2)]);
+        let mb2 = H256::random();
+        db.add_processed_messages(mb2, vec![record(3), record(1)]);
+
+        // Reprocessed message is listed once, with its latest processing.
+        assert_eq!(
+            db.processed_message(MessageId::from(1)),
+            Some(ProcessedMessage {
+                mb_hash: mb2,
+                record: record(1),
+            })
+        );
+        assert_eq!(
+            db.program_messages(program_id, 0, 10),
+            [3, 2, 1].map(MessageId::from).to_vec()
+        );
+        assert_eq!(
+            db.program_messages(program_id, 1, 1),
+            vec![MessageId::from(2)]
+        );
+        assert!(db.program_messages(program_id, 3, 10).is_empty());
+        assert!(db.program_messages(ActorId::from(43), 0, 10).is_empty());
+    }
+
+    #[test]
+    fn processed_messages_are_written_in_one_batch() {
+        use crate::MemDb;
+        use ethexe_common::db::MessageOutcome;
+        use std::sync::Mutex;
+
+        /// Key-value database, which only accepts batched writes.
+        #[derive(Clone, Default)]
+        struct BatchOnlyDb {
+            mem: MemDb,
+            batches: Arc<Mutex<Vec<usize>>>,
+        }
+
+        impl KVDatabase for BatchOnlyDb {
+            fn clone_boxed(&self) -> Box<dyn KVDatabase> {
+                Box::new(self.clone())
+            }
+
+            fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+                self.mem.get(key)
+            }
+
+            unsafe fn take(&self, _key: &[u8]) -> Option<Vec<u8>> {
+                unreachable!("unexpected take")
+            }
+
+            unsafe fn delete(&self, _key: &[u8]) {
+                unreachable!("unexpected delete")
+            }
+
+            fn contains(&self, key: &[u8]) -> bool {
+                KVDatabase::contains(&self.mem, key)
+            }
+
+            fn put(&self, _key: &[u8], _data: Vec<u8>) {
+                unreachable!("unexpected unbatched write")
+            }
+
+            fn put_batch(&self, batch: Vec<(Vec<u8>, Vec<u8>)>) {
+                self.batches.lock().unwrap().push(batch.len());
+                self.mem.put_batch(batch);
+            }
+
+            fn iter_prefix<'a>(
+                &'a self,
+                prefix: &'a [u8],
+            ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a> {
+                self.mem.iter_prefix(prefix)
+            }
+
+            fn is_empty(&self) -> bool {
+                KVDatabase::is_empty(&self.mem)
+            }
+        }
+
+        let kv = BatchOnlyDb::default();
+        let db = RawDatabase::from_refs(&MemDb::default(), &kv);
+
+        let record = |message_id: u64, program_id: u64| MessageRecord {
+            message_id: MessageId::from(message_id),
+            program_id: ActorId::from(program_id),
+            source: ActorId::from(1),
+            value: 0,
+            outcome: MessageOutcome::Success,
+            gas_burned: 100,
+            value_sent: 0,
+            waited: false,
+            reply: None,
+        };
+
+        let mb_hash = H256::random();
+        db.add_processed_messages(
+            mb_hash,
+            vec![record(1, 42), record(2, 43), record(3, 42), record(1, 42)],
+        );
+
+        // 3 processed messages, 3 program messages and 2 program lengths.
+        assert_eq!(*kv.batches.lock().unwrap(), vec![9]);
+        assert_eq!(
+            db.program_messages(ActorId::from(42), 0, 10),
+            [3, 1].map(MessageId::from).to_vec()
+        );
+        assert_eq!(
+            db.program_messages(ActorId::from(43), 0, 10),
+            vec![MessageId::from(2)]
+        );
+        assert_eq!(
+            db.processed_message(MessageId::from(1)).map(|m| m.mb_hash),
+            Some(mb_hash)
+        );
+    }
 }
@@ -3,8 +3,8 @@
 
 //! State dump collection from database.
 
-use super::StateDump;
-use anyhow::{Context, Result};
+use super::{StateDump, StateDumpHeader, StateDumpWriter};
+use anyhow::{Context, Result, ensure};
 use ethexe_common::{
     HashOf, MaybeHashOf, StateHashWithQueueSize,
     db::{BlockMetaStorageRO, CodesStorageRO, HashStorageRO, MbStorageRO},
@@ -14,17 +14,38 @@ use ethexe_runtime_common::state::{
     MemoryPagesRegionInner, MessageQueue, PayloadLookup, Program, ProgramState, UserMailbox,
     Waitlist,
 };
-use gprimitives::{CodeId, H256};
+use gprimitives::{ActorId, CodeId, H256};
 use parity_scale_codec::Decode;
 use std::{
     any::TypeId,
     collections::{BTreeMap, BTreeSet, VecDeque},
+    io::Write,
 };
 
-/// Collects all content-addressed blobs reachable from program states.
-struct BlobCollector<'a, S: ?Sized> {
+/// Selects programs to include into a state dump.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DumpFilter {
+    /// All programs and all valid codes.
+    #[default]
+    All,
+    /// Only the given programs and the codes they reference.
+    Programs(BTreeSet<ActorId>),
+}
+
+impl DumpFilter {
+    fn includes(&self, program_id: ActorId) -> bool {
+        match self {
+            Self::All => true,
+            Self::Programs(programs) => programs.contains(&program_id),
+        }
+    }
+}
+
+/// Collects all content-addressed blobs reachable from program states,
+/// passing each one to the sink exactly once.
+struct BlobCollector<'a, S: ?Sized, F> {
     storage: &'a S,
-    /// Dedup of blobs pushed into [`Self::blobs`], keyed by CAS hash alone.
+    /// Dedup of blobs passed to [`Self::sink`], keyed by CAS hash alone.
     collected: BTreeSet<H256>,
     /// Dedup of graph traversal, keyed by `(TypeId, H256)`.
     ///
@@ -36,10 +57,14 @@ struct BlobCollector<'a, S: ?Sized> {
     /// the second visit to skip its own children and drop their reachable
     /// blobs from the dump.
     visited: BTreeSet<(TypeId, H256)>,
-    blobs: Vec<Vec<u8>>,
+    sink: F,
 }
 
-impl<S: HashStorageRO + ?Sized> BlobCollector<'_, S> {
+impl<S, F> BlobCollector<'_, S, F>
+where
+    S: HashStorageRO + ?Sized,
+    F: FnMut(Vec<u8>) -> Result<()>,
+{
     /// Read raw bytes from CAS by hash and record them as a blob.
     ///
     /// Use for leaf blobs that have no children to traverse (original code,
@@ -55,8 +80,7 @@ impl<S: HashStorageRO + ?Sized> BlobCollector<'_, S> {
             .read_by_hash(hash)
             .with_context(|| format!("missing CAS blob for hash {hash}"))?;
 
-        self.blobs.push(data);
-        Ok(())
+        (self.sink)(data)
     }
 
     /// Read, record and decode a blob whose children must be traversed.
@@ -78,12 +102,13 @@ impl<S: HashStorageRO + ?Sized> BlobCollector<'_, S> {
             .read_by_hash(hash)
             .with_context(|| format!("missing CAS blob for hash {hash}"))?;
 
+        let value = T::decode(&mut &data[..])
+            .with_context(|| format!("failed to decode blob at hash {hash}"))?;
+
         if self.collected.insert(hash) {
-            self.blobs.push(data.clone());
+            (self.sink)(data)?;
         }
 
-        let value = T::decode(&mut &data[..])
-            .with_context(|| format!("failed to decode blob at hash {hash}"))?;
         Ok(Some(value))
     }
 
@@ -270,9 +295,10 @@ impl<S: HashStorageRO + ?Sized> BlobCollector<'_, S> {
             return Ok(());
         };
 
-        // Collect allocations and memory pages.
+        // Collect allocations, gas reservations and memory pages.
         if let Program::Active(active) = &program {
             let _ = self.collect_maybe_hash(active.allocations_hash)?;
+            let _ = self.collect_maybe_hash(active.gas_reservations_hash)?;
             self.collect_memory_pages(active.pages_hash)?;
         }
 
@@ -293,6 +319,110 @@ impl<S: HashStorageRO + ?Sized> BlobCollector<'_, S> {
     }
 }
 
+fn collect_header(
+    storage: &(impl MbStorageRO + CodesStorageRO),
+    mb_hash: H256,
+    eb_hash: H256,
+    filter: &DumpFilter,
+) -> Result<StateDumpHeader> {
+    let program_states = storage
+        .mb_program_states(mb_hash)
+        .with_context(|| format!("program states not found for MB {mb_hash}"))?;
+
+    if let DumpFilter::Programs(selected) = filter {
+        for program_id in selected {
+            ensure!(
+                program_states.contains_key(program_id),
+                "program {program_id} not found in MB {mb_hash}"
+            );
+        }
+    }
+
+    let mut programs = BTreeMap::new();
+
+    // `canonical_queue_size` and `injected_queue_size` are not included in the program state blob
+    for (
+        program_id,
+        StateHashWithQueueSize {
+            hash: state_hash,
+            canonical_queue_size: _,
+            injected_queue_size: _,
+        },
+    ) in program_states
+    {
+        if !filter.includes(program_id) {
+            continue;
+        }
+
+        let code_id = storage
+            .program_code_id(program_id)
+            .with_context(|| format!("code id not found for program {program_id}"))?;
+
+        programs.insert(program_id, (code_id, state_hash));
+    }
+
+    let codes = match filter {
+        DumpFilter::All => storage.valid_codes(),
+        DumpFilter::Programs(_) => programs.values().map(|(code_id, _)| *code_id).collect(),
+    };
+
+    Ok(StateDumpHeader {
+        metadata: mb_hash,
+        eb_hash,
+        codes,
+        programs,
+    })
+}
+
+/// Pass all blobs reachable from the header codes and programs to the sink.
+fn collect_blobs(
+    storage: &impl HashStorageRO,
+    header: &StateDumpHeader,
+    sink: impl FnMut(Vec<u8>) -> Result<()>,
+) -> Result<()> {
+    let mut collector = BlobCollector {
+        storage,
+        collected: BTreeSet::new(),
+        visited: BTreeSet::new(),
+        sink,
+    };
+
+    // Collect codes into blobs.
+    for code_id in &header.codes {
+        let code_hash = CodeId::into_bytes(*code_id).into();
+        collector.read_and_collect(code_hash)?;
+    }
+
+    // Collect program state trees.
+    for (_code_id, state_hash) in header.programs.values() {
+        collector.collect_program_state(*state_hash)?;
+    }
+
+    Ok(())
+}
+
+/// Find the MB committed at the Eth block.
+fn committed_mb(storage: &impl BlockMetaStorageRO, eb_hash: H256) -> Result<H256> {
+    let block_meta = storage.block_meta(eb_hash);
+
+    let mb_hash = block_meta
+        .last_committed_mb
+        .context("no committed MB found for block")?;
+
+    let codes_queue = block_meta
+        .codes_queue
+        .with_context(|| format!("codes queue not found for block {eb_hash}"))?;
+
+    if !codes_queue.is_empty() {
+        // StorageDump does not include codes queue, so after re-genesis the queue will be lost.
+        log::warn!(
+            "Codes queue is not empty at block {eb_hash:?}. This may cause hanging codes after re-genesis."
+        );
+    }
+
+    Ok(mb_hash)
+}
+
 impl StateDump {
     /// Collect a state dump for a specific MB hash. The Eth block at
     /// which the MB observably "applies" is recorded as `eb_hash`
@@ -306,53 +436,29 @@ impl StateDump {
         storage: &(impl MbStorageRO + CodesStorageRO + HashStorageRO),
         mb_hash: H256,
         eb_hash: H256,
+        filter: &DumpFilter,
     ) -> Result<Self> {
-        let mut collector = BlobCollector {
-            storage,
-            collected: BTreeSet::new(),
-            visited: BTreeSet::new(),
-            blobs: Vec::new(),
-        };
-
-        // Collect all valid codes into blobs.
-        let codes = storage.valid_codes();
-        for code_id in &codes {
-            let code_hash = CodeId::into_bytes(*code_id).into();
-            collector.read_and_collect(code_hash)?;
-        }
-
-        let program_states = storage
-            .mb_program_states(mb_hash)
-            .with_context(|| format!("program states not found for MB {mb_hash}"))?;
+        let header = collect_header(storage, mb_hash, eb_hash, filter)?;
 
-        // Collect programs and their state trees.
-        let mut programs = BTreeMap::new();
-
-        // `canonical_queue_size` and `injected_queue_size` are not included in the program state blob
-        for (
-            program_id,
-            StateHashWithQueueSize {
-                hash: state_hash,
-                canonical_queue_size: _,
-                injected_queue_size: _,
-            },
-        ) in &program_states
-        {
-            let code_id = storage
-                .program_code_id(*program_id)
-                .with_context(|| format!("code id not found for program {program_id}"))?;
+        let mut blobs = Vec::new();
+        collect_blobs(storage, &header, |blob| {
+            blobs.push(blob);
+            Ok(())
+        })?;
 
-            programs.insert(*program_id, (code_id, *state_hash));
-
-            collector.collect_program_state(*state_hash)?;
-        }
+        let StateDumpHeader {
+            metadata,
+            eb_hash,
+            codes,
+            programs,
+        } = header;
 
         Ok(StateDump {
-            metadata: mb_hash,
+            metadata,
             eb_hash,
             codes,
             programs,
-            blobs: collector.blobs,
+            blobs,
         })
     }
 
@@ -362,24 +468,131 @@ impl StateDump {
     pub fn collect_from_storage(
         storage: &(impl MbStorageRO + CodesStorageRO + BlockMetaStorageRO + HashStorageRO),
         eb_hash: H256,
+        filter: &DumpFilter,
     ) -> Result<Self> {
-        let block_meta = storage.block_meta(eb_hash);
+        let mb_hash = committed_mb(storage, eb_hash)?;
+        Self::collect_from_mb_storage(storage, mb_hash, eb_hash, filter)
+    }
+
+    /// Streaming counterpart of [`Self::collect_from_mb_storage`]: blobs are
+    /// written as soon as they are collected, instead of being kept in memory.
+    ///
+    /// Returns the header and the writer, which must be finished by the caller.
+    pub fn stream_from_mb_storage<W: Write>(
+        storage: &(impl MbStorageRO + CodesStorageRO + HashStorageRO),
+        mb_hash: H256,
+        eb_hash: H256,
+        filter: &DumpFilter,
+        writer: W,
+    ) -> Result<(StateDumpHeader, StateDumpWriter<W>)> {
+        let header = collect_header(storage, mb_hash, eb_hash, filter)?;
 
-        let mb_hash = block_meta
-            .last_committed_mb
-            .context("no committed MB found for block")?;
+        let mut writer = StateDumpWriter::new(writer, &header)?;
+        collect_blobs(storage, &header, |blob| {
+            writer.write_blob(blob).map_err(Into::into)
+        })?;
 
-        let codes_queue = block_meta
-            .codes_queue
-            .with_context(|| format!("codes queue not found for block {eb_hash}"))?;
+        Ok((header, writer))
+    }
 
-        if !codes_queue.is_empty() {
-            // StorageDump does not include codes queue, so after re-genesis the queue will be lost.
-            log::warn!(
-                "Codes queue is not empty at block {eb_hash:?}. This may cause hanging codes after re-genesis."
-            );
-        }
+    /// Streaming counterpart of [`Self::collect_from_storage`].
+    pub fn stream_from_storage<W: Write>(
+        storage: &(impl MbStorageRO + CodesStorageRO + BlockMetaStorageRO + HashStorageRO),
+        eb_hash: H256,
+        filter: &DumpFilter,
+        writer: W,
+    ) -> Result<(StateDumpHeader, StateDumpWriter<W>)> {
+        let mb_hash = committed_mb(storage, eb_hash)?;
+        Self::stream_from_mb_storage(storage, mb_hash, eb_hash, filter, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Database, dump::StateDumpReader};
+    use ethexe_common::db::{CodesStorageRW, MbStorageRW};
+    use ethexe_runtime_common::state::Storage;
+
+    /// Creates programs with distinct codes and balances in the MB.
+    fn setup(db: &Database, mb_hash: H256, amount: u64) -> Vec<(ActorId, CodeId)> {
+        let mut program_states = BTreeMap::new();
+        let programs: Vec<_> = (0..amount)
+            .map(|i| {
+                let program_id = ActorId::from(i);
+                let code_id = db.set_original_code(&i.to_le_bytes());
+                db.set_code_valid(code_id, true);
+                db.set_program_code_id(program_id, code_id);
+
+                let hash = db.write_program_state(ProgramState {
+                    balance: i.into(),
+                    ..ProgramState::zero()
+                });
+                program_states.insert(
+                    program_id,
+                    StateHashWithQueueSize {
+                        hash,
+                        canonical_queue_size: 0,
+                        injected_queue_size: 0,
+                    },
+                );
+
+                (program_id, code_id)
+            })
+            .collect();
+        db.set_mb_program_states(mb_hash, program_states);
+
+        programs
+    }
+
+    #[test]
+    fn filter_selects_programs_and_their_codes() {
+        let db = Database::memory();
+        let mb_hash = H256::random();
+        let programs = setup(&db, mb_hash, 3);
+
+        let dump = StateDump::collect_from_mb_storage(&db, mb_hash, H256::zero(), &DumpFilter::All)
+            .unwrap();
+        assert_eq!(dump.programs.len(), 3);
+        assert_eq!(dump.codes.len(), 3);
+        // 3 codes and 3 program states.
+        assert_eq!(dump.blobs.len(), 6);
+
+        let (program_id, code_id) = programs[1];
+        let filter = DumpFilter::Programs([program_id].into());
+        let dump = StateDump::collect_from_mb_storage(&db, mb_hash, H256::zero(), &filter).unwrap();
+        assert_eq!(dump.programs.keys().collect::<Vec<_>>(), [&program_id]);
+        assert_eq!(dump.codes, [code_id].into());
+        assert_eq!(dump.blobs.len(), 2);
+
+        let filter = DumpFilter::Programs([ActorId::from(42)].into());
+        assert!(StateDump::collect_from_mb_storage(&db, mb_hash, H256::zero(), &filter).is_err());
+    }
 
-        Self::collect_from_mb_storage(storage, mb_hash, eb_hash)
+    #[test]
+    fn stream_matches_collected_dump() {
+        let db = Database::memory();
+        let mb_hash = H256::random();
+        setup(&db, mb_hash, 5);
+
+        let dump = StateDump::collect_from_mb_storage(&db, mb_hash, H256::zero(), &DumpFilter::All)
+            .unwrap();
+
+        let (header, writer) = StateDump::stream_from_mb_storage(
+            &db,
+            mb_hash,
+            H256::zero(),
+            &DumpFilter::All,
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(header, dump.header());
+        assert_eq!(writer.blobs_amount(), dump.blobs.len());
+        let data = writer.finish().unwrap();
+
+        let mut reader = StateDumpReader::new(&data[..]).unwrap();
+        assert_eq!(reader.header(), &header);
+        let blobs = reader.by_ref().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blobs, dump.blobs);
     }
 }
@@ -0,0 +1,506 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Structural diff between two state dumps.
+
+use super::{StateDump, StateDumpHeader};
+use crate::{
+    CASDatabase, Database, MemDb,
+    iterator::{DatabaseIteratorError, DatabaseIteratorStorage, ProgramStateNode},
+    visitor::{DatabaseVisitor, walk},
+};
+use anyhow::{Context, Result, anyhow};
+use ethexe_runtime_common::state::{
+    DispatchStash, MemoryPagesRegion, MessageQueue, ProgramState, Storage, UserMailbox, Waitlist,
+};
+use gprimitives::{ActorId, CodeId, H256, MessageId};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// Value changed between two dumps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn new(old: T, new: T) -> Option<Self> {
+        (old != new).then_some(Self { old, new })
+    }
+}
+
+/// Items added and removed between two dumps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SetDiff<T> {
+    pub added: BTreeSet<T>,
+    pub removed: BTreeSet<T>,
+}
+
+impl<T: Ord + Clone> SetDiff<T> {
+    fn new(old: &BTreeSet<T>, new: &BTreeSet<T>) -> Self {
+        Self {
+            added: new.difference(old).cloned().collect(),
+            removed: old.difference(new).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Changes of a program present in both dumps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProgramDiff {
+    pub code_id: Option<Change<CodeId>>,
+    pub balance: Option<Change<u128>>,
+    pub executable_balance: Option<Change<u128>>,
+    /// Messages in canonical and injected queues.
+    pub queue: SetDiff<MessageId>,
+    pub waitlist: SetDiff<MessageId>,
+    /// Messages in mailboxes of all users.
+    pub mailbox: SetDiff<MessageId>,
+    pub stash: SetDiff<MessageId>,
+    /// Memory pages whose data hashes differ, including added and removed ones.
+    pub changed_pages: BTreeSet<u32>,
+}
+
+/// Structural diff between two state dumps, see [`StateDump::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StateDumpDiff {
+    /// Block hash the old dump was created for.
+    pub old_eb_hash: H256,
+    /// Block hash the new dump was created for.
+    pub new_eb_hash: H256,
+    pub codes: SetDiff<CodeId>,
+    pub programs: SetDiff<ActorId>,
+    /// Programs present in both dumps with different code or state.
+    pub changed_programs: BTreeMap<ActorId, ProgramDiff>,
+}
+
+impl StateDumpDiff {
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty() && self.programs.is_empty() && self.changed_programs.is_empty()
+    }
+}
+
+impl fmt::Display for StateDumpDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_set<T: fmt::Display>(
+            f: &mut fmt::Formatter<'_>,
+            indent: &str,
+            name: &str,
+            diff: &SetDiff<T>,
+        ) -> fmt::Result {
+            for item in &diff.added {
+                writeln!(f, "{indent}+ {name} {item}")?;
+            }
+            for item in &diff.removed {
+                writeln!(f, "{indent}- {name} {item}")?;
+            }
+            Ok(())
+        }
+
+        fn write_change<T: fmt::Display>(
+            f: &mut fmt::Formatter<'_>,
+            name: &str,
+            change: &Option<Change<T>>,
+        ) -> fmt::Result {
+            match change {
+                Some(Change { old, new }) => writeln!(f, "    ~ {name}: {old} -> {new}"),
+                None => Ok(()),
+            }
+        }
+
+        writeln!(
+            f,
+            "State diff {:?} -> {:?}",
+            self.old_eb_hash, self.new_eb_hash
+        )?;
+
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        write_set(f, "", "code", &self.codes)?;
+        write_set(f, "", "program", &self.programs)?;
+
+        for (program_id, diff) in &self.changed_programs {
+            writeln!(f, "~ program {program_id}")?;
+            write_change(f, "code", &diff.code_id)?;
+            write_change(f, "balance", &diff.balance)?;
+            write_change(f, "executable balance", &diff.executable_balance)?;
+            write_set(f, "    ", "queued message", &diff.queue)?;
+            write_set(f, "    ", "waiting message", &diff.waitlist)?;
+            write_set(f, "    ", "mailbox message", &diff.mailbox)?;
+            write_set(f, "    ", "stashed message", &diff.stash)?;
+            if !diff.changed_pages.is_empty() {
+                let pages: Vec<_> = diff.changed_pages.iter().map(u32::to_string).collect();
+                writeln!(f, "    ~ pages: {}", pages.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Summary of a program state, collected by walking it.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ProgramSummary {
+    balance: u128,
+    executable_balance: u128,
+    queue: BTreeSet<MessageId>,
+    waitlist: BTreeSet<MessageId>,
+    mailbox: BTreeSet<MessageId>,
+    stash: BTreeSet<MessageId>,
+    pages: BTreeMap<u32, H256>,
+}
+
+struct SummaryCollector {
+    db: Database,
+    summary: ProgramSummary,
+    errors: Vec<DatabaseIteratorError>,
+}
+
+impl DatabaseVisitor for SummaryCollector {
+    fn db(&self) -> &dyn DatabaseIteratorStorage {
+        &self.db
+    }
+
+    fn clone_boxed_db(&self) -> Box<dyn DatabaseIteratorStorage> {
+        Box::new(self.db.clone())
+    }
+
+    fn on_db_error(&mut self, error: DatabaseIteratorError) {
+        self.errors.push(error);
+    }
+
+    fn visit_program_state(&mut self, program_state: ProgramState) {
+        self.summary.balance = program_state.balance;
+        self.summary.executable_balance = program_state.executable_balance;
+    }
+
+    fn visit_message_queue(&mut self, message_queue: MessageQueue) {
+        self.summary
+            .queue
+            .extend(message_queue.as_ref().iter().map(|dispatch| dispatch.id));
+    }
+
+    fn visit_waitlist(&mut self, waitlist: Waitlist) {
+        self.summary
+            .waitlist
+            .extend(waitlist.as_ref().keys().copied());
+    }
+
+    fn visit_user_mailbox(&mut self, user_mailbox: UserMailbox) {
+        self.summary
+            .mailbox
+            .extend(user_mailbox.as_ref().keys().copied());
+    }
+
+    fn visit_dispatch_stash(&mut self, dispatch_stash: DispatchStash) {
+        self.summary
+            .stash
+            .extend(dispatch_stash.as_ref().keys().copied());
+    }
+
+    fn visit_memory_pages_region(&mut self, memory_pages_region: MemoryPagesRegion) {
+        self.summary.pages.extend(
+            memory_pages_region
+                .as_inner()
+                .iter()
+                .map(|(page, hash)| (page.raw(), hash.inner())),
+        );
+    }
+}
+
+/// Dump with its blobs in a database, so its states can be walked.
+struct LoadedDump<'a> {
+    header: &'a StateDumpHeader,
+    db: Database,
+}
+
+impl<'a> LoadedDump<'a> {
+    fn new(header: &'a StateDumpHeader, cas: &dyn CASDatabase) -> Self {
+        Self {
+            header,
+            db: Database::with_memory_kv(cas),
+        }
+    }
+
+    fn summary(&self, state_hash: H256) -> Result<ProgramSummary> {
+        let program_state = self
+            .db
+            .program_state(state_hash)
+            .with_context(|| format!("program state {state_hash} is missing in dump"))?;
+
+        let mut collector = SummaryCollector {
+            db: self.db.clone(),
+            summary: ProgramSummary::default(),
+            errors: Vec::new(),
+        };
+        walk(&mut collector, ProgramStateNode { program_state });
+
+        match collector.errors.first() {
+            Some(error) => Err(anyhow!(
+                "program state {state_hash} is incomplete in dump: {error:?}"
+            )),
+            None => Ok(collector.summary),
+        }
+    }
+}
+
+fn diff_pages(old: &BTreeMap<u32, H256>, new: &BTreeMap<u32, H256>) -> BTreeSet<u32> {
+    old.keys()
+        .chain(new.keys())
+        .filter(|page| old.get(page) != new.get(page))
+        .copied()
+        .collect()
+}
+
+impl StateDump {
+    /// Computes the structural diff from `self` to `new`.
+    ///
+    /// Fails if a program state of any dump is incomplete.
+    pub fn diff(&self, new: &StateDump) -> Result<StateDumpDiff> {
+        let load = |dump: &StateDump| {
+            let mem = MemDb::default();
+            for blob in &dump.blobs {
+                CASDatabase::write(&mem, blob);
+            }
+            mem
+        };
+
+        StateDumpDiff::new(&self.header(), &load(self), &new.header(), &load(new))
+    }
+}
+
+impl StateDumpDiff {
+    /// Computes the structural diff between dumps, whose blobs are stored
+    /// in `old_cas` and `new_cas` respectively.
+    ///
+    /// Fails if a program state of any dump is incomplete.
+    pub fn new(
+        old: &StateDumpHeader,
+        old_cas: &dyn CASDatabase,
+        new: &StateDumpHeader,
+        new_cas: &dyn CASDatabase,
+    ) -> Result<Self> {
+        let old = LoadedDump::new(old, old_cas);
+        let new = LoadedDump::new(new, new_cas);
+
+        let old_programs: BTreeSet<ActorId> = old.header.programs.keys().copied().collect();
+        let new_programs: BTreeSet<ActorId> = new.header.programs.keys().copied().collect();
+
+        let mut changed_programs = BTreeMap::new();
+        for program_id in old_programs.intersection(&new_programs) {
+            let (old_code_id, old_state_hash) = old.header.programs[program_id];
+            let (new_code_id, new_state_hash) = new.header.programs[program_id];
+            if old_code_id == new_code_id && old_state_hash == new_state_hash {
+                continue;
+            }
+
+            let old_summary = old.summary(old_state_hash)?;
+            let new_summary = new.summary(new_state_hash)?;
+
+            changed_programs.insert(
+                *program_id,
+                ProgramDiff {
+                    code_id: Change::new(old_code_id, new_code_id),
+                    balance: Change::new(old_summary.balance, new_summary.balance),
+                    executable_balance: Change::new(
+                        old_summary.executable_balance,
+                        new_summary.executable_balance,
+                    ),
+                    queue: SetDiff::new(&old_summary.queue, &new_summary.queue),
+                    waitlist: SetDiff::new(&old_summary.waitlist, &new_summary.waitlist),
+                    mailbox: SetDiff::new(&old_summary.mailbox, &new_summary.mailbox),
+                    stash: SetDiff::new(&old_summary.stash, &new_summary.stash),
+                    changed_pages: diff_pages(&old_summary.pages, &new_summary.pages),
+                },
+            );
+        }
+
+        Ok(Self {
+            old_eb_hash: old.header.eb_hash,
+            new_eb_hash: new.header.eb_hash,
+            codes: SetDiff::new(&old.header.codes, &new.header.codes),
+            programs: SetDiff::new(&old_programs, &new_programs),
+            changed_programs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KVDatabase;
+    use ethexe_common::gear::MessageType;
+    use ethexe_runtime_common::state::{Dispatch, Mailbox, MessageQueueHashWithSize};
+    use parity_scale_codec::Encode;
+
+    fn dump(programs: impl IntoIterator<Item = (ActorId, CodeId, ProgramState)>) -> StateDump {
+        let mut dump = StateDump {
+            metadata: H256::zero(),
+            eb_hash: H256::random(),
+            codes: BTreeSet::new(),
+            programs: BTreeMap::new(),
+            blobs: Vec::new(),
+        };
+
+        for (program_id, code_id, state) in programs {
+            let blob = state.encode();
+            dump.codes.insert(code_id);
+            dump.programs
+                .insert(program_id, (code_id, crate::hash(&blob)));
+            dump.blobs.push(blob);
+        }
+
+        dump
+    }
+
+    fn with_balance(balance: u128) -> ProgramState {
+        ProgramState {
+            balance,
+            ..ProgramState::zero()
+        }
+    }
+
+    #[test]
+    fn diff_of_same_dump_is_empty() {
+        let dump = dump([(ActorId::from(1), CodeId::from(1), with_balance(10))]);
+
+        let diff = dump.diff(&dump).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(diff.old_eb_hash, diff.new_eb_hash);
+    }
+
+    #[test]
+    fn diff_reports_programs_codes_and_balances() {
+        let [kept, changed, removed, added] = [1, 2, 3, 4].map(ActorId::from);
+        let [old_code, new_code] = [1, 2].map(CodeId::from);
+
+        let old = dump([
+            (kept, old_code, with_balance(1)),
+            (changed, old_code, with_balance(1)),
+            (removed, old_code, with_balance(1)),
+        ]);
+        let new = dump([
+            (kept, old_code, with_balance(1)),
+            (changed, new_code, with_balance(2)),
+            (added, new_code, with_balance(1)),
+        ]);
+
+        let diff = old.diff(&new).unwrap();
+        assert_eq!(diff.codes.added, [new_code].into());
+        assert!(diff.codes.removed.is_empty());
+        assert_eq!(diff.programs.added, [added].into());
+        assert_eq!(diff.programs.removed, [removed].into());
+        assert_eq!(diff.changed_programs.keys().collect::<Vec<_>>(), [&changed]);
+
+        let program_diff = &diff.changed_programs[&changed];
+        assert_eq!(program_diff.code_id, Change::new(old_code, new_code));
+        assert_eq!(program_diff.balance, Some(Change { old: 1, new: 2 }));
+        assert_eq!(program_diff.executable_balance, None);
+        assert!(program_diff.queue.is_empty());
+        assert!(program_diff.changed_pages.is_empty());
+    }
+
+    #[test]
+    fn diff_reports_messages() {
+        let program_id = ActorId::from(1);
+        let code_id = CodeId::from(1);
+        let [kept, removed, added] = [1, 2, 3].map(MessageId::from);
+
+        // Each message is put in every message section of the program.
+        let dump = |messages: [MessageId; 2]| {
+            let mem = MemDb::default();
+            let db = Database::with_memory_kv(&mem);
+            let dispatch = |id| {
+                Dispatch::new(
+                    &db,
+                    id,
+                    ActorId::from(2),
+                    vec![],
+                    0,
+                    false,
+                    MessageType::Canonical,
+                    false,
+                )
+                .unwrap()
+            };
+
+            let mut queue = MessageQueue::default();
+            let mut waitlist = Waitlist::default();
+            let mut mailbox = Mailbox::default();
+            let mut stash = DispatchStash::default();
+            for id in messages {
+                queue.queue(dispatch(id));
+                waitlist.wait(dispatch(id), 10);
+                mailbox.add_and_store_user_mailbox(
+                    &db,
+                    ActorId::from(3),
+                    id,
+                    dispatch(id).into(),
+                    10,
+                );
+                stash.add_to_program(dispatch(id), 10);
+            }
+
+            let state_hash = db.write_program_state(ProgramState {
+                canonical_queue: MessageQueueHashWithSize {
+                    hash: queue.store(&db),
+                    cached_queue_size: messages.len() as u8,
+                },
+                waitlist_hash: waitlist.store(&db).unwrap(),
+                mailbox_hash: mailbox.store(&db).unwrap(),
+                stash_hash: stash.store(&db),
+                ..ProgramState::zero()
+            });
+
+            StateDump {
+                metadata: H256::zero(),
+                eb_hash: H256::random(),
+                codes: [code_id].into(),
+                programs: [(program_id, (code_id, state_hash))].into(),
+                blobs: KVDatabase::iter_prefix(&mem, &[])
+                    .map(|(_hash, blob)| blob)
+                    .collect(),
+            }
+        };
+
+        let diff = dump([kept, removed]).diff(&dump([kept, added])).unwrap();
+        let program_diff = &diff.changed_programs[&program_id];
+        let expected = SetDiff {
+            added: [added].into(),
+            removed: [removed].into(),
+        };
+        assert_eq!(program_diff.queue, expected);
+        assert_eq!(program_diff.waitlist, expected);
+        assert_eq!(program_diff.mailbox, expected);
+        assert_eq!(program_diff.stash, expected);
+        assert_eq!(program_diff.balance, None);
+    }
+
+    #[test]
+    fn diff_fails_on_incomplete_dump() {
+        let mut old = dump([(ActorId::from(1), CodeId::from(1), with_balance(1))]);
+        let new = dump([(ActorId::from(1), CodeId::from(1), with_balance(2))]);
+        old.blobs.clear();
+
+        assert!(old.diff(&new).is_err());
+    }
+
+    #[test]
+    fn diff_pages_reports_changed_added_and_removed() {
+        let [a, b] = [H256::random(), H256::random()];
+        let old = [(0, a), (1, a), (2, a)].into();
+        let new = [(0, a), (1, b), (3, b)].into();
+
+        assert_eq!(diff_pages(&old, &new), [1, 2, 3].into());
+    }
+}
@@ -4,6 +4,12 @@
 //! Ethexe state dump for re-genesis.
 
 mod collect;
+mod diff;
+mod stream;
+
+pub use collect::DumpFilter;
+pub use diff::{Change, ProgramDiff, SetDiff, StateDumpDiff};
+pub use stream::{CHUNK_SIZE, StateDumpBlobs, StateDumpHeader, StateDumpReader, StateDumpWriter};
 
 use flate2::{Compression, read::DeflateDecoder, write::DeflateEncoder};
 use gprimitives::{ActorId, CodeId, H256};
@@ -99,23 +105,25 @@ impl StateDump {
         serde_json::from_reader(file).map_err(Into::into)
     }
 
-    /// Read a dump file, auto-detecting format by extension (`.blob` or `.json`).
+    /// Read a dump file, auto-detecting format by extension (`.blob`, `.json` or `.dump`).
     pub fn read_from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
         match path.as_ref().extension().and_then(|e| e.to_str()) {
             Some("blob") => Self::read_from_blob(path),
             Some("json") => Self::read_from_json(path),
+            Some("dump") => Self::read_from_stream(path),
             Some(ext) => anyhow::bail!("unsupported dump file extension: .{ext}"),
-            None => anyhow::bail!("dump file must have .blob or .json extension"),
+            None => anyhow::bail!("dump file must have .blob, .json or .dump extension"),
         }
     }
 
-    /// Write a dump file, auto-detecting format by extension (`.blob` or `.json`).
+    /// Write a dump file, auto-detecting format by extension (`.blob`, `.json` or `.dump`).
     pub fn write_to_file(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
         match path.as_ref().extension().and_then(|e| e.to_str()) {
             Some("blob") => self.write_to_blob(path),
             Some("json") => self.write_to_json(path),
+            Some("dump") => self.write_to_stream(path),
             Some(ext) => anyhow::bail!("unsupported dump file extension: .{ext}"),
-            None => anyhow::bail!("dump file must have .blob or .json extension"),
+            None => anyhow::bail!("dump file must have .blob, .json or .dump extension"),
         }
     }
 }
@@ -0,0 +1,492 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Streaming state dump format.
+//!
+//! Unlike `.blob` and `.json` dumps, which keep all blobs in memory at once,
+//! a streaming dump is written and read incrementally. The file is a deflate
+//! stream of:
+//! - [`MAGIC`];
+//! - SCALE-encoded [`StateDumpHeader`];
+//! - chunks of SCALE-encoded blobs, up to [`CHUNK_SIZE`] bytes each;
+//! - an empty chunk, marking the end of the dump.
+//!
+//! Header and every chunk are prefixed with their encoded length as LE `u32`.
+
+use super::StateDump;
+use anyhow::{Context, Result, bail, ensure};
+use flate2::{Compression, read::DeflateDecoder, write::DeflateEncoder};
+use gprimitives::{ActorId, CodeId, H256};
+use parity_scale_codec::{Compact, Decode, Encode};
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    fs::File,
+    io::{self, BufReader, Read, Write},
+    mem,
+    path::Path,
+    vec,
+};
+
+const MAGIC: &[u8; 8] = b"ethxdmp1";
+
+/// Approximate size of blobs in one chunk, in bytes.
+pub const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Everything in [`StateDump`] except blobs.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize)]
+pub struct StateDumpHeader {
+    pub metadata: H256,
+    /// Block hash for which this dump was created.
+    pub eb_hash: H256,
+    /// Valid code ids. Code bytes are stored in blobs (keyed by CodeId in CAS).
+    pub codes: BTreeSet<CodeId>,
+    /// Programs: program id -> (code id, state hash).
+    pub programs: BTreeMap<ActorId, (CodeId, H256)>,
+}
+
+/// Incremental writer of a streaming dump.
+///
+/// [`Self::finish`] must be called after the last blob, otherwise the dump
+/// is incomplete and can't be read.
+pub struct StateDumpWriter<W: Write> {
+    encoder: DeflateEncoder<W>,
+    chunk: Vec<Vec<u8>>,
+    chunk_len: usize,
+    chunk_size: usize,
+    blobs_amount: usize,
+}
+
+impl<W: Write> StateDumpWriter<W> {
+    pub fn new(writer: W, header: &StateDumpHeader) -> io::Result<Self> {
+        Self::with_chunk_size(writer, header, CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(
+        writer: W,
+        header: &StateDumpHeader,
+        chunk_size: usize,
+    ) -> io::Result<Self> {
+        let mut encoder = DeflateEncoder::new(writer, Compression::default());
+        encoder.write_all(MAGIC)?;
+        write_frame(&mut encoder, &header.encode())?;
+
+        Ok(Self {
+            encoder,
+            chunk: Vec::new(),
+            chunk_len: 0,
+            chunk_size,
+            blobs_amount: 0,
+        })
+    }
+
+    /// Appends the blob, flushing the current chunk if it's full.
+    pub fn write_blob(&mut self, blob: Vec<u8>) -> io::Result<()> {
+        self.chunk_len += blob.len();
+        self.chunk.push(blob);
+        self.blobs_amount += 1;
+
+        if self.chunk_len >= self.chunk_size {
+            self.flush_chunk()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns amount of blobs written so far.
+    pub fn blobs_amount(&self) -> usize {
+        self.blobs_amount
+    }
+
+    /// Writes the remaining blobs and the end marker, returning the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush_chunk()?;
+        write_frame(&mut self.encoder, &[])?;
+        self.encoder.finish()
+    }
+
+    fn flush_chunk(&mut self) -> io::Result<()> {
+        if self.chunk.is_empty() {
+            return Ok(());
+        }
+
+        let chunk = mem::take(&mut self.chunk);
+        self.chunk_len = 0;
+        write_frame(&mut self.encoder, &chunk.encode())
+    }
+}
+
+/// Incremental reader of a streaming dump, yielding its blobs.
+pub struct StateDumpReader<R: Read> {
+    decoder: DeflateDecoder<R>,
+    header: StateDumpHeader,
+    chunk: vec::IntoIter<Vec<u8>>,
+    finished: bool,
+}
+
+impl<R: Read> StateDumpReader<R> {
+    pub fn new(reader: R) -> Result<Self> {
+        let mut decoder = DeflateDecoder::new(reader);
+
+        let mut magic = [0; MAGIC.len()];
+        decoder
+            .read_exact(&mut magic)
+            .context("failed to read dump magic")?;
+        ensure!(&magic == MAGIC, "not a streaming state dump");
+
+        let header = read_frame(&mut decoder).context("failed to read dump header")?;
+        let header =
+            StateDumpHeader::decode(&mut &header[..]).context("failed to decode dump header")?;
+
+        Ok(Self {
+            decoder,
+            header,
+            chunk: Vec::new().into_iter(),
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &StateDumpHeader {
+        &self.header
+    }
+
+    fn next_chunk(&mut self) -> Result<Option<Vec<Vec<u8>>>> {
+        let chunk = read_frame(&mut self.decoder).context("failed to read dump chunk")?;
+        if chunk.is_empty() {
+            return Ok(None);
+        }
+
+        let chunk =
+            Vec::<Vec<u8>>::decode(&mut &chunk[..]).context("failed to decode dump chunk")?;
+        if chunk.is_empty() {
+            bail!("dump contains an empty chunk");
+        }
+
+        Ok(Some(chunk))
+    }
+}
+
+impl<R: Read> Iterator for StateDumpReader<R> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(blob) = self.chunk.next() {
+                return Some(Ok(blob));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            match self.next_chunk() {
+                Ok(Some(chunk)) => self.chunk = chunk.into_iter(),
+                Ok(None) => self.finished = true,
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Blobs of a dump file, see [`StateDump::open_file`].
+pub enum StateDumpBlobs {
+    /// Blobs read incrementally from a streaming dump.
+    Stream(StateDumpReader<BufReader<File>>),
+    /// Blobs of a dump loaded into memory.
+    Memory(vec::IntoIter<Vec<u8>>),
+}
+
+impl From<Vec<Vec<u8>>> for StateDumpBlobs {
+    fn from(blobs: Vec<Vec<u8>>) -> Self {
+        Self::Memory(blobs.into_iter())
+    }
+}
+
+impl Iterator for StateDumpBlobs {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Stream(reader) => reader.next(),
+            Self::Memory(blobs) => blobs.next().map(Ok),
+        }
+    }
+}
+
+/// Hex-encodes everything written to the inner writer.
+struct HexWriter<W: fmt::Write>(W);
+
+impl<W: fmt::Write> Write for HexWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .write_str(&hex::encode(buf))
+            .map_err(io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Blobs of a dump file, displayed the same way as `serialize_blobs` encodes them,
+/// so they are streamed into JSON with [`Serializer::collect_str`].
+struct JsonBlobs {
+    amount: u32,
+    blobs: Cell<Option<StateDumpBlobs>>,
+    /// Error of reading the blobs, which can't be returned from [`fmt::Display`].
+    error: Cell<Option<anyhow::Error>>,
+}
+
+impl fmt::Display for JsonBlobs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let blobs = self.blobs.take().expect("blobs are displayed once");
+
+        // Same as `Vec<Vec<u8>>` encoding, see `serialize_blobs`.
+        f.write_str("0x")?;
+        let mut encoder = DeflateEncoder::new(HexWriter(f), Compression::default());
+        encoder
+            .write_all(&Compact(self.amount).encode())
+            .map_err(|_| fmt::Error)?;
+        for blob in blobs {
+            match blob {
+                Ok(blob) => encoder.write_all(&blob.encode()).map_err(|_| fmt::Error)?,
+                Err(err) => {
+                    self.error.set(Some(err));
+                    return Ok(());
+                }
+            }
+        }
+        encoder.finish().map_err(|_| fmt::Error)?;
+
+        Ok(())
+    }
+}
+
+impl Serialize for JsonBlobs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+fn write_frame(writer: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "dump frame is too large"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(data)
+}
+
+fn read_frame(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+
+    let mut data = Vec::new();
+    reader
+        .by_ref()
+        .take(u32::from_le_bytes(len).into())
+        .read_to_end(&mut data)?;
+
+    if data.len() != u32::from_le_bytes(len) as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(data)
+}
+
+impl StateDump {
+    pub fn header(&self) -> StateDumpHeader {
+        StateDumpHeader {
+            metadata: self.metadata,
+            eb_hash: self.eb_hash,
+            codes: self.codes.clone(),
+            programs: self.programs.clone(),
+        }
+    }
+
+    /// Write the dump to a streaming `.dump` file.
+    pub fn write_to_stream(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = io::BufWriter::new(File::create(path)?);
+        let mut writer = StateDumpWriter::new(file, &self.header())?;
+        for blob in &self.blobs {
+            writer.write_blob(blob.clone())?;
+        }
+        writer.finish()?.flush()?;
+
+        Ok(())
+    }
+
+    /// Splits the dump into its header and blobs.
+    pub fn into_parts(self) -> (StateDumpHeader, StateDumpBlobs) {
+        let header = self.header();
+        (header, self.blobs.into())
+    }
+
+    /// Opens a dump file, auto-detecting format by extension (`.blob`, `.json` or `.dump`).
+    ///
+    /// Blobs of a streaming `.dump` file are read as they are consumed,
+    /// other formats are loaded into memory entirely.
+    pub fn open_file(path: impl AsRef<Path>) -> Result<(StateDumpHeader, StateDumpBlobs)> {
+        let path = path.as_ref();
+        if path.extension().is_some_and(|ext| ext == "dump") {
+            let reader = StateDumpReader::new(BufReader::new(File::open(path)?))?;
+            Ok((reader.header().clone(), StateDumpBlobs::Stream(reader)))
+        } else {
+            Self::read_from_file(path).map(Self::into_parts)
+        }
+    }
+
+    /// Writes a dump file as JSON, in the same format as `.json` dumps.
+    ///
+    /// The file is read twice, counting its blobs first,
+    /// so streaming dumps aren't loaded into memory.
+    pub fn file_to_json(path: impl AsRef<Path>, writer: impl Write) -> Result<()> {
+        let path = path.as_ref();
+        let (_, mut blobs) = Self::open_file(path)?;
+        let blobs_amount = blobs.try_fold(0u32, |amount, blob| blob.map(|_| amount + 1))?;
+        let (header, blobs) = Self::open_file(path)?;
+
+        let StateDumpHeader {
+            metadata,
+            eb_hash,
+            codes,
+            programs,
+        } = header;
+        let blobs = JsonBlobs {
+            amount: blobs_amount,
+            blobs: Cell::new(Some(blobs)),
+            error: Cell::new(None),
+        };
+
+        let mut serializer = serde_json::Serializer::pretty(io::BufWriter::new(writer));
+        let mut dump = serializer.serialize_struct("StateDump", 5)?;
+        dump.serialize_field("metadata", &metadata)?;
+        dump.serialize_field("eb_hash", &eb_hash)?;
+        dump.serialize_field("codes", &codes)?;
+        dump.serialize_field("programs", &programs)?;
+        dump.serialize_field("blobs", &blobs)?;
+        dump.end()?;
+
+        if let Some(err) = blobs.error.take() {
+            return Err(err);
+        }
+
+        let mut writer = serializer.into_inner();
+        writeln!(writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Read a streaming `.dump` file entirely into memory.
+    pub fn read_from_stream(path: impl AsRef<Path>) -> Result<Self> {
+        let file = io::BufReader::new(File::open(path)?);
+        let mut reader = StateDumpReader::new(file)?;
+        let blobs = reader.by_ref().collect::<Result<_>>()?;
+        let StateDumpHeader {
+            metadata,
+            eb_hash,
+            codes,
+            programs,
+        } = reader.header;
+
+        Ok(Self {
+            metadata,
+            eb_hash,
+            codes,
+            programs,
+            blobs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header() -> StateDumpHeader {
+        StateDumpHeader {
+            metadata: H256::random(),
+            eb_hash: H256::random(),
+            codes: [CodeId::from(1)].into(),
+            programs: [(ActorId::from(2), (CodeId::from(1), H256::random()))].into(),
+        }
+    }
+
+    #[test]
+    fn write_and_read_in_chunks() {
+        let header = header();
+        let blobs: Vec<Vec<u8>> = (0..100u8).map(|i| vec![i; i as usize]).collect();
+
+        let mut writer = StateDumpWriter::with_chunk_size(Vec::new(), &header, 64).unwrap();
+        for blob in &blobs {
+            writer.write_blob(blob.clone()).unwrap();
+        }
+        assert_eq!(writer.blobs_amount(), blobs.len());
+        let data = writer.finish().unwrap();
+
+        let mut reader = StateDumpReader::new(&data[..]).unwrap();
+        assert_eq!(reader.header(), &header);
+        let read_blobs = reader.by_ref().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read_blobs, blobs);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn empty_dump() {
+        let header = header();
+        let data = StateDumpWriter::new(Vec::new(), &header)
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        let mut reader = StateDumpReader::new(&data[..]).unwrap();
+        assert_eq!(reader.header(), &header);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn streaming_file_to_json() {
+        let dump = StateDump {
+            metadata: H256::random(),
+            eb_hash: H256::random(),
+            codes: [CodeId::from(1)].into(),
+            programs: [(ActorId::from(2), (CodeId::from(1), H256::random()))].into(),
+            blobs: (0..10u8).map(|i| vec![i; i as usize * 100]).collect(),
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.dump");
+        dump.write_to_stream(&path).unwrap();
+
+        let (header, blobs) = StateDump::open_file(&path).unwrap();
+        assert!(matches!(blobs, StateDumpBlobs::Stream(_)));
+        assert_eq!(header, dump.header());
+        assert_eq!(blobs.collect::<Result<Vec<_>>>().unwrap(), dump.blobs);
+
+        let mut json = Vec::new();
+        StateDump::file_to_json(&path, &mut json).unwrap();
+        let parsed: StateDump = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed.header(), dump.header());
+        assert_eq!(parsed.blobs, dump.blobs);
+    }
+
+    #[test]
+    fn truncated_dump_is_rejected() {
+        let mut writer = StateDumpWriter::with_chunk_size(Vec::new(), &header(), 1).unwrap();
+        for i in 0..10 {
+            writer.write_blob(vec![i; 10]).unwrap();
+        }
+        // Not finished: the end marker is missing.
+        writer.flush_chunk().unwrap();
+        let data = writer.encoder.finish().unwrap();
+
+        let reader = StateDumpReader::new(&data[..]).unwrap();
+        let result = reader.collect::<Result<Vec<_>>>();
+        assert!(result.is_err());
+    }
+}
@@ -9,12 +9,12 @@ use ethexe_common::{
         OnChainStorageRO,
     },
     events::BlockEvent,
-    gear::StateTransition,
+    gear::{ProgramCreation, StateTransition},
 };
 use ethexe_runtime_common::state::{
-    ActiveProgram, Allocations, DispatchStash, Expiring, Mailbox, MemoryPages, MemoryPagesRegion,
-    MessageQueue, MessageQueueHashWithSize, PayloadLookup, Program, ProgramState, Storage,
-    UserMailbox, Waitlist,
+    ActiveProgram, Allocations, DispatchStash, Expiring, GasReservations, Mailbox, MemoryPages,
+    MemoryPagesRegion, MessageQueue, MessageQueueHashWithSize, PayloadLookup, Program,
+    ProgramState, Storage, UserMailbox, Waitlist,
 };
 use gear_core::{
     buffer::Payload,
@@ -251,6 +251,13 @@ node! {
                 pub mb_committed_message_ids: BTreeSet<MessageId>,
             }
         ),
+        MbProgramCreations(
+            #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+            pub struct MbProgramCreationsNode {
+                pub mb_hash: H256,
+                pub mb_program_creations: Vec<ProgramCreation>,
+            }
+        ),
         StateTransition(
             #[derive(Debug, Clone, Eq, PartialEq, Hash)]
             pub struct StateTransitionNode {
@@ -263,6 +270,12 @@ node! {
                 pub allocations: Allocations,
             }
         ),
+        GasReservations(
+            #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+            pub struct GasReservationsNode {
+                pub gas_reservations: GasReservations,
+            }
+        ),
         MemoryPages[Box<>](
             #[derive(Debug, Clone, Eq, PartialEq, Hash)]
             pub struct MemoryPagesNode {
@@ -378,6 +391,7 @@ pub enum DatabaseIteratorError {
     NoMailbox(HashOf<Mailbox>),
     NoUserMailbox(HashOf<UserMailbox>),
     NoAllocations(HashOf<Allocations>),
+    NoGasReservations(HashOf<GasReservations>),
     NoProgramState(H256),
     NoPayload(HashOf<Payload>),
     NoProgramCodeId(ActorId),
@@ -462,8 +476,10 @@ where
             Node::ScheduledTask(node) => self.iter_scheduled_task(*node),
             Node::MbOutcome(node) => self.iter_mb_outcome(node),
             Node::MbCommittedMessageIds(_) => {}
+            Node::MbProgramCreations(_) => {}
             Node::StateTransition(node) => self.iter_state_transition(node),
             Node::Allocations(_) => {}
+            Node::GasReservations(_) => {}
             Node::MemoryPages(node) => self.iter_memory_pages(node),
             Node::MemoryPagesRegion(node) => self.iter_memory_pages_region(node),
             Node::PageData(_) => {}
@@ -573,6 +589,13 @@ where
                     mb_committed_message_ids,
                 });
             }
+            // Absence is valid: it's written only when programs were created by programs.
+            if let Some(mb_program_creations) = self.storage.mb_program_creations(mb_hash) {
+                self.push_node(MbProgramCreationsNode {
+                    mb_hash,
+                    mb_program_creations,
+                });
+            }
             try_push_node!(with_hash: self.mb_program_states(mb_hash));
         }
     }
@@ -680,6 +703,7 @@ where
         if let Program::Active(ActiveProgram {
             allocations_hash,
             pages_hash,
+            gas_reservations_hash,
             memory_infix: _,
             initialized: _,
         }) = program
@@ -688,6 +712,10 @@ where
                 try_push_node!(no_hash: self.allocations(allocations));
             }
 
+            if let Some(gas_reservations) = gas_reservations_hash.to_inner() {
+                try_push_node!(no_hash: self.gas_reservations(gas_reservations));
+            }
+
             if let Some(memory_pages) = pages_hash.to_inner() {
                 if let Some(x) = self.storage.memory_pages(memory_pages) {
                     self.push_node(Node::MemoryPages(Box::new(MemoryPagesNode {
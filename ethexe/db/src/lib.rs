@@ -112,6 +112,11 @@ pub trait KVDatabase: Send + Sync {
     /// Put (insert) value by key.
     fn put(&self, key: &[u8], data: Vec<u8>);
 
+    /// Put (insert) several values at once.
+    ///
+    /// Persistent backends write the whole batch atomically.
+    fn put_batch(&self, batch: Vec<(Vec<u8>, Vec<u8>)>);
+
     fn iter_prefix<'a>(
         &'a self,
         prefix: &'a [u8],
@@ -158,6 +163,17 @@ mod tests {
         assert_eq!(db.get(key.as_slice()), Some(data));
     }
 
+    pub fn kv_put_batch<DB: KVDatabase>(db: DB) {
+        db.put(b"key1", b"old".to_vec());
+        db.put_batch(vec![
+            (b"key1".to_vec(), b"value1".to_vec()),
+            (b"key2".to_vec(), b"value2".to_vec()),
+            (b"key2".to_vec(), b"latest".to_vec()),
+        ]);
+        assert_eq!(db.get(b"key1"), Some(b"value1".to_vec()));
+        assert_eq!(db.get(b"key2"), Some(b"latest".to_vec()));
+    }
+
     pub fn kv_iter_prefix<DB: KVDatabase>(db: DB) {
         let testcase = |prefix: &str, expectations: &[(&str, &str)]| {
             let actual: BTreeSet<_> = db.iter_prefix(prefix.as_bytes()).collect();
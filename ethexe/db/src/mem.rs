@@ -58,6 +58,12 @@ impl KVDatabase for MemDb {
         self.inner.insert(key.to_vec(), value);
     }
 
+    fn put_batch(&self, batch: Vec<(Vec<u8>, Vec<u8>)>) {
+        for (key, value) in batch {
+            self.inner.insert(key, value);
+        }
+    }
+
     fn iter_prefix<'a>(
         &'a self,
         prefix: &'a [u8],
@@ -96,6 +102,11 @@ mod tests {
         tests::kv_read_write(MemDb::default());
     }
 
+    #[test]
+    fn kv_put_batch() {
+        tests::kv_put_batch(MemDb::default());
+    }
+
     #[test]
     fn kv_iter_prefix() {
         tests::kv_iter_prefix(MemDb::default());
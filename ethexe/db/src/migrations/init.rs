@@ -1,12 +1,13 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-use std::collections::BTreeMap;
-
 use super::{InitConfig, LATEST_VERSION, migrate};
-use crate::{Database, RawDatabase, dump::StateDump, migrations::GenesisInitializer};
+use crate::{
+    Database, RawDatabase, dump::StateDumpHeader, migrations::GenesisInitializer,
+    verifier::IntegrityVerifier,
+};
 use alloy::providers::{Provider as _, RootProvider};
-use anyhow::{Context as _, Result, ensure};
+use anyhow::{Context as _, Result, anyhow, ensure};
 use ethexe_common::{
     BlockHeader, ProgramStates, ProtocolTimelines, Schedule, SimpleBlockData,
     StateHashWithQueueSize,
@@ -188,14 +189,17 @@ async fn genesis_data_initialization(
 ) -> Result<(ProgramStates, Schedule)> {
     log::info!("Start genesis {genesis_eb} data initialization...");
 
-    let StateDump {
-        metadata: _,
-        eb_hash,
-        codes,
-        programs,
+    let (
+        StateDumpHeader {
+            metadata: _,
+            eb_hash,
+            codes,
+            programs,
+        },
         blobs,
-    } = initializer.get_genesis_data()?;
+    ) = initializer.get_genesis_data()?;
 
+    // Dumps of another chain are allowed, e.g. to fork production state locally.
     if eb_hash != genesis_eb.hash {
         log::warn!(
             "Genesis data block hash {eb_hash} does not match the actual genesis block hash {}",
@@ -204,31 +208,24 @@ async fn genesis_data_initialization(
     }
 
     log::info!(
-        "Genesis data for ethereum block {eb_hash} \
-         contains {} codes, {} programs, {} blobs",
+        "Genesis data for ethereum block {eb_hash} contains {} codes, {} programs",
         codes.len(),
         programs.len(),
-        blobs.len()
     );
 
-    let mut code_bytes = BTreeMap::<CodeId, Vec<u8>>::new();
+    let mut blobs_amount = 0;
     for blob in blobs {
-        let hash = db.cas.write(&blob);
-        let code_id = CodeId::from(hash.0);
-        if codes.contains(&code_id) {
-            code_bytes.insert(code_id, blob);
-        };
+        let blob = blob.context("Failed to read genesis data blob")?;
+        db.cas.write(&blob);
+        blobs_amount += 1;
     }
-
-    ensure!(
-        code_bytes.len() == codes.len(),
-        "Genesis data contains {} valid codes, but only {} code blobs were provided",
-        codes.len(),
-        code_bytes.len()
-    );
+    log::info!("Genesis data blobs written: {blobs_amount}");
 
     let code_processing_futures = FuturesUnordered::new();
-    for (code_id, code) in code_bytes {
+    for &code_id in &codes {
+        let code = db.cas.read(code_id.into()).with_context(|| {
+            format!("Genesis data contains valid code {code_id}, but its blob is missing")
+        })?;
         let process = initializer.process_code(code_id, code);
         let db_clone = db.clone();
         code_processing_futures.push(async move {
@@ -241,8 +238,7 @@ async fn genesis_data_initialization(
                 return Ok::<_, anyhow::Error>(());
             };
 
-            // Should not happen because we checked that code_bytes.len() == codes.len(),
-            // so all codes must be present in the database.
+            // Should not happen because code has been read from the database above.
             ensure!(
                 db_clone.original_code_exists(code_id),
                 "code {code_id} must be already present in database",
@@ -263,6 +259,10 @@ async fn genesis_data_initialization(
 
     let mut program_states = ProgramStates::new();
     for (program_id, (code_id, state_hash)) in programs {
+        ensure!(
+            codes.contains(&code_id),
+            "Incorrect genesis data: program {program_id} has unknown code {code_id}"
+        );
         db.set_program_code_id(program_id, code_id);
         let program_state = db
             .cas
@@ -278,6 +278,16 @@ async fn genesis_data_initialization(
         );
     }
 
+    IntegrityVerifier::new(Database::with_memory_kv(&*db.cas))
+        .verify_program_states(program_states.values().map(|state| state.hash))
+        .map_err(|errors| {
+            anyhow!(
+                "Incorrect genesis data: program states are incomplete, {} errors, first: {:?}",
+                errors.len(),
+                errors[0]
+            )
+        })?;
+
     let schedule = ScheduleRestorer::from_storage(&db.cas, &program_states)?.restore();
     log::info!(
         "Genesis schedule restored, tasks amount {}",
@@ -288,3 +298,50 @@ async fn genesis_data_initialization(
 
     Ok((program_states, schedule))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CodeProcessingFuture, MemDb,
+        dump::{StateDump, StateDumpBlobs},
+    };
+
+    struct DumpInitializer(Option<StateDump>);
+
+    impl GenesisInitializer for DumpInitializer {
+        fn get_genesis_data(&mut self) -> Result<(StateDumpHeader, StateDumpBlobs)> {
+            self.0
+                .take()
+                .map(StateDump::into_parts)
+                .context("genesis data is taken twice")
+        }
+
+        fn process_code(&mut self, _code_id: CodeId, _code: Vec<u8>) -> CodeProcessingFuture {
+            unreachable!("dump has no codes")
+        }
+    }
+
+    #[tokio::test]
+    async fn dump_for_other_block_is_accepted() {
+        let db = RawDatabase::from_one(&MemDb::default());
+        let dump = StateDump {
+            metadata: H256::zero(),
+            eb_hash: H256::from_low_u64_be(1),
+            codes: Default::default(),
+            programs: Default::default(),
+            blobs: Default::default(),
+        };
+        let genesis_eb = SimpleBlockData {
+            hash: H256::from_low_u64_be(2),
+            header: BlockHeader::dummy(0),
+        };
+
+        let (program_states, schedule) =
+            genesis_data_initialization(Box::new(DumpInitializer(Some(dump))), &db, genesis_eb)
+                .await
+                .unwrap();
+        assert!(program_states.is_empty());
+        assert!(schedule.is_empty());
+    }
+}
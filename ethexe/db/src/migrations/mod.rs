@@ -4,7 +4,10 @@
 use self::migration::Migration;
 #[cfg(feature = "mock")]
 use crate::{Database, MemDb};
-use crate::{RawDatabase, dump::StateDump};
+use crate::{
+    RawDatabase,
+    dump::{StateDumpBlobs, StateDumpHeader},
+};
 use anyhow::{Context, Result};
 use futures::future::BoxFuture;
 use gear_core::code::{CodeMetadata, InstrumentedCode};
@@ -12,16 +15,18 @@ use gprimitives::CodeId;
 use gsigner::Address;
 
 pub use init::initialize_db;
+pub(crate) use v2::decode_program_state;
 
 mod init;
 mod migration;
 mod v1;
+mod v2;
 
-pub const LATEST_VERSION: u32 = v1::VERSION;
+pub const LATEST_VERSION: u32 = v2::VERSION;
 
 pub const OLDEST_SUPPORTED_VERSION: u32 = v1::VERSION;
 
-pub const MIGRATIONS: &[&dyn Migration] = &[];
+pub const MIGRATIONS: &[&dyn Migration] = &[&v2::MigrationFromV1];
 
 const _: () = assert!(
     (LATEST_VERSION - OLDEST_SUPPORTED_VERSION) as usize == MIGRATIONS.len(),
@@ -32,7 +37,8 @@ pub type CodeProcessingFuture =
     BoxFuture<'static, anyhow::Result<Option<(InstrumentedCode, CodeMetadata)>>>;
 
 pub trait GenesisInitializer {
-    fn get_genesis_data(&mut self) -> anyhow::Result<StateDump>;
+    /// Returns the genesis state dump header and its blobs, which are consumed incrementally.
+    fn get_genesis_data(&mut self) -> anyhow::Result<(StateDumpHeader, StateDumpBlobs)>;
     fn process_code(&mut self, code_id: CodeId, code: Vec<u8>) -> CodeProcessingFuture;
 }
 
@@ -0,0 +1,281 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Version 2 adds gas reservations to [`ActiveProgram`].
+//!
+//! Program states referenced by the per-MB program states maps are re-encoded
+//! with an empty reservations hash, and the maps are updated to point at the
+//! new state hashes. State hashes recorded in MB outcomes and committed to the
+//! `Router` can't be changed, so their v1 blobs are read with
+//! [`decode_program_state`].
+
+use super::{InitConfig, migration::Migration};
+use crate::RawDatabase;
+use anyhow::{Context, Result};
+use ethexe_common::{MaybeHashOf, ProgramStates};
+use ethexe_runtime_common::state::{
+    ActiveProgram, Allocations, DispatchStash, Mailbox, MemoryPages, MessageQueueHashWithSize,
+    Program, ProgramState, Waitlist,
+};
+use gear_core::{message::Value, program::MemoryInfix};
+use gprimitives::{ActorId, H256};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use std::{collections::HashMap, pin::Pin};
+
+pub const VERSION: u32 = 2;
+
+/// Discriminant of the `MbProgramStates` key as of v1.
+const MB_PROGRAM_STATES_KEY: u64 = 19;
+
+pub struct MigrationFromV1;
+
+impl Migration for MigrationFromV1 {
+    fn source_version(&self) -> u32 {
+        super::v1::VERSION
+    }
+
+    fn migrate<'a>(
+        &'a self,
+        _config: &'a InitConfig,
+        db: &'a RawDatabase,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + 'a>> {
+        Box::pin(async move { migrate_program_states(db) })
+    }
+}
+
+fn migrate_program_states(db: &RawDatabase) -> Result<()> {
+    let prefix = H256::from_low_u64_be(MB_PROGRAM_STATES_KEY);
+    let entries: Vec<_> = db.kv.iter_prefix(prefix.as_bytes()).collect();
+
+    // Most programs don't change between MBs, so states are shared between maps.
+    let mut migrated = HashMap::new();
+
+    for (key, value) in entries {
+        let mut states = ProgramStates::decode(&mut value.as_slice())
+            .context("failed to decode MB program states")?;
+
+        for state in states.values_mut() {
+            if state.hash.is_zero() {
+                continue;
+            }
+
+            state.hash = match migrated.get(&state.hash) {
+                Some(hash) => *hash,
+                None => {
+                    let new_hash = migrate_program_state(db, state.hash)?;
+                    migrated.insert(state.hash, new_hash);
+                    new_hash
+                }
+            };
+        }
+
+        db.kv.put(&key, states.encode());
+    }
+
+    log::info!("Migrated {} program states to v{VERSION}", migrated.len());
+
+    let mut config = db.kv.config()?;
+    config.version = VERSION;
+    db.kv.set_config(config);
+
+    Ok(())
+}
+
+fn migrate_program_state(db: &RawDatabase, hash: H256) -> Result<H256> {
+    let data = db
+        .cas
+        .read(hash)
+        .with_context(|| format!("program state {hash} is not found"))?;
+
+    match ProgramStateV1::decode_all(&mut data.as_slice()) {
+        Ok(state) => Ok(db.cas.write(&ProgramState::from(state).encode())),
+        // Already migrated by an interrupted previous run.
+        Err(_) if ProgramState::decode_all(&mut data.as_slice()).is_ok() => Ok(hash),
+        Err(err) => Err(err).with_context(|| format!("failed to decode program state {hash}")),
+    }
+}
+
+/// Decodes a program state blob of either the current or the v1 encoding.
+pub(crate) fn decode_program_state(data: &[u8]) -> Result<ProgramState, parity_scale_codec::Error> {
+    ProgramState::decode_all(&mut &data[..]).or_else(|err| {
+        ProgramStateV1::decode_all(&mut &data[..])
+            .map(Into::into)
+            .map_err(|_| err)
+    })
+}
+
+#[derive(Encode, Decode)]
+struct ActiveProgramV1 {
+    allocations_hash: MaybeHashOf<Allocations>,
+    pages_hash: MaybeHashOf<MemoryPages>,
+    memory_infix: MemoryInfix,
+    initialized: bool,
+}
+
+#[derive(Encode, Decode)]
+enum ProgramV1 {
+    Active(ActiveProgramV1),
+    Exited(ActorId),
+    Terminated(ActorId),
+}
+
+#[derive(Encode, Decode)]
+struct ProgramStateV1 {
+    program: ProgramV1,
+    canonical_queue: MessageQueueHashWithSize,
+    injected_queue: MessageQueueHashWithSize,
+    waitlist_hash: MaybeHashOf<Waitlist>,
+    stash_hash: MaybeHashOf<DispatchStash>,
+    mailbox_hash: MaybeHashOf<Mailbox>,
+    balance: Value,
+    executable_balance: Value,
+}
+
+impl From<ProgramStateV1> for ProgramState {
+    fn from(state: ProgramStateV1) -> Self {
+        let program = match state.program {
+            ProgramV1::Active(ActiveProgramV1 {
+                allocations_hash,
+                pages_hash,
+                memory_infix,
+                initialized,
+            }) => Program::Active(ActiveProgram {
+                allocations_hash,
+                pages_hash,
+                gas_reservations_hash: MaybeHashOf::empty(),
+                memory_infix,
+                initialized,
+            }),
+            ProgramV1::Exited(inheritor) => Program::Exited(inheritor),
+            ProgramV1::Terminated(inheritor) => Program::Terminated(inheritor),
+        };
+
+        Self {
+            program,
+            canonical_queue: state.canonical_queue,
+            injected_queue: state.injected_queue,
+            waitlist_hash: state.waitlist_hash,
+            stash_hash: state.stash_hash,
+            mailbox_hash: state.mailbox_hash,
+            balance: state.balance,
+            executable_balance: state.executable_balance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Database, KVDatabase, MemDb};
+    use ethexe_common::{
+        Address, ProtocolTimelines, StateHashWithQueueSize,
+        db::{DBConfig, MbStorageRO},
+    };
+    use ethexe_runtime_common::state::Storage;
+
+    #[test]
+    fn program_states_are_reencoded() {
+        let mem_db = MemDb::default();
+        let raw = RawDatabase::from_one(&mem_db);
+
+        <dyn KVDatabase>::set_config(
+            &mem_db,
+            DBConfig {
+                version: super::super::v1::VERSION,
+                chain_id: 0,
+                router_address: Address([0; 20]),
+                timelines: ProtocolTimelines {
+                    genesis_ts: 0,
+                    era: 1.try_into().unwrap(),
+                    election: 0,
+                    slot: 1.try_into().unwrap(),
+                },
+                genesis_block_hash: H256::zero(),
+                max_validators: 10,
+            },
+        );
+
+        let v1_state = |program| ProgramStateV1 {
+            program,
+            canonical_queue: MessageQueueHashWithSize {
+                hash: MaybeHashOf::empty(),
+                cached_queue_size: 1,
+            },
+            injected_queue: MessageQueueHashWithSize {
+                hash: MaybeHashOf::empty(),
+                cached_queue_size: 0,
+            },
+            waitlist_hash: MaybeHashOf::empty(),
+            stash_hash: MaybeHashOf::empty(),
+            mailbox_hash: MaybeHashOf::empty(),
+            balance: 42,
+            executable_balance: 1_000,
+        };
+        let active = raw.cas.write(
+            &v1_state(ProgramV1::Active(ActiveProgramV1 {
+                allocations_hash: MaybeHashOf::empty(),
+                pages_hash: MaybeHashOf::empty(),
+                memory_infix: MemoryInfix::new(3),
+                initialized: true,
+            }))
+            .encode(),
+        );
+        let exited = raw
+            .cas
+            .write(&v1_state(ProgramV1::Exited(ActorId::from(7))).encode());
+
+        let states = |active, exited| -> ProgramStates {
+            [
+                (ActorId::from(1), active),
+                (ActorId::from(2), exited),
+                (ActorId::from(3), StateHashWithQueueSize::zero()),
+            ]
+            .into_iter()
+            .collect()
+        };
+        let with_hash = |hash| StateHashWithQueueSize {
+            hash,
+            canonical_queue_size: 1,
+            injected_queue_size: 0,
+        };
+
+        let mb_hashes = [H256::from_low_u64_be(1), H256::from_low_u64_be(2)];
+        for mb_hash in mb_hashes {
+            let mut key = H256::from_low_u64_be(MB_PROGRAM_STATES_KEY).0.to_vec();
+            key.extend(mb_hash.as_bytes());
+            raw.kv
+                .put(&key, states(with_hash(active), with_hash(exited)).encode());
+        }
+
+        migrate_program_states(&raw).unwrap();
+
+        let db = Database::try_from_raw(raw.clone()).unwrap();
+        let migrated = db.mb_program_states(mb_hashes[0]).unwrap();
+        assert_eq!(db.mb_program_states(mb_hashes[1]).unwrap(), migrated);
+
+        let active_state = db.program_state(migrated[&ActorId::from(1)].hash).unwrap();
+        assert_eq!(
+            active_state.program,
+            Program::Active(ActiveProgram {
+                allocations_hash: MaybeHashOf::empty(),
+                pages_hash: MaybeHashOf::empty(),
+                gas_reservations_hash: MaybeHashOf::empty(),
+                memory_infix: MemoryInfix::new(3),
+                initialized: true,
+            })
+        );
+        assert_eq!(active_state.balance, 42);
+        assert_eq!(active_state.executable_balance, 1_000);
+
+        // Encoding of exited and terminated programs is unchanged.
+        assert_eq!(migrated[&ActorId::from(2)].hash, exited);
+        assert_eq!(migrated[&ActorId::from(3)], StateHashWithQueueSize::zero());
+
+        // Hashes committed before the migration still point at readable v1 blobs.
+        assert_eq!(db.program_state(active).unwrap(), active_state);
+
+        // Repeated run keeps already migrated states.
+        migrate_program_states(&raw).unwrap();
+        assert_eq!(db.mb_program_states(mb_hashes[0]).unwrap(), migrated);
+    }
+}
@@ -114,6 +114,13 @@ impl KVDatabase for KVOverlay {
         self.mem.put(key, value)
     }
 
+    fn put_batch(&self, batch: Vec<(Vec<u8>, Vec<u8>)>) {
+        for (key, _) in &batch {
+            self.erased_keys.remove(key);
+        }
+        self.mem.put_batch(batch)
+    }
+
     fn iter_prefix<'a>(
         &'a self,
         prefix: &'a [u8],
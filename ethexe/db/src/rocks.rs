@@ -4,7 +4,7 @@
 use crate::{CASDatabase, KVDatabase};
 use anyhow::Result;
 use gprimitives::H256;
-use rocksdb::{DB, DBIteratorWithThreadMode, Options};
+use rocksdb::{DB, DBIteratorWithThreadMode, Options, WriteBatch};
 use std::{path::PathBuf, sync::Arc};
 
 /// Database for storing states and codes in memory.
@@ -93,6 +93,17 @@ impl KVDatabase for RocksDatabase {
             .expect("Failed to write data, database is not in valid state");
     }
 
+    fn put_batch(&self, batch: Vec<(Vec<u8>, Vec<u8>)>) {
+        let mut write_batch = WriteBatch::default();
+        for (key, value) in batch {
+            write_batch.put(key, value);
+        }
+
+        self.inner
+            .write(write_batch)
+            .expect("Failed to write data, database is not in valid state");
+    }
+
     fn iter_prefix<'a>(
         &'a self,
         prefix: &'a [u8],
@@ -200,6 +211,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn kv_put_batch() {
+        with_database(|db| {
+            tests::kv_put_batch(db);
+        });
+    }
+
     #[test]
     fn kv_iter_prefix() {
         with_database(|db| {
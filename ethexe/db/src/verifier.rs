@@ -3,19 +3,19 @@
 
 use crate::{
     Database,
-    iterator::{ChainNode, DatabaseIteratorError, DatabaseIteratorStorage},
+    iterator::{ChainNode, DatabaseIteratorError, DatabaseIteratorStorage, ProgramStateNode},
     visitor::{DatabaseVisitor, walk},
 };
 use ethexe_common::{
     BlockHeader, HashOf, ScheduledTask,
     db::{BlockMeta, MbStorageRO},
 };
-use ethexe_runtime_common::state::{MessageQueue, MessageQueueHashWithSize};
+use ethexe_runtime_common::state::{MessageQueue, MessageQueueHashWithSize, Storage};
 use gear_core::code::CodeMetadata;
 use gprimitives::{CodeId, H256};
 use parity_scale_codec::Encode;
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeSet, HashMap, HashSet},
     hash::Hash,
 };
 
@@ -91,10 +91,33 @@ impl IntegrityVerifier {
         self.bottom = Some(bottom);
         walk(&mut self, ChainNode { head, bottom });
 
+        self.finish()
+    }
+
+    /// Verifies that program states are complete, i.e. all the blobs they refer to are present.
+    pub fn verify_program_states(
+        mut self,
+        state_hashes: impl IntoIterator<Item = H256>,
+    ) -> Result<(), Vec<IntegrityVerifierError>> {
+        for state_hash in state_hashes.into_iter().collect::<BTreeSet<_>>() {
+            match self.db.program_state(state_hash) {
+                Some(program_state) => walk(&mut self, ProgramStateNode { program_state }),
+                None => self.errors.push(IntegrityVerifierError::DatabaseIterator(
+                    DatabaseIteratorError::NoProgramState(state_hash),
+                )),
+            }
+        }
+
+        // Program states may share blobs, so the same blob may be reported missing several times.
+        let mut reported = HashSet::new();
+        self.errors.retain(|error| reported.insert(*error));
+
+        self.finish()
+    }
+
+    fn finish(self) -> Result<(), Vec<IntegrityVerifierError>> {
         #[cfg(debug_assertions)]
         {
-            use std::collections::HashSet;
-
             self.errors
                 .clone()
                 .into_iter()
@@ -282,7 +305,7 @@ mod tests {
         Digest, MaybeHashOf,
         db::{BlockMetaStorageRW, CodesStorageRW, OnChainStorageRW},
     };
-    use ethexe_runtime_common::state::Storage;
+    use ethexe_runtime_common::state::ProgramState;
     use gear_core::{
         code::{CodeMetadata, InstantiatedSectionSizes, InstrumentationStatus, InstrumentedCode},
         pages::WasmPagesAmount,
@@ -609,6 +632,38 @@ mod tests {
         assert_eq!(verifier.errors, []);
     }
 
+    #[test]
+    fn test_verify_program_states() {
+        let db = setup_db();
+
+        let complete = db.write_program_state(ProgramState::zero());
+        IntegrityVerifier::new(db.clone())
+            .verify_program_states([complete])
+            .unwrap();
+
+        // Two states sharing the same missing waitlist.
+        let waitlist_hash = unsafe { HashOf::new(H256::random()) };
+        let incomplete = [1, 2].map(|balance| {
+            db.write_program_state(ProgramState {
+                waitlist_hash: MaybeHashOf::from(Some(waitlist_hash)),
+                balance,
+                ..ProgramState::zero()
+            })
+        });
+        let missing = H256::random();
+
+        let errors = IntegrityVerifier::new(db)
+            .verify_program_states(incomplete.into_iter().chain([complete, missing]))
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(&IntegrityVerifierError::DatabaseIterator(
+            DatabaseIteratorError::NoWaitlist(waitlist_hash)
+        )));
+        assert!(errors.contains(&IntegrityVerifierError::DatabaseIterator(
+            DatabaseIteratorError::NoProgramState(missing)
+        )));
+    }
+
     #[test]
     fn test_multiple_errors_collected() {
         let db = setup_db();
@@ -9,8 +9,8 @@ use ethexe_common::{
     gear::StateTransition,
 };
 use ethexe_runtime_common::state::{
-    Allocations, DispatchStash, Mailbox, MemoryPages, MemoryPagesRegion, MessageQueue,
-    MessageQueueHashWithSize, PayloadLookup, ProgramState, UserMailbox, Waitlist,
+    Allocations, DispatchStash, GasReservations, Mailbox, MemoryPages, MemoryPagesRegion,
+    MessageQueue, MessageQueueHashWithSize, PayloadLookup, ProgramState, UserMailbox, Waitlist,
 };
 use gear_core::{
     buffer::Payload,
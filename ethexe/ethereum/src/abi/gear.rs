@@ -22,6 +22,11 @@ impl From<AggregatedPublicKey> for Gear::AggregatedPublicKey {
 impl From<ChainCommitment> for Gear::ChainCommitment {
     fn from(value: ChainCommitment) -> Self {
         Self {
+            programCreations: value
+                .program_creations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
             transitions: value.transitions.into_iter().map(Into::into).collect(),
             head: value.head.0.into(),
             lastAdvancedEthBlock: value.last_advanced_eth_block.0.into(),
@@ -158,6 +163,17 @@ impl From<ReplyDetails> for Gear::ReplyDetails {
     }
 }
 
+impl From<ProgramCreation> for Gear::ProgramCreation {
+    fn from(value: ProgramCreation) -> Self {
+        Self {
+            actorId: actor_id_to_address_lossy(value.actor_id),
+            parent: actor_id_to_address_lossy(value.parent),
+            codeId: code_id_to_bytes32(value.code_id),
+            salt: h256_to_bytes32(value.salt),
+        }
+    }
+}
+
 impl From<StateTransition> for Gear::StateTransition {
     fn from(value: StateTransition) -> Self {
         Self {
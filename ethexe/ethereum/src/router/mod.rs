@@ -19,13 +19,13 @@ use alloy::{
         PendingTransactionBuilder, Provider, ProviderBuilder, RootProvider,
         utils::{Eip1559Estimation, Eip1559Estimator},
     },
-    rpc::types::{TransactionReceipt, eth::state::AccountOverride},
+    rpc::types::{Filter, Topic, TransactionReceipt, eth::state::AccountOverride},
 };
 use anyhow::{Result, anyhow};
 use ethexe_common::{
     Address, Digest, ValidatorsVec,
     ecdsa::ContractSignature,
-    events::router::CodeGotValidatedEvent,
+    events::router::{CodeGotValidatedEvent, ProgramCreatedEvent},
     gear::{
         AggregatedPublicKey, BatchCommitment, CodeState, ComputationSettings, SignatureType,
         Timelines,
@@ -41,7 +41,10 @@ use futures::StreamExt;
 use gear_core::ids::prelude::CodeIdExt as _;
 use gprimitives::{ActorId, CodeId, H256};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    ops::RangeInclusive,
+};
 
 pub mod events;
 
@@ -575,6 +578,15 @@ pub struct CodeValidationResult {
     pub block_number: Option<u64>,
 }
 
+/// Programs created in a range of blocks, split by where they were created.
+#[derive(Clone, Debug, Default)]
+pub struct CreatedPrograms {
+    /// Programs created by other programs, committed together with MBs.
+    pub by_programs: BTreeSet<ActorId>,
+    /// Programs created by Ethereum transactions.
+    pub on_ethereum: BTreeSet<ActorId>,
+}
+
 #[derive(Clone)]
 pub struct RouterQuery {
     instance: QueryInstance,
@@ -891,6 +903,46 @@ impl RouterQuery {
         Ok(count)
     }
 
+    /// Returns programs created within `blocks` (inclusive block numbers).
+    pub async fn created_programs(&self, blocks: RangeInclusive<u64>) -> Result<CreatedPrograms> {
+        let filter = Filter::new()
+            .address(*self.instance.address())
+            .event_signature(Topic::from_iter([
+                signatures::MB_COMMITTED,
+                signatures::PROGRAM_CREATED,
+            ]))
+            .from_block(*blocks.start())
+            .to_block(*blocks.end());
+        let logs = self.instance.provider().get_logs(&filter).await?;
+
+        // Programs created by programs are emitted by the same transaction that commits the MB
+        let commitment_txs: HashSet<_> = logs
+            .iter()
+            .filter(|log| log.topic0() == Some(&signatures::MB_COMMITTED))
+            .filter_map(|log| log.transaction_hash)
+            .collect();
+
+        let mut created = CreatedPrograms::default();
+        for log in logs
+            .iter()
+            .filter(|log| log.topic0() == Some(&signatures::PROGRAM_CREATED))
+        {
+            let tx_hash = log
+                .transaction_hash
+                .ok_or_else(|| anyhow!("ProgramCreated log without transaction hash"))?;
+            let ProgramCreatedEvent { actor_id, .. } =
+                crate::decode_log::<IRouter::ProgramCreated>(log)?.into();
+
+            if commitment_txs.contains(&tx_hash) {
+                created.by_programs.insert(actor_id);
+            } else {
+                created.on_ethereum.insert(actor_id);
+            }
+        }
+
+        Ok(created)
+    }
+
     pub async fn validated_codes_count(&self) -> Result<u64> {
         self.validated_codes_count_at(BlockId::latest()).await
     }
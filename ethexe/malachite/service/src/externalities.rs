@@ -20,6 +20,8 @@
 //!   committed injected txs out of the mempool, advance
 //!   `globals.latest_finalized_mb_hash`, and fire
 //!   [`MalachiteEvent::BlockFinalized`].
+//! - MBs up to the fast-sync pivot are persisted but never fired:
+//!   their resulting state was fetched by fast-sync instead.
 //! - [`EthexeExternalities::build_block_above`] — when this node is
 //!   proposer, wait for proposable content (a new EB past quarantine
 //!   or a non-empty mempool), then assemble an [`Operations`] list.
@@ -132,6 +134,11 @@ impl Externalities for EthexeExternalities {
             meta.last_advanced_eb = last_advanced;
         });
 
+        if self.covered_by_fast_sync(mb.height) {
+            trace!(height = mb.height, %mb_hash, "MB is covered by fast-sync, not emitting");
+            return Ok(());
+        }
+
         self.try_emit_or_queue(
             MalachiteEvent::BlockProposal {
                 height: mb.height,
@@ -174,6 +181,11 @@ impl Externalities for EthexeExternalities {
             }
         }
 
+        if self.covered_by_fast_sync(cert.height) {
+            trace!(height = cert.height, %mb_hash, "MB is covered by fast-sync, not emitting");
+            return Ok(());
+        }
+
         self.db
             .globals_mutate(|g| g.latest_finalized_mb_hash = mb_hash);
 
@@ -451,6 +463,28 @@ impl Externalities for EthexeExternalities {
 }
 
 impl EthexeExternalities {
+    /// Check whether the MB at `height` is already covered by the fast-sync pivot,
+    /// i.e. the pivot MB committed at the start block or one of its ancestors.
+    /// Their state is already in local DB, while their prerequisite EBs are not,
+    /// so such MBs are only persisted and never emitted.
+    fn covered_by_fast_sync(&self, height: u64) -> bool {
+        use ethexe_common::db::BlockMetaStorageRO;
+        let start_block_hash = self.db.globals().start_block_hash;
+        let Some(pivot) = self
+            .db
+            .block_meta(start_block_hash)
+            .last_committed_mb
+            .filter(|pivot| !pivot.is_zero())
+        else {
+            return false;
+        };
+
+        // Until the pivot itself is synced, every incoming MB is its ancestor.
+        self.db
+            .mb_compact_block(pivot)
+            .is_none_or(|pivot| height <= pivot.height)
+    }
+
     /// Check whether the prerequisite EB is prepared in local DB.
     /// Zero hash is a special case that always passes.
     fn prerequisite_satisfied(&self, prerequisite: H256) -> bool {
@@ -755,6 +789,46 @@ mod tests {
         }
     }
 
+    /// MBs up to the fast-sync pivot (the MB committed at the start
+    /// block) are persisted, but neither emitted nor advance globals;
+    /// MBs above the pivot are handled as usual.
+    #[tokio::test]
+    async fn fast_sync_pivot_ancestry_is_not_emitted() {
+        use ethexe_common::db::{GlobalsStorageRO, MbStorageRO};
+        let db = Database::memory();
+        let (ext, mut rx) = make_externalities(db.clone());
+
+        let pivot = wrap(payload(None, 1), 1, H256::zero());
+        let pivot_hash = pivot.hash();
+        let start_block = H256::random();
+        db.globals_mutate(|g| g.start_block_hash = start_block);
+        db.mutate_block_meta(start_block, |meta| {
+            meta.last_committed_mb = Some(pivot_hash);
+        });
+
+        ext.process_mb_proposal(pivot_hash, pivot).await.unwrap();
+        ext.process_mb_finalized(pivot_hash, fake_cert(1))
+            .await
+            .unwrap();
+        assert!(db.mb_compact_block(pivot_hash).is_some());
+        assert!(db.globals().latest_finalized_mb_hash.is_zero());
+        assert!(rx.try_recv().is_err());
+
+        let block = wrap(payload(None, 2), 2, pivot_hash);
+        let mb_hash = block.hash();
+        ext.process_mb_proposal(mb_hash, block).await.unwrap();
+        match rx.try_recv().expect("event").expect("ok") {
+            MalachiteEvent::BlockProposal {
+                height,
+                mb_hash: proposed,
+            } => {
+                assert_eq!(height, 2);
+                assert_eq!(proposed, mb_hash);
+            }
+            other => panic!("expected BlockProposal, got {other:?}"),
+        }
+    }
+
     /// Crash-recovery: build externalities A on a fresh DB, save +
     /// finalize K MBs, drop A, build externalities B on the same DB.
     /// B sees the persisted globals and `CompactMb` chain; the
@@ -1165,6 +1239,7 @@ mod tests {
             program: Program::Active(ActiveProgram {
                 allocations_hash: MaybeHashOf::empty(),
                 pages_hash: MaybeHashOf::empty(),
+                gas_reservations_hash: MaybeHashOf::empty(),
                 memory_infix: ethexe_common::gear_core::program::MemoryInfix::new(0),
                 initialized: true,
             }),
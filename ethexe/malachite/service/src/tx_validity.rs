@@ -336,6 +336,7 @@ mod tests {
             program: Program::Active(ActiveProgram {
                 allocations_hash: MaybeHashOf::empty(),
                 pages_hash: MaybeHashOf::empty(),
+                gas_reservations_hash: MaybeHashOf::empty(),
                 memory_infix: MemoryInfix::new(0),
                 initialized,
             }),
@@ -65,6 +65,9 @@ pub enum RequestFailure {
     /// Request had been processing for too long
     #[display("Request had been processing for too long")]
     Timeout,
+    /// Request timed out after peers' responses failed validation
+    #[display("Responses failed validation")]
+    InvalidResponse,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -186,11 +189,19 @@ pub struct HashesRequest(
     #[debug("{:?}", AlternateCollectionFmt::set(_0, "hashes"))] pub BTreeSet<H256>,
 );
 
-/// Request to fetch the program-to-code mapping visible at a specific block.
+/// Request to fetch the programs of an MB and verify their code ids
+/// using [`ExternalDataProvider`] at a specific block.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ProgramIdsRequest {
+    /// Ethereum block the response is verified at.
     pub at: H256,
+    /// MB whose program states are requested.
+    pub mb_hash: H256,
+    /// Number of programs in the MB.
     pub expected_count: u64,
+    /// Programs known at `at` which must not be in the MB,
+    /// e.g. ones created on Ethereum after the MB's block.
+    pub excluded_programs: BTreeSet<ActorId>,
 }
 
 /// Request to fetch the current set of valid codes and verify the response
@@ -206,7 +217,7 @@ pub struct ValidCodesRequest {
 pub enum Request {
     /// Fetch raw CAS blobs by hash.
     Hashes(HashesRequest),
-    /// Fetch the program-to-code mapping for a block.
+    /// Fetch the program-to-code mapping for an MB.
     ProgramIds(ProgramIdsRequest),
     /// Fetch the node's locally stored set of valid code IDs.
     ValidCodes(ValidCodesRequest),
@@ -218,9 +229,20 @@ impl Request {
         Self::Hashes(HashesRequest(request.into()))
     }
 
-    /// Build a request for program-to-code mappings at `at`.
-    pub fn program_ids(at: H256, expected_count: u64) -> Self {
-        Self::ProgramIds(ProgramIdsRequest { at, expected_count })
+    /// Build a request for program-to-code mappings of `mb_hash`, verified
+    /// at the Ethereum block `at`.
+    pub fn program_ids(
+        at: H256,
+        mb_hash: H256,
+        expected_count: u64,
+        excluded_programs: BTreeSet<ActorId>,
+    ) -> Self {
+        Self::ProgramIds(ProgramIdsRequest {
+            at,
+            mb_hash,
+            expected_count,
+            excluded_programs,
+        })
     }
 
     /// Build a request for the valid code set, using `at` only for response
@@ -238,7 +260,7 @@ impl Request {
 pub enum Response {
     /// Raw CAS blobs keyed by hash.
     Hashes(#[debug("{:?}", AlternateCollectionFmt::map(_0, "entries"))] BTreeMap<H256, Vec<u8>>),
-    /// Program-to-code mapping reconstructed for a block.
+    /// Program-to-code mapping reconstructed for an MB.
     ProgramIds(
         #[debug("{:?}", AlternateCollectionFmt::map(_0, "programs"))] BTreeMap<ActorId, CodeId>,
     ),
@@ -314,7 +336,7 @@ impl Handle {
 
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
 pub(crate) struct InnerProgramIdsRequest {
-    at: H256,
+    mb_hash: H256,
 }
 
 /// Network-only type to be encoded-decoded and sent over the network
@@ -1146,7 +1168,12 @@ pub(crate) mod tests {
         alice.connect(&mut bob).await;
         tokio::spawn(bob.loop_on_next());
 
-        let request = alice_handle.request(Request::program_ids(H256::zero(), 2));
+        let request = alice_handle.request(Request::program_ids(
+            H256::zero(),
+            H256::zero(),
+            2,
+            BTreeSet::new(),
+        ));
         let request_id = request.request_id();
 
         let event = alice.next_behaviour_event().await;
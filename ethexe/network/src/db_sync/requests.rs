@@ -281,8 +281,12 @@ pub enum HashesResponseError {
 
 #[derive(Debug, derive_more::Display)]
 pub enum ProgramIdsResponseError {
-    #[display("not enough program-code ids expected {expected} but got {received}")]
-    NotEnoughIds { expected: usize, received: usize },
+    #[display("unexpected number of program-code ids: expected {expected} but got {received}")]
+    UnexpectedIdsCount { expected: u64, received: usize },
+    #[display("program {_0} must not be in the MB")]
+    ExcludedProgram(ActorId),
+    #[display("program {_0} is unknown to router")]
+    UnknownProgram(ActorId),
     #[display("router failed: {_0}")]
     RouterQuery(anyhow::Error),
 }
@@ -358,10 +362,12 @@ impl ResponseHandler {
             ResponseHandler::ProgramIds {
                 request:
                     ProgramIdsRequest {
-                        at,
+                        at: _,
+                        mb_hash,
                         expected_count: _,
+                        excluded_programs: _,
                     },
-            } => InnerRequest::ProgramIds(InnerProgramIdsRequest { at: *at }),
+            } => InnerRequest::ProgramIds(InnerProgramIdsRequest { mb_hash: *mb_hash }),
             ResponseHandler::ValidCodes {
                 request:
                     ValidCodesRequest {
@@ -433,18 +439,32 @@ impl ResponseHandler {
         let InnerProgramIdsResponse(response) = response;
 
         if response.len() as u64 != request.expected_count {
-            return Err(ProgramIdsResponseError::NotEnoughIds {
-                expected: request.expected_count as usize,
+            return Err(ProgramIdsResponseError::UnexpectedIdsCount {
+                expected: request.expected_count,
                 received: response.len(),
             });
         }
 
+        if let Some(&program_id) = response
+            .iter()
+            .find(|program_id| request.excluded_programs.contains(program_id))
+        {
+            return Err(ProgramIdsResponseError::ExcludedProgram(program_id));
+        }
+
         let code_ids = external_data_provider
             .programs_code_ids_at(response.clone(), request.at)
             .await
             .context("failed to get code ids at block")
             .map_err(ProgramIdsResponseError::RouterQuery)?;
 
+        // router returns zero code id for programs it doesn't know
+        if let Some((program_id, _)) =
+            iter::zip(&response, &code_ids).find(|(_, code_id)| code_id.is_zero())
+        {
+            return Err(ProgramIdsResponseError::UnknownProgram(*program_id));
+        }
+
         let program_code_ids = iter::zip(response, code_ids).collect();
         Ok(program_code_ids)
     }
@@ -554,6 +574,8 @@ impl ResponseHandler {
 struct OngoingRequest {
     response_handler: Option<ResponseHandler>,
     tried_peers: HashSet<PeerId>,
+    /// Whether any response has been rejected during the current attempt.
+    response_rejected: bool,
 }
 
 impl OngoingRequest {
@@ -561,6 +583,7 @@ impl OngoingRequest {
         Self {
             response_handler: Some(ResponseHandler::new(request)),
             tried_peers: Default::default(),
+            response_rejected: false,
         }
     }
 
@@ -649,6 +672,7 @@ impl OngoingRequest {
                 log::warn!("response processing failed for request from {peer}: {err:?}");
                 peer_score_handle.invalid_data(peer);
                 self.response_handler = Some(handler);
+                self.response_rejected = true;
                 Err(())
             }
         }
@@ -660,23 +684,29 @@ impl OngoingRequest {
         external_data_provider: Box<dyn ExternalDataProvider>,
         request_timeout: Duration,
     ) -> Result<Response, (RequestFailure, Self)> {
+        self.response_rejected = false;
+
         let request_loop = async {
             loop {
                 match self
                     .next_round(&peer_score_handle, external_data_provider.clone_boxed())
                     .await
                 {
-                    Ok(response) => break Ok(response),
+                    Ok(response) => break response,
                     Err(()) => continue,
                 };
             }
         };
 
-        let res = time::timeout(request_timeout, request_loop)
-            .await
-            .map_err(|_elapsed| RequestFailure::Timeout)
-            .and_then(|res| res);
-        res.map_err(|failure| (failure, self))
+        let res = time::timeout(request_timeout, request_loop).await;
+        res.map_err(|_elapsed| {
+            let failure = if self.response_rejected {
+                RequestFailure::InvalidResponse
+            } else {
+                RequestFailure::Timeout
+            };
+            (failure, self)
+        })
     }
 }
 
@@ -728,6 +758,7 @@ impl RetriableRequest {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use assert_matches::assert_matches;
     use async_trait::async_trait;
 
     struct UnreachableExternalDataProvider;
@@ -796,6 +827,54 @@ mod tests {
         assert!(!stripped);
     }
 
+    #[tokio::test]
+    async fn validate_program_ids_count() {
+        let request = ProgramIdsRequest {
+            at: H256::zero(),
+            mb_hash: H256::zero(),
+            expected_count: 2,
+            excluded_programs: Default::default(),
+        };
+
+        for count in [1, 3] {
+            let response = InnerProgramIdsResponse((0..count).map(ActorId::from).collect());
+            let err = ResponseHandler::handle_program_ids(
+                response,
+                &request,
+                Box::new(UnreachableExternalDataProvider),
+            )
+            .await
+            .unwrap_err();
+            assert_matches!(
+                err,
+                ProgramIdsResponseError::UnexpectedIdsCount { received, .. } if received == count as usize
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_program_ids_excluded() {
+        let request = ProgramIdsRequest {
+            at: H256::zero(),
+            mb_hash: H256::zero(),
+            expected_count: 2,
+            excluded_programs: [ActorId::from(1)].into(),
+        };
+
+        let response = InnerProgramIdsResponse([ActorId::from(0), ActorId::from(1)].into());
+        let err = ResponseHandler::handle_program_ids(
+            response,
+            &request,
+            Box::new(UnreachableExternalDataProvider),
+        )
+        .await
+        .unwrap_err();
+        assert_matches!(
+            err,
+            ProgramIdsResponseError::ExcludedProgram(program_id) if program_id == ActorId::from(1)
+        );
+    }
+
     #[tokio::test]
     async fn validate_data_hash_incomplete() {
         let hash1 = ethexe_db::hash(b"1");
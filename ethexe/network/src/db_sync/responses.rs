@@ -78,12 +78,12 @@ impl OngoingResponses {
                 InnerHashesResponse(response).into()
             }
             InnerRequest::ProgramIds(request) => {
-                let actor_ids = match db.mb_program_states(request.at) {
+                let actor_ids = match db.mb_program_states(request.mb_hash) {
                     Some(states) => states.into_keys().collect(),
                     None => {
                         log::warn!(
                             "mb_program_states({}) not found; responder returning empty set",
-                            request.at,
+                            request.mb_hash,
                         );
                         Default::default()
                     }
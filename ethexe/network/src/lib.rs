@@ -1099,7 +1099,12 @@ mod tests {
 
         let expected_response = fill_data_provider(alice_data_provider).await;
 
-        let request = alice_handle.request(db_sync::Request::program_ids(H256::zero(), 2));
+        let request = alice_handle.request(db_sync::Request::program_ids(
+            H256::zero(),
+            H256::zero(),
+            2,
+            Default::default(),
+        ));
         let response = timeout(Duration::from_secs(5), request)
             .await
             .expect("time has elapsed")
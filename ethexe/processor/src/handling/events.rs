@@ -56,6 +56,12 @@ impl ProcessingHandler {
     pub(crate) fn handle_router_event(&mut self, event: RouterRequestEvent) -> Result<()> {
         match event {
             RouterRequestEvent::ProgramCreated(ProgramCreatedEvent { actor_id, code_id }) => {
+                // Programs created by programs are registered during execution.
+                if self.transitions.is_program(&actor_id) {
+                    log::trace!("Program {actor_id} is already registered");
+                    return Ok(());
+                }
+
                 if !self.db.code_valid(code_id).unwrap_or(false) {
                     return Err(ProcessorError::MissingCode { actor_id, code_id });
                 }
@@ -17,7 +17,7 @@ use gear_core::{
     message::ReplyDetails,
 };
 use gear_core_processor::common::JournalNote;
-use gprimitives::{ActorId, MessageId};
+use gprimitives::{ActorId, H256, MessageId};
 use std::collections::HashSet;
 
 /// Overlay execution context.
@@ -43,6 +43,7 @@ impl OverlaidRunContext {
         instance_creator: InstanceCreator,
         height: u32,
         timestamp: u64,
+        random_seed: H256,
     ) -> Self {
         let mut transition_controller = TransitionController {
             transitions: &mut transitions,
@@ -72,6 +73,8 @@ impl OverlaidRunContext {
                 chunk_size,
                 height,
                 timestamp,
+                random_seed,
+                false,
                 None,
             ),
             base_program,
@@ -28,6 +28,7 @@ pub enum ChunkJournalsProcessingOutput {
 /// The loop in the functions handles the output of each task:
 /// - modifies the state by setting a new state hash calculated by the [`ethexe_runtime_common::RuntimeJournalHandler`]
 /// - collects journals for later processing
+/// - records processed dispatches for the message history
 /// - tracks the maximum gas spent among all programs in the chunk
 ///
 /// Due to the nature of the parallel program queues execution (see [`chunk_execution_spawn::spawn_chunk_execution`] gas allowance clarifications),
@@ -41,12 +42,13 @@ pub fn collect_chunk_journals(
     let chunk_journals = chunk_outputs
         .into_iter()
         .map(
-            |(program_id, new_state_hash, program_journals, gas_spent)| {
+            |(program_id, new_state_hash, program_journals, gas_spent, records)| {
                 // Handle state updates that occurred during journal processing within the runtime (allocations, pages).
                 // This should happen before processing the journal notes because `send_dispatch` from another program can modify the state.
                 ctx.inner_mut().transitions.modify(program_id, |state, _| {
                     state.hash = new_state_hash;
                 });
+                ctx.inner_mut().transitions.record_messages(records);
 
                 max_gas_spent_in_chunk = max_gas_spent_in_chunk.max(gas_spent);
 
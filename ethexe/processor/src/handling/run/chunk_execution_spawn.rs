@@ -7,11 +7,12 @@
 
 use super::*;
 use crate::thread_pool;
+use ethexe_common::db::MessageRecord;
 use ethexe_runtime_common::ProcessQueueContext;
 use futures::stream::FuturesOrdered;
 
 /// An alias introduced for better readability of the chunks execution steps.
-pub type ChunkItemOutput = (ActorId, H256, ProgramJournals, u64);
+pub type ChunkItemOutput = (ActorId, H256, ProgramJournals, u64, Vec<MessageRecord>);
 
 /// Prepared input for executing one program queue in a chunk.
 pub struct ChunkItemInput {
@@ -43,6 +44,8 @@ pub async fn spawn_chunk_execution(
         height: ctx.inner().height,
         timestamp: ctx.inner().timestamp,
     };
+    let random_seed = ctx.inner().random_seed;
+    let record_messages = ctx.inner().message_history;
 
     chunk
         .into_iter()
@@ -56,19 +59,21 @@ pub async fn spawn_chunk_execution(
             let mut executor = ctx.inner().instance_creator.instantiate()?;
             let promise_sink = ctx.inner().promise_sink.clone();
             Ok(thread_pool::spawn(move || {
-                let (jn, new_state_hash, gas_spent) = executor.run(
+                let (jn, new_state_hash, gas_spent, records) = executor.run(
                     ProcessQueueContext {
                         program_id,
                         state_root: state_hash,
                         queue_type,
                         gas_allowance: GasAllowanceCounter::new(gas_allowance_for_chunk),
                         block_info,
+                        random_seed,
                         promise_policy,
                         code,
+                        record_messages,
                     },
                     promise_sink,
                 )?;
-                Ok((program_id, new_state_hash, jn, gas_spent))
+                Ok((program_id, new_state_hash, jn, gas_spent, records))
             }))
         })
         .collect::<Result<FuturesOrdered<_>>>()?
@@ -305,6 +305,7 @@ pub(super) trait RunContext {
             out_of_gas,
             ..
         } = self.inner_mut();
+        let db: &'a Database = db;
 
         JournalHandler {
             program_id,
@@ -320,6 +321,7 @@ pub(super) trait RunContext {
             outgoing_messages_limiter,
             outgoing_messages_bytes_limiter,
             call_reply_limiter,
+            is_code_valid: Box::new(move |code_id| db.code_valid(code_id).unwrap_or(false)),
         }
     }
 
@@ -362,6 +364,8 @@ pub(crate) struct CommonRunContext {
     chunk_size: usize,
     height: u32,
     timestamp: u64,
+    random_seed: H256,
+    message_history: bool,
     promise_sink: Option<BoundPromiseSink>,
 }
 
@@ -375,20 +379,30 @@ impl CommonRunContext {
         chunk_size: usize,
         height: u32,
         timestamp: u64,
+        random_seed: H256,
+        message_history: bool,
         promise_sink: Option<BoundPromiseSink>,
     ) -> Self {
+        // Messages delivered before queues processing (e.g. delayed ones,
+        // sent by scheduled tasks) are counted towards the block limits.
+        let (delivered_messages, delivered_bytes) = in_block_transitions.outgoing_messages_stats();
+
         CommonRunContext {
             db,
             instance_creator,
             transitions: in_block_transitions,
             gas_allowance_counter: GasAllowanceCounter::new(gas_allowance),
-            outgoing_messages_limiter: OUTGOING_MESSAGES_SOFT_LIMIT,
-            outgoing_messages_bytes_limiter: OUTGOING_MESSAGES_BYTES_SOFT_LIMIT,
+            outgoing_messages_limiter: OUTGOING_MESSAGES_SOFT_LIMIT
+                .saturating_sub(delivered_messages),
+            outgoing_messages_bytes_limiter: OUTGOING_MESSAGES_BYTES_SOFT_LIMIT
+                .saturating_sub(delivered_bytes),
             call_reply_limiter: CALL_REPLY_SOFT_LIMIT,
             out_of_gas: false,
             chunk_size,
             height,
             timestamp,
+            random_seed,
+            message_history,
             promise_sink,
         }
     }
@@ -602,6 +616,8 @@ mod tests {
             CHUNK_PROCESSING_THREADS,
             3,
             3,
+            H256::zero(),
+            false,
             None,
         );
 
@@ -643,6 +659,7 @@ mod tests {
                 program: Program::Active(ActiveProgram {
                     allocations_hash: MaybeHashOf::empty(),
                     pages_hash: MaybeHashOf::empty(),
+                    gas_reservations_hash: MaybeHashOf::empty(),
                     memory_infix: Default::default(),
                     initialized: true,
                 }),
@@ -765,6 +782,7 @@ mod tests {
             InstanceCreator::new(db.clone(), host::runtime()).unwrap(),
             3,
             3,
+            H256::zero(),
         );
         access_state(
             pid2,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::BoundPromiseSink;
-use ethexe_common::gear::MessageType;
+use ethexe_common::{db::MessageRecord, gear::MessageType};
 use ethexe_db::Database;
 use ethexe_runtime_common::{ProcessQueueContext, ProgramJournals, unpack_i64_to_u32};
 use gear_core::code::{CodeMetadata, InstrumentedCode};
@@ -169,17 +169,18 @@ impl InstanceWrapper {
     ///
     /// The `run` function actually executed program's queue in accordance to
     /// the gear protocol. The returned sequence of `JournalNote`s is later
-    /// processed out of the wasm module.
+    /// processed out of the wasm module, along with records of processed
+    /// dispatches.
     pub fn run(
         &mut self,
         ctx: ProcessQueueContext,
         promise_sink: Option<BoundPromiseSink>,
-    ) -> Result<(ProgramJournals, H256, u64)> {
+    ) -> Result<(ProgramJournals, H256, u64, Vec<MessageRecord>)> {
         threads::set(self.data().db.clone_boxed(), ctx.state_root);
 
         self.with_promise_sink(promise_sink, |instance_wrapper| {
             // Pieces of resulting journal. Hack to avoid single allocation limit.
-            let (ptr_lens, gas_spent): (Vec<i64>, i64) =
+            let (ptr_lens, gas_spent, records): (Vec<i64>, i64, Vec<MessageRecord>) =
                 instance_wrapper.call("run", ctx.encode())?;
 
             let mut mega_journal = Vec::with_capacity(ptr_lens.len());
@@ -192,7 +193,7 @@ impl InstanceWrapper {
 
             let new_state_hash = threads::state_hash();
 
-            Ok((mega_journal, new_state_hash, gas_spent as u64))
+            Ok((mega_journal, new_state_hash, gas_spent as u64, records))
         })
     }
 
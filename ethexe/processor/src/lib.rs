@@ -124,9 +124,12 @@
 //!
 //! ## Configuration
 //!
-//! [`ProcessorConfig`] currently exposes a single knob, `chunk_size`,
-//! which controls the number of programs executed in parallel per pass.
-//! The default is [`DEFAULT_CHUNK_SIZE`] (16).
+//! [`ProcessorConfig`] exposes the following knobs:
+//! - `chunk_size` controls the number of programs executed in parallel
+//!   per pass. The default is [`DEFAULT_CHUNK_SIZE`] (16).
+//! - `module_cache` enables the on-disk cache of compiled program modules,
+//!   so they don't have to be recompiled after restart. Disabled by default.
+//!   The cache is process-wide, so it only has to be enabled once.
 //!
 //! ## When modifying this crate
 //!
@@ -141,9 +144,11 @@
 //! - Processor is designed to write only in CAS, it must NEVER modify
 //!   key-value storage from Database.
 
+pub use gear_wasmtime_cache::DiskCacheConfig;
 pub use host::InstanceError;
 pub use promise::BoundPromiseSink;
 
+use anyhow::Context;
 use core::num::NonZero;
 use ethexe_common::{
     CodeAndIdUnchecked, MAILBOX_VALIDITY_VERSION_2, ProgramStates, Schedule,
@@ -222,12 +227,18 @@ type Result<T, E = ProcessorError> = std::result::Result<T, E>;
 pub struct ProcessorConfig {
     /// Number of programs to be processed in one chunk (in parallel).
     pub chunk_size: usize,
+    /// On-disk cache of compiled program modules, if enabled.
+    pub module_cache: Option<DiskCacheConfig>,
+    /// Whether to record processed messages for the message history.
+    pub message_history: bool,
 }
 
 impl Default for ProcessorConfig {
     fn default() -> Self {
         Self {
             chunk_size: DEFAULT_CHUNK_SIZE.get(),
+            module_cache: None,
+            message_history: false,
         }
     }
 }
@@ -248,6 +259,11 @@ impl Processor {
     }
 
     pub fn with_config(config: ProcessorConfig, db: Database) -> Result<Self> {
+        if let Some(module_cache) = config.module_cache.clone() {
+            gear_wasmtime_cache::enable_disk_cache(module_cache)
+                .context("failed to enable on-disk module cache")?;
+        }
+
         let creator = InstanceCreator::new(db.clone(), host::runtime())?;
         Ok(Self {
             config,
@@ -315,6 +331,7 @@ impl Processor {
         let ExecutableData {
             height,
             timestamp,
+            random_seed,
             program_states,
             schedule,
             injected_transactions,
@@ -342,7 +359,14 @@ impl Processor {
         // Third step: process queues until limits are exhausted or all queues are empty.
         if let Some(gas_allowance) = gas_allowance {
             transitions = self
-                .process_queues(transitions, height, timestamp, gas_allowance, promise_sink)
+                .process_queues(
+                    transitions,
+                    height,
+                    timestamp,
+                    random_seed,
+                    gas_allowance,
+                    promise_sink,
+                )
                 .await?;
         }
 
@@ -382,6 +406,7 @@ impl Processor {
         transitions: InBlockTransitions,
         height: u32,
         timestamp: u64,
+        random_seed: H256,
         gas_allowance: u64,
         promise_sink: Option<BoundPromiseSink>,
     ) -> Result<InBlockTransitions> {
@@ -393,6 +418,8 @@ impl Processor {
             self.config.chunk_size,
             height,
             timestamp,
+            random_seed,
+            self.config.message_history,
             promise_sink,
         )
         .run()
@@ -420,6 +447,14 @@ impl Processor {
     }
 }
 
+/// Derives the randomness seed for processing the MB `mb_hash`, anchored to `anchor_block`.
+///
+/// The seed is deterministic, so it can be influenced by the MB producer and
+/// must not be relied on for gambling-grade randomness.
+pub fn derive_random_seed(anchor_block: H256, mb_hash: H256) -> H256 {
+    gear_core::ids::hash_of_array([anchor_block.0, mb_hash.0]).into()
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ProcessedCodeInfo {
     pub code_id: CodeId,
@@ -442,6 +477,8 @@ pub struct ValidCodeInfo {
 pub struct ExecutableData {
     pub height: u32,
     pub timestamp: u64,
+    /// Randomness seed exposed to programs via `gr_random`.
+    pub random_seed: H256,
     pub program_states: ProgramStates,
     pub schedule: Schedule,
     pub injected_transactions: Vec<VerifiedData<InjectedTransaction>>,
@@ -457,6 +494,7 @@ impl Default for ExecutableData {
         Self {
             height: 0,
             timestamp: 0,
+            random_seed: H256::zero(),
             program_states: ProgramStates::default(),
             schedule: Schedule::default(),
             injected_transactions: vec![],
@@ -477,6 +515,8 @@ impl Default for ExecutableData {
 pub struct ExecutableDataForReply {
     pub height: u32,
     pub timestamp: u64,
+    /// Randomness seed exposed to programs via `gr_random`.
+    pub random_seed: H256,
     pub program_states: ProgramStates,
     pub source: ActorId,
     pub program_id: ActorId,
@@ -505,6 +545,7 @@ impl OverlaidProcessor {
         let ExecutableDataForReply {
             height,
             timestamp,
+            random_seed,
             program_states,
             source,
             program_id,
@@ -574,6 +615,7 @@ impl OverlaidProcessor {
             self.0.creator.clone(),
             height,
             timestamp,
+            random_seed,
         )
         .run()
         .await?;
@@ -10,22 +10,26 @@ use ethexe_common::{
     db::*,
     events::{
         BlockRequestEvent, MirrorRequestEvent, RouterRequestEvent,
-        mirror::{ExecutableBalanceTopUpRequestedEvent, MessageQueueingRequestedEvent},
+        mirror::{
+            ExecutableBalanceTopUpRequestedEvent, MessageQueueingRequestedEvent,
+            ReplyQueueingRequestedEvent,
+        },
         router::ProgramCreatedEvent,
     },
-    gear::Message,
+    gear::{Message, ProgramCreation, program_creation_actor_id},
     mock::*,
 };
 use ethexe_runtime_common::{
-    CODES_INSTRUMENTATION_VERSION, RUNTIME_ID, TransitionsConfig, WAIT_UP_TO_SAFE_DURATION,
-    state::MessageQueue,
+    CODES_INSTRUMENTATION_VERSION, CREATED_PROGRAM_EXECUTABLE_BALANCE, RUNTIME_ID,
+    TransitionsConfig, WAIT_UP_TO_SAFE_DURATION,
+    state::{ActiveProgram, MessageQueue, Program},
 };
 use gear_core::{
     code::{CodeMetadata, InstantiatedSectionSizes, InstrumentationStatus, InstrumentedCode},
     ids::prelude::CodeIdExt,
     message::{ErrorReplyReason, ReplyCode, SuccessReplyReason},
 };
-use gear_core_errors::{SimpleExecutionError, SimpleUnavailableActorError};
+use gear_core_errors::{SignalCode, SimpleExecutionError, SimpleUnavailableActorError};
 use gprimitives::{ActorId, MessageId};
 use parity_scale_codec::Encode;
 use tokio::sync::mpsc;
@@ -170,6 +174,7 @@ mod utils {
                 handler.transitions,
                 block1.header.height,
                 block1.header.timestamp,
+                block1.hash,
                 DEFAULT_BLOCK_GAS_LIMIT,
                 None,
             )
@@ -374,6 +379,7 @@ async fn process_programs_instruments_valid_code_missing_current_runtime_instrum
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -477,6 +483,7 @@ async fn create_program_and_run_first_dispatch(
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -700,6 +707,7 @@ async fn ping_pong() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -820,6 +828,7 @@ async fn async_and_ping() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -922,6 +931,7 @@ async fn many_waits() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -955,6 +965,7 @@ async fn many_waits() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -988,6 +999,7 @@ async fn many_waits() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1015,6 +1027,7 @@ async fn many_waits() {
             transitions,
             wake_block.header.height,
             wake_block.header.timestamp,
+            wake_block.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1112,6 +1125,7 @@ async fn cross_height_wake_drain() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1144,6 +1158,7 @@ async fn cross_height_wake_drain() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1167,6 +1182,7 @@ async fn cross_height_wake_drain() {
             transitions,
             wake_block.header.height,
             wake_block.header.timestamp,
+            wake_block.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1424,6 +1440,7 @@ async fn overlay_execution() {
     let executable = ExecutableDataForReply {
         height: block3.header.height,
         timestamp: block3.header.timestamp,
+        random_seed: block3.hash,
         program_states: states,
         source: user_id,
         program_id: async_id,
@@ -1503,6 +1520,7 @@ async fn overlay_execution_returns_messages_sent_to_users() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1518,6 +1536,7 @@ async fn overlay_execution_returns_messages_sent_to_users() {
             ExecutableDataForReply {
                 height: block2.header.height,
                 timestamp: block2.header.timestamp,
+                random_seed: block2.hash,
                 program_states: states,
                 source: user_id,
                 program_id: actor_id,
@@ -1584,6 +1603,7 @@ async fn overlay_execution_with_top_up_works_for_depleted_programs() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1616,6 +1636,7 @@ async fn overlay_execution_with_top_up_works_for_depleted_programs() {
     let executable = ExecutableDataForReply {
         height: block2.header.height,
         timestamp: block2.header.timestamp,
+        random_seed: block2.hash,
         program_states: depleted_states.clone(),
         source: user_id,
         program_id: actor_id,
@@ -1638,6 +1659,7 @@ async fn overlay_execution_with_top_up_works_for_depleted_programs() {
             ExecutableDataForReply {
                 height: block2.header.height,
                 timestamp: block2.header.timestamp,
+                random_seed: block2.hash,
                 program_states: depleted_states,
                 source: user_id,
                 program_id: actor_id,
@@ -1706,6 +1728,7 @@ async fn injected_ping_pong() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1735,6 +1758,7 @@ async fn injected_ping_pong() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             Some(promise_sink.clone()),
         )
@@ -1816,6 +1840,7 @@ async fn committable_filter_canonical_vs_injected_value0() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1848,6 +1873,7 @@ async fn committable_filter_canonical_vs_injected_value0() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1948,6 +1974,7 @@ async fn injected_prioritized_over_canonical() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -1985,6 +2012,7 @@ async fn injected_prioritized_over_canonical() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             Some(promise_sink.clone()),
         )
@@ -2070,6 +2098,7 @@ async fn executable_balance_charged() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -2166,6 +2195,7 @@ async fn executable_balance_injected_panic_not_charged() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             Some(promise_sink.clone()),
         )
@@ -2184,6 +2214,7 @@ async fn executable_balance_injected_panic_not_charged() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             Some(promise_sink.clone()),
         )
@@ -2234,6 +2265,7 @@ async fn executable_balance_injected_panic_not_charged() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             Some(promise_sink.clone()),
         )
@@ -2302,6 +2334,7 @@ async fn insufficient_executable_balance_still_charged() {
             handler.transitions,
             block1.header.height,
             block1.header.timestamp,
+            block1.hash,
             DEFAULT_BLOCK_GAS_LIMIT,
             None,
         )
@@ -2371,6 +2404,192 @@ async fn call_wake_with_delay_is_unsupported() {
     assert_eq!(reply_code, ReplyCode::Success(SuccessReplyReason::Auto));
 }
 
+#[tokio::test]
+async fn call_random() {
+    init_logger();
+
+    let wat = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_random" (func $random (param i32 i32)))
+            (import "env" "gr_reply" (func $reply (param i32 i32 i32 i32)))
+            (export "init" (func $init))
+            (func $init
+                (call $random (i32.const 0x0) (i32.const 0x100))
+                (call $reply (i32.const 0x100) (i32.const 36) (i32.const 0x400) (i32.const 0x600))
+            )
+        )
+    "#;
+
+    let (_, code) = wat_to_wasm(wat);
+
+    let reply_payload = |transitions: &InBlockTransitions| {
+        let message = &transitions.current_messages()[0].1;
+        assert_eq!(
+            message
+                .reply_details
+                .expect("must be reply")
+                .to_reply_code(),
+            ReplyCode::Success(SuccessReplyReason::Manual)
+        );
+        message.payload.clone()
+    };
+
+    let first = reply_payload(&simple_init_test(code.clone()).await);
+    let second = reply_payload(&simple_init_test(code).await);
+
+    assert_eq!(first.len(), 36);
+    assert_ne!(first[4..], [0; 32]);
+    assert_eq!(
+        first, second,
+        "Random data must be deterministic for the same block"
+    );
+}
+
+#[tokio::test]
+async fn create_program_from_program() {
+    init_logger();
+
+    let (_, child_code) = wat_to_wasm(VALID_PROGRAM);
+    let child_code_id = CodeId::generate(&child_code);
+
+    // Creates the child with zero value, replying with the result: error code,
+    // init message id and child program id.
+    let parent_wat = format!(
+        r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_create_program" (func $create_program (param i32 i32 i32 i32 i32 i32 i32)))
+            (import "env" "gr_reply" (func $reply (param i32 i32 i32 i32)))
+            (export "init" (func $init))
+            (func $init
+                (call $create_program (i32.const 0x0) (i32.const 0x0) (i32.const 0x0) (i32.const 0x0) (i32.const 0x0) (i32.const 0x0) (i32.const 0x100))
+                (call $reply (i32.const 0x100) (i32.const 68) (i32.const 0x400) (i32.const 0x600))
+            )
+            (data (i32.const 0x0) "{}")
+        )
+        "#,
+        child_code_id
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("\\{byte:02x}"))
+            .collect::<String>()
+    );
+    let (_, parent_code) = wat_to_wasm(&parent_wat);
+
+    let (mut processor, chain, [parent_code_id, _]) =
+        setup_test_env_and_load_codes([&parent_code, &child_code]).await;
+    let block1 = chain.blocks[1].to_simple();
+    let mut handler = setup_handler(processor.db.clone(), block1.header.height);
+
+    let user_id = ActorId::from(10);
+    let parent_id = ActorId::from(0x10000);
+    let parent_executable_balance = CREATED_PROGRAM_EXECUTABLE_BALANCE + 350_000_000_000;
+
+    handler
+        .handle_router_event(RouterRequestEvent::ProgramCreated(ProgramCreatedEvent {
+            actor_id: parent_id,
+            code_id: parent_code_id,
+        }))
+        .expect("failed to create new program");
+    handler
+        .handle_mirror_event(
+            parent_id,
+            MirrorRequestEvent::ExecutableBalanceTopUpRequested(
+                ExecutableBalanceTopUpRequestedEvent {
+                    value: parent_executable_balance,
+                },
+            ),
+        )
+        .expect("failed to top up balance");
+    handler
+        .handle_mirror_event(
+            parent_id,
+            MirrorRequestEvent::MessageQueueingRequested(MessageQueueingRequestedEvent {
+                id: MessageId::from(1),
+                source: user_id,
+                payload: vec![],
+                value: 0,
+                call_reply: false,
+            }),
+        )
+        .expect("failed to queue message");
+
+    handler.transitions = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let to_users = handler.transitions.current_messages();
+    assert_eq!(to_users.len(), 1);
+
+    let message = &to_users[0].1;
+    assert_eq!(message.destination, user_id);
+    assert_eq!(
+        message
+            .reply_details
+            .expect("must be reply")
+            .to_reply_code(),
+        ReplyCode::Success(SuccessReplyReason::Manual)
+    );
+    assert_eq!(
+        message.payload[..4],
+        [0; 4],
+        "program creation must succeed"
+    );
+
+    let init_message_id = MessageId::try_from(&message.payload[4..36]).unwrap();
+    let child_id = ActorId::try_from(&message.payload[36..68]).unwrap();
+    assert_eq!(
+        child_id,
+        program_creation_actor_id(parent_id, init_message_id.into_bytes().into())
+    );
+
+    assert_eq!(
+        handler.transitions.registered_programs().get(&child_id),
+        Some(&child_code_id)
+    );
+
+    // Executable balance is moved from parent, child init is charged from its own balance.
+    let parent_state = handler.program_state(parent_id);
+    assert!(
+        parent_state.executable_balance
+            < parent_executable_balance - CREATED_PROGRAM_EXECUTABLE_BALANCE
+    );
+
+    let child_state = handler.program_state(child_id);
+    assert!(matches!(
+        child_state.program,
+        Program::Active(ActiveProgram {
+            initialized: true,
+            ..
+        })
+    ));
+    assert!(child_state.executable_balance > 0);
+    assert!(child_state.executable_balance < CREATED_PROGRAM_EXECUTABLE_BALANCE);
+
+    let FinalizedBlockTransitions {
+        child_program_creations,
+        ..
+    } = handler.transitions.finalize();
+    assert_eq!(
+        child_program_creations,
+        vec![ProgramCreation {
+            actor_id: child_id,
+            parent: parent_id,
+            code_id: child_code_id,
+            salt: init_message_id.into_bytes().into(),
+        }]
+    );
+}
+
 /// Tests that process_programs phases execute in strict order:
 /// 1. Injected transactions + events (populate queues)
 /// 2. Scheduled tasks (e.g., WakeMessage moves dispatches back to queues)
@@ -2676,3 +2895,437 @@ async fn call_wait_up_to_with_huge_duration() {
     let task = tasks.into_iter().next().unwrap();
     assert!(matches!(task, ScheduledTask::WakeMessage(_, _)));
 }
+
+#[tokio::test]
+async fn call_wait_is_woken_after_safe_duration() {
+    init_logger();
+
+    let wat = r#"
+        (module
+            (import "env" "memory" (memory 0))
+            (import "env" "gr_wait" (func $wait))
+            (export "init" (func $init))
+            (func $init
+                (call $wait)
+            )
+        )
+    "#;
+
+    let transitions = simple_init_test(wat_to_wasm(wat).1).await;
+    let block_height = transitions.cfg().block_height;
+    let FinalizedBlockTransitions { schedule, .. } = transitions.finalize();
+    let (block, tasks) = schedule.into_iter().next().unwrap();
+    assert_eq!(
+        block,
+        block_height + WAIT_UP_TO_SAFE_DURATION,
+        "gr_wait should be woken after WAIT_UP_TO_SAFE_DURATION"
+    );
+    let task = tasks.into_iter().next().unwrap();
+    assert!(matches!(task, ScheduledTask::WakeMessage(_, _)));
+}
+
+#[tokio::test]
+async fn trap_sends_signal_to_program() {
+    init_logger();
+
+    // Handle function reserves gas for system messages and traps, so a signal is sent.
+    // Signal handler sends the received signal code to the user.
+    let wat = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_system_reserve_gas" (func $system_reserve_gas (param i64 i32)))
+            (import "env" "gr_signal_code" (func $signal_code (param i32)))
+            (import "env" "gr_send" (func $send (param i32 i32 i32 i32 i32)))
+            (export "handle" (func $handle))
+            (export "handle_signal" (func $handle_signal))
+            (func $handle
+                (call $system_reserve_gas (i64.const 1000000) (i32.const 0x300))
+                unreachable
+            )
+            (func $handle_signal
+                (call $signal_code (i32.const 0x100))
+                (call $send (i32.const 0x200) (i32.const 0x104) (i32.const 4) (i32.const 0) (i32.const 0x300))
+            )
+            ;; `ActorId::from(10)` as destination of the signal report.
+            (data (i32.const 0x20C) "\0a")
+        )
+    "#;
+
+    let (_, code) = wat_to_wasm(wat);
+    let (mut processor, chain, [code_id]) = setup_test_env_and_load_codes([code.as_slice()]).await;
+
+    let user = ActorId::from(10);
+    let actor_id = ActorId::from(0x10000);
+    let block1 = chain.blocks[1].to_simple();
+
+    let queue_message = |id: u64| BlockRequestEvent::Mirror {
+        actor_id,
+        event: MirrorRequestEvent::MessageQueueingRequested(MessageQueueingRequestedEvent {
+            id: MessageId::from(id),
+            source: user,
+            payload: vec![],
+            value: 0,
+            call_reply: false,
+        }),
+    };
+
+    let events = vec![
+        BlockRequestEvent::Router(RouterRequestEvent::ProgramCreated(ProgramCreatedEvent {
+            actor_id,
+            code_id,
+        })),
+        BlockRequestEvent::Mirror {
+            actor_id,
+            event: MirrorRequestEvent::ExecutableBalanceTopUpRequested(
+                ExecutableBalanceTopUpRequestedEvent {
+                    value: 500_000_000_000,
+                },
+            ),
+        },
+        queue_message(1),
+        queue_message(2),
+    ];
+
+    let executable = ExecutableData {
+        height: block1.header.height,
+        timestamp: block1.header.timestamp,
+        events,
+        gas_allowance: Some(DEFAULT_BLOCK_GAS_LIMIT),
+        ..Default::default()
+    };
+    let FinalizedBlockTransitions { transitions, .. } =
+        processor.process_programs(executable, None).await.unwrap();
+
+    let messages: Vec<_> = transitions
+        .iter()
+        .flat_map(|t| t.messages.iter())
+        .filter(|m| m.destination == user)
+        .collect();
+
+    let trap_reply = messages
+        .iter()
+        .find(|m| {
+            m.reply_details
+                .is_some_and(|details| details.to_message_id() == MessageId::from(2))
+        })
+        .expect("trapped message must be replied");
+    assert_eq!(
+        trap_reply.reply_details.unwrap().to_reply_code(),
+        ReplyCode::Error(ErrorReplyReason::Execution(
+            SimpleExecutionError::UnreachableInstruction
+        ))
+    );
+
+    let signal_report = messages
+        .iter()
+        .find(|m| m.reply_details.is_none())
+        .expect("signal handler must send a message");
+    assert_eq!(
+        signal_report.payload,
+        SignalCode::Execution(SimpleExecutionError::UnreachableInstruction)
+            .to_u32()
+            .to_le_bytes()
+    );
+}
+
+#[tokio::test]
+async fn signal_entry_handles_panic() {
+    init_logger();
+
+    let (mut processor, chain, [code_id]) =
+        setup_test_env_and_load_codes([demo_signal_entry::WASM_BINARY]).await;
+    let block1 = chain.blocks[1].to_simple();
+
+    let user_id = ActorId::from(10);
+    let actor_id = ActorId::from(0x10000);
+
+    let mut handler = setup_handler(processor.db.clone(), block1.header.height);
+
+    handler
+        .handle_router_event(RouterRequestEvent::ProgramCreated(ProgramCreatedEvent {
+            actor_id,
+            code_id,
+        }))
+        .expect("failed to create new program");
+
+    handler
+        .handle_mirror_event(
+            actor_id,
+            MirrorRequestEvent::ExecutableBalanceTopUpRequested(
+                ExecutableBalanceTopUpRequestedEvent {
+                    value: 5_000_000_000_000,
+                },
+            ),
+        )
+        .expect("failed to top up balance");
+
+    for (id, payload) in [
+        (1, b"init_program".to_vec()),
+        (2, demo_signal_entry::HandleAction::Panic.encode()),
+    ] {
+        handler
+            .handle_mirror_event(
+                actor_id,
+                MirrorRequestEvent::MessageQueueingRequested(MessageQueueingRequestedEvent {
+                    id: MessageId::from(id),
+                    source: user_id,
+                    payload,
+                    value: 0,
+                    call_reply: false,
+                }),
+            )
+            .expect("failed to send message");
+    }
+
+    let to_users = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap()
+        .current_messages();
+
+    let panic_reply = to_users
+        .iter()
+        .find(|(_, m)| {
+            m.reply_details
+                .is_some_and(|details| details.to_message_id() == MessageId::from(2))
+        })
+        .expect("panicked message must be replied");
+    assert_eq!(
+        panic_reply.1.reply_details.unwrap().to_reply_code(),
+        ReplyCode::Error(ErrorReplyReason::Execution(
+            SimpleExecutionError::UserspacePanic
+        ))
+    );
+
+    // Signal handler reports to the program initiator.
+    assert!(
+        to_users
+            .iter()
+            .any(|(_, m)| m.destination == user_id && m.payload == b"handle_signal")
+    );
+}
+
+#[tokio::test]
+async fn async_critical_hook_runs_on_signal() {
+    init_logger();
+
+    let (mut processor, chain, [code_id]) =
+        setup_test_env_and_load_codes([demo_async_critical::WASM_BINARY]).await;
+    let block1 = chain.blocks[1].to_simple();
+
+    let user_id = ActorId::from(10);
+    let actor_id = ActorId::from(0x10000);
+
+    let mut handler = setup_handler(processor.db.clone(), block1.header.height);
+
+    handler
+        .handle_router_event(RouterRequestEvent::ProgramCreated(ProgramCreatedEvent {
+            actor_id,
+            code_id,
+        }))
+        .expect("failed to create new program");
+
+    handler
+        .handle_mirror_event(
+            actor_id,
+            MirrorRequestEvent::ExecutableBalanceTopUpRequested(
+                ExecutableBalanceTopUpRequestedEvent {
+                    value: 5_000_000_000_000,
+                },
+            ),
+        )
+        .expect("failed to top up balance");
+
+    for (id, payload) in [
+        (1, vec![]),
+        (2, demo_async_critical::HandleAction::Panic.encode()),
+    ] {
+        handler
+            .handle_mirror_event(
+                actor_id,
+                MirrorRequestEvent::MessageQueueingRequested(MessageQueueingRequestedEvent {
+                    id: MessageId::from(id),
+                    source: user_id,
+                    payload,
+                    value: 0,
+                    call_reply: false,
+                }),
+            )
+            .expect("failed to send message");
+    }
+
+    handler.transitions = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Handle waits for the reply to its message, so the hook is kept.
+    let for_reply = handler
+        .transitions
+        .current_messages()
+        .into_iter()
+        .map(|(_, m)| m)
+        .find(|m| m.destination == user_id && m.payload == b"for_reply")
+        .expect("program must send a message to wait a reply for");
+
+    handler.transitions.modifications_mut().clear();
+    handler
+        .handle_mirror_event(
+            actor_id,
+            MirrorRequestEvent::ReplyQueueingRequested(ReplyQueueingRequestedEvent {
+                replied_to: for_reply.id,
+                source: user_id,
+                payload: vec![],
+                value: 0,
+            }),
+        )
+        .expect("failed to send reply");
+
+    let to_users = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap()
+        .current_messages();
+
+    let panic_reply = to_users
+        .iter()
+        .find(|(_, m)| {
+            m.reply_details
+                .is_some_and(|details| details.to_message_id() == MessageId::from(2))
+        })
+        .expect("panicked message must be replied");
+    assert_eq!(
+        panic_reply.1.reply_details.unwrap().to_reply_code(),
+        ReplyCode::Error(ErrorReplyReason::Execution(
+            SimpleExecutionError::UserspacePanic
+        ))
+    );
+
+    // Critical hook is executed by the signal handler.
+    assert!(
+        to_users
+            .iter()
+            .any(|(_, m)| m.destination == user_id && m.payload == b"critical")
+    );
+}
+
+#[tokio::test]
+async fn async_reply_hook_runs_on_reply() {
+    init_logger();
+
+    let (mut processor, chain, [code_id]) =
+        setup_test_env_and_load_codes([demo_async_reply_hook::WASM_BINARY]).await;
+    let block1 = chain.blocks[1].to_simple();
+
+    let user_id = ActorId::from(10);
+    let actor_id = ActorId::from(0x10000);
+
+    let mut handler = setup_handler(processor.db.clone(), block1.header.height);
+
+    handler
+        .handle_router_event(RouterRequestEvent::ProgramCreated(ProgramCreatedEvent {
+            actor_id,
+            code_id,
+        }))
+        .expect("failed to create new program");
+
+    handler
+        .handle_mirror_event(
+            actor_id,
+            MirrorRequestEvent::ExecutableBalanceTopUpRequested(
+                ExecutableBalanceTopUpRequestedEvent {
+                    value: 5_000_000_000_000,
+                },
+            ),
+        )
+        .expect("failed to top up balance");
+
+    for id in [1, 2] {
+        handler
+            .handle_mirror_event(
+                actor_id,
+                MirrorRequestEvent::MessageQueueingRequested(MessageQueueingRequestedEvent {
+                    id: MessageId::from(id),
+                    source: user_id,
+                    payload: vec![],
+                    value: 0,
+                    call_reply: false,
+                }),
+            )
+            .expect("failed to send message");
+    }
+
+    handler.transitions = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let for_reply = handler
+        .transitions
+        .current_messages()
+        .into_iter()
+        .map(|(_, m)| m)
+        .find(|m| m.destination == user_id && m.payload == b"for_reply_3")
+        .expect("program must send a message with reply hook");
+
+    handler.transitions.modifications_mut().clear();
+    handler
+        .handle_mirror_event(
+            actor_id,
+            MirrorRequestEvent::ReplyQueueingRequested(ReplyQueueingRequestedEvent {
+                replied_to: for_reply.id,
+                source: user_id,
+                payload: vec![3],
+                value: 0,
+            }),
+        )
+        .expect("failed to send reply");
+
+    let to_users = processor
+        .process_queues(
+            handler.transitions,
+            block1.header.height,
+            block1.header.timestamp,
+            block1.hash,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            None,
+        )
+        .await
+        .unwrap()
+        .current_messages();
+
+    // Reply hook is executed in `handle_reply` entry point.
+    assert!(
+        to_users
+            .iter()
+            .any(|(_, m)| m.destination == user_id && m.payload == b"saw_reply_3")
+    );
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::types::{CalculateReplyForHandleResult, FullProgramState, ProgramBestState};
+use ethexe_common::db::ProcessedMessage;
 use ethexe_runtime_common::state::{
     DispatchStash, Mailbox, MemoryPages, MemoryPagesRegion, MessageQueue, ProgramState,
     UserMailbox, Waitlist,
@@ -62,6 +63,20 @@ pub trait Program {
     #[method(name = "program_readPayload")]
     async fn read_payload(&self, hash: H256) -> jsonrpsee::core::RpcResult<Bytes>;
 
+    #[method(name = "program_messageHistory")]
+    async fn message_history(
+        &self,
+        message_id: H256,
+    ) -> jsonrpsee::core::RpcResult<Option<ProcessedMessage>>;
+
+    #[method(name = "program_recentMessages")]
+    async fn recent_messages(
+        &self,
+        program_id: H160,
+        offset: u64,
+        limit: u64,
+    ) -> jsonrpsee::core::RpcResult<Vec<ProcessedMessage>>;
+
     /// Subscribes to the program's best state, emitted on every newly computed MB.
     #[subscription(
         name = "program_subscribeBestState",
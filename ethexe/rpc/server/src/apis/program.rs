@@ -9,10 +9,10 @@ use crate::{
 };
 use ethexe_common::{
     HashOf,
-    db::{CodesStorageRO, MbStorageRO},
+    db::{CodesStorageRO, MbStorageRO, MessageHistoryStorageRO, ProcessedMessage},
 };
 use ethexe_db::Database;
-use ethexe_processor::{ExecutableDataForReply, OverlaidProcessor};
+use ethexe_processor::{ExecutableDataForReply, OverlaidProcessor, derive_random_seed};
 use ethexe_runtime_common::state::{
     DispatchStash, Mailbox, MemoryPages, MemoryPagesRegion, MessageQueue, ProgramState,
     QueryableStorage, Storage, UserMailbox, Waitlist,
@@ -27,6 +27,9 @@ use jsonrpsee::{
 use parity_scale_codec::Encode;
 use sp_core::Bytes;
 
+/// Maximum amount of messages returned by `program_recentMessages`.
+const MAX_RECENT_MESSAGES: u64 = 1000;
+
 #[rpc(server)]
 pub trait Program {
     #[method(name = "program_calculateReplyForHandle")]
@@ -79,6 +82,26 @@ pub trait Program {
     #[method(name = "program_readPayload")]
     async fn read_payload(&self, hash: H256) -> jsonrpsee::core::RpcResult<Bytes>;
 
+    /// Returns the latest processing of the message by its destination program.
+    ///
+    /// Requires the node to index message history, otherwise always returns `None`.
+    #[method(name = "program_messageHistory")]
+    async fn message_history(
+        &self,
+        message_id: H256,
+    ) -> jsonrpsee::core::RpcResult<Option<ProcessedMessage>>;
+
+    /// Returns messages processed by the program, most recent first.
+    ///
+    /// Requires the node to index message history, otherwise always returns nothing.
+    #[method(name = "program_recentMessages")]
+    async fn recent_messages(
+        &self,
+        program_id: H160,
+        offset: u64,
+        limit: u64,
+    ) -> jsonrpsee::core::RpcResult<Vec<ProcessedMessage>>;
+
     /// Subscribes to the program's best state, emitted on every newly computed MB.
     #[subscription(
         name = "program_subscribeBestState",
@@ -157,6 +180,7 @@ impl ProgramServer for ProgramApi {
         let executable = ExecutableDataForReply {
             height: block.header.height,
             timestamp: block.header.timestamp,
+            random_seed: derive_random_seed(block.hash, mb_hash),
             program_states: self
                 .db
                 .mb_program_states(mb_hash)
@@ -289,6 +313,36 @@ impl ProgramServer for ProgramApi {
             .ok_or_else(|| errors::db("Failed to read payload by hash"))
     }
 
+    async fn message_history(
+        &self,
+        message_id: H256,
+    ) -> jsonrpsee::core::RpcResult<Option<ProcessedMessage>> {
+        Ok(self.db.processed_message(message_id.into()))
+    }
+
+    async fn recent_messages(
+        &self,
+        program_id: H160,
+        offset: u64,
+        limit: u64,
+    ) -> jsonrpsee::core::RpcResult<Vec<ProcessedMessage>> {
+        if limit > MAX_RECENT_MESSAGES {
+            return Err(errors::invalid_params(format!(
+                "limit must not exceed {MAX_RECENT_MESSAGES}"
+            )));
+        }
+
+        self.db
+            .program_messages(program_id.into(), offset, limit)
+            .into_iter()
+            .map(|message_id| {
+                self.db
+                    .processed_message(message_id)
+                    .ok_or_else(|| errors::db("Failed to read processed message"))
+            })
+            .collect()
+    }
+
     async fn subscribe_best_state(
         &self,
         pending: PendingSubscriptionSink,
@@ -119,6 +119,8 @@ impl RpcServer {
         let processor = Processor::with_config(
             ProcessorConfig {
                 chunk_size: self.config.chunk_size,
+                module_cache: None,
+                message_history: false,
             },
             self.db.clone(),
         )?
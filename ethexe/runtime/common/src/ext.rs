@@ -5,6 +5,7 @@
 
 use crate::RuntimeInterface;
 use alloc::collections::btree_set::BTreeSet;
+use ethexe_common::gear::program_creation_actor_id;
 use gear_core::{
     buffer::PayloadSlice,
     costs::{CostToken, LazyPagesCosts},
@@ -17,7 +18,7 @@ use gear_core::{
     program::MemoryInfix,
 };
 use gear_core_backend::BackendExternalities;
-use gear_core_errors::{ExtError, ReplyCode};
+use gear_core_errors::{ExtError, ReplyCode, SignalCode};
 use gear_core_processor::{
     Ext as CoreExt, ExtInfo, FallibleExtError, ProcessorContext, ProcessorExternalities,
     configs::SyscallName,
@@ -25,6 +26,9 @@ use gear_core_processor::{
 use gear_lazy_pages_common::{GlobalsAccessConfig, ProcessAccessError};
 use gprimitives::{ActorId, MessageId, ReservationId};
 
+/// Externalities used to execute programs on ethexe.
+///
+/// Wraps the core externalities, forbidding or restricting syscalls ethexe doesn't support.
 pub struct Ext<RI: RuntimeInterface> {
     core: CoreExt<RI::LazyPages>,
 }
@@ -112,6 +116,16 @@ impl<RI: RuntimeInterface> Externalities for Ext<RI> {
             fn value_available(&self) -> Result<u128, Self::UnrecoverableError>;
             fn wait_for(&mut self, duration: u32) -> Result<(), Self::UnrecoverableError>;
             fn wait_up_to(&mut self, duration: u32) -> Result<bool, Self::UnrecoverableError>;
+            fn random(&self) -> Result<(&[u8], u32), Self::UnrecoverableError>;
+            fn reserve_gas(&mut self, amount: u64, duration: u32) -> Result<ReservationId, Self::FallibleError>;
+            fn unreserve_gas(&mut self, id: ReservationId) -> Result<u64, Self::FallibleError>;
+            fn system_reserve_gas(&mut self, amount: u64) -> Result<(), Self::FallibleError>;
+            fn reply_deposit(&mut self, message_id: MessageId, amount: u64) -> Result<(), Self::FallibleError>;
+            fn reservation_send_commit(&mut self, id: ReservationId, handle: u32, msg: HandlePacket, delay: u32) -> Result<MessageId, Self::FallibleError>;
+            fn reservation_reply_commit(&mut self, id: ReservationId, msg: ReplyPacket) -> Result<MessageId, Self::FallibleError>;
+            fn signal_from(&self) -> Result<MessageId, Self::FallibleError>;
+            fn signal_code(&self) -> Result<SignalCode, Self::FallibleError>;
+            fn wait(&mut self) -> Result<(), Self::UnrecoverableError>;
             fn forbidden_funcs(&self) -> &BTreeSet<SyscallName>;
             fn msg_ctx(&self) -> &MessageContext;
         }
@@ -125,63 +139,25 @@ impl<RI: RuntimeInterface> Externalities for Ext<RI> {
         }
     }
 
-    fn reservation_send_commit(
-        &mut self,
-        _: ReservationId,
-        _: u32,
-        _: HandlePacket,
-        _: u32,
-    ) -> Result<MessageId, Self::FallibleError> {
-        unreachable!("reservation_send_commit syscall is forbidden in ethexe runtime")
-    }
-
-    fn reservation_reply_commit(
-        &mut self,
-        _: ReservationId,
-        _: ReplyPacket,
-    ) -> Result<MessageId, Self::FallibleError> {
-        unreachable!("reservation_reply_commit syscall is forbidden in ethexe runtime")
-    }
-
-    fn signal_from(&self) -> Result<MessageId, Self::FallibleError> {
-        unreachable!("signal_from syscall is forbidden in ethexe runtime")
-    }
-
-    fn signal_code(&self) -> Result<gear_core_errors::SignalCode, Self::FallibleError> {
-        unreachable!("signal_code syscall is forbidden in ethexe runtime")
-    }
-
-    fn wait(&mut self) -> Result<(), Self::UnrecoverableError> {
-        unreachable!("wait syscall is forbidden in ethexe runtime")
-    }
-
-    fn random(&self) -> Result<(&[u8], u32), Self::UnrecoverableError> {
-        // TODO: #5238 implement random data generation in ethexe runtime
-        unreachable!("random syscall is forbidden in ethexe runtime")
-    }
-
     fn create_program(
         &mut self,
-        _: InitPacket,
-        _: u32,
+        packet: InitPacket,
+        delay: u32,
     ) -> Result<(MessageId, ActorId), Self::FallibleError> {
-        // TODO: #5239 implement program creation in ethexe runtime
-        unreachable!("create_program syscall is forbidden in ethexe runtime")
-    }
-
-    fn reply_deposit(&mut self, _: MessageId, _: u64) -> Result<(), Self::FallibleError> {
-        unreachable!("reply_deposit syscall is forbidden in ethexe runtime")
-    }
-    fn reserve_gas(&mut self, _: u64, _: u32) -> Result<ReservationId, Self::FallibleError> {
-        unreachable!("reserve_gas syscall is forbidden in ethexe runtime")
-    }
+        if delay != 0 {
+            return Err(FallibleExtError::Core(ExtError::Unsupported));
+        }
 
-    fn unreserve_gas(&mut self, _: ReservationId) -> Result<u64, Self::FallibleError> {
-        unreachable!("unreserve_gas syscall is forbidden in ethexe runtime")
-    }
+        // Program id is derived the same way, as `Mirror` deploys the child via `CREATE3`,
+        // with the init message id used as a salt.
+        let init_msg_id = self.core.context.message_context.next_message_id();
+        let program_id = program_creation_actor_id(
+            self.core.context.program_id,
+            init_msg_id.into_bytes().into(),
+        );
 
-    fn system_reserve_gas(&mut self, _: u64) -> Result<(), Self::FallibleError> {
-        unreachable!("system_reserve_gas syscall is forbidden in ethexe runtime")
+        self.core
+            .create_program(packet.with_program_id(program_id), delay)
     }
 }
 
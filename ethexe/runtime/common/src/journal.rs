@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::{
-    TransitionController,
+    CREATED_PROGRAM_EXECUTABLE_BALANCE, TransitionController, VALUE_PER_GAS,
     state::{
         ActiveProgram, Dispatch, Expiring, MailboxMessage, ModifiableStorage, PayloadLookup,
         Program, ProgramState, Storage,
@@ -10,29 +10,35 @@ use crate::{
     transitions::{NonFinalTransition, is_event_destination},
 };
 use alloc::{
+    boxed::Box,
     collections::{BTreeMap, BTreeSet},
     vec::Vec,
 };
 use core::{mem, num::NonZero, panic};
 use ethexe_common::{
-    ScheduledTask,
-    gear::{INJECTED_MESSAGE_PANIC_GAS_CHARGE_THRESHOLD, Message, MessageType},
+    MaybeHashOf, ScheduledTask,
+    db::MessageRecord,
+    gear::{INJECTED_MESSAGE_PANIC_GAS_CHARGE_THRESHOLD, Message, MessageType, ProgramCreation},
 };
 use gear_core::{
     env::MessageWaitedType,
     gas::GasAllowanceCounter,
     memory::PageBuf,
-    message::{Dispatch as CoreDispatch, StoredDispatch},
+    message::{Dispatch as CoreDispatch, DispatchKind, SignalMessage, StoredDispatch},
     pages::{GearPage, WasmPage, num_traits::Zero as _, numerated::tree::IntervalsTree},
     reservation::GasReserver,
 };
-use gear_core_errors::{SignalCode, SuccessReplyReason};
+use gear_core_errors::{
+    ErrorReplyReason, SignalCode, SimpleUnavailableActorError, SuccessReplyReason,
+};
 use gear_core_processor::common::{DispatchOutcome, JournalHandler, JournalNote};
 use gprimitives::{ActorId, CodeId, H256, MessageId, ReservationId};
 use gsys::GasMultiplier;
 
 /// Maximum duration for gr_wait_up_to in blocks,
 /// when not enough gas was provided for the requested duration.
+///
+/// Also used as the duration of gr_wait, which doesn't specify one.
 pub const WAIT_UP_TO_SAFE_DURATION: u32 = 64;
 
 /// Pushes an outgoing user-bound message into the single `messages` bucket and
@@ -70,6 +76,8 @@ pub struct NativeJournalHandler<'a, S: Storage + ?Sized> {
     pub outgoing_messages_limiter: &'a mut u32,
     pub outgoing_messages_bytes_limiter: &'a mut u32,
     pub call_reply_limiter: &'a mut u32,
+    /// Checks whether the code is validated, so programs with it could be created.
+    pub is_code_valid: Box<dyn Fn(CodeId) -> bool + 'a>,
 }
 
 impl<S: Storage + ?Sized> NativeJournalHandler<'_, S> {
@@ -117,21 +125,47 @@ impl<S: Storage + ?Sized> NativeJournalHandler<'_, S> {
             })
     }
 
+    /// Replies with an error to the init message of the program, which wasn't created,
+    /// e.g. because of invalid code.
+    fn send_program_not_created_reply(&mut self, dispatch: StoredDispatch) {
+        let source = dispatch.source();
+        let message_type = self.message_type;
+
+        self.controller.update_state(source, |state, storage, _| {
+            let reply = Dispatch::reply(
+                dispatch.id(),
+                dispatch.destination(),
+                PayloadLookup::empty(),
+                0,
+                ErrorReplyReason::UnavailableActor(SimpleUnavailableActorError::ProgramNotCreated),
+                message_type,
+                false,
+            );
+
+            let queue = state.queue_from_msg_type(message_type);
+            queue.modify_queue(storage, |queue| queue.queue(reply));
+        });
+    }
+
     fn send_dispatch_to_user(
         &mut self,
         _message_id: MessageId,
         dispatch: StoredDispatch,
         delay: u32,
     ) {
-        // TODO: #5227 delay must be taken into account
-        *self.outgoing_messages_limiter = self.outgoing_messages_limiter.saturating_sub(1);
-        *self.outgoing_messages_bytes_limiter =
-            self.outgoing_messages_bytes_limiter.saturating_sub(
-                u32::try_from(dispatch.payload_bytes().len())
-                    .expect("payload size is too big for u32 in outgoing messages bytes limiter"),
-            );
-        if dispatch.is_reply() && self.call_reply {
-            *self.call_reply_limiter = self.call_reply_limiter.saturating_sub(1);
+        // Delayed messages are emitted only once their scheduled task is processed,
+        // so they are accounted for in the limits of the block they're delivered in.
+        if delay == 0 {
+            *self.outgoing_messages_limiter = self.outgoing_messages_limiter.saturating_sub(1);
+            *self.outgoing_messages_bytes_limiter =
+                self.outgoing_messages_bytes_limiter.saturating_sub(
+                    u32::try_from(dispatch.payload_bytes().len()).expect(
+                        "payload size is too big for u32 in outgoing messages bytes limiter",
+                    ),
+                );
+            if dispatch.is_reply() && self.call_reply {
+                *self.call_reply_limiter = self.call_reply_limiter.saturating_sub(1);
+            }
         }
 
         if dispatch.is_reply() {
@@ -291,7 +325,9 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
         // TODO (breathx): handle rest of value cases; exec balance into value_to_receive.
         let balance = self
             .controller
-            .update_state(id_exited, |state, _, transitions| {
+            .update_state(id_exited, |state, storage, transitions| {
+                release_gas_reservations(state, storage);
+
                 state.program = Program::Exited(inheritor);
 
                 transitions.modify_transition(id_exited, |transition| {
@@ -337,7 +373,8 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
         delay: u32,
         reservation: Option<ReservationId>,
     ) {
-        // Reservations are deprecated and gas_limited message dispatches are not supported anymore.
+        // Reservations are released inside runtime by `RuntimeJournalHandler`
+        // and gas_limited message dispatches are not supported anymore.
         if reservation.is_some() || dispatch.gas_limit().map(|v| v != 0).unwrap_or(false) {
             unreachable!("deprecated: {dispatch:?}");
         }
@@ -354,6 +391,8 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
             );
 
             self.send_dispatch_to_program(message_id, destination, dispatch, delay);
+        } else if dispatch.kind() == DispatchKind::Init {
+            self.send_program_not_created_reply(dispatch);
         } else {
             self.send_dispatch_to_user(message_id, dispatch, delay);
         }
@@ -365,18 +404,19 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
         duration: Option<u32>,
         waited_type: MessageWaitedType,
     ) {
-        let Some(mut duration) = duration else {
-            unreachable!("Wait dispatch without specified duration is forbidden in ethexe runtime");
-        };
-
-        match waited_type {
-            MessageWaitedType::Wait => unreachable!("gr_wait is forbidden in ethexe runtime"),
-            MessageWaitedType::WaitUpTo => {
-                // If not gas was not enough for duration, we use safe duration as max
-                duration = duration.min(WAIT_UP_TO_SAFE_DURATION);
+        let duration = match (waited_type, duration) {
+            // `gr_wait` has no duration, so the message is woken after the safe duration,
+            // unless another message wakes it earlier.
+            (MessageWaitedType::Wait, _) => WAIT_UP_TO_SAFE_DURATION,
+            // If not gas was not enough for duration, we use safe duration as max
+            (MessageWaitedType::WaitUpTo, Some(duration)) => duration.min(WAIT_UP_TO_SAFE_DURATION),
+            (MessageWaitedType::WaitFor | MessageWaitedType::WaitUpToFull, Some(duration)) => {
+                duration
             }
-            MessageWaitedType::WaitFor | MessageWaitedType::WaitUpToFull => {}
-        }
+            (_, None) => unreachable!(
+                "Wait dispatch without specified duration is only possible for gr_wait"
+            ),
+        };
 
         let in_blocks =
             NonZero::<u32>::try_from(duration).expect("must be checked on backend side");
@@ -507,11 +547,40 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
 
     fn store_new_programs(
         &mut self,
-        _program_id: ActorId,
-        _code_id: CodeId,
-        _candidates: Vec<(MessageId, ActorId)>,
+        program_id: ActorId,
+        code_id: CodeId,
+        candidates: Vec<(MessageId, ActorId)>,
     ) {
-        todo!()
+        if !(self.is_code_valid)(code_id) {
+            log::debug!("No valid code {code_id} for candidate programs of {program_id}");
+            return;
+        }
+
+        for (init_message_id, candidate_id) in candidates {
+            if self.controller.transitions.is_program(&candidate_id) {
+                log::debug!("Program with id {candidate_id} already exists");
+                continue;
+            }
+
+            self.controller.transitions.register_child(ProgramCreation {
+                actor_id: candidate_id,
+                parent: program_id,
+                code_id,
+                salt: init_message_id.into_bytes().into(),
+            });
+
+            let executable_balance = self.controller.update_state(program_id, |state, _, _| {
+                let value = state
+                    .executable_balance
+                    .min(CREATED_PROGRAM_EXECUTABLE_BALANCE);
+                state.executable_balance -= value;
+                value
+            });
+
+            self.controller.update_state(candidate_id, |state, _, _| {
+                state.executable_balance = executable_balance;
+            });
+        }
     }
 
     fn stop_processing(&mut self, _dispatch: StoredDispatch, _gas_burned: u64) {
@@ -521,46 +590,117 @@ impl<S: Storage + ?Sized> JournalHandler for NativeJournalHandler<'_, S> {
         }
     }
 
-    fn reserve_gas(&mut self, _: MessageId, _: ReservationId, _: ActorId, _: u64, _: u32) {
-        unreachable!("deprecated");
+    fn reserve_gas(
+        &mut self,
+        _message_id: MessageId,
+        reservation_id: ReservationId,
+        program_id: ActorId,
+        _amount: u64,
+        duration: u32,
+    ) {
+        let in_blocks =
+            NonZero::<u32>::try_from(duration).expect("must be checked on backend side");
+
+        self.controller.transitions.schedule_task(
+            in_blocks,
+            ScheduledTask::RemoveGasReservation(program_id, reservation_id),
+        );
     }
 
-    fn unreserve_gas(&mut self, _: ReservationId, _: ActorId, _: u32) {
-        unreachable!("deprecated");
+    fn unreserve_gas(
+        &mut self,
+        reservation_id: ReservationId,
+        program_id: ActorId,
+        expiration: u32,
+    ) {
+        self.controller
+            .transitions
+            .remove_task(
+                expiration,
+                &ScheduledTask::RemoveGasReservation(program_id, reservation_id),
+            )
+            .expect("failed to remove scheduled task");
     }
 
-    fn update_gas_reservation(&mut self, _: ActorId, _: GasReserver) {
-        unreachable!("deprecated");
+    fn update_gas_reservation(&mut self, _program_id: ActorId, _reserver: GasReserver) {
+        unreachable!("Handled inside runtime by `RuntimeJournalHandler`")
     }
 
-    fn system_reserve_gas(&mut self, _: MessageId, _: u64) {
-        unreachable!("deprecated");
+    fn system_reserve_gas(&mut self, _message_id: MessageId, _amount: u64) {
+        unreachable!("Handled inside runtime by `RuntimeJournalHandler`")
     }
 
-    fn system_unreserve_gas(&mut self, _: MessageId) {
-        unreachable!("deprecated");
+    fn system_unreserve_gas(&mut self, _message_id: MessageId) {
+        unreachable!("Handled inside runtime by `RuntimeJournalHandler`")
     }
 
-    fn send_signal(&mut self, _: MessageId, _: ActorId, _: SignalCode) {
-        unreachable!("deprecated");
+    fn send_signal(&mut self, message_id: MessageId, destination: ActorId, code: SignalCode) {
+        let message_type = self.message_type;
+
+        self.controller
+            .update_state(destination, |state, storage, _| {
+                let signal = SignalMessage::new(message_id, code)
+                    .into_dispatch(message_id, destination)
+                    .into_stored();
+                let dispatch = Dispatch::from_core_stored(storage, signal, message_type, false);
+
+                let queue = state.queue_from_msg_type(message_type);
+                queue.modify_queue(storage, |queue| queue.queue(dispatch));
+            });
     }
 
-    fn reply_deposit(&mut self, _: MessageId, _: MessageId, _: u64) {
-        unreachable!("deprecated");
+    fn reply_deposit(&mut self, _message_id: MessageId, _future_reply_id: MessageId, _amount: u64) {
+        unreachable!("Handled inside runtime by `RuntimeJournalHandler`")
     }
 }
 
+/// Returns value held by gas reservations of the program back to its executable balance,
+/// dropping the reservations.
+///
+/// Their removal tasks are kept scheduled and become no-op.
+pub(crate) fn release_gas_reservations<S: Storage + ?Sized>(state: &mut ProgramState, storage: &S) {
+    let Program::Active(ActiveProgram {
+        gas_reservations_hash,
+        ..
+    }) = &mut state.program
+    else {
+        return;
+    };
+
+    let reserved = gas_reservations_hash
+        .map_or_default(|hash| {
+            storage
+                .gas_reservations(hash)
+                .expect("failed to read gas reservations")
+        })
+        .total_amount();
+    *gas_reservations_hash = MaybeHashOf::empty();
+
+    state.executable_balance = state
+        .executable_balance
+        .checked_add(gas_to_value(reserved))
+        .expect("Overflow in executable_balance += reserved value");
+}
+
+/// Converts gas into value charged from the executable balance.
+pub(crate) fn gas_to_value(gas: u64) -> u128 {
+    GasMultiplier::from_value_per_gas(VALUE_PER_GAS).gas_to_value(gas)
+}
+
 // Handles unprocessed journal notes during message processing in the runtime.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct RuntimeQueueReport {
     pub dispatched: Vec<RuntimeDispatchReport>,
     pub gas_burned: Vec<RuntimeGasBurnReport>,
+    /// Records of processed dispatches, for the message history index.
+    pub records: Vec<MessageRecord>,
 }
 
 impl RuntimeQueueReport {
     pub fn extend(&mut self, other: Self) {
         self.dispatched.extend(other.dispatched);
         self.gas_burned.extend(other.gas_burned);
+        self.records.extend(other.records);
     }
 }
 
@@ -646,6 +786,7 @@ where
     pub program_state: &'s mut ProgramState,
     pub gas_allowance_counter: &'s mut GasAllowanceCounter,
     pub gas_multiplier: &'s GasMultiplier,
+    pub block_height: u32,
     pub message_type: MessageType,
     pub is_first_execution: bool,
     pub stop_processing: bool,
@@ -678,7 +819,15 @@ where
         let mut messages_to_skip = BTreeSet::new();
 
         let filtered: Vec<_> = journal
-            .filter_map(|note| {
+            .filter_map(|mut note| {
+                // Messages are executed at the receiver's expense on ethexe,
+                // so sending from a reservation releases the gas it holds.
+                if let JournalNote::SendDispatch { reservation, .. } = &mut note
+                    && let Some(reservation_id) = reservation.take()
+                {
+                    self.release_gas_reservation(reservation_id);
+                }
+
                 match note {
                     JournalNote::MessageDispatched {
                         message_id,
@@ -730,6 +879,17 @@ where
                             messages_to_skip.insert(message_id);
                         }
                     }
+                    JournalNote::UpdateGasReservations {
+                        program_id: _,
+                        reserver,
+                    } => {
+                        self.update_gas_reservations(reserver);
+                    }
+                    // Executions are paid from the executable balance of the receiver,
+                    // so there is no gas to hold for system messages and replies.
+                    JournalNote::SystemReserveGas { .. }
+                    | JournalNote::SystemUnreserveGas { .. }
+                    | JournalNote::ReplyDeposit { .. } => {}
                     note @ JournalNote::StopProcessing {
                         dispatch: _,
                         gas_burned,
@@ -745,12 +905,15 @@ where
                     note => {
                         match &note {
                             JournalNote::SendDispatch { message_id, .. }
+                            | JournalNote::SendSignal { message_id, .. }
                                 if messages_to_skip.contains(message_id) =>
                             {
                                 return None;
                             }
-                            JournalNote::SendDispatch { dispatch, .. } => {
-                                // TODO: #5227 delay must be taken into account
+                            // Delayed dispatches don't leave the program within this run.
+                            JournalNote::SendDispatch {
+                                dispatch, delay, ..
+                            } if *delay == 0 => {
                                 self.limiter.outgoing_messages =
                                     self.limiter.outgoing_messages.saturating_sub(1);
                                 self.limiter.outgoing_messages_bytes =
@@ -827,6 +990,8 @@ where
             } => {
                 log::trace!("Dispatch {message_id} failed init of program {program_id}: {reason}");
 
+                release_gas_reservations(self.program_state, self.storage);
+
                 self.program_state.program = Program::Terminated(origin)
             }
 
@@ -879,6 +1044,63 @@ where
         }
     }
 
+    fn update_gas_reservations(&mut self, reserver: GasReserver) {
+        let Program::Active(ActiveProgram {
+            gas_reservations_hash,
+            ..
+        }) = &mut self.program_state.program
+        else {
+            panic!("an attempt to update gas reservations of inactive program");
+        };
+
+        let block_height = self.block_height;
+        let (reserved_before, reserved_after) =
+            self.storage.modify(gas_reservations_hash, |reservations| {
+                let reserved_before = reservations.total_amount();
+                reservations.update(reserver.into_map(block_height, |duration| {
+                    block_height.saturating_add(duration)
+                }));
+
+                (reserved_before, reservations.total_amount())
+            });
+
+        // Reserved gas isn't burned, so its value is held from the executable balance
+        // until the reservation is unreserved, sent from or expired.
+        if let Some(reserved) = reserved_after.checked_sub(reserved_before) {
+            self.charge_exec_balance(reserved);
+        } else {
+            self.refund_exec_balance(reserved_before - reserved_after);
+        }
+    }
+
+    fn release_gas_reservation(&mut self, reservation_id: ReservationId) {
+        let Program::Active(ActiveProgram {
+            gas_reservations_hash,
+            ..
+        }) = &mut self.program_state.program
+        else {
+            panic!("an attempt to send from gas reservation of inactive program");
+        };
+
+        let slot = self
+            .storage
+            .modify(gas_reservations_hash, |reservations| {
+                reservations.remove(&reservation_id)
+            })
+            .expect("an attempt to send from unknown gas reservation");
+
+        self.refund_exec_balance(slot.amount);
+    }
+
+    fn refund_exec_balance(&mut self, gas: u64) {
+        let refunded_value = self.gas_multiplier.gas_to_value(gas);
+        self.program_state.executable_balance = self
+            .program_state
+            .executable_balance
+            .checked_add(refunded_value)
+            .expect("Overflow in executable_balance += refunded_value");
+    }
+
     fn charge_exec_balance(&mut self, gas_burned: u64) {
         let spent_value = self.gas_multiplier.gas_to_value(gas_burned);
         self.program_state.executable_balance = self
@@ -932,8 +1154,8 @@ mod tests {
     use gear_core::{
         ids::prelude::MessageIdExt,
         message::{
-            DispatchKind, Message as CoreMessage, MessageDetails, ReplyCode, ReplyDetails,
-            StoredMessage,
+            DispatchKind, IncomingDispatch, IncomingMessage, Message as CoreMessage,
+            MessageDetails, ReplyCode, ReplyDetails, StoredMessage,
         },
     };
 
@@ -941,8 +1163,10 @@ mod tests {
 
     use crate::{
         InBlockTransitions, TransitionsConfig,
-        state::MemStorage,
-        transitions::{ETH_SAILS_EVENT, GEAR_SAILS_EVENT, NonFinalTransition},
+        state::{MemStorage, QueryableStorage},
+        transitions::{
+            ETH_SAILS_EVENT, FinalizedBlockTransitions, GEAR_SAILS_EVENT, NonFinalTransition,
+        },
     };
 
     fn init_setup(
@@ -972,6 +1196,7 @@ mod tests {
             program_state,
             gas_allowance_counter,
             gas_multiplier,
+            block_height: 0,
             message_type,
             is_first_execution,
             stop_processing: false,
@@ -1038,6 +1263,7 @@ mod tests {
                 outgoing_messages_limiter: &mut outgoing_messages_limiter,
                 outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
                 call_reply_limiter: &mut call_reply_limiter,
+                is_code_valid: Box::new(|_| true),
             };
 
             handler.send_dispatch(MessageId::from(9), dispatch_to(destination, 11), 0, None);
@@ -1146,6 +1372,7 @@ mod tests {
                 outgoing_messages_limiter: &mut outgoing_messages_limiter,
                 outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
                 call_reply_limiter: &mut call_reply_limiter,
+                is_code_valid: Box::new(|_| true),
             };
 
             handler.send_dispatch(
@@ -1156,6 +1383,10 @@ mod tests {
             );
         }
 
+        // Delayed message isn't emitted yet, so it doesn't consume outgoing limits.
+        assert_eq!(outgoing_messages_limiter, 10);
+        assert_eq!(outgoing_messages_bytes_limiter, 1024);
+
         let transition = transitions.modifications_mut().get(&source).unwrap();
         assert!(transition.messages.is_empty());
         assert!(transition.claims.is_empty());
@@ -1294,6 +1525,92 @@ mod tests {
         assert!(report.gas_burned[0].charged_to_executable_balance);
     }
 
+    #[test]
+    fn runtime_journal_handler_limits_skip_delayed_dispatches() {
+        const INITIAL_EXEC_BALANCE: u128 = 500_000_000_000;
+
+        let mut handler = init_setup(INITIAL_EXEC_BALANCE, MessageType::Canonical, true);
+        let send_dispatch = |delay| JournalNote::SendDispatch {
+            message_id: MessageId::from(42),
+            dispatch: dispatch_to(ActorId::from(8), 0),
+            delay,
+            reservation: None,
+        };
+
+        let (filtered, _hash, _report) =
+            handler.handle_journal_with_report(vec![send_dispatch(0), send_dispatch(5)]);
+
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(handler.limiter.outgoing_messages, 31);
+    }
+
+    #[test]
+    fn runtime_journal_handler_holds_reserved_gas() {
+        const INITIAL_EXEC_BALANCE: u128 = 500_000_000_000;
+
+        let mut handler = init_setup(INITIAL_EXEC_BALANCE, MessageType::Canonical, true);
+        handler.block_height = 10;
+
+        let message_id = MessageId::from(42);
+        let incoming = IncomingDispatch::new(
+            DispatchKind::Handle,
+            IncomingMessage::new(message_id, ActorId::from(7), Default::default(), 0, 0, None),
+            None,
+        );
+        let mut reserver = GasReserver::new(
+            &incoming,
+            Default::default(),
+            crate::MAX_RESERVATIONS_PER_PROGRAM,
+        );
+        let kept = reserver.reserve(1_000, 5).unwrap();
+        let sent_from = reserver.reserve(2_000, 5).unwrap();
+        reserver.mark_used(sent_from).unwrap();
+
+        let (filtered, _hash, _report) = handler.handle_journal_with_report(vec![
+            JournalNote::UpdateGasReservations {
+                program_id: ActorId::from(1),
+                reserver,
+            },
+            JournalNote::SendDispatch {
+                message_id,
+                dispatch: dispatch_to(ActorId::from(8), 0),
+                delay: 0,
+                reservation: Some(sent_from),
+            },
+        ]);
+
+        // Sending from a reservation releases it, as the receiver pays for execution.
+        assert!(matches!(
+            filtered.as_slice(),
+            [JournalNote::SendDispatch {
+                reservation: None,
+                ..
+            }]
+        ));
+        assert_eq!(
+            handler.program_state.executable_balance,
+            INITIAL_EXEC_BALANCE - handler.gas_multiplier.gas_to_value(1_000)
+        );
+
+        let Program::Active(ActiveProgram {
+            gas_reservations_hash,
+            ..
+        }) = handler.program_state.program
+        else {
+            unreachable!("program is active");
+        };
+        let reservations = handler.storage.query(&gas_reservations_hash).unwrap();
+        let slot = reservations.as_ref().get(&kept).unwrap();
+        assert_eq!((slot.amount, slot.start, slot.finish), (1_000, 10, 15));
+        assert_eq!(reservations.total_amount(), 1_000);
+
+        release_gas_reservations(handler.program_state, handler.storage);
+        assert_eq!(
+            handler.program_state.executable_balance,
+            INITIAL_EXEC_BALANCE
+        );
+    }
+
     #[test]
     fn runtime_journal_handler_reports_injected_panic_charge_exception() {
         const INITIAL_EXEC_BALANCE: u128 = 500_000_000_000;
@@ -1433,6 +1750,7 @@ mod tests {
                 outgoing_messages_limiter: &mut outgoing_messages_limiter,
                 outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
                 call_reply_limiter: &mut call_reply_limiter,
+                is_code_valid: Box::new(|_| true),
             };
             handler.send_dispatch(MessageId::from(9), dispatch_to(destination, value), 0, None);
         }
@@ -1501,6 +1819,7 @@ mod tests {
                 outgoing_messages_limiter: &mut outgoing_messages_limiter,
                 outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
                 call_reply_limiter: &mut call_reply_limiter,
+                is_code_valid: Box::new(|_| true),
             };
             handler.send_dispatch(MessageId::from(9), reply_dispatch, 0, None);
         }
@@ -1640,4 +1959,140 @@ mod tests {
             );
         }
     }
+
+    fn create_program_transitions(code_valid: bool) -> (MemStorage, InBlockTransitions) {
+        let storage = MemStorage::default();
+        let parent = ActorId::from(7);
+        let child = ActorId::from(8);
+        let code_id = CodeId::from(42);
+        let mut state = ProgramState::zero();
+        state.executable_balance = 100;
+        let state_hash = storage.write_program_state(state);
+        let states = ProgramStates::from_iter([(
+            parent,
+            StateHashWithQueueSize {
+                hash: state_hash,
+                canonical_queue_size: 0,
+                injected_queue_size: 0,
+            },
+        )]);
+        let mut transitions =
+            InBlockTransitions::new(Default::default(), states, Default::default());
+        let gas_allowance_counter = GasAllowanceCounter::new(1_000_000);
+        let mut out_of_gas = false;
+        let mut outgoing_messages_limiter = 10;
+        let mut outgoing_messages_bytes_limiter = 1024;
+        let mut call_reply_limiter = 10;
+
+        {
+            let mut handler = NativeJournalHandler {
+                program_id: parent,
+                message_type: MessageType::Canonical,
+                call_reply: false,
+                controller: TransitionController {
+                    storage: &storage,
+                    transitions: &mut transitions,
+                },
+                gas_allowance_counter: &gas_allowance_counter,
+                chunk_gas_limit: 1_000_000,
+                out_of_gas: &mut out_of_gas,
+                outgoing_messages_limiter: &mut outgoing_messages_limiter,
+                outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
+                call_reply_limiter: &mut call_reply_limiter,
+                is_code_valid: Box::new(move |id| code_valid && id == code_id),
+            };
+
+            handler.store_new_programs(parent, code_id, vec![(MessageId::from(10), child)]);
+            handler.send_dispatch(
+                MessageId::from(9),
+                CoreDispatch::new(
+                    DispatchKind::Init,
+                    CoreMessage::new(
+                        MessageId::from(10),
+                        parent,
+                        child,
+                        Default::default(),
+                        None,
+                        0,
+                        None,
+                    ),
+                ),
+                0,
+                None,
+            );
+        }
+
+        (storage, transitions)
+    }
+
+    #[test]
+    fn store_new_programs_registers_child_with_executable_balance() {
+        let parent = ActorId::from(7);
+        let child = ActorId::from(8);
+        let (storage, transitions) = create_program_transitions(true);
+
+        assert!(transitions.is_program(&child));
+        assert_eq!(
+            transitions.registered_programs().get(&child),
+            Some(&CodeId::from(42))
+        );
+
+        let parent_state = storage
+            .program_state(transitions.state_of(&parent).unwrap().hash)
+            .unwrap();
+        assert_eq!(parent_state.executable_balance, 0);
+        assert!(parent_state.canonical_queue.is_empty());
+
+        let child_state = storage
+            .program_state(transitions.state_of(&child).unwrap().hash)
+            .unwrap();
+        assert_eq!(child_state.executable_balance, 100);
+
+        let mut queue = child_state.canonical_queue.query(&storage).unwrap();
+        let init = queue.dequeue().expect("init message must be queued");
+        assert_eq!(init.id, MessageId::from(10));
+        assert_eq!(init.kind, DispatchKind::Init);
+
+        let FinalizedBlockTransitions {
+            child_program_creations,
+            ..
+        } = transitions.finalize();
+        assert_eq!(
+            child_program_creations,
+            vec![ProgramCreation {
+                actor_id: child,
+                parent,
+                code_id: CodeId::from(42),
+                salt: MessageId::from(10).into_bytes().into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn store_new_programs_replies_with_error_on_invalid_code() {
+        let parent = ActorId::from(7);
+        let child = ActorId::from(8);
+        let (storage, transitions) = create_program_transitions(false);
+
+        assert!(!transitions.is_program(&child));
+
+        let parent_state = storage
+            .program_state(transitions.state_of(&parent).unwrap().hash)
+            .unwrap();
+        assert_eq!(parent_state.executable_balance, 100);
+
+        let mut queue = parent_state.canonical_queue.query(&storage).unwrap();
+        let reply = queue.dequeue().expect("error reply must be queued");
+        assert_eq!(reply.kind, DispatchKind::Reply);
+        assert_eq!(reply.source, child);
+
+        let details = reply.details.unwrap().to_reply_details().unwrap();
+        assert_eq!(details.to_message_id(), MessageId::from(10));
+        assert_eq!(
+            details.to_reply_code(),
+            ReplyCode::Error(ErrorReplyReason::UnavailableActor(
+                SimpleUnavailableActorError::ProgramNotCreated
+            ))
+        );
+    }
 }
@@ -22,14 +22,14 @@
 //! ## Public API
 //!
 //! - [`process_queue`] / [`process_queue_with_report`] — Entry points: dequeue and execute a program's pending dispatches within
-//!   the gas budget, returning [`ProgramJournals`] and gas spent; the `_with_report` variant additionally returns a per-run
-//!   runtime queue report.
+//!   the gas budget, returning [`ProgramJournals`], gas spent and records of processed dispatches, if requested by the context;
+//!   the `_with_report` variant returns a full per-run runtime queue report instead of the records.
 //! - [`RuntimeInterface`] — Seam to the embedding environment; extends [`state::Storage`] with lazy-page init, randomness,
 //!   state-hash notification, and promise publishing. Associated `LazyPages: LazyPagesInterface`.
 //! - [`state::Storage`] — Content-addressed read/write of [`state::ProgramState`] and every state component (queues, waitlist,
 //!   dispatch stash, mailbox, memory pages, allocations).
 //! - [`ProcessQueueContext`] — SCALE-encoded input for one queue-processing run: program id, state root, queue type, instrumented
-//!   code, block info, promise policy.
+//!   code, block info, random seed, promise policy.
 //! - [`TransitionController`] — Wraps `&Storage` + `&mut InBlockTransitions`; `update_state` reads a program's state, applies a
 //!   closure, writes it back, and records the new hash.
 //! - [`InBlockTransitions`] / [`FinalizedBlockTransitions`] / [`NonFinalTransition`] — Per-block accumulators of per-program
@@ -48,8 +48,12 @@
 //! - Promise policy must be disabled for the canonical queue.
 //! - Uninitialized programs accept only `Init` or `Reply` dispatches; any other kind produces an
 //!   error reply.
-//! - Forbidden syscalls (reservations, signals, `Random`, `CreateProgram`, and all deprecated `*WGas`
-//!   variants) are blocked on every [`process_queue`] call.
+//! - Forbidden syscalls (all deprecated `*WGas` variants) are blocked on every [`process_queue`]
+//!   call.
+//! - Gas reserved by a program is held from its executable balance until the reservation is
+//!   unreserved, sent from or expired.
+//! - Random data exposed to programs is derived only from [`ProcessQueueContext::random_seed`] and
+//!   the dispatch id, so every validator observes the same value for the same dispatch.
 //! - [`TransitionController::update_state`] requires the program to be in the tracked set with a state
 //!   readable from storage.
 
@@ -61,10 +65,10 @@ use crate::journal::{Limiter, LimitsStatus};
 use alloc::vec::Vec;
 use ethexe_common::{
     HashOf, PromisePolicy,
+    db::{MessageOutcome, MessageRecord, MessageReply},
     gear::{CHUNK_PROCESSING_GAS_LIMIT, MessageType},
     injected::Promise,
 };
-use ext::Ext;
 use gear_core::{
     code::{
         CodeMetadata, InstrumentedCode, InstrumentedCodeAndMetadata, MAX_WASM_PAGES_AMOUNT,
@@ -78,7 +82,7 @@ use gear_core::{
 };
 use gear_core_processor::{
     ContextCharged, ProcessExecutionContext,
-    common::{ExecutableActorData, JournalNote},
+    common::{DispatchOutcome, ExecutableActorData, JournalNote},
     configs::{BlockConfig, SyscallName},
 };
 use gear_lazy_pages_common::LazyPagesInterface;
@@ -88,6 +92,7 @@ use journal::RuntimeJournalHandler;
 use parity_scale_codec::{Decode, Encode};
 use state::{Dispatch, ProgramState, Storage};
 
+pub use ext::Ext;
 pub use gear_core_processor::configs::BlockInfo;
 pub use journal::{
     NativeJournalHandler as JournalHandler, RuntimeDispatchReport, RuntimeGasBurnReport,
@@ -125,6 +130,18 @@ pub const MAX_OUTGOING_MESSAGES_PER_RUN: u32 = 16;
 pub const MAX_OUTGOING_MESSAGES_BYTES_PER_RUN: u32 = 4 * 1024;
 /// Maximum number of call replies per process_queue run.
 pub const MAX_CALL_REPLIES_PER_RUN: u32 = 1;
+/// Maximum number of gas reservations a program can hold at once.
+pub const MAX_RESERVATIONS_PER_PROGRAM: u64 = 256;
+/// Amount of value charged from executable balance for one unit of gas.
+pub const VALUE_PER_GAS: u128 = 100;
+/// Executable balance transferred from the parent program to the program it creates,
+/// limited by the parent's own executable balance.
+///
+/// A single dispatch can't burn more than [`CHUNK_PROCESSING_GAS_LIMIT`], so this
+/// is exactly enough to pay for the child's init message in the worst case without
+/// locking more of the parent's balance than the init may need.
+pub const CREATED_PROGRAM_EXECUTABLE_BALANCE: u128 =
+    CHUNK_PROCESSING_GAS_LIMIT as u128 * VALUE_PER_GAS;
 
 pub type ProgramJournals = Vec<(Vec<JournalNote>, MessageType, bool)>;
 
@@ -137,15 +154,30 @@ pub struct ProcessQueueContext {
     pub queue_type: MessageType,
     pub gas_allowance: GasAllowanceCounter,
     pub block_info: BlockInfo,
+    /// Randomness seed of the block being processed.
+    ///
+    /// Must be the same for every validator processing the block.
+    pub random_seed: H256,
     pub promise_policy: PromisePolicy,
     pub code: Option<(InstrumentedCode, CodeMetadata)>,
+    /// Whether to build records of processed dispatches for the message history.
+    pub record_messages: bool,
 }
 
 pub trait RuntimeInterface: Storage {
     type LazyPages: LazyPagesInterface + 'static;
 
     fn init_lazy_pages(&self);
-    fn random_data(&self) -> (Vec<u8>, u32);
+
+    /// Returns random data, exposed to the program via `gr_random`, for the given dispatch.
+    ///
+    /// The default implementation mixes the block seed with the dispatch id and reports
+    /// the block height as the block the randomness is determinable from.
+    fn random_data(&self, ctx: &ProcessQueueContext, dispatch_id: MessageId) -> (Vec<u8>, u32) {
+        let random = gear_core::ids::hash_of_array([ctx.random_seed.0, dispatch_id.into_bytes()]);
+        (random.to_vec(), ctx.block_info.height)
+    }
+
     fn update_state_hash(&self, state_hash: &H256);
     /// Publish a promise produced during execution to the compute service layer.
     /// The implementation is expected to forward it to external subscribers.
@@ -197,13 +229,16 @@ impl<S: Storage + ?Sized> TransitionController<'_, S> {
     }
 }
 
-pub fn process_queue<RI>(ctx: ProcessQueueContext, ri: &RI) -> (ProgramJournals, u64)
+pub fn process_queue<RI>(
+    ctx: ProcessQueueContext,
+    ri: &RI,
+) -> (ProgramJournals, u64, Vec<MessageRecord>)
 where
     RI: RuntimeInterface + 'static,
     RI::LazyPages: Send,
 {
-    let (journals, gas_spent, _report) = process_queue_with_report(ctx, ri);
-    (journals, gas_spent)
+    let (journals, gas_spent, report) = process_queue_with_report(ctx, ri);
+    (journals, gas_spent, report.records)
 }
 
 pub fn process_queue_with_report<RI>(
@@ -242,29 +277,16 @@ where
     let block_config = BlockConfig {
         block_info: ctx.block_info,
         forbidden_funcs: [
-            SyscallName::CreateProgramWGas,      // Deprecated
-            SyscallName::CreateProgram,          // Unimplemented
-            SyscallName::ReplyDeposit,           // Deprecated
-            SyscallName::SignalCode,             // TBD about deprecation
-            SyscallName::Random,                 // Unimplemented
-            SyscallName::ReplyCommitWGas,        // Deprecated
-            SyscallName::SignalFrom,             // TBD about deprecation
-            SyscallName::ReplyInputWGas,         // Deprecated
-            SyscallName::ReplyWGas,              // Deprecated
-            SyscallName::ReservationReplyCommit, // Deprecated
-            SyscallName::ReservationReply,       // Deprecated
-            SyscallName::ReservationSendCommit,  // Deprecated
-            SyscallName::ReservationSend,        // Deprecated
-            SyscallName::ReserveGas,             // Deprecated
-            SyscallName::SendCommitWGas,         // Deprecated
-            SyscallName::SendInputWGas,          // Deprecated
-            SyscallName::SendWGas,               // Deprecated
-            SyscallName::SystemReserveGas,       // Deprecated
-            SyscallName::UnreserveGas,           // Deprecated
-            SyscallName::Wait,                   // Deprecated
+            SyscallName::CreateProgramWGas, // Deprecated
+            SyscallName::ReplyCommitWGas,   // Deprecated
+            SyscallName::ReplyInputWGas,    // Deprecated
+            SyscallName::ReplyWGas,         // Deprecated
+            SyscallName::SendCommitWGas,    // Deprecated
+            SyscallName::SendInputWGas,     // Deprecated
+            SyscallName::SendWGas,          // Deprecated
         ]
         .into(),
-        gas_multiplier: GasMultiplier::from_value_per_gas(100),
+        gas_multiplier: GasMultiplier::from_value_per_gas(VALUE_PER_GAS),
         costs: Schedule::default().process_costs(),
         max_pages: MAX_WASM_PAGES_AMOUNT.into(),
         outgoing_limit: MAX_OUTGOING_MESSAGES_PER_EXECUTION,
@@ -274,8 +296,8 @@ where
         // Deprecated
         existential_deposit: 0,
         mailbox_threshold: 0,
-        max_reservations: 0,
         reserve_for: 0,
+        max_reservations: MAX_RESERVATIONS_PER_PROGRAM,
     };
 
     let mut mega_journal = Vec::new();
@@ -292,6 +314,8 @@ where
 
     for dispatch in queue {
         let dispatch_id = dispatch.id;
+        let source = dispatch.source;
+        let value = dispatch.value;
         let message_type = dispatch.message_type;
         let call_reply = dispatch.call;
         let is_first_execution = dispatch.context.is_none();
@@ -303,6 +327,7 @@ where
             program_state: &mut program_state,
             gas_allowance_counter: &mut ctx.gas_allowance,
             gas_multiplier: &block_config.gas_multiplier,
+            block_height: ctx.block_info.height,
             message_type: ctx.queue_type,
             is_first_execution,
             stop_processing: false,
@@ -319,9 +344,15 @@ where
             parse_journal_for_injected_dispatch(ri, &journal, dispatch_id);
         }
 
+        let record = ctx
+            .record_messages
+            .then(|| message_record(ctx.program_id, dispatch_id, source, value, &journal))
+            .flatten();
+
         let (unhandled_journal_notes, new_state_hash, dispatch_report) =
             handler.handle_journal_with_report(journal);
         report.extend(dispatch_report);
+        report.records.extend(record);
         mega_journal.push((unhandled_journal_notes, message_type, call_reply));
 
         // Update state hash if it was changed.
@@ -393,6 +424,75 @@ where
     }
 }
 
+/// Builds the [`MessageRecord`] of the dispatch from its journal.
+///
+/// Returns `None` if processing was stopped, so the dispatch stays in the queue.
+fn message_record(
+    program_id: ActorId,
+    dispatch_id: MessageId,
+    source: ActorId,
+    value: u128,
+    journal: &[JournalNote],
+) -> Option<MessageRecord> {
+    let mut record = MessageRecord {
+        message_id: dispatch_id,
+        program_id,
+        source,
+        value,
+        outcome: MessageOutcome::NoExecution,
+        gas_burned: 0,
+        value_sent: 0,
+        waited: false,
+        reply: None,
+    };
+
+    for note in journal {
+        match note {
+            JournalNote::MessageDispatched { outcome, .. } => {
+                record.outcome = match outcome {
+                    DispatchOutcome::Success => MessageOutcome::Success,
+                    DispatchOutcome::NoExecution => MessageOutcome::NoExecution,
+                    DispatchOutcome::Exit { .. } => MessageOutcome::Exit,
+                    DispatchOutcome::InitSuccess { .. } => MessageOutcome::InitSuccess,
+                    DispatchOutcome::InitFailure { reason, .. } => MessageOutcome::InitFailure {
+                        reason: reason.clone(),
+                    },
+                    DispatchOutcome::MessageTrap { trap, .. } => MessageOutcome::Trap {
+                        reason: trap.clone(),
+                    },
+                };
+            }
+            JournalNote::GasBurned { amount, .. } => {
+                record.gas_burned = record.gas_burned.saturating_add(*amount);
+            }
+            JournalNote::SendDispatch {
+                message_id,
+                dispatch,
+                ..
+            } if *message_id == dispatch_id => {
+                record.value_sent = record.value_sent.saturating_add(dispatch.value());
+
+                if let Some(details) = dispatch.reply_details()
+                    && details.to_message_id() == dispatch_id
+                {
+                    record.reply = Some(MessageReply {
+                        message_id: dispatch.id(),
+                        code: details.to_reply_code(),
+                        value: dispatch.value(),
+                    });
+                }
+            }
+            JournalNote::WaitDispatch { dispatch, .. } if dispatch.id() == dispatch_id => {
+                record.waited = true;
+            }
+            JournalNote::StopProcessing { .. } => return None,
+            _ => {}
+        }
+    }
+
+    Some(record)
+}
+
 fn process_dispatch<RI>(
     dispatch: Dispatch,
     block_config: &BlockConfig,
@@ -499,9 +599,14 @@ where
         Err(journal) => return journal,
     };
 
+    let gas_reservations = active_state.gas_reservations_hash.map_or_default(|hash| {
+        ri.gas_reservations(hash)
+            .expect("Cannot get gas reservations")
+    });
+
     let actor_data = ExecutableActorData {
         allocations: allocations.into(),
-        gas_reservation_map: Default::default(), // TODO (gear_v2): deprecate it.
+        gas_reservation_map: gas_reservations.into_inner(),
         memory_infix: active_state.memory_infix,
     };
 
@@ -525,7 +630,7 @@ where
         SyscallKind::Eth,
     );
 
-    let random_data = ri.random_data();
+    let random_data = ri.random_data(ctx, dispatch_id);
 
     gear_core_processor::process::<Ext<RI>>(block_config, execution_context, random_data)
         .unwrap_or_else(|err| unreachable!("{err}"))
@@ -558,10 +663,6 @@ mod tests {
 
         fn init_lazy_pages(&self) {}
 
-        fn random_data(&self) -> (Vec<u8>, u32) {
-            (Vec::new(), 0)
-        }
-
         fn update_state_hash(&self, _state_hash: &H256) {}
 
         fn publish_promise(&self, _promise: &Promise) {}
@@ -574,7 +675,9 @@ mod tests {
             queue_type: MessageType::Canonical,
             gas_allowance: GasAllowanceCounter::new(1_000_000),
             block_info: BlockInfo::default(),
+            random_seed: H256::zero(),
             promise_policy: PromisePolicy::Disabled,
+            record_messages: true,
             code: Some((
                 InstrumentedCode::new(Vec::new(), InstantiatedSectionSizes::new(0, 0, 0, 0, 0, 0)),
                 CodeMetadata::new(
@@ -594,7 +697,7 @@ mod tests {
 
         let (journals, gas_spent, report) =
             process_queue_with_report(empty_queue_context(&storage), &storage);
-        let (legacy_journals, legacy_gas_spent) =
+        let (legacy_journals, legacy_gas_spent, records) =
             process_queue(empty_queue_context(&storage), &storage);
 
         assert!(journals.is_empty());
@@ -602,5 +705,24 @@ mod tests {
         assert_eq!(report, RuntimeQueueReport::default());
         assert!(legacy_journals.is_empty());
         assert_eq!(legacy_gas_spent, gas_spent);
+        assert!(records.is_empty());
+    }
+
+    #[test]
+    fn random_data_depends_on_seed_and_dispatch() {
+        let storage = MemStorage::default();
+        let mut ctx = empty_queue_context(&storage);
+        ctx.block_info.height = 7;
+        ctx.random_seed = H256::repeat_byte(1);
+
+        let (random, bn) = storage.random_data(&ctx, MessageId::from(1));
+
+        assert_eq!(bn, 7);
+        assert_eq!(random.len(), 32);
+        assert_eq!(storage.random_data(&ctx, MessageId::from(1)).0, random);
+        assert_ne!(storage.random_data(&ctx, MessageId::from(2)).0, random);
+
+        ctx.random_seed = H256::repeat_byte(2);
+        assert_ne!(storage.random_data(&ctx, MessageId::from(1)).0, random);
     }
 }
@@ -4,9 +4,10 @@
 use crate::{
     FinalizedBlockTransitions, InBlockTransitions, NonFinalTransition, TransitionsConfig,
     state::{
-        ActiveProgram, Allocations, Dispatch, DispatchStash, Expiring, Mailbox, MailboxMessage,
-        MemStorage, MemoryPages, MemoryPagesRegion, MessageQueue, MessageQueueHashWithSize,
-        PayloadLookup, Program, ProgramState, RegionIdx, Storage, UserMailbox, Waitlist,
+        ActiveProgram, Allocations, Dispatch, DispatchStash, Expiring, GasReservations, Mailbox,
+        MailboxMessage, MemStorage, MemoryPages, MemoryPagesRegion, MessageQueue,
+        MessageQueueHashWithSize, PayloadLookup, Program, ProgramState, RegionIdx, Storage,
+        UserMailbox, Waitlist,
     },
 };
 #[cfg(test)]
@@ -738,15 +739,25 @@ impl Arbitrary for ActiveProgram {
         (
             maybe_hash_of_strategy::<Allocations>(),
             maybe_hash_of_strategy::<MemoryPages>(),
+            maybe_hash_of_strategy::<GasReservations>(),
             any::<u32>().prop_map(MemoryInfix::new),
             any::<bool>(),
         )
             .prop_map(
-                |(allocations_hash, pages_hash, memory_infix, initialized)| Self {
+                |(
                     allocations_hash,
                     pages_hash,
+                    gas_reservations_hash,
                     memory_infix,
                     initialized,
+                )| {
+                    Self {
+                        allocations_hash,
+                        pages_hash,
+                        gas_reservations_hash,
+                        memory_infix,
+                        initialized,
+                    }
                 },
             )
             .boxed()
@@ -1053,7 +1064,9 @@ impl Arbitrary for FinalizedBlockTransitions {
                 states,
                 schedule,
                 program_creations,
+                child_program_creations: Default::default(),
                 committed_message_ids: Default::default(),
+                message_records: Default::default(),
             })
             .boxed()
     }
@@ -3,10 +3,11 @@
 
 use crate::{
     TransitionController,
-    journal::push_outgoing,
+    journal::{gas_to_value, push_outgoing},
     state::{
-        Dispatch, DispatchStash, Expiring, MailboxMessage, ModifiableStorage, PayloadLookup,
-        ProgramState, QueryableStorage, Storage, UserMailbox, Waitlist,
+        ActiveProgram, Dispatch, DispatchStash, Expiring, GasReservations, MailboxMessage,
+        ModifiableStorage, PayloadLookup, Program, ProgramState, QueryableStorage, Storage,
+        UserMailbox, Waitlist,
     },
     transitions::is_event_destination,
 };
@@ -70,11 +71,19 @@ impl<S: Storage> TaskHandler<Rfm, Sd, Sum> for Handler<'_, S> {
 
     fn send_dispatch(&mut self, (program_id, message_id): (ActorId, MessageId)) -> u64 {
         self.controller
-            .update_state(program_id, |state, storage, _| {
+            .update_state(program_id, |state, storage, transitions| {
                 let dispatch = storage.modify(&mut state.stash_hash, |stash| {
                     stash.remove_to_program(&message_id)
                 });
 
+                let payload_len = dispatch
+                    .payload
+                    .clone()
+                    .query(storage)
+                    .expect("failed to query payload of delayed dispatch")
+                    .len();
+                transitions.record_delivered_dispatch(payload_len);
+
                 let queue = state.queue_from_msg_type(dispatch.message_type);
                 queue.modify_queue(storage, |queue| {
                     queue.queue(dispatch);
@@ -177,13 +186,41 @@ impl<S: Storage> TaskHandler<Rfm, Sd, Sum> for Handler<'_, S> {
         0
     }
 
+    fn remove_gas_reservation(
+        &mut self,
+        program_id: ActorId,
+        reservation_id: ReservationId,
+    ) -> u64 {
+        self.controller
+            .update_state(program_id, |state, storage, _| {
+                let Program::Active(ActiveProgram {
+                    gas_reservations_hash,
+                    ..
+                }) = &mut state.program
+                else {
+                    return;
+                };
+
+                // Reservations sent from or released on exit keep their task scheduled.
+                let Some(slot) = storage.modify(gas_reservations_hash, |reservations| {
+                    reservations.remove(&reservation_id)
+                }) else {
+                    return;
+                };
+
+                state.executable_balance = state
+                    .executable_balance
+                    .checked_add(gas_to_value(slot.amount))
+                    .expect("Overflow in executable_balance += reserved value");
+            });
+
+        0
+    }
+
     /* Deprecated APIs */
     fn remove_from_waitlist(&mut self, _program_id: ActorId, _message_id: MessageId) -> u64 {
         unreachable!("considering deprecation of it; use `wake_message` instead")
     }
-    fn remove_gas_reservation(&mut self, _: ActorId, _: ReservationId) -> u64 {
-        unreachable!("deprecated")
-    }
 }
 
 /// A [`Schedule`] restorer.
@@ -228,12 +265,24 @@ impl Restorer {
                 .program_state(hash)
                 .context("failed to read ['Waitlist'] from storage by hash")?;
             let ProgramState {
+                program,
                 waitlist_hash,
                 stash_hash,
                 mailbox_hash,
                 ..
             } = program_state;
 
+            if let Program::Active(ActiveProgram {
+                gas_reservations_hash,
+                ..
+            }) = program
+                && let Ok(reservations) = storage.query(&gas_reservations_hash)
+            {
+                for &program_id in &program_ids {
+                    restorer.gas_reservations(program_id, &reservations);
+                }
+            }
+
             if let Ok(waitlist) = storage.query(&waitlist_hash) {
                 for &program_id in &program_ids {
                     restorer.waitlist(program_id, &waitlist);
@@ -321,6 +370,14 @@ impl Restorer {
         }
     }
 
+    pub fn gas_reservations(&mut self, program_id: ActorId, reservations: &GasReservations) {
+        for (&reservation_id, slot) in reservations.as_ref() {
+            self.schedule.entry(slot.finish).or_default().insert(
+                ScheduledTask::RemoveGasReservation(program_id, reservation_id),
+            );
+        }
+    }
+
     pub fn restore(self) -> Schedule {
         self.schedule
     }
@@ -868,4 +925,62 @@ mod tests {
         assert!(state.stash_hash.is_empty());
         assert!(state.canonical_queue.is_empty());
     }
+
+    #[test]
+    fn send_dispatch_is_counted_in_outgoing_stats() {
+        use crate::{InBlockTransitions, TransitionController};
+        use ethexe_common::{ProgramStates, StateHashWithQueueSize};
+
+        let storage = MemStorage::default();
+        let program_id = ActorId::from(7);
+        let message_id = MessageId::from(10);
+
+        let dispatch = Dispatch::new(
+            &storage,
+            message_id,
+            ActorId::from(8),
+            vec![1, 2, 3],
+            0,
+            false,
+            MessageType::Canonical,
+            false,
+        )
+        .expect("dispatch");
+
+        let mut stash = DispatchStash::default();
+        stash.add_to_program(dispatch, 1000);
+
+        let mut state = ProgramState::zero();
+        state.stash_hash = stash.store(&storage);
+        let state_hash = storage.write_program_state(state);
+        let states = ProgramStates::from_iter([(
+            program_id,
+            StateHashWithQueueSize {
+                hash: state_hash,
+                canonical_queue_size: 0,
+                injected_queue_size: 0,
+            },
+        )]);
+
+        let mut transitions =
+            InBlockTransitions::new(Default::default(), states, Default::default());
+        assert_eq!(transitions.outgoing_messages_stats(), (0, 0));
+
+        {
+            let mut handler = Handler {
+                controller: TransitionController {
+                    storage: &storage,
+                    transitions: &mut transitions,
+                },
+            };
+            handler.send_dispatch((program_id, message_id));
+        }
+
+        assert_eq!(transitions.outgoing_messages_stats(), (1, 3));
+
+        let state_hash = transitions.state_of(&program_id).unwrap().hash;
+        let state = storage.program_state(state_hash).unwrap();
+        assert!(state.stash_hash.is_empty());
+        assert!(!state.canonical_queue.is_empty());
+    }
 }
@@ -29,9 +29,10 @@ use gear_core::{
     message::{ContextStore, DispatchKind, MessageDetails, ReplyDetails, StoredDispatch, Value},
     pages::{GearPage, WasmPage, numerated::tree::IntervalsTree},
     program::MemoryInfix,
+    reservation::{GasReservationMap, GasReservationSlot},
 };
 use gear_core_errors::{ReplyCode, SuccessReplyReason};
-use gprimitives::{ActorId, H256, MessageId};
+use gprimitives::{ActorId, H256, MessageId, ReservationId};
 use parity_scale_codec::{Decode, Encode};
 
 #[allow(unused)]
@@ -141,6 +142,32 @@ impl<S: Storage> ModifiableStorage<Allocations> for S {
     }
 }
 
+impl<S: Storage> QueryableStorage<GasReservations> for S {
+    fn query(&self, hash: &MaybeHashOf<GasReservations>) -> Result<GasReservations> {
+        hash.try_map_or_default(|hash| {
+            self.gas_reservations(hash).ok_or(anyhow!(
+                "failed to read ['GasReservations'] from storage by hash"
+            ))
+        })
+    }
+}
+
+impl<S: Storage> ModifiableStorage<GasReservations> for S {
+    fn modify<U>(
+        &self,
+        hash: &mut MaybeHashOf<GasReservations>,
+        f: impl FnOnce(&mut GasReservations) -> U,
+    ) -> U {
+        let mut reservations = self.query(hash).expect("failed to modify gas reservations");
+
+        let r = f(&mut reservations);
+
+        hash.replace(reservations.store(&self));
+
+        r
+    }
+}
+
 impl<S: Storage> QueryableStorage<DispatchStash> for S {
     fn query(&self, hash: &MaybeHashOf<DispatchStash>) -> Result<DispatchStash> {
         hash.try_map_or_default(|hash| {
@@ -302,6 +329,8 @@ pub struct ActiveProgram {
     pub allocations_hash: MaybeHashOf<Allocations>,
     /// Hash of memory pages table, see [`MemoryPages`].
     pub pages_hash: MaybeHashOf<MemoryPages>,
+    /// Hash of gas reservations made by the program, see [`GasReservations`].
+    pub gas_reservations_hash: MaybeHashOf<GasReservations>,
     /// Program memory infix.
     pub memory_infix: MemoryInfix,
     /// Program initialization status.
@@ -360,6 +389,7 @@ impl ProgramState {
             program: Program::Active(ActiveProgram {
                 allocations_hash: MaybeHashOf::empty(),
                 pages_hash: MaybeHashOf::empty(),
+                gas_reservations_hash: MaybeHashOf::empty(),
                 memory_infix: MemoryInfix::new(0),
                 initialized: false,
             }),
@@ -1132,6 +1162,61 @@ impl Allocations {
     }
 }
 
+/// Gas reserved by the program, backed by its executable balance.
+#[derive(
+    Clone,
+    Default,
+    Debug,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Into,
+    derive_more::AsRef,
+)]
+pub struct GasReservations {
+    #[as_ref]
+    inner: GasReservationMap,
+    #[into(ignore)]
+    #[codec(skip)]
+    changed: bool,
+}
+
+impl GasReservations {
+    /// Total amount of gas held by all reservations.
+    pub fn total_amount(&self) -> u64 {
+        self.inner
+            .values()
+            .fold(0u64, |acc, slot| acc.saturating_add(slot.amount))
+    }
+
+    pub fn update(&mut self, reservations: GasReservationMap) {
+        if self.inner != reservations {
+            self.changed = true;
+            self.inner = reservations;
+        }
+    }
+
+    pub fn remove(&mut self, reservation_id: &ReservationId) -> Option<GasReservationSlot> {
+        self.inner
+            .remove(reservation_id)
+            .inspect(|_| self.changed = true)
+    }
+
+    pub fn store<S: Storage>(self, storage: &S) -> Option<MaybeHashOf<Self>> {
+        self.changed.then(|| {
+            MaybeHashOf::from_inner(
+                (!self.inner.is_empty()).then(|| storage.write_gas_reservations(self)),
+            )
+        })
+    }
+
+    pub fn into_inner(self) -> GasReservationMap {
+        self.into()
+    }
+}
+
 #[auto_impl::auto_impl(&, Box)]
 pub trait Storage {
     /// Reads program state by state hash.
@@ -1191,6 +1276,12 @@ pub trait Storage {
     /// Writes allocations and returns its hash.
     fn write_allocations(&self, allocations: Allocations) -> HashOf<Allocations>;
 
+    /// Reads gas reservations by their hash.
+    fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations>;
+
+    /// Writes gas reservations and returns their hash.
+    fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations>;
+
     /// Reads payload by payload hash.
     fn payload(&self, hash: HashOf<Payload>) -> Option<Payload>;
 
@@ -1340,6 +1431,14 @@ impl Storage for MemStorage {
         unsafe { HashOf::new(self.write(allocations)) }
     }
 
+    fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations> {
+        self.read(hash.inner())
+    }
+
+    fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations> {
+        unsafe { HashOf::new(self.write(reservations)) }
+    }
+
     fn payload(&self, hash: HashOf<Payload>) -> Option<Payload> {
         self.read(hash.inner())
     }
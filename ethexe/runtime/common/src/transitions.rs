@@ -9,7 +9,7 @@ use anyhow::{Result, anyhow};
 use core::num::NonZero;
 use ethexe_common::{
     MAILBOX_VALIDITY_VERSION_2, ProgramStates, Schedule, ScheduledTask, StateHashWithQueueSize,
-    gear::{Message, StateTransition, ValueClaim},
+    gear::{Message, ProgramCreation, StateTransition, ValueClaim},
 };
 use gprimitives::{ActorId, CodeId, H256, MessageId};
 
@@ -59,6 +59,10 @@ pub struct InBlockTransitions {
     schedule: Schedule,
     modifications: BTreeMap<ActorId, NonFinalTransition>,
     program_creations: BTreeMap<ActorId, CodeId>,
+    child_program_creations: Vec<ProgramCreation>,
+    message_records: Vec<MessageRecord>,
+    /// Amount and total payload size of delayed dispatches delivered to programs.
+    delivered_dispatches: (u32, u32),
 }
 
 #[derive(Debug, Clone, Default)]
@@ -67,12 +71,16 @@ pub struct FinalizedBlockTransitions {
     pub states: ProgramStates,
     pub schedule: Schedule,
     pub program_creations: Vec<(ActorId, CodeId)>,
+    /// Programs created by programs in creation order, to be deployed by `Router`.
+    pub child_program_creations: Vec<ProgramCreation>,
     /// Flat set of message ids that are committable to Ethereum for this block.
     ///
     /// A message is committable when `message_type.is_canonical() || msg.value != 0 || msg.call`.
     /// Populated at finalize time by unioning per-actor committed_message_ids.
     // TODO(regenesis): carry committable flag on Message and drop mb_committed_message_ids.
     pub committed_message_ids: BTreeSet<MessageId>,
+    /// Records of dispatches processed in this block, in processing order.
+    pub message_records: Vec<MessageRecord>,
 }
 
 impl InBlockTransitions {
@@ -112,6 +120,27 @@ impl InBlockTransitions {
             .collect()
     }
 
+    /// Returns the amount of outgoing messages already pushed or delivered
+    /// within the block and the total size of their payloads.
+    pub fn outgoing_messages_stats(&self) -> (u32, u32) {
+        self.modifications
+            .values()
+            .flat_map(|trans| trans.messages.iter())
+            .map(|message| message.payload.len())
+            .fold(self.delivered_dispatches, |(amount, bytes), len| {
+                let len = u32::try_from(len).unwrap_or(u32::MAX);
+                (amount.saturating_add(1), bytes.saturating_add(len))
+            })
+    }
+
+    /// Counts a delayed dispatch delivered to a program, so it's accounted
+    /// for in the outgoing limits of the block.
+    pub fn record_delivered_dispatch(&mut self, payload_len: usize) {
+        let (amount, bytes) = &mut self.delivered_dispatches;
+        *amount = amount.saturating_add(1);
+        *bytes = bytes.saturating_add(u32::try_from(payload_len).unwrap_or(u32::MAX));
+    }
+
     pub fn modifications_len(&self) -> usize {
         self.modifications.len()
     }
@@ -161,6 +190,17 @@ impl InBlockTransitions {
         self.program_creations.insert(actor_id, code_id);
     }
 
+    /// Registers program created by another program, which must be deployed on Ethereum.
+    pub fn register_child(&mut self, creation: ProgramCreation) {
+        self.register_new(creation.actor_id, creation.code_id);
+        self.child_program_creations.push(creation);
+    }
+
+    /// Appends records of dispatches processed by a program.
+    pub fn record_messages(&mut self, records: Vec<MessageRecord>) {
+        self.message_records.extend(records);
+    }
+
     pub fn registered_programs(&self) -> &BTreeMap<ActorId, CodeId> {
         &self.program_creations
     }
@@ -227,6 +267,8 @@ impl InBlockTransitions {
             schedule,
             modifications,
             program_creations,
+            child_program_creations,
+            message_records,
             ..
         } = self;
 
@@ -262,7 +304,9 @@ impl InBlockTransitions {
             states,
             schedule,
             program_creations: program_creations.into_iter().collect(),
+            child_program_creations,
             committed_message_ids,
+            message_records,
         }
     }
 
@@ -284,6 +328,8 @@ impl InBlockTransitions {
             schedule,
             modifications,
             program_creations,
+            child_program_creations: Vec::new(),
+            message_records: Vec::new(),
         }
     }
 
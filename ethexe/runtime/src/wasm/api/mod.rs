@@ -39,7 +39,7 @@ unsafe fn _run(arg_ptr: i32, arg_len: i32) -> i64 {
         Decode::decode(&mut unsafe { core::slice::from_raw_parts(arg_ptr as _, arg_len as usize) })
             .unwrap();
 
-    let (program_journals, gas_spent) = run::run(ctx);
+    let (program_journals, gas_spent, records) = run::run(ctx);
 
     // Split to chunks to prevent alloc limit (32MiB)
     let res: Vec<_> = program_journals
@@ -58,7 +58,7 @@ unsafe fn _run(arg_ptr: i32, arg_len: i32) -> i64 {
         })
         .collect();
 
-    return_val((res, gas_spent))
+    return_val((res, gas_spent, records))
 }
 
 fn return_val(val: impl Encode) -> i64 {
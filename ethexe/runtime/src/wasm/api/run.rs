@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::wasm::storage::NativeRuntimeInterface;
+use alloc::vec::Vec;
+use ethexe_common::db::MessageRecord;
 use ethexe_runtime_common::{ProcessQueueContext, ProgramJournals, process_queue};
 
-pub fn run(ctx: ProcessQueueContext) -> (ProgramJournals, u64) {
+pub fn run(ctx: ProcessQueueContext) -> (ProgramJournals, u64, Vec<MessageRecord>) {
     log::debug!("You're calling 'run(..)'");
 
     let ri = NativeRuntimeInterface;
 
-    let (journals, gas_spent) = process_queue(ctx, &ri);
+    let (journals, gas_spent, records) = process_queue(ctx, &ri);
 
     for (journal, message_type, call_reply) in &journals {
         for note in journal {
@@ -18,5 +20,5 @@ pub fn run(ctx: ProcessQueueContext) -> (ProgramJournals, u64) {
         log::debug!("Message type: {message_type:?}, call_reply {call_reply:?}");
     }
 
-    (journals, gas_spent)
+    (journals, gas_spent, records)
 }
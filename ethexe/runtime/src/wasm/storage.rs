@@ -4,13 +4,12 @@
 use crate::wasm::interface::promise_ri;
 
 use super::interface::database_ri;
-use alloc::vec::Vec;
 use ethexe_common::{HashOf, injected::Promise};
 use ethexe_runtime_common::{
     RuntimeInterface,
     state::{
-        Allocations, DispatchStash, Mailbox, MemoryPages, MemoryPagesRegion, MessageQueue,
-        ProgramState, Storage, UserMailbox, Waitlist,
+        Allocations, DispatchStash, GasReservations, Mailbox, MemoryPages, MemoryPagesRegion,
+        MessageQueue, ProgramState, Storage, UserMailbox, Waitlist,
     },
 };
 use gear_core::{buffer::Payload, memory::PageBuf};
@@ -104,6 +103,14 @@ impl Storage for NativeRuntimeInterface {
         unsafe { HashOf::new(database_ri::write(allocations)) }
     }
 
+    fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations> {
+        database_ri::read_unwrapping(&hash.inner())
+    }
+
+    fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations> {
+        unsafe { HashOf::new(database_ri::write(reservations)) }
+    }
+
     fn payload(&self, hash: HashOf<Payload>) -> Option<Payload> {
         // TODO: review this.
         database_ri::read_raw(&hash.inner()).map(|slice| slice.to_vec().try_into().unwrap())
@@ -129,11 +136,6 @@ impl RuntimeInterface for NativeRuntimeInterface {
         assert!(Self::LazyPages::try_to_enable_lazy_pages(Default::default()))
     }
 
-    fn random_data(&self) -> (Vec<u8>, u32) {
-        // TODO: set real value
-        Default::default()
-    }
-
     fn update_state_hash(&self, hash: &H256) {
         database_ri::update_state_hash(hash);
     }
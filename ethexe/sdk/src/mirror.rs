@@ -6,6 +6,7 @@ use alloy::{eips::BlockId, rpc::types::TransactionReceipt};
 use anyhow::{Context, Result, anyhow, bail, ensure};
 use ethexe_common::{
     Address, BlockHeader, HashOf, MaybeHashOf, SimpleBlockData,
+    db::ProcessedMessage,
     gear::ValueClaim,
     gear_core::{buffer::Payload, memory::PageBuf, pages::GearPage, rpc::ReplyInfo},
     injected::{
@@ -187,6 +188,31 @@ impl<'a> Mirror<'a> {
             .await
     }
 
+    /// Returns outcome of the message processed by the program.
+    ///
+    /// Requires the node to run with message history enabled.
+    pub async fn message_history(&self, message_id: MessageId) -> Result<Option<ProcessedMessage>> {
+        let processed = self
+            .api
+            .vara_eth_client
+            .message_history(message_id.into())
+            .await?;
+
+        Ok(processed.filter(|processed| processed.record.program_id == self.actor_id()))
+    }
+
+    /// Returns up to `limit` messages recently processed by the program,
+    /// most recent first, skipping `offset` most recent ones.
+    ///
+    /// Requires the node to run with message history enabled.
+    pub async fn recent_messages(&self, offset: u64, limit: u64) -> Result<Vec<ProcessedMessage>> {
+        self.api
+            .vara_eth_client
+            .recent_messages(self.actor_id().to_address_lossy(), offset, limit)
+            .map_err(Into::into)
+            .await
+    }
+
     pub async fn memory(&self) -> Result<Option<MirrorMemory<'_, 'a>>> {
         self.memory_at(BlockId::latest()).await
     }
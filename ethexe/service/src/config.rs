@@ -5,6 +5,7 @@
 
 use anyhow::Result;
 use ethexe_network::NetworkConfig;
+use ethexe_processor::DiskCacheConfig;
 use ethexe_prometheus::PrometheusConfig;
 use ethexe_rpc_server::RpcConfig;
 use gsigner::secp256k1::{Address, PublicKey};
@@ -64,6 +65,8 @@ pub struct NodeConfig {
     pub worker_threads: Option<usize>,
     pub blocking_threads: Option<usize>,
     pub chunk_processing_threads: usize,
+    /// On-disk cache of compiled program modules, if enabled.
+    pub module_cache: Option<DiskCacheConfig>,
     pub block_gas_limit: u64,
     pub batch_size_limit: u64,
     pub canonical_quarantine: u8,
@@ -95,6 +98,11 @@ pub struct NodeConfig {
     /// Prune old MB schedules on startup, right after the database is
     /// opened. Temporary hot fix knob (#5585).
     pub db_cleanup: bool,
+    /// Index processed messages for the message history RPC.
+    pub message_history: bool,
+    /// Unix socket of a remote signer daemon, used instead of the keys
+    /// in `key_path` if set. Can't be combined with `validator`.
+    pub signer_socket: Option<PathBuf>,
 }
 
 impl NodeConfig {
@@ -1,16 +1,491 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-//! Fast synchronization stub — always fails so `--fast-sync` doesn't
-//! silently degrade to a full-from-genesis catch-up.
+//! Fast synchronization of a fresh node.
+//!
+//! Instead of computing every MB since genesis, the node takes the latest MB
+//! committed to Router as a pivot and downloads the state it resulted in:
+//!
+//! 1. Walk back from the latest finalized Ethereum block to the latest one
+//!    containing a chain commitment. Its `MBCommitted` event gives the pivot MB,
+//!    its `EBCommitted` event gives the latest Ethereum block folded into the pivot.
+//! 2. Fetch original codes validated on Router from peers and process them locally.
+//! 3. Fetch the program-to-code mapping of the pivot MB from peers,
+//!    verified against Router at the commitment block.
+//! 4. Fetch program state hashes from Mirrors, and all the state blobs from peers.
+//! 5. Restore the schedule and seed the pivot MB as the latest computed one,
+//!    and the folded Ethereum block as the new start block.
+//!
+//! Malachite then syncs MBs up to the pivot without emitting them
+//! (see `ethexe-malachite` externalities), and the node continues from the pivot as usual.
+//!
+//! Everything except blobs is checked against Ethereum, while blobs are checked by their hashes,
+//! so peers can't make the node accept a state that differs from the committed one.
 
 use crate::Service;
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use ethexe_common::{
+    BlockData, CodeAndIdUnchecked, Digest, ProgramStates, SimpleBlockData, StateHashWithQueueSize,
+    db::{
+        CodesStorageRO, CodesStorageRW, ConfigStorageRO, GlobalsStorageRO, GlobalsStorageRW,
+        MbStorageRW, PreparedBlockData,
+    },
+    events::{
+        BlockEvent, RouterEvent,
+        router::{BatchCommittedEvent, EBCommittedEvent, MBCommittedEvent},
+    },
+};
+use ethexe_compute::ComputeEvent;
+use ethexe_db::{
+    Database,
+    iterator::{DatabaseIteratorError, DatabaseIteratorStorage, ProgramStateNode},
+    visitor::{DatabaseVisitor, walk},
+};
+use ethexe_ethereum::mirror::MirrorQuery;
+use ethexe_network::{
+    NetworkService,
+    db_sync::{Request, RequestFailure, Response},
+};
+use ethexe_observer::utils::{BlockId, BlockLoader};
+use ethexe_runtime_common::{ScheduleRestorer, state::Storage};
+use futures::{StreamExt, future};
+use gprimitives::{ActorId, CodeId, H256};
+use gsigner::secp256k1::Address;
+use std::collections::{BTreeMap, BTreeSet};
 
-// TODO: #5487 implement the actual fast-sync logic.
-pub(crate) async fn sync(_service: &mut Service) -> Result<()> {
-    bail!(
-        "fast-sync is not implemented for the MB-driven recovery path yet; \
-         start the node without --fast-sync (or omit `fast_sync = true` in config)"
+/// Latest chain commitment landed on Ethereum.
+#[derive(Debug)]
+struct Pivot {
+    /// Ethereum block the commitment landed in.
+    commitment_block: H256,
+    /// Digest of the committed batch.
+    batch: Digest,
+    /// Committed MB head.
+    mb_hash: H256,
+    /// Latest Ethereum block folded into `mb_hash`.
+    eb_hash: H256,
+}
+
+impl Pivot {
+    /// Extracts the commitment from block events, if any.
+    fn from_events(block: H256, events: &[BlockEvent]) -> Option<Result<Self>> {
+        let mut batch = None;
+        let mut mb_hash = None;
+        let mut eb_hash = None;
+
+        for event in events {
+            match event {
+                BlockEvent::Router(RouterEvent::BatchCommitted(BatchCommittedEvent { digest })) => {
+                    batch = Some(*digest)
+                }
+                BlockEvent::Router(RouterEvent::MBCommitted(MBCommittedEvent(hash))) => {
+                    mb_hash = Some(*hash)
+                }
+                BlockEvent::Router(RouterEvent::EBCommitted(EBCommittedEvent(hash))) => {
+                    eb_hash = Some(*hash)
+                }
+                _ => {}
+            }
+        }
+
+        let mb_hash = mb_hash?;
+        let pivot = batch
+            .zip(eb_hash)
+            .map(|(batch, eb_hash)| Self {
+                commitment_block: block,
+                batch,
+                mb_hash,
+                eb_hash,
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "MB {mb_hash} committed in block {block} has no Ethereum block folded in, \
+                     nothing to fast-sync yet; start the node without fast-sync"
+                )
+            });
+
+        Some(pivot)
+    }
+}
+
+/// Collects hashes of blobs missing in the database.
+struct MissingHashes {
+    db: Database,
+    missing: BTreeSet<H256>,
+}
+
+impl MissingHashes {
+    fn new(db: Database) -> Self {
+        Self {
+            db,
+            missing: BTreeSet::new(),
+        }
+    }
+}
+
+impl DatabaseVisitor for MissingHashes {
+    fn db(&self) -> &dyn DatabaseIteratorStorage {
+        &self.db
+    }
+
+    fn clone_boxed_db(&self) -> Box<dyn DatabaseIteratorStorage> {
+        Box::new(self.db.clone())
+    }
+
+    fn on_db_error(&mut self, error: DatabaseIteratorError) {
+        let hash = match error {
+            DatabaseIteratorError::NoProgramState(hash) => hash,
+            DatabaseIteratorError::NoMemoryPages(hash) => hash.inner(),
+            DatabaseIteratorError::NoMemoryPagesRegion(hash) => hash.inner(),
+            DatabaseIteratorError::NoPageData(hash) => hash.inner(),
+            DatabaseIteratorError::NoMessageQueue(hash) => hash.inner(),
+            DatabaseIteratorError::NoWaitlist(hash) => hash.inner(),
+            DatabaseIteratorError::NoDispatchStash(hash) => hash.inner(),
+            DatabaseIteratorError::NoMailbox(hash) => hash.inner(),
+            DatabaseIteratorError::NoUserMailbox(hash) => hash.inner(),
+            DatabaseIteratorError::NoAllocations(hash) => hash.inner(),
+            DatabaseIteratorError::NoGasReservations(hash) => hash.inner(),
+            DatabaseIteratorError::NoPayload(hash) => hash.inner(),
+            error => {
+                log::warn!("Unexpected database error while walking program states: {error:?}");
+                return;
+            }
+        };
+
+        self.missing.insert(hash);
+    }
+}
+
+/// Sends a db-sync request, retrying it on timeouts.
+///
+/// Fails if peers' responses were rejected, since they contradict
+/// the data on Ethereum and retrying wouldn't help.
+async fn request(network: &mut NetworkService, request: Request) -> Result<Response> {
+    let handle = network.db_sync_handle();
+    let mut future = handle.request(request);
+
+    loop {
+        tokio::select! {
+            result = &mut future => match result {
+                Ok(response) => return Ok(response),
+                Err((RequestFailure::InvalidResponse, request)) => {
+                    bail!("Request {:?} failed: responses failed validation", request.id());
+                }
+                Err((err, request)) => {
+                    log::warn!("Request {:?} failed: {err}, retrying...", request.id());
+                    future = handle.retry(request);
+                }
+            },
+            event = network.select_next_some() => {
+                log::trace!("Skip network event during fast-sync: {event:?}");
+            }
+        }
+    }
+}
+
+async fn find_pivot(service: &Service) -> Result<Pivot> {
+    let loader = service.observer.block_loader();
+    let genesis_block_hash = service.db.config().genesis_block_hash;
+
+    let finalized = loader.load_simple(BlockId::Finalized).await?;
+    let mut block = finalized.hash;
+    loop {
+        let BlockData {
+            hash,
+            header,
+            events,
+        } = loader.load(block, None).await?;
+        if let Some(pivot) = Pivot::from_events(hash, &events) {
+            return pivot;
+        }
+
+        if hash == genesis_block_hash || header.height == 0 {
+            bail!(
+                "no chain commitment found up to finalized block {finalized}, \
+                 nothing to fast-sync yet; start the node without fast-sync"
+            );
+        }
+
+        block = header.parent_hash;
+    }
+}
+
+/// Fetches and processes codes validated on Router at `at`.
+async fn sync_codes(service: &mut Service, at: H256) -> Result<()> {
+    let validated_count = service
+        .observer
+        .router_query()
+        .validated_codes_count_at(at)
+        .await?;
+    let codes = request(
+        &mut service.network,
+        Request::valid_codes(at, validated_count),
+    )
+    .await?
+    .unwrap_valid_codes();
+
+    let mut pending: BTreeSet<CodeId> = codes
+        .into_iter()
+        .filter(|&code_id| service.db.code_valid(code_id).is_none())
+        .collect();
+    log::info!("Fast-sync: {} codes to process", pending.len());
+
+    let missing: BTreeSet<H256> = pending
+        .iter()
+        .filter(|&&code_id| !service.db.original_code_exists(code_id))
+        .map(|&code_id| code_id.into())
+        .collect();
+    if !missing.is_empty() {
+        let blobs = request(&mut service.network, Request::hashes(missing))
+            .await?
+            .unwrap_hashes();
+        for data in blobs.into_values() {
+            service.db.set_original_code(&data);
+        }
+    }
+
+    for &code_id in &pending {
+        let code = service
+            .db
+            .original_code(code_id)
+            .with_context(|| format!("original code {code_id} is not fetched"))?;
+        service
+            .compute
+            .process_code(CodeAndIdUnchecked { code, code_id });
+    }
+
+    while !pending.is_empty() {
+        match service.compute.select_next_some().await? {
+            ComputeEvent::CodeProcessed(code_id) => {
+                ensure!(
+                    service.db.code_valid(code_id) == Some(true),
+                    "code {code_id} validated on Router is invalid locally"
+                );
+                pending.remove(&code_id);
+            }
+            event => log::trace!("Skip compute event during fast-sync: {event:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches blobs of the given program states and everything they refer to.
+async fn sync_states(
+    service: &mut Service,
+    state_hashes: impl IntoIterator<Item = H256> + Clone,
+) -> Result<()> {
+    let mut visitor = MissingHashes::new(service.db.clone());
+
+    loop {
+        for state_hash in state_hashes.clone() {
+            match service.db.program_state(state_hash) {
+                Some(program_state) => walk(&mut visitor, ProgramStateNode { program_state }),
+                None => {
+                    visitor.missing.insert(state_hash);
+                }
+            }
+        }
+
+        let missing = std::mem::take(&mut visitor.missing);
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        log::info!("Fast-sync: fetching {} blobs", missing.len());
+        let blobs = request(&mut service.network, Request::hashes(missing))
+            .await?
+            .unwrap_hashes();
+        for data in blobs.into_values() {
+            service.db.cas().write(&data);
+        }
+    }
+}
+
+pub(crate) async fn sync(service: &mut Service) -> Result<()> {
+    let latest_computed_mb_hash = service.db.globals().latest_computed_mb_hash;
+    if !latest_computed_mb_hash.is_zero() {
+        log::info!(
+            "Database already has computed MB {latest_computed_mb_hash}, skipping fast-sync"
+        );
+
+        #[cfg(test)]
+        {
+            let start_block_hash = service.db.globals().start_block_hash;
+            service
+                .sender
+                .send(crate::tests::utils::TestingEvent::FastSyncDone(
+                    start_block_hash,
+                ))
+                .await;
+        }
+
+        return Ok(());
+    }
+
+    log::info!("Fast-sync started");
+
+    let Pivot {
+        commitment_block,
+        batch,
+        mb_hash,
+        eb_hash,
+    } = find_pivot(service).await?;
+    log::info!(
+        "Fast-sync: pivot MB {mb_hash} with Ethereum block {eb_hash} folded in, \
+         committed in block {commitment_block}"
+    );
+
+    sync_codes(service, commitment_block).await?;
+
+    // The pivot MB knows programs created on Ethereum up to the folded block,
+    // and programs created by MBs, which are committed no later than the commitment block.
+    // Programs created on Ethereum after the folded block must not be in the pivot MB.
+    let router_query = service.observer.router_query();
+    let loader = service.observer.block_loader();
+    let eb_height = loader
+        .load_simple(BlockId::Hash(eb_hash))
+        .await?
+        .header
+        .height;
+    let commitment_height = loader
+        .load_simple(BlockId::Hash(commitment_block))
+        .await?
+        .header
+        .height;
+    let created = router_query
+        .created_programs(eb_height as u64 + 1..=commitment_height as u64)
+        .await?;
+    let programs_count =
+        router_query.programs_count_at(eb_hash).await? + created.by_programs.len() as u64;
+    let program_code_ids: BTreeMap<ActorId, CodeId> = request(
+        &mut service.network,
+        Request::program_ids(
+            commitment_block,
+            mb_hash,
+            programs_count,
+            created.on_ethereum,
+        ),
+    )
+    .await?
+    .unwrap_program_ids();
+    log::info!("Fast-sync: {} programs to sync", program_code_ids.len());
+
+    for (&program_id, &code_id) in &program_code_ids {
+        ensure!(
+            service.db.code_valid(code_id) == Some(true),
+            "program {program_id} has code {code_id} which is not validated"
+        );
+        service.db.set_program_code_id(program_id, code_id);
+    }
+
+    // Mirrors store the states committed along with the pivot.
+    let provider = service.observer.provider().clone();
+    let state_hashes: BTreeMap<ActorId, H256> =
+        future::try_join_all(program_code_ids.keys().map(|&program_id| {
+            let mirror = Address::try_from(program_id).expect("infallible");
+            let query = MirrorQuery::new(provider.clone(), mirror);
+            async move {
+                let state_hash = query.state_hash_at(commitment_block).await?;
+                Ok::<_, anyhow::Error>((program_id, state_hash))
+            }
+        }))
+        .await?
+        .into_iter()
+        .collect();
+
+    sync_states(service, state_hashes.values().copied()).await?;
+
+    let mut program_states = ProgramStates::new();
+    for (program_id, hash) in state_hashes {
+        let program_state = service
+            .db
+            .program_state(hash)
+            .with_context(|| format!("program state {hash} is not fetched"))?;
+        // States committed before the v2 database are re-encoded,
+        // since the runtime reads only the current encoding.
+        let hash = service.db.write_program_state(program_state);
+        program_states.insert(
+            program_id,
+            StateHashWithQueueSize {
+                hash,
+                canonical_queue_size: program_state.canonical_queue.cached_queue_size,
+                injected_queue_size: program_state.injected_queue.cached_queue_size,
+            },
+        );
+    }
+
+    let schedule = ScheduleRestorer::from_storage(&service.db, &program_states)?.restore();
+    log::info!(
+        "Fast-sync: schedule restored, tasks amount {}",
+        schedule.values().flat_map(|tasks| tasks.iter()).count()
+    );
+
+    // The pivot's `CompactMb` is not written here: it's fetched by Malachite sync later.
+    service.db.set_mb_program_states(mb_hash, program_states);
+    service.db.set_mb_schedule(mb_hash, schedule);
+    service.db.set_mb_outcome(mb_hash, Vec::new());
+    service.db.mutate_mb_meta(mb_hash, |meta| {
+        meta.computed = true;
+        meta.last_advanced_eb = eb_hash;
+    });
+
+    let validation_settings = router_query
+        .storage_view_at(commitment_block)
+        .await?
+        .validationSettings;
+    let timelines = service.db.config().timelines;
+    let latest_era_with_committed_validators = [
+        validation_settings.validators0.useFromTimestamp,
+        validation_settings.validators1.useFromTimestamp,
+    ]
+    .into_iter()
+    .filter_map(|ts| timelines.era_from_ts(ts.to::<u64>()))
+    .max()
+    .unwrap_or_default();
+
+    let BlockData {
+        hash: _,
+        header,
+        events,
+    } = service.observer.block_loader().load(eb_hash, None).await?;
+
+    // Commitment-related meta reflects the commitment landed in `commitment_block`,
+    // so the node doesn't try to commit it once again.
+    ethexe_common::setup_block_in_db(
+        &service.db,
+        eb_hash,
+        PreparedBlockData {
+            header,
+            events,
+            latest_era_with_committed_validators,
+            // Codes requested for validation before `eb_hash` are validated by now
+            // or will be reported by `CodeGotValidated` events of later blocks.
+            codes_queue: Default::default(),
+            last_committed_batch: batch,
+            last_committed_mb: mb_hash,
+            last_committed_eb: eb_hash,
+        },
     );
+
+    service.db.globals_mutate(|globals| {
+        globals.start_block_hash = eb_hash;
+        globals.latest_synced_eb = SimpleBlockData {
+            hash: eb_hash,
+            header,
+        };
+        globals.latest_prepared_eb_hash = eb_hash;
+        globals.latest_finalized_mb_hash = mb_hash;
+        globals.latest_computed_mb_hash = mb_hash;
+    });
+
+    log::info!("Fast-sync done, start block {eb_hash}, pivot MB {mb_hash}");
+
+    #[cfg(test)]
+    service
+        .sender
+        .send(crate::tests::utils::TestingEvent::FastSyncDone(eb_hash))
+        .await;
+
+    Ok(())
 }
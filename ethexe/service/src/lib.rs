@@ -53,7 +53,8 @@ use ethexe_common::{
 use ethexe_compute::{ComputeEvent, ComputeService};
 use ethexe_consensus::{ConsensusEvent, ConsensusService, ValidatorConfig, ValidatorService};
 use ethexe_db::{
-    Database, GenesisInitializer, InitConfig, RawDatabase, RocksDatabase, dump::StateDump,
+    Database, GenesisInitializer, InitConfig, RawDatabase, RocksDatabase,
+    dump::{StateDump, StateDumpBlobs, StateDumpHeader},
 };
 use ethexe_ethereum::{Ethereum, EthereumBuilder, deploy::EthereumDeployer, router::RouterQuery};
 use ethexe_malachite::{
@@ -300,6 +301,17 @@ impl Service {
     }
 
     pub async fn new(config: &Config) -> Result<Self> {
+        // Malachite consensus signs with the validator private key,
+        // which a remote signer never exposes.
+        if config.node.signer_socket.is_some()
+            && !matches!(config.node.validator, ConfigPublicKey::Disabled)
+        {
+            bail!(
+                "validator mode is not supported with a remote signer (`--signer-socket`): \
+                 Malachite consensus requires the validator private key"
+            );
+        }
+
         let rocks_db = RocksDatabase::open(
             config
                 .node
@@ -409,7 +421,13 @@ impl Service {
             config.node.chunk_processing_threads
         );
 
-        let signer = Signer::fs(config.node.key_path.clone())?;
+        let signer = match &config.node.signer_socket {
+            Some(socket) => {
+                log::info!("🔑 Remote signer: {}", socket.display());
+                Signer::remote(socket.clone())
+            }
+            None => Signer::fs(config.node.key_path.clone())?,
+        };
 
         let validator_pub_key = Self::get_config_public_key(config.node.validator, &signer)
             .with_context(|| "failed to get validator private key")?;
@@ -488,9 +506,12 @@ impl Service {
         };
         let processor_config = ProcessorConfig {
             chunk_size: config.node.chunk_processing_threads,
+            module_cache: config.node.module_cache.clone(),
+            message_history: config.node.message_history,
         };
         let processor = Processor::with_config(processor_config, db.clone())?;
-        let compute = ComputeService::with_promise_mode(db.clone(), processor, promises_mode);
+        let compute = ComputeService::with_promise_mode(db.clone(), processor, promises_mode)
+            .with_message_history(config.node.message_history);
 
         // Malachite consensus service.
 
@@ -1038,12 +1059,14 @@ impl Service {
     }
 }
 
-struct GenesisInitializerFromFile {
+/// Genesis initializer loading the genesis state from a [`StateDump`] file.
+pub struct GenesisInitializerFromFile {
     state_path: PathBuf,
     processor: Processor,
 }
 
 impl GenesisInitializerFromFile {
+    /// Creates an initializer for the dump file (`.blob`, `.json` or `.dump`).
     pub fn new(genesis_state_path: PathBuf) -> Result<Self> {
         // Safety: in context of GenesisInitializerFromFile, processor doesn't access the database,
         // it's only used for code processing, so it's safe to create it with an empty database.
@@ -1059,8 +1082,8 @@ impl GenesisInitializerFromFile {
 }
 
 impl GenesisInitializer for GenesisInitializerFromFile {
-    fn get_genesis_data(&mut self) -> anyhow::Result<ethexe_db::dump::StateDump> {
-        StateDump::read_from_file(&self.state_path)
+    fn get_genesis_data(&mut self) -> anyhow::Result<(StateDumpHeader, StateDumpBlobs)> {
+        StateDump::open_file(&self.state_path)
     }
 
     fn process_code(&mut self, code_id: CodeId, code: Vec<u8>) -> ethexe_db::CodeProcessingFuture {
@@ -28,7 +28,10 @@ use ethexe_common::{
     mock::*,
 };
 use ethexe_consensus::BatchCommitter;
-use ethexe_db::{Database, dump::StateDump};
+use ethexe_db::{
+    Database,
+    dump::{DumpFilter, StateDump},
+};
 use ethexe_ethereum::{EthereumBuilder, TryGetReceipt, router::Router};
 use ethexe_processor::Processor;
 use ethexe_rpc_client::{InjectedClient, ProgramClient};
@@ -3310,8 +3313,99 @@ async fn reply_callback() {
 }
 
 #[tokio::test]
-#[ignore = "TODO: #5487 port to MB-driven test harness"]
-async fn fast_sync() {}
+#[ntest::timeout(120_000)]
+async fn fast_sync() {
+    init_logger();
+
+    let mut env = TestEnv::new(TestEnvConfig {
+        network: EnvNetworkConfig::Enabled,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let mut connect_node = env.new_node(NodeConfig::named("connect")).await;
+    connect_node.start_service().await;
+
+    let mut validator = env
+        .new_node(NodeConfig::named("validator").validator(env.validators[0]))
+        .await;
+    validator.start_service().await;
+
+    test_info!("Upload, create and ping the demo-ping program");
+    let code_id = env
+        .upload_code(demo_ping::WASM_BINARY)
+        .await
+        .unwrap()
+        .wait_for()
+        .await
+        .map(|res| {
+            assert!(res.valid);
+            res.code_id
+        })
+        .unwrap();
+    let program_id = env
+        .create_program(code_id, 500_000_000_000_000)
+        .await
+        .unwrap()
+        .wait_for()
+        .await
+        .unwrap()
+        .program_id;
+    let reply = env
+        .send_message(program_id, b"PING")
+        .await
+        .unwrap()
+        .wait_for()
+        .await
+        .unwrap();
+    assert_eq!(reply.payload, b"PONG");
+
+    test_info!("Start fast-synced node");
+    let mut fast_node = env.new_node(NodeConfig::named("fast").fast_sync()).await;
+    fast_node.start_service().await;
+
+    let start_block = fast_node
+        .latest_fast_synced_block
+        .expect("fast-sync must be done");
+    let pivot_mb = fast_node.db.globals().latest_computed_mb_hash;
+    assert!(!pivot_mb.is_zero());
+    assert_eq!(fast_node.db.globals().start_block_hash, start_block);
+    assert_eq!(fast_node.db.mb_meta(pivot_mb).last_advanced_eb, start_block);
+    assert_eq!(
+        fast_node.db.mb_program_states(pivot_mb),
+        connect_node.db.mb_program_states(pivot_mb)
+    );
+    assert_eq!(fast_node.db.program_code_id(program_id), Some(code_id));
+
+    test_info!("Fast-synced node continues from the pivot");
+    let reply = env
+        .send_message(program_id, b"PING")
+        .await
+        .unwrap()
+        .wait_for()
+        .await
+        .unwrap();
+    assert_eq!(reply.code, ReplyCode::Success(SuccessReplyReason::Manual));
+    assert_eq!(reply.payload, b"PONG");
+
+    let latest_block = env.latest_block().await.hash;
+    fast_node
+        .events()
+        .wait_till_eth_block_finalized_in_mb(latest_block)
+        .await;
+    let latest_mb = fast_node.db.globals().latest_computed_mb_hash;
+    connect_node
+        .events()
+        .find_map_with_db(|db, _| db.mb_meta(latest_mb).computed.then_some(()))
+        .await;
+    assert_eq!(
+        fast_node.db.mb_program_states(latest_mb),
+        connect_node.db.mb_program_states(latest_mb)
+    );
+
+    stop_nodes([connect_node, validator, fast_node]).await;
+}
 
 #[tokio::test]
 #[ntest::timeout(120_000)]
@@ -3388,7 +3482,8 @@ async fn re_genesis_with_state_dump() {
         .await;
 
     log::info!("📗 Phase 3: collect state dump at the new genesis block.");
-    let dump = StateDump::collect_from_storage(&node.db, new_genesis_hash).unwrap();
+    let dump =
+        StateDump::collect_from_storage(&node.db, new_genesis_hash, &DumpFilter::All).unwrap();
     log::info!(
         "Dump: {} codes, {} programs, {} blobs",
         dump.codes.len(),
@@ -3564,7 +3659,8 @@ async fn re_genesis_delayed_message() {
     // pending delayed send in the dispatch stash because the 5-block delay
     // hasn't elapsed yet.
     log::info!("📗 Phase 3: collect state dump at the new genesis block.");
-    let dump = StateDump::collect_from_storage(&node.db, new_genesis_hash).unwrap();
+    let dump =
+        StateDump::collect_from_storage(&node.db, new_genesis_hash, &DumpFilter::All).unwrap();
     log::info!(
         "Dump: {} codes, {} programs, {} blobs",
         dump.codes.len(),
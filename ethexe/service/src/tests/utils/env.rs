@@ -856,7 +856,6 @@ impl NodeConfig {
         self
     }
 
-    #[allow(dead_code)]
     pub fn fast_sync(mut self) -> Self {
         self.fast_sync = true;
         self
@@ -124,7 +124,6 @@ impl TestingRpcEvent {
 #[derive(Debug, Clone, Eq, PartialEq, derive_more::TryUnwrap)]
 pub enum TestingEvent {
     // Fast sync done. Sent just once.
-    #[allow(dead_code)]
     FastSyncDone(H256),
     // Basic event to notify that service has started. Sent just once.
     ServiceStarted,
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 pub use env::*;
-use ethexe_db::{GenesisInitializer, dump::StateDump};
+use ethexe_db::{
+    GenesisInitializer,
+    dump::{StateDump, StateDumpBlobs, StateDumpHeader},
+};
 use ethexe_processor::Processor;
 pub use events::*;
 use futures::FutureExt;
@@ -47,9 +50,10 @@ pub struct GenesisInitializerFromDump {
 }
 
 impl GenesisInitializer for GenesisInitializerFromDump {
-    fn get_genesis_data(&mut self) -> anyhow::Result<StateDump> {
+    fn get_genesis_data(&mut self) -> anyhow::Result<(StateDumpHeader, StateDumpBlobs)> {
         self.dump
             .take()
+            .map(StateDump::into_parts)
             .ok_or_else(|| anyhow::anyhow!("genesis data already consumed"))
     }
 
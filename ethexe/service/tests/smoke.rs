@@ -12,25 +12,15 @@ use ethexe_service::{
 use gsigner::secp256k1::Signer;
 use std::{
     net::{Ipv4Addr, SocketAddr},
+    path::Path,
     time::Duration,
 };
 use tempfile::tempdir;
 
-#[tokio::test]
-async fn constructor() {
-    let tmp_dir = tempdir().unwrap();
-    let tmp_dir = tmp_dir.path().to_path_buf();
-    let key_path = tmp_dir.join("key");
-    let network_key_path = tmp_dir.join("net");
-
-    let network_key = Signer::fs(network_key_path)
-        .expect("failed to create signer")
-        .generate()
-        .unwrap();
-
-    let node_cfg = config::NodeConfig {
+fn node_config(tmp_dir: &Path) -> config::NodeConfig {
+    config::NodeConfig {
         database_path: tmp_dir.join("db"),
-        key_path,
+        key_path: tmp_dir.join("key"),
         net_path: tmp_dir.join("net"),
         validator: Default::default(),
         validator_session: Default::default(),
@@ -38,6 +28,7 @@ async fn constructor() {
         worker_threads: None,
         blocking_threads: None,
         chunk_processing_threads: 16,
+        module_cache: None,
         block_gas_limit: 4_000_000_000_000,
         canonical_quarantine: 0,
         post_quarantine_delay: 0,
@@ -51,9 +42,13 @@ async fn constructor() {
         batch_size_limit: DEFAULT_BATCH_SIZE_LIMIT,
         genesis_state_dump: None,
         db_cleanup: false,
-    };
+        message_history: false,
+        signer_socket: None,
+    }
+}
 
-    let eth_cfg = EthereumConfig {
+fn ethereum_config() -> EthereumConfig {
+    EthereumConfig {
         rpc: "wss://hoodi-reth-rpc.gear-tech.io/ws".into(),
         beacon_rpc: "https://hoodi-lighthouse-rpc.gear-tech.io".into(),
         router_address: "0xE549b0AfEdA978271FF7E712232B9F7f39A0b060"
@@ -63,7 +58,22 @@ async fn constructor() {
         eip1559_fee_increase_percentage: Ethereum::NO_EIP1559_FEE_INCREASE_PERCENTAGE,
         eip1559_max_fee_per_gas_in_gwei: Ethereum::NO_EIP1559_MAX_FEE_PER_GAS_IN_GWEI,
         blob_gas_multiplier: Ethereum::NO_BLOB_GAS_MULTIPLIER,
-    };
+    }
+}
+
+#[tokio::test]
+async fn constructor() {
+    let tmp_dir = tempdir().unwrap();
+    let tmp_dir = tmp_dir.path().to_path_buf();
+    let network_key_path = tmp_dir.join("net");
+
+    let network_key = Signer::fs(network_key_path)
+        .expect("failed to create signer")
+        .generate()
+        .unwrap();
+
+    let node_cfg = node_config(&tmp_dir);
+    let eth_cfg = ethereum_config();
 
     // `Service::new` resolves the Malachite validator set by looking
     // each on-chain validator address up in
@@ -128,3 +138,34 @@ async fn constructor() {
 
     Service::new(&config).await.unwrap();
 }
+
+#[tokio::test]
+async fn validator_with_remote_signer_is_rejected() {
+    let tmp_dir = tempdir().unwrap();
+    let tmp_dir = tmp_dir.path().to_path_buf();
+
+    let network_key = Signer::fs(tmp_dir.join("net"))
+        .expect("failed to create signer")
+        .generate()
+        .unwrap();
+
+    let mut node_cfg = node_config(&tmp_dir);
+    node_cfg.validator = config::ConfigPublicKey::Random;
+    node_cfg.signer_socket = Some(tmp_dir.join("signer.sock"));
+
+    let eth_cfg = ethereum_config();
+    let router_address = eth_cfg.router_address;
+    let config = Config {
+        node: node_cfg,
+        ethereum: eth_cfg,
+        network: ethexe_network::NetworkConfig::new_local(network_key, router_address),
+        malachite: config::MalachiteCliConfig {
+            validator_pub_keys: Default::default(),
+        },
+        rpc: None,
+        prometheus: None,
+    };
+
+    let err = Service::new(&config).await.err().expect("must be rejected");
+    assert!(err.to_string().contains("remote signer"), "{err}");
+}
@@ -177,6 +177,12 @@ where
         add_function!(MessageId, message_id);
         add_function!(ProgramId, program_id);
         add_function!(Random, random);
+        add_function!(Blake2b256, blake2b_256);
+        add_function!(Keccak256, keccak256);
+        add_function!(Sha256, sha256);
+        add_function!(Ed25519Verify, ed25519_verify);
+        add_function!(Sr25519Verify, sr25519_verify);
+        add_function!(Secp256k1Recover, secp256k1_recover);
         add_function!(Read, read);
         add_function!(Reply, reply);
         add_function!(ReplyCommit, reply_commit);
@@ -377,6 +383,9 @@ where
 
         prepare_memory(&mut store, &mut memory, globals_config);
 
+        #[cfg(feature = "profiling")]
+        crate::profiling::begin_execution();
+
         let needs_execution = entry_point
             .try_into_kind()
             .map(|kind| entries.contains(&kind))
@@ -431,6 +440,15 @@ where
             .and_then(i64::try_from_value)
             .ok_or(System(WrongInjectedGas))? as u64;
 
+        #[cfg(feature = "profiling")]
+        crate::profiling::record_functions(|index| {
+            let name = gear_wasm_instrument::profiler::profiler_global_name(index);
+            instance
+                .get_global_val(&mut store, &name)
+                .and_then(i64::try_from_value)
+                .map(|gas| gas as u64)
+        });
+
         let state = store.data_mut().take().unwrap_or_else(|| {
             let err_msg = "Environment::execute: State must be set";
 
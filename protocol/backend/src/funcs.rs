@@ -37,10 +37,13 @@ use gear_sandbox::{AsContextExt, ReturnValue, Value};
 use gear_sandbox_env::{HostError, WasmReturnValue};
 use gear_wasm_instrument::{SyscallName, SystemBreakCode};
 use gsys::{
-    BlockNumberWithHash, ErrorBytes, ErrorWithGas, ErrorWithHandle, ErrorWithHash,
-    ErrorWithReplyCode, ErrorWithSignalCode, ErrorWithTwoHashes, Gas, Hash, HashWithValue,
-    TwoHashesWithValue,
+    BlockNumberWithHash, EcdsaPublicKey, EcdsaSignature, ErrorBytes, ErrorWithGas, ErrorWithHandle,
+    ErrorWithHash, ErrorWithReplyCode, ErrorWithSignalCode, ErrorWithTwoHashes, Gas, Hash,
+    HashWithValue, PublicKey, Signature, TwoHashesWithValue,
 };
+use k256::ecdsa::{self, RecoveryId, VerifyingKey};
+use sha2::Sha256;
+use sha3::Keccak256;
 
 /// BLAKE2b-256 hasher state.
 type Blake2b256 = Blake2b<U32>;
@@ -316,10 +319,19 @@ where
             .ext
             .decrease_current_counter_to(gas);
 
+        #[cfg(feature = "profiling")]
+        let burned = Self::gas_burned(&mut memory_caller_context);
+
         let syscall = builder.build(&mut memory_caller_context, args)?;
 
         let (gas, value) = syscall.execute(&mut memory_caller_context, ctx, syscall_name)?;
 
+        #[cfg(feature = "profiling")]
+        crate::profiling::record_syscall(
+            syscall_name,
+            Self::gas_burned(&mut memory_caller_context).saturating_sub(burned),
+        );
+
         let value = value.into();
 
         Ok(WasmReturnValue {
@@ -328,6 +340,13 @@ where
         })
     }
 
+    // Gas sent with messages or reserved isn't burned, so it's not taken into
+    // account by the profiler.
+    #[cfg(feature = "profiling")]
+    fn gas_burned(ctx: &mut MemoryCallerContext<Caller>) -> u64 {
+        ctx.caller_wrap.state_mut().ext.gas_amount().burned()
+    }
+
     fn read_payload(payload: ReadPayloadLimited) -> Result<Payload, RunFallibleError> {
         payload
             .into_inner()
@@ -713,6 +732,129 @@ where
         )
     }
 
+    fn hash<D: Digest<OutputSize = U32>>(
+        token: CostToken,
+        data: Read,
+        hash_write: WriteAs<Hash>,
+    ) -> impl Syscall<Caller> {
+        InfallibleSyscall::new(token, move |ctx: &mut MemoryCallerContext<Caller>| {
+            let data = data.into_inner()?;
+            let hash: Hash = D::digest(data).into();
+
+            hash_write.write(ctx, &hash).map_err(Into::into)
+        })
+    }
+
+    pub fn blake2b_256(data: Read, hash_write: WriteAs<Hash>) -> impl Syscall<Caller> {
+        Self::hash::<Blake2b256>(CostToken::Blake2b256(data.size().into()), data, hash_write)
+    }
+
+    pub fn keccak256(data: Read, hash_write: WriteAs<Hash>) -> impl Syscall<Caller> {
+        Self::hash::<Keccak256>(CostToken::Keccak256(data.size().into()), data, hash_write)
+    }
+
+    pub fn sha256(data: Read, hash_write: WriteAs<Hash>) -> impl Syscall<Caller> {
+        Self::hash::<Sha256>(CostToken::Sha256(data.size().into()), data, hash_write)
+    }
+
+    pub fn ed25519_verify(
+        public_key: ReadAs<PublicKey>,
+        signature: ReadAs<Signature>,
+        message: Read,
+        verified_write: WriteAs<u8>,
+    ) -> impl Syscall<Caller> {
+        InfallibleSyscall::new(
+            CostToken::Ed25519Verify(message.size().into()),
+            move |ctx: &mut MemoryCallerContext<Caller>| {
+                use ed25519_dalek::Verifier;
+
+                let public_key = public_key.into_inner()?;
+                let signature = ed25519_dalek::Signature::from_bytes(&signature.into_inner()?);
+                let message = message.into_inner()?;
+
+                let verified = ed25519_dalek::VerifyingKey::from_bytes(&public_key)
+                    .is_ok_and(|key| key.verify(&message, &signature).is_ok());
+
+                verified_write
+                    .write(ctx, &u8::from(verified))
+                    .map_err(Into::into)
+            },
+        )
+    }
+
+    pub fn sr25519_verify(
+        public_key: ReadAs<PublicKey>,
+        signature: ReadAs<Signature>,
+        message: Read,
+        verified_write: WriteAs<u8>,
+    ) -> impl Syscall<Caller> {
+        InfallibleSyscall::new(
+            CostToken::Sr25519Verify(message.size().into()),
+            move |ctx: &mut MemoryCallerContext<Caller>| {
+                /// Signing context used by Substrate for sr25519 signatures.
+                const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+                let public_key = public_key.into_inner()?;
+                let signature = signature.into_inner()?;
+                let message = message.into_inner()?;
+
+                let verified = schnorrkel::PublicKey::from_bytes(&public_key)
+                    .and_then(|key| {
+                        let signature = schnorrkel::Signature::from_bytes(&signature)?;
+                        key.verify_simple(SIGNING_CONTEXT, &message, &signature)
+                    })
+                    .is_ok();
+
+                verified_write
+                    .write(ctx, &u8::from(verified))
+                    .map_err(Into::into)
+            },
+        )
+    }
+
+    pub fn secp256k1_recover(
+        message_hash: ReadAs<Hash>,
+        signature: ReadAs<EcdsaSignature>,
+        public_key_write: WriteAs<EcdsaPublicKey>,
+        recovered_write: WriteAs<u8>,
+    ) -> impl Syscall<Caller> {
+        InfallibleSyscall::new(
+            CostToken::Secp256k1Recover,
+            move |ctx: &mut MemoryCallerContext<Caller>| {
+                let message_hash = message_hash.into_inner()?;
+                let EcdsaSignature { rs, v } = signature.into_inner()?;
+
+                // Ethereum-style recovery ids are shifted by 27.
+                let v = if v >= 27 { v - 27 } else { v };
+
+                let public_key = RecoveryId::from_byte(v).and_then(|mut recovery_id| {
+                    let mut signature = ecdsa::Signature::from_slice(&rs).ok()?;
+
+                    // High `s` signatures are valid for Ethereum, but rejected by `k256`.
+                    if let Some(normalized) = signature.normalize_s() {
+                        recovery_id =
+                            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+                        signature = normalized;
+                    }
+
+                    let key =
+                        VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id)
+                            .ok()?;
+
+                    EcdsaPublicKey::try_from(&key.to_encoded_point(false).as_bytes()[1..]).ok()
+                });
+
+                if let Some(public_key) = &public_key {
+                    public_key_write.write(ctx, public_key)?;
+                }
+
+                recovered_write
+                    .write(ctx, &u8::from(public_key.is_some()))
+                    .map_err(Into::into)
+            },
+        )
+    }
+
     fn reply_inner(
         ctx: &mut MemoryCallerContext<Caller>,
         payload: ReadPayloadLimited,
@@ -15,6 +15,8 @@ mod log;
 pub mod memory;
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
+#[cfg(feature = "profiling")]
+pub mod profiling;
 mod runtime;
 pub mod state;
 
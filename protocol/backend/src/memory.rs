@@ -224,8 +224,14 @@ where
         let ext = ctx.ext_mut();
         let mut gas_counter = ext.define_current_counter();
 
+        #[cfg(feature = "profiling")]
+        let gas_before = gas_counter;
+
         let res = ext.pre_process_memory_accesses(&self.reads, &self.writes, &mut gas_counter);
 
+        #[cfg(feature = "profiling")]
+        crate::profiling::record_memory_accesses(gas_before.saturating_sub(gas_counter));
+
         ext.decrease_current_counter_to(gas_counter);
 
         res?;
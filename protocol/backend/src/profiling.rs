@@ -0,0 +1,113 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Gas profiling of the program execution.
+//!
+//! Profiling is enabled for the current thread with [`enable`] and lasts
+//! until [`take`] is called. The backend records the gas charged by every
+//! syscall and, for programs instrumented with
+//! [`gear_wasm_instrument::profiler`], the gas burned by the instructions of
+//! every function.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::cell::RefCell;
+use gear_wasm_instrument::SyscallName;
+
+/// Gas charged by the syscall.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyscallProfile {
+    /// Number of calls.
+    pub calls: u64,
+    /// Gas charged by all calls, excluding lazy-pages charges for memory
+    /// accesses.
+    pub gas: u64,
+}
+
+/// Gas burned during the program execution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExecutionProfile {
+    /// Gas charged by syscalls.
+    pub syscalls: BTreeMap<SyscallName, SyscallProfile>,
+    /// Gas burned by the instructions of wasm functions, by function index.
+    pub functions: BTreeMap<u32, u64>,
+}
+
+#[derive(Debug, Default)]
+struct Profiler {
+    // Indices of functions having gas counters.
+    functions: Vec<u32>,
+    // Set once the execution starts.
+    profile: Option<ExecutionProfile>,
+    // Gas charged for memory accesses of the current syscall.
+    memory_accesses: u64,
+}
+
+std::thread_local! {
+    static PROFILER: RefCell<Option<Profiler>> = const { RefCell::new(None) };
+}
+
+/// Enables profiling of the next execution on the current thread.
+///
+/// `functions` are the indices of the program functions having gas counters.
+pub fn enable(functions: Vec<u32>) {
+    PROFILER.with_borrow_mut(|profiler| {
+        *profiler = Some(Profiler {
+            functions,
+            ..Default::default()
+        })
+    });
+}
+
+/// Disables profiling, returning the profile of the execution.
+///
+/// Returns `None` if profiling isn't enabled or the program hasn't been
+/// executed since [`enable`], e.g. because its instantiation failed.
+pub fn take() -> Option<ExecutionProfile> {
+    PROFILER.take().and_then(|profiler| profiler.profile)
+}
+
+fn with_profile(f: impl FnOnce(&mut Profiler, &mut ExecutionProfile)) {
+    PROFILER.with_borrow_mut(|profiler| {
+        if let Some(profiler) = profiler
+            && let Some(mut profile) = profiler.profile.take()
+        {
+            f(profiler, &mut profile);
+            profiler.profile = Some(profile);
+        }
+    });
+}
+
+pub(crate) fn begin_execution() {
+    PROFILER.with_borrow_mut(|profiler| {
+        if let Some(profiler) = profiler {
+            profiler.profile = Some(Default::default());
+        }
+    });
+}
+
+pub(crate) fn record_memory_accesses(gas: u64) {
+    with_profile(|profiler, _| {
+        profiler.memory_accesses = profiler.memory_accesses.saturating_add(gas);
+    });
+}
+
+pub(crate) fn record_syscall(name: SyscallName, gas: u64) {
+    with_profile(|profiler, profile| {
+        let memory_accesses = core::mem::take(&mut profiler.memory_accesses);
+        let syscall = profile.syscalls.entry(name).or_default();
+        syscall.calls += 1;
+        syscall.gas = syscall
+            .gas
+            .saturating_add(gas.saturating_sub(memory_accesses));
+    });
+}
+
+pub(crate) fn record_functions(mut counter: impl FnMut(u32) -> Option<u64>) {
+    with_profile(|profiler, profile| {
+        for &index in &profiler.functions {
+            if let Some(gas) = counter(index).filter(|&gas| gas != 0) {
+                profile.functions.insert(index, gas);
+            }
+        }
+    });
+}
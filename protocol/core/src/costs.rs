@@ -143,6 +143,39 @@ pub struct SyscallCosts {
     /// Cost of calling `gr_random`.
     pub gr_random: CostOf<CallsAmount>,
 
+    /// Cost of calling `gr_blake2b_256`.
+    pub gr_blake2b_256: CostOf<CallsAmount>,
+
+    /// Cost per hashed byte by `gr_blake2b_256`.
+    pub gr_blake2b_256_per_byte: CostOf<BytesAmount>,
+
+    /// Cost of calling `gr_keccak256`.
+    pub gr_keccak256: CostOf<CallsAmount>,
+
+    /// Cost per hashed byte by `gr_keccak256`.
+    pub gr_keccak256_per_byte: CostOf<BytesAmount>,
+
+    /// Cost of calling `gr_sha256`.
+    pub gr_sha256: CostOf<CallsAmount>,
+
+    /// Cost per hashed byte by `gr_sha256`.
+    pub gr_sha256_per_byte: CostOf<BytesAmount>,
+
+    /// Cost of calling `gr_ed25519_verify`.
+    pub gr_ed25519_verify: CostOf<CallsAmount>,
+
+    /// Cost per message byte by `gr_ed25519_verify`.
+    pub gr_ed25519_verify_per_byte: CostOf<BytesAmount>,
+
+    /// Cost of calling `gr_sr25519_verify`.
+    pub gr_sr25519_verify: CostOf<CallsAmount>,
+
+    /// Cost per message byte by `gr_sr25519_verify`.
+    pub gr_sr25519_verify_per_byte: CostOf<BytesAmount>,
+
+    /// Cost of calling `gr_secp256k1_recover`.
+    pub gr_secp256k1_recover: CostOf<CallsAmount>,
+
     /// Cost of calling `gr_reply_deposit`.
     pub gr_reply_deposit: CostOf<CallsAmount>,
 
@@ -335,6 +368,18 @@ pub enum CostToken {
     BlockTimestamp,
     /// Cost of calling `gr_random`.
     Random,
+    /// Cost of calling `gr_blake2b_256`, taking in account data size.
+    Blake2b256(BytesAmount),
+    /// Cost of calling `gr_keccak256`, taking in account data size.
+    Keccak256(BytesAmount),
+    /// Cost of calling `gr_sha256`, taking in account data size.
+    Sha256(BytesAmount),
+    /// Cost of calling `gr_ed25519_verify`, taking in account message size.
+    Ed25519Verify(BytesAmount),
+    /// Cost of calling `gr_sr25519_verify`, taking in account message size.
+    Sr25519Verify(BytesAmount),
+    /// Cost of calling `gr_secp256k1_recover`.
+    Secp256k1Recover,
     /// Cost of calling `gr_reply_deposit`.
     ReplyDeposit,
     /// Cost of calling `gr_send`, taking in account payload size.
@@ -440,6 +485,12 @@ impl SyscallCosts {
             BlockHeight => self.gr_block_height.cost_for_one(),
             BlockTimestamp => self.gr_block_timestamp.cost_for_one(),
             Random => self.gr_random.cost_for_one(),
+            Blake2b256(len) => cost_with_per_byte!(gr_blake2b_256, len),
+            Keccak256(len) => cost_with_per_byte!(gr_keccak256, len),
+            Sha256(len) => cost_with_per_byte!(gr_sha256, len),
+            Ed25519Verify(len) => cost_with_per_byte!(gr_ed25519_verify, len),
+            Sr25519Verify(len) => cost_with_per_byte!(gr_sr25519_verify, len),
+            Secp256k1Recover => self.gr_secp256k1_recover.cost_for_one(),
             ReplyDeposit => self.gr_reply_deposit.cost_for_one(),
             Send(len) => cost_with_per_byte!(gr_send, len),
             SendWGas(len) => cost_with_per_byte!(gr_send_wgas, len),
@@ -398,6 +398,28 @@ pub struct SyscallWeights {
     pub gr_block_timestamp: Weight,
     #[doc = " Weight of calling `gr_random`."]
     pub gr_random: Weight,
+    #[doc = " Weight of calling `gr_blake2b_256`."]
+    pub gr_blake2b_256: Weight,
+    #[doc = " Weight per hashed byte by `gr_blake2b_256`."]
+    pub gr_blake2b_256_per_byte: Weight,
+    #[doc = " Weight of calling `gr_keccak256`."]
+    pub gr_keccak256: Weight,
+    #[doc = " Weight per hashed byte by `gr_keccak256`."]
+    pub gr_keccak256_per_byte: Weight,
+    #[doc = " Weight of calling `gr_sha256`."]
+    pub gr_sha256: Weight,
+    #[doc = " Weight per hashed byte by `gr_sha256`."]
+    pub gr_sha256_per_byte: Weight,
+    #[doc = " Weight of calling `gr_ed25519_verify`."]
+    pub gr_ed25519_verify: Weight,
+    #[doc = " Weight per message byte by `gr_ed25519_verify`."]
+    pub gr_ed25519_verify_per_byte: Weight,
+    #[doc = " Weight of calling `gr_sr25519_verify`."]
+    pub gr_sr25519_verify: Weight,
+    #[doc = " Weight per message byte by `gr_sr25519_verify`."]
+    pub gr_sr25519_verify_per_byte: Weight,
+    #[doc = " Weight of calling `gr_secp256k1_recover`."]
+    pub gr_secp256k1_recover: Weight,
     #[doc = " Weight of calling `gr_reply_deposit`."]
     pub gr_reply_deposit: Weight,
     #[doc = " Weight of calling `gr_send`."]
@@ -583,6 +605,54 @@ impl Default for SyscallWeights {
                 ref_time: 1668151,
                 proof_size: 0,
             },
+            // Not benchmarked: provisional `gr_blake2b_256*`, `gr_keccak256*`
+            // and `gr_sha256*` weights, see `vara/pallets/gear/src/weights.rs`.
+            gr_blake2b_256: Weight {
+                ref_time: 1173452,
+                proof_size: 0,
+            },
+            gr_blake2b_256_per_byte: Weight {
+                ref_time: 1156,
+                proof_size: 0,
+            },
+            gr_keccak256: Weight {
+                ref_time: 1190738,
+                proof_size: 0,
+            },
+            gr_keccak256_per_byte: Weight {
+                ref_time: 2327,
+                proof_size: 0,
+            },
+            gr_sha256: Weight {
+                ref_time: 1184617,
+                proof_size: 0,
+            },
+            gr_sha256_per_byte: Weight {
+                ref_time: 2768,
+                proof_size: 0,
+            },
+            // Not benchmarked: provisional `gr_ed25519_verify*`, `gr_sr25519_verify*`
+            // and `gr_secp256k1_recover` weights, see `vara/pallets/gear/src/weights.rs`.
+            gr_ed25519_verify: Weight {
+                ref_time: 47412186,
+                proof_size: 0,
+            },
+            gr_ed25519_verify_per_byte: Weight {
+                ref_time: 1138,
+                proof_size: 0,
+            },
+            gr_sr25519_verify: Weight {
+                ref_time: 53108419,
+                proof_size: 0,
+            },
+            gr_sr25519_verify_per_byte: Weight {
+                ref_time: 1791,
+                proof_size: 0,
+            },
+            gr_secp256k1_recover: Weight {
+                ref_time: 62874012,
+                proof_size: 0,
+            },
             gr_reply_deposit: Weight {
                 ref_time: 4133750,
                 proof_size: 0,
@@ -1136,6 +1206,17 @@ impl From<SyscallWeights> for SyscallCosts {
             gr_block_height: val.gr_block_height.ref_time().into(),
             gr_block_timestamp: val.gr_block_timestamp.ref_time().into(),
             gr_random: val.gr_random.ref_time().into(),
+            gr_blake2b_256: val.gr_blake2b_256.ref_time().into(),
+            gr_blake2b_256_per_byte: val.gr_blake2b_256_per_byte.ref_time().into(),
+            gr_keccak256: val.gr_keccak256.ref_time().into(),
+            gr_keccak256_per_byte: val.gr_keccak256_per_byte.ref_time().into(),
+            gr_sha256: val.gr_sha256.ref_time().into(),
+            gr_sha256_per_byte: val.gr_sha256_per_byte.ref_time().into(),
+            gr_ed25519_verify: val.gr_ed25519_verify.ref_time().into(),
+            gr_ed25519_verify_per_byte: val.gr_ed25519_verify_per_byte.ref_time().into(),
+            gr_sr25519_verify: val.gr_sr25519_verify.ref_time().into(),
+            gr_sr25519_verify_per_byte: val.gr_sr25519_verify_per_byte.ref_time().into(),
+            gr_secp256k1_recover: val.gr_secp256k1_recover.ref_time().into(),
             gr_reply_deposit: val.gr_reply_deposit.ref_time().into(),
             gr_send: val.gr_send.ref_time().into(),
             gr_send_per_byte: val.gr_send_per_byte.ref_time().into(),
@@ -257,6 +257,11 @@ impl MessageContext {
         self.outcome.reply.is_some()
     }
 
+    /// Id of the next outgoing message, e.g. init message of the program to be created.
+    pub fn next_message_id(&self) -> MessageId {
+        MessageId::generate_outgoing(self.current.id(), self.store.local_nonce)
+    }
+
     /// Send a new program initialization message.
     ///
     /// Generates a new message from provided data packet.
@@ -630,6 +635,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_message_id_matches_init_message_id() {
+        let mut message_context = MessageContext::new(
+            Default::default(),
+            Default::default(),
+            ContextSettings::with_outgoing_limits(1024, u32::MAX),
+        );
+
+        assert_ok!(message_context.send_init());
+
+        let expected = message_context.next_message_id();
+        let (message_id, _) = message_context
+            .init_program(Default::default(), 0)
+            .expect("failed to init program");
+
+        assert_eq!(message_id, expected);
+        assert_ne!(message_context.next_message_id(), expected);
+    }
+
     #[test]
     fn send_push_bytes_exceeded() {
         let mut message_context = MessageContext::new(
@@ -151,6 +151,13 @@ impl InitPacket {
         }
     }
 
+    /// Overrides newly created program id.
+    ///
+    /// Used by runtimes deriving program ids differently, e.g. ethexe.
+    pub fn with_program_id(self, program_id: ActorId) -> Self {
+        Self { program_id, ..self }
+    }
+
     /// Packet destination (newly created program id).
     pub fn destination(&self) -> ActorId {
         self.program_id
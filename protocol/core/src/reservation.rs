@@ -399,7 +399,9 @@ impl From<GasReservationSlot> for GasReservationState {
 pub type GasReservationMap = BTreeMap<ReservationId, GasReservationSlot>;
 
 /// Gas reservation slot.
-#[derive(Debug, Clone, Eq, PartialEq, Encode, EncodeAsType, Decode, DecodeAsType, TypeInfo)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, Hash, Encode, EncodeAsType, Decode, DecodeAsType, TypeInfo,
+)]
 pub struct GasReservationSlot {
     /// Amount of reserved gas.
     pub amount: u64,
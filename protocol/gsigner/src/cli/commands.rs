@@ -217,12 +217,47 @@ pub enum SchemeSubcommand {
         #[arg(short, long, help = "Signature (hex)")]
         signature: String,
     },
+    #[cfg(feature = "keyring")]
+    #[command(
+        name = "sign-typed",
+        about = "Sign EIP-712 typed data with a stored key"
+    )]
+    SignTyped {
+        #[arg(long, help = "Public key (hex)")]
+        public_key: String,
+        #[arg(
+            short,
+            long,
+            help = "Typed data (JSON)",
+            conflicts_with = "file",
+            required_unless_present = "file"
+        )]
+        data: Option<String>,
+        #[arg(short, long, value_name = "PATH", help = "Typed data file (JSON)")]
+        file: Option<PathBuf>,
+        #[command(flatten)]
+        storage: StorageLocationArgs,
+    },
     #[cfg(feature = "peer-id")]
     #[command(about = "Derive PeerId from public key")]
     PeerId {
         #[arg(long, help = "Public key (hex)")]
         public_key: String,
     },
+    #[cfg(all(unix, feature = "remote"))]
+    #[command(about = "Run a remote signer daemon serving stored keys over a Unix socket")]
+    Server {
+        #[command(flatten)]
+        storage: StorageLocationArgs,
+        #[arg(long, value_name = "PATH", help = "Unix socket path to listen on")]
+        socket: PathBuf,
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Key policy file (JSON); every request is denied if omitted"
+        )]
+        policy: Option<PathBuf>,
+    },
 }
 
 /// Unified keyring-backed commands
@@ -448,11 +448,78 @@ fn dispatch_subcommand(
                 signature,
             },
         ),
+        #[cfg(feature = "keyring")]
+        SchemeSubcommand::SignTyped {
+            public_key,
+            data,
+            file,
+            storage,
+        } => {
+            let typed_data = match (data, file) {
+                (Some(data), _) => data,
+                (None, Some(file)) => std::fs::read_to_string(file)?,
+                (None, None) => anyhow::bail!("Either --data or --file must be provided"),
+            };
+            execute_scheme_command(
+                descriptor,
+                SchemeCommand::SignTyped {
+                    storage,
+                    public_key,
+                    typed_data,
+                },
+            )
+        }
         #[cfg(feature = "peer-id")]
         SchemeSubcommand::PeerId { public_key } => {
             execute_scheme_command(descriptor, SchemeCommand::PeerId { public_key })
         }
+        #[cfg(all(unix, feature = "remote"))]
+        SchemeSubcommand::Server {
+            storage,
+            socket,
+            policy,
+        } => execute_scheme_command(
+            descriptor,
+            SchemeCommand::Server {
+                storage,
+                socket,
+                policy,
+            },
+        ),
+    }
+}
+
+#[cfg(all(unix, feature = "remote"))]
+fn serve_remote<S: crate::remote::RemoteScheme>(
+    storage: StorageLocationArgs,
+    socket: std::path::PathBuf,
+    policy: Option<std::path::PathBuf>,
+) -> Result<SchemeResult> {
+    use crate::remote::{PolicySet, SignerServer};
+
+    let policies = match policy {
+        Some(path) => PolicySet::load(path)?,
+        None => {
+            tracing::warn!("No policy file is given, all requests will be denied");
+            PolicySet::default()
+        }
+    };
+    let mut server = SignerServer::new(create_signer::<S>(&storage)?, policies);
+    if let Some(password) = storage.key_password {
+        server = server.with_password(password);
     }
+
+    let listener = crate::remote::bind(&socket)?;
+    tracing::info!(
+        "{} remote signer listening on {}",
+        S::NAME,
+        socket.display()
+    );
+    server.serve(listener);
+
+    Ok(SchemeResult::Message(MessageResult {
+        message: "Remote signer stopped".into(),
+    }))
 }
 
 #[allow(clippy::vec_init_then_push)]
@@ -514,8 +581,12 @@ fn secp256k1_scheme_descriptor() -> SchemeDescriptor<SchemeKeyringCommands> {
             let effective_prefix = prefix.or_else(|| Some(EIP191_PREFIX.to_string()));
             recover(data, effective_prefix, signature)
         }),
+        #[cfg(feature = "keyring")]
+        sign_typed: Some(secp256k1_sign_typed),
         #[cfg(feature = "peer-id")]
         peer_id: Some(secp256k1_peer_id),
+        #[cfg(all(unix, feature = "remote"))]
+        server: serve_remote::<crate::schemes::secp256k1::Secp256k1>,
     }
 }
 
@@ -524,6 +595,30 @@ fn secp256k1_handle_keyring(command: SchemeKeyringCommands) -> Result<SchemeResu
     execute_keyring_command::<Secp256k1KeyringOps>(command)
 }
 
+#[cfg(all(feature = "secp256k1", feature = "keyring"))]
+fn secp256k1_sign_typed(
+    storage: StorageLocationArgs,
+    public_key: String,
+    typed_data: String,
+) -> Result<SchemeResult> {
+    use crate::schemes::secp256k1::{PublicKey, Secp256k1, Secp256k1SignerExt, eip712::TypedData};
+
+    validate_hex_len(&public_key, 33, "public key")?;
+    let public_key: PublicKey = public_key.parse()?;
+    let typed_data = TypedData::from_json(&typed_data)?;
+    let password = storage
+        .key_password
+        .as_ref()
+        .map(|secret| secret.expose_secret().as_str());
+
+    with_signer::<Secp256k1, _, _>(&storage, |signer| {
+        let signature = signer.sign_typed_data(public_key, &typed_data, password)?;
+        Ok(SchemeResult::Sign(SignResult {
+            signature: hex::encode(signature.into_pre_eip155_bytes()),
+        }))
+    })
+}
+
 #[cfg(all(feature = "secp256k1", feature = "peer-id"))]
 fn secp256k1_peer_id(public_key: String) -> Result<SchemeResult> {
     use crate::schemes::secp256k1::PublicKey;
@@ -545,8 +640,12 @@ fn ed25519_scheme_descriptor() -> SchemeDescriptor<SchemeKeyringCommands> {
         verify: ed25519_verify,
         address: ed25519_address,
         recover: None,
+        #[cfg(feature = "keyring")]
+        sign_typed: None,
         #[cfg(feature = "peer-id")]
         peer_id: Some(ed25519_peer_id),
+        #[cfg(all(unix, feature = "remote"))]
+        server: serve_remote::<crate::schemes::ed25519::Ed25519>,
     }
 }
 
@@ -607,8 +706,12 @@ fn sr25519_scheme_descriptor() -> SchemeDescriptor<SchemeKeyringCommands> {
         verify: sr25519_verify,
         address: sr25519_address,
         recover: None,
+        #[cfg(feature = "keyring")]
+        sign_typed: None,
         #[cfg(feature = "peer-id")]
         peer_id: None,
+        #[cfg(all(unix, feature = "remote"))]
+        server: serve_remote::<crate::schemes::sr25519::Sr25519>,
     }
 }
 
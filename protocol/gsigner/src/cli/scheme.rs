@@ -3,8 +3,12 @@
 
 //! Scheme descriptors and result types shared between CLI handlers and display.
 
+#[cfg(all(unix, feature = "remote"))]
+use crate::cli::commands::StorageLocationArgs;
 use anyhow::Result;
 use serde::Serialize;
+#[cfg(all(unix, feature = "remote"))]
+use std::path::PathBuf;
 
 /// Result of key generation.
 #[derive(Debug, Clone, Serialize)]
@@ -135,16 +139,32 @@ pub enum SchemeCommand<KeyringCommand> {
         prefix: Option<String>,
         signature: String,
     },
+    #[cfg(feature = "keyring")]
+    SignTyped {
+        storage: StorageLocationArgs,
+        public_key: String,
+        typed_data: String,
+    },
     #[cfg(feature = "peer-id")]
     PeerId { public_key: String },
+    #[cfg(all(unix, feature = "remote"))]
+    Server {
+        storage: StorageLocationArgs,
+        socket: PathBuf,
+        policy: Option<PathBuf>,
+    },
 }
 
 pub type SchemeVerifyFn =
     fn(String, String, Option<String>, String, Option<String>) -> Result<SchemeResult>;
 pub type SchemeAddressFn = fn(String, Option<String>) -> Result<SchemeResult>;
 pub type SchemeRecoverFn = fn(String, Option<String>, String) -> Result<SchemeResult>;
+#[cfg(feature = "keyring")]
+pub type SchemeSignTypedFn = fn(StorageLocationArgs, String, String) -> Result<SchemeResult>;
 #[cfg(feature = "peer-id")]
 pub type SchemePeerIdFn = fn(String) -> Result<SchemeResult>;
+#[cfg(all(unix, feature = "remote"))]
+pub type SchemeServerFn = fn(StorageLocationArgs, PathBuf, Option<PathBuf>) -> Result<SchemeResult>;
 
 /// Generic descriptor for a signing scheme.
 pub struct SchemeDescriptor<KeyringCommand> {
@@ -153,8 +173,12 @@ pub struct SchemeDescriptor<KeyringCommand> {
     pub verify: SchemeVerifyFn,
     pub address: SchemeAddressFn,
     pub recover: Option<SchemeRecoverFn>,
+    #[cfg(feature = "keyring")]
+    pub sign_typed: Option<SchemeSignTypedFn>,
     #[cfg(feature = "peer-id")]
     pub peer_id: Option<SchemePeerIdFn>,
+    #[cfg(all(unix, feature = "remote"))]
+    pub server: SchemeServerFn,
 }
 
 pub fn execute_scheme_command<KeyringCommand>(
@@ -185,6 +209,17 @@ pub fn execute_scheme_command<KeyringCommand>(
                 .ok_or_else(|| anyhow::anyhow!("Recovery is not supported for this scheme"))?;
             recover_fn(data, prefix, signature)
         }
+        #[cfg(feature = "keyring")]
+        SchemeCommand::SignTyped {
+            storage,
+            public_key,
+            typed_data,
+        } => {
+            let sign_typed_fn = descriptor.sign_typed.ok_or_else(|| {
+                anyhow::anyhow!("Typed data signing is not supported for this scheme")
+            })?;
+            sign_typed_fn(storage, public_key, typed_data)
+        }
         #[cfg(feature = "peer-id")]
         SchemeCommand::PeerId { public_key } => {
             let peer_id_fn = descriptor.peer_id.ok_or_else(|| {
@@ -192,5 +227,11 @@ pub fn execute_scheme_command<KeyringCommand>(
             })?;
             peer_id_fn(public_key)
         }
+        #[cfg(all(unix, feature = "remote"))]
+        SchemeCommand::Server {
+            storage,
+            socket,
+            policy,
+        } => (descriptor.server)(storage, socket, policy),
     }
 }
@@ -49,6 +49,15 @@ pub enum SignerError {
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
+    /// Invalid EIP-712 typed data.
+    #[error("Invalid typed data: {0}")]
+    InvalidTypedData(String),
+
+    /// Remote signer request failed or is not supported.
+    #[cfg(feature = "std")]
+    #[error("Remote signer error: {0}")]
+    Remote(String),
+
     /// Feature not enabled.
     #[error("Feature not enabled: {0}")]
     FeatureNotEnabled(&'static str),
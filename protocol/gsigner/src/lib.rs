@@ -14,6 +14,7 @@
 //! - `cli` - Enable command-line interface tools
 //! - `codec` - Enable parity-scale-codec support for serialization
 //! - `keyring` - Keyring support with primary key management
+//! - `remote` - Remote signer daemon and client (Unix only)
 //! - `gprimitives` - Enable gprimitives integration (for ActorId conversions)
 //! - `alloy-primitives` - Enable alloy-primitives integration
 //! - `sp-core` - Enable sp-core integration (Substrate compatibility)
@@ -48,6 +49,8 @@ pub mod hash;
 pub mod peer_id;
 #[cfg(feature = "peer-id")]
 pub use peer_id::ToPeerId;
+#[cfg(all(unix, feature = "remote"))]
+pub mod remote;
 pub mod scheme;
 pub mod schemes;
 #[cfg(all(feature = "std", feature = "keyring", feature = "serde"))]
@@ -0,0 +1,162 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Remote signer client.
+
+use super::{RemoteRequest, RemoteResponse};
+use crate::{
+    error::{Result, SignerError},
+    scheme::CryptoScheme,
+};
+use std::{
+    io::{BufRead, BufReader, Write},
+    os::unix::net::UnixStream,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Default timeout for a single request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Client of the remote signer daemon.
+///
+/// Opens a new connection for every request, so the client stays usable
+/// across daemon restarts.
+#[derive(Debug, Clone)]
+pub struct RemoteClient {
+    socket: PathBuf,
+    timeout: Duration,
+}
+
+impl RemoteClient {
+    /// Create a client for the daemon listening on the socket.
+    pub fn new(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: socket.into(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Set the timeout for a single request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Get the daemon socket path.
+    pub fn socket(&self) -> &Path {
+        &self.socket
+    }
+
+    /// Send a raw request to the daemon.
+    pub fn request(&self, request: &RemoteRequest) -> Result<RemoteResponse> {
+        let mut stream = UnixStream::connect(&self.socket).map_err(|e| {
+            SignerError::Remote(format!(
+                "Failed to connect to {}: {e}",
+                self.socket.display()
+            ))
+        })?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut payload = serde_json::to_vec(request)?;
+        payload.push(b'\n');
+        stream.write_all(&payload)?;
+
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line)?;
+        if line.is_empty() {
+            return Err(SignerError::Remote("Connection closed by daemon".into()));
+        }
+
+        Ok(serde_json::from_str(&line)?)
+    }
+
+    /// List public keys available to the client.
+    pub fn list_keys<S: CryptoScheme>(&self) -> Result<Vec<S::PublicKey>> {
+        let request = RemoteRequest::List {
+            scheme: S::NAME.into(),
+        };
+        match self.request(&request)? {
+            RemoteResponse::Keys { keys } => {
+                keys.iter().map(|key| S::public_key_from_hex(key)).collect()
+            }
+            response => Err(Self::unexpected(response)),
+        }
+    }
+
+    /// Sign data with the key stored in the daemon.
+    pub fn sign<S: CryptoScheme>(
+        &self,
+        public_key: &S::PublicKey,
+        data: &[u8],
+    ) -> Result<S::Signature> {
+        self.sign_inner::<S>(public_key, data, false, None)
+    }
+
+    /// Sign a precomputed digest with the key stored in the daemon.
+    pub fn sign_prehashed<S: CryptoScheme>(
+        &self,
+        public_key: &S::PublicKey,
+        digest: &[u8],
+    ) -> Result<S::Signature> {
+        self.sign_inner::<S>(public_key, digest, true, None)
+    }
+
+    /// Sign data with a custom signing context with the key stored in the daemon.
+    pub fn sign_with_context<S: CryptoScheme>(
+        &self,
+        public_key: &S::PublicKey,
+        context: &[u8],
+        data: &[u8],
+    ) -> Result<S::Signature> {
+        self.sign_inner::<S>(public_key, data, false, Some(context))
+    }
+
+    fn sign_inner<S: CryptoScheme>(
+        &self,
+        public_key: &S::PublicKey,
+        data: &[u8],
+        prehashed: bool,
+        context: Option<&[u8]>,
+    ) -> Result<S::Signature> {
+        let request = RemoteRequest::Sign {
+            scheme: S::NAME.into(),
+            public_key: S::public_key_to_hex(public_key),
+            data: hex::encode(data),
+            prehashed,
+            context: context.map(hex::encode),
+        };
+        match self.request(&request)? {
+            RemoteResponse::Signature { signature } => S::signature_from_hex(&signature),
+            response => Err(Self::unexpected(response)),
+        }
+    }
+
+    /// Verify a signature by the daemon.
+    pub fn verify<S: CryptoScheme>(
+        &self,
+        public_key: &S::PublicKey,
+        data: &[u8],
+        signature: &S::Signature,
+    ) -> Result<()> {
+        let request = RemoteRequest::Verify {
+            scheme: S::NAME.into(),
+            public_key: S::public_key_to_hex(public_key),
+            data: hex::encode(data),
+            signature: S::signature_to_hex(signature),
+        };
+        match self.request(&request)? {
+            RemoteResponse::Verified { valid: true } => Ok(()),
+            RemoteResponse::Verified { valid: false } => Err(SignerError::VerificationFailed),
+            response => Err(Self::unexpected(response)),
+        }
+    }
+
+    fn unexpected(response: RemoteResponse) -> SignerError {
+        match response {
+            RemoteResponse::Error { message } => SignerError::Remote(message),
+            response => SignerError::Remote(format!("Unexpected response: {response:?}")),
+        }
+    }
+}
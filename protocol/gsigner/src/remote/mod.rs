@@ -0,0 +1,261 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Remote signer daemon and client.
+//!
+//! Keeps key material out of the process that needs signatures. The daemon
+//! ([`SignerServer`]) owns a keyring-backed [`Signer`](crate::Signer) and
+//! serves `list`, `sign` and `verify` requests over a Unix socket, checking
+//! every request against a per-key [`KeyPolicy`]. The client ([`RemoteClient`])
+//! is used by [`Signer::remote`](crate::Signer::remote), so existing code keeps
+//! working with the usual `Signer` API while the private keys never leave the
+//! daemon.
+//!
+//! Requests and responses are JSON objects, one per line. See [`protocol`] for
+//! the wire format.
+//!
+//! ```rust,ignore
+//! use gsigner::{remote::{PolicySet, SignerServer}, secp256k1};
+//!
+//! // Daemon side
+//! let server = SignerServer::new(secp256k1::Signer::fs(path)?, PolicySet::load(policy)?);
+//! server.serve(gsigner::remote::bind("/run/gsigner.sock")?);
+//!
+//! // Client side
+//! let signer = secp256k1::Signer::remote("/run/gsigner.sock");
+//! let signature = signer.sign(public_key, b"hello world")?;
+//! ```
+
+mod client;
+mod policy;
+pub mod protocol;
+mod server;
+
+pub use client::RemoteClient;
+pub use policy::{KeyPolicy, PolicySet};
+pub use protocol::{RemoteRequest, RemoteResponse};
+pub use server::{MAX_CONNECTIONS, MAX_REQUEST_LEN, SignerServer, bind};
+
+use crate::{
+    error::{Result, SignerError},
+    keyring::KeyringScheme,
+};
+
+/// Signature schemes that can be served by the remote signer.
+pub trait RemoteScheme: KeyringScheme {
+    /// Sign a precomputed digest.
+    ///
+    /// Schemes without prehashed signing keep the default implementation,
+    /// which rejects the request.
+    fn sign_prehashed(private_key: &Self::PrivateKey, digest: &[u8]) -> Result<Self::Signature> {
+        let _ = (private_key, digest);
+        Err(SignerError::Crypto(format!(
+            "{} does not support prehashed signing",
+            Self::NAME
+        )))
+    }
+
+    /// Sign data with a custom signing context.
+    ///
+    /// Schemes without signing contexts keep the default implementation,
+    /// which rejects the request.
+    fn sign_with_context(
+        private_key: &Self::PrivateKey,
+        context: &[u8],
+        data: &[u8],
+    ) -> Result<Self::Signature> {
+        let _ = (private_key, context, data);
+        Err(SignerError::Crypto(format!(
+            "{} does not support signing contexts",
+            Self::NAME
+        )))
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+impl RemoteScheme for crate::schemes::secp256k1::Secp256k1 {
+    fn sign_prehashed(private_key: &Self::PrivateKey, digest: &[u8]) -> Result<Self::Signature> {
+        use crate::schemes::secp256k1::{Digest, Signature};
+
+        let digest: [u8; 32] = digest.try_into().map_err(|_| {
+            SignerError::Crypto(format!("Expected 32 bytes digest, got {}", digest.len()))
+        })?;
+        Signature::create_from_digest(private_key, Digest(digest))
+            .map_err(|e| SignerError::Crypto(format!("Signing failed: {e}")))
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl RemoteScheme for crate::schemes::ed25519::Ed25519 {}
+
+#[cfg(feature = "sr25519")]
+impl RemoteScheme for crate::schemes::sr25519::Sr25519 {
+    fn sign_with_context(
+        private_key: &Self::PrivateKey,
+        context: &[u8],
+        data: &[u8],
+    ) -> Result<Self::Signature> {
+        use crate::schemes::sr25519::Signature;
+
+        let ctx = schnorrkel::signing_context(context);
+        Ok(Signature::from(private_key.keypair().sign(ctx.bytes(data))))
+    }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+    use super::*;
+    use crate::{
+        Signer,
+        schemes::secp256k1::{Digest, Secp256k1, Secp256k1SignerExt, Signature},
+    };
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Write},
+        os::unix::{fs::PermissionsExt, net::UnixStream},
+        thread,
+    };
+
+    fn spawn_server<S: RemoteScheme>(server: SignerServer<S>) -> (tempfile::TempDir, Signer<S>)
+    where
+        SignerServer<S>: Send + 'static,
+    {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("gsigner.sock");
+        let listener = bind(&socket).unwrap();
+        thread::spawn(move || server.serve(listener));
+
+        (dir, Signer::remote(socket))
+    }
+
+    #[test]
+    fn remote_signer_forwards_requests() {
+        let local = Signer::<Secp256k1>::memory();
+        let public_key = local.generate().unwrap();
+
+        let policies = PolicySet::from_json(r#"{ "default": {} }"#).unwrap();
+        let (_dir, remote) = spawn_server(SignerServer::new(local.clone(), policies));
+
+        assert_eq!(remote.list_keys().unwrap(), vec![public_key]);
+        assert!(remote.has_key(public_key).unwrap());
+        assert_eq!(
+            remote.get_key_by_address(public_key.to_address()).unwrap(),
+            Some(public_key)
+        );
+
+        let signature = remote.sign(public_key, b"hello world").unwrap();
+        assert_eq!(signature, local.sign(public_key, b"hello world").unwrap());
+        remote
+            .verify(public_key, b"hello world", &signature)
+            .unwrap();
+
+        // Prehashed signing is used by the secp256k1 extensions
+        let digest = Digest::random();
+        assert_eq!(
+            remote.sign_digest(public_key, digest, None).unwrap(),
+            Signature::create_from_digest(&local.private_key(public_key).unwrap(), digest).unwrap()
+        );
+        let signed = remote
+            .signed_data(public_key, b"data".to_vec(), None)
+            .unwrap();
+        assert_eq!(signed.public_key(), public_key);
+
+        // Key material never leaves the daemon
+        assert!(matches!(
+            remote.private_key(public_key),
+            Err(SignerError::Remote(_))
+        ));
+        assert!(matches!(remote.generate(), Err(SignerError::Remote(_))));
+    }
+
+    #[test]
+    fn remote_signer_enforces_policies() {
+        let local = Signer::<Secp256k1>::memory();
+        let allowed = local.generate().unwrap();
+        let hidden = local.generate().unwrap();
+
+        let policies = PolicySet::from_json(&format!(
+            r#"{{
+                "keys": {{
+                    "0x{}": {{ "sign_prehashed": false, "max_data_len": 4 }}
+                }}
+            }}"#,
+            hex::encode(allowed.to_bytes())
+        ))
+        .unwrap();
+
+        let (_dir, remote) = spawn_server(SignerServer::new(local, policies));
+
+        // Keys without a policy entry fall back to the deny-all default
+        assert_eq!(remote.list_keys().unwrap(), vec![allowed]);
+        assert!(matches!(
+            remote.sign(hidden, b"data"),
+            Err(SignerError::Remote(_))
+        ));
+
+        remote.sign(allowed, b"data").unwrap();
+        assert!(matches!(
+            remote.sign(allowed, b"too long"),
+            Err(SignerError::Remote(_))
+        ));
+        assert!(matches!(
+            remote.sign_digest(allowed, Digest::random(), None),
+            Err(SignerError::Remote(_))
+        ));
+    }
+
+    #[test]
+    fn oversized_request_is_rejected() {
+        let local = Signer::<Secp256k1>::memory();
+        let (dir, _remote) = spawn_server(SignerServer::new(local, PolicySet::default()));
+
+        // Only the socket is left in the directory, accessible to the owner only
+        let socket = dir.path().join("gsigner.sock");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+        assert_eq!(
+            fs::metadata(&socket).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        let mut stream = UnixStream::connect(&socket).unwrap();
+        stream.write_all(&vec![b' '; MAX_REQUEST_LEN + 1]).unwrap();
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert!(matches!(
+            serde_json::from_str::<RemoteResponse>(&line).unwrap(),
+            RemoteResponse::Error { .. }
+        ));
+
+        // The connection is closed after the error
+        line.clear();
+        assert_eq!(reader.read_line(&mut line).unwrap(), 0);
+    }
+
+    #[cfg(feature = "sr25519")]
+    #[test]
+    fn remote_signer_signs_with_context() {
+        use crate::schemes::sr25519::{Sr25519, Sr25519SignerExt};
+
+        let local = Signer::<Sr25519>::memory();
+        let public_key = local.generate().unwrap();
+
+        let policies = PolicySet::from_json(r#"{ "default": {} }"#).unwrap();
+        let (_dir, remote) = spawn_server(SignerServer::new(local.clone(), policies));
+
+        let signature = remote
+            .sign_with_context(public_key, b"substrate", b"data", None)
+            .unwrap();
+        local
+            .verify_with_context(public_key, b"substrate", b"data", &signature)
+            .unwrap();
+
+        // Key passwords are managed by the daemon
+        assert!(
+            remote
+                .sign_with_context(public_key, b"substrate", b"data", Some("password"))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Per-key access policies of the remote signer.
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::Path};
+
+/// Operations a client may perform with a single key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyPolicy {
+    /// Whether the key is returned by `list` requests.
+    pub list: bool,
+    /// Whether the key may sign arbitrary data.
+    pub sign: bool,
+    /// Whether the key may sign precomputed digests.
+    ///
+    /// The daemon can't check what a digest was computed from, so this
+    /// effectively allows signing arbitrary data (including transactions),
+    /// bypassing `max_data_len`.
+    pub sign_prehashed: bool,
+    /// Maximum length of data accepted for signing, in bytes.
+    pub max_data_len: Option<usize>,
+}
+
+impl Default for KeyPolicy {
+    fn default() -> Self {
+        Self {
+            list: true,
+            sign: true,
+            sign_prehashed: true,
+            max_data_len: None,
+        }
+    }
+}
+
+impl KeyPolicy {
+    /// Policy that forbids every operation.
+    pub fn deny() -> Self {
+        Self {
+            list: false,
+            sign: false,
+            sign_prehashed: false,
+            max_data_len: None,
+        }
+    }
+
+    /// Check whether signing `len` bytes is allowed.
+    pub fn check_sign(&self, len: usize, prehashed: bool) -> core::result::Result<(), String> {
+        if prehashed && !self.sign_prehashed {
+            return Err("Prehashed signing is not allowed for this key".into());
+        }
+        if !prehashed && !self.sign {
+            return Err("Signing is not allowed for this key".into());
+        }
+        if let Some(max) = self.max_data_len
+            && len > max
+        {
+            return Err(format!(
+                "Data length {len} exceeds the limit of {max} bytes"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Policies for all keys served by the daemon.
+///
+/// Loaded from a JSON file of the following form:
+///
+/// ```json
+/// {
+///     "default": { "list": false, "sign": false, "sign_prehashed": false },
+///     "keys": {
+///         "0x02…": { "sign_prehashed": true, "max_data_len": 1024 }
+///     }
+/// }
+/// ```
+///
+/// Keys without an entry fall back to `default`, which denies everything
+/// when omitted from the file. Fields omitted from a key entry are allowed.
+///
+/// Without a policy file, [`PolicySet::default`] denies every request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicySet {
+    /// Policy applied to keys without an explicit entry.
+    #[serde(default = "KeyPolicy::deny")]
+    pub default: KeyPolicy,
+    /// Policies by public key (hex).
+    #[serde(default)]
+    pub keys: HashMap<String, KeyPolicy>,
+}
+
+impl Default for PolicySet {
+    /// Policy set denying every operation for every key.
+    fn default() -> Self {
+        Self {
+            default: KeyPolicy::deny(),
+            keys: HashMap::new(),
+        }
+    }
+}
+
+impl PolicySet {
+    /// Parse a policy set from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let mut policies: Self = serde_json::from_str(json)?;
+        policies.keys = policies
+            .keys
+            .into_iter()
+            .map(|(key, policy)| (normalize_key(&key), policy))
+            .collect();
+        Ok(policies)
+    }
+
+    /// Load a policy set from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Get the policy for the public key (hex).
+    pub fn policy(&self, public_key: &str) -> &KeyPolicy {
+        self.keys
+            .get(&normalize_key(public_key))
+            .unwrap_or(&self.default)
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.strip_prefix("0x").unwrap_or(key).to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_lookup() {
+        let policies = PolicySet::from_json(
+            r#"{ "keys": { "0xABCD": { "list": false, "max_data_len": 2 } } }"#,
+        )
+        .unwrap();
+
+        let policy = policies.policy("abcd");
+        assert!(!policy.list);
+        assert!(policy.check_sign(2, false).is_ok());
+        assert!(policy.check_sign(3, false).is_err());
+
+        assert_eq!(policies.policy("0x1234"), &KeyPolicy::deny());
+        assert!(KeyPolicy::deny().check_sign(0, false).is_err());
+        assert_eq!(PolicySet::default().policy("1234"), &KeyPolicy::deny());
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Wire format of the remote signer.
+//!
+//! Every message is a single JSON object terminated by a newline. Binary
+//! values (public keys, data, signatures) are hex encoded, with or without
+//! the `0x` prefix. A connection may carry any number of request/response
+//! pairs.
+//!
+//! ```text
+//! → {"method":"sign","scheme":"secp256k1","public_key":"02…","data":"68656c6c6f"}
+//! ← {"status":"signature","signature":"…"}
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Request sent to the remote signer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteRequest {
+    /// List public keys visible to the client.
+    List {
+        /// Scheme name, must match the daemon scheme.
+        scheme: String,
+    },
+    /// Sign data with a stored key.
+    Sign {
+        /// Scheme name, must match the daemon scheme.
+        scheme: String,
+        /// Public key (hex).
+        public_key: String,
+        /// Data to sign (hex).
+        data: String,
+        /// Whether `data` is a precomputed digest.
+        #[serde(default)]
+        prehashed: bool,
+        /// Signing context (hex), if other than the scheme default.
+        ///
+        /// Only supported by sr25519, e.g. `substrate` for Substrate extrinsics.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        context: Option<String>,
+    },
+    /// Verify a signature.
+    Verify {
+        /// Scheme name, must match the daemon scheme.
+        scheme: String,
+        /// Public key (hex).
+        public_key: String,
+        /// Signed data (hex).
+        data: String,
+        /// Signature (hex).
+        signature: String,
+    },
+}
+
+/// Response returned by the remote signer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RemoteResponse {
+    /// Response to [`RemoteRequest::List`].
+    Keys {
+        /// Public keys (hex).
+        keys: Vec<String>,
+    },
+    /// Response to [`RemoteRequest::Sign`].
+    Signature {
+        /// Signature (hex).
+        signature: String,
+    },
+    /// Response to [`RemoteRequest::Verify`].
+    Verified {
+        /// Whether the signature is valid.
+        valid: bool,
+    },
+    /// The request was rejected or failed.
+    Error {
+        /// Human-readable reason.
+        message: String,
+    },
+}
+
+impl RemoteResponse {
+    /// Build an error response.
+    pub fn error(message: impl ToString) -> Self {
+        Self::Error {
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wire_format() {
+        let request: RemoteRequest = serde_json::from_str(
+            r#"{"method":"sign","scheme":"secp256k1","public_key":"0x02","data":"ff"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            RemoteRequest::Sign {
+                scheme: "secp256k1".into(),
+                public_key: "0x02".into(),
+                data: "ff".into(),
+                prehashed: false,
+                context: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::to_string(&RemoteResponse::Verified { valid: true }).unwrap(),
+            r#"{"status":"verified","valid":true}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&RemoteResponse::error("denied")).unwrap(),
+            r#"{"status":"error","message":"denied"}"#
+        );
+    }
+}
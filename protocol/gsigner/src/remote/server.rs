@@ -0,0 +1,265 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Remote signer daemon.
+
+use super::{PolicySet, RemoteRequest, RemoteResponse, RemoteScheme};
+use crate::{Signer, error::Result, utils::decode_hex};
+use secrecy::{ExposeSecret, SecretString};
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    os::unix::{
+        fs::{FileTypeExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Maximum length of a single request line, in bytes.
+pub const MAX_REQUEST_LEN: usize = 1024 * 1024;
+
+/// Maximum number of connections served at the same time.
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// Bind a Unix socket for the daemon.
+///
+/// A stale socket left by a previous run is removed. The socket is made
+/// accessible to the owner only, so access can be granted by running the
+/// client under the same user (or by adjusting the permissions afterwards).
+///
+/// The socket is bound inside a fresh directory accessible to the owner only
+/// and moved into place once its permissions are set, so no other user can
+/// connect in between.
+pub fn bind(path: impl AsRef<Path>) -> Result<UnixListener> {
+    let path = path.as_ref();
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path)?,
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            )
+            .into());
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    // Created with `0700` permissions.
+    let private_dir = tempfile::Builder::new()
+        .prefix(".gsigner-")
+        .tempdir_in(parent)?;
+    let private_path = private_dir.path().join("socket");
+
+    let listener = UnixListener::bind(&private_path)?;
+    fs::set_permissions(&private_path, fs::Permissions::from_mode(0o600))?;
+    fs::rename(&private_path, path)?;
+    Ok(listener)
+}
+
+/// Daemon serving signing requests with keys from a local keyring.
+pub struct SignerServer<S: RemoteScheme> {
+    signer: Signer<S>,
+    policies: PolicySet,
+    password: Option<SecretString>,
+}
+
+impl<S: RemoteScheme> SignerServer<S> {
+    /// Create a server for the signer keys restricted by the policies.
+    pub fn new(signer: Signer<S>, policies: PolicySet) -> Self {
+        Self {
+            signer,
+            policies,
+            password: None,
+        }
+    }
+
+    /// Set the password used to decrypt stored keys.
+    pub fn with_password(mut self, password: SecretString) -> Self {
+        self.password = Some(password);
+        self
+    }
+
+    /// Serve connections accepted by the listener.
+    ///
+    /// Every connection is handled in its own thread. Connections above
+    /// [`MAX_CONNECTIONS`] are closed right away. Blocks for as long as the
+    /// listener accepts connections.
+    pub fn serve(&self, listener: UnixListener) {
+        let active = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        tracing::warn!("Failed to accept remote signer connection: {e}");
+                        continue;
+                    }
+                };
+
+                if active.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                    active.fetch_sub(1, Ordering::AcqRel);
+                    tracing::warn!("Too many remote signer connections, rejecting a new one");
+                    continue;
+                }
+
+                let active = &active;
+                scope.spawn(move || {
+                    if let Err(e) = self.handle_connection(stream) {
+                        tracing::debug!("Remote signer connection closed: {e}");
+                    }
+                    active.fetch_sub(1, Ordering::AcqRel);
+                });
+            }
+        })
+    }
+
+    fn handle_connection(&self, stream: UnixStream) -> io::Result<()> {
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            // One extra byte tells a line of exactly the maximum length from a longer one.
+            let read = (&mut reader)
+                .take(MAX_REQUEST_LEN as u64 + 1)
+                .read_until(b'\n', &mut line)?;
+            if read == 0 {
+                return Ok(());
+            }
+
+            if line.last() != Some(&b'\n') && line.len() > MAX_REQUEST_LEN {
+                let response = RemoteResponse::error(format!(
+                    "Request exceeds the limit of {MAX_REQUEST_LEN} bytes"
+                ));
+                Self::write_response(&mut writer, &response)?;
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "request is too long",
+                ));
+            }
+
+            if line.trim_ascii().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_slice(&line) {
+                Ok(request) => self.handle(request),
+                Err(e) => RemoteResponse::error(format!("Invalid request: {e}")),
+            };
+            Self::write_response(&mut writer, &response)?;
+        }
+    }
+
+    fn write_response(writer: &mut UnixStream, response: &RemoteResponse) -> io::Result<()> {
+        let mut response = serde_json::to_vec(response).map_err(io::Error::other)?;
+        response.push(b'\n');
+        writer.write_all(&response)?;
+        writer.flush()
+    }
+
+    /// Handle a single request.
+    pub fn handle(&self, request: RemoteRequest) -> RemoteResponse {
+        let scheme = match &request {
+            RemoteRequest::List { scheme }
+            | RemoteRequest::Sign { scheme, .. }
+            | RemoteRequest::Verify { scheme, .. } => scheme,
+        };
+        if scheme != S::NAME {
+            return RemoteResponse::error(format!(
+                "Scheme mismatch: daemon serves {}, requested {scheme}",
+                S::NAME
+            ));
+        }
+
+        let result = match request {
+            RemoteRequest::List { .. } => self.list(),
+            RemoteRequest::Sign {
+                public_key,
+                data,
+                prehashed,
+                context,
+                ..
+            } => self.sign(&public_key, &data, prehashed, context.as_deref()),
+            RemoteRequest::Verify {
+                public_key,
+                data,
+                signature,
+                ..
+            } => Self::verify(&public_key, &data, &signature),
+        };
+
+        result.unwrap_or_else(|e| {
+            tracing::warn!("Remote signer request failed: {e}");
+            RemoteResponse::error(e)
+        })
+    }
+
+    fn list(&self) -> Result<RemoteResponse> {
+        let keys = self
+            .signer
+            .list_keys()?
+            .iter()
+            .map(S::public_key_to_hex)
+            .filter(|key| self.policies.policy(key).list)
+            .collect();
+
+        Ok(RemoteResponse::Keys { keys })
+    }
+
+    fn sign(
+        &self,
+        public_key: &str,
+        data: &str,
+        prehashed: bool,
+        context: Option<&str>,
+    ) -> Result<RemoteResponse> {
+        let public_key = S::public_key_from_hex(public_key)?;
+        let data = decode_hex(data)?;
+        let context = context.map(decode_hex).transpose()?;
+        if prehashed && context.is_some() {
+            return Ok(RemoteResponse::error(
+                "Signing context can't be used with prehashed signing",
+            ));
+        }
+
+        let hex = S::public_key_to_hex(&public_key);
+        if let Err(reason) = self.policies.policy(&hex).check_sign(data.len(), prehashed) {
+            tracing::warn!("Rejected signing request for {hex}: {reason}");
+            return Ok(RemoteResponse::error(reason));
+        }
+
+        let private_key = match &self.password {
+            Some(password) => self
+                .signer
+                .private_key_encrypted(public_key, password.expose_secret())?,
+            None => self.signer.private_key(public_key)?,
+        };
+        let signature = match context {
+            Some(context) => S::sign_with_context(&private_key, &context, &data)?,
+            None if prehashed => S::sign_prehashed(&private_key, &data)?,
+            None => S::sign(&private_key, &data)?,
+        };
+
+        Ok(RemoteResponse::Signature {
+            signature: S::signature_to_hex(&signature),
+        })
+    }
+
+    fn verify(public_key: &str, data: &str, signature: &str) -> Result<RemoteResponse> {
+        let public_key = S::public_key_from_hex(public_key)?;
+        let data = decode_hex(data)?;
+        let signature = S::signature_from_hex(signature)?;
+
+        Ok(RemoteResponse::Verified {
+            valid: S::verify(&public_key, &data, &signature).is_ok(),
+        })
+    }
+}
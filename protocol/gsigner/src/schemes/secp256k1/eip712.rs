@@ -0,0 +1,454 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! EIP-712 typed structured data hashing.
+//!
+//! [`TypedData`] follows the JSON format accepted by `eth_signTypedData_v4`:
+//!
+//! ```json
+//! {
+//!     "types": {
+//!         "EIP712Domain": [{ "name": "name", "type": "string" }, ...],
+//!         "Mail": [{ "name": "contents", "type": "string" }, ...]
+//!     },
+//!     "primaryType": "Mail",
+//!     "domain": { "name": "Ether Mail", ... },
+//!     "message": { "contents": "Hello, Bob!", ... }
+//! }
+//! ```
+//!
+//! The `EIP712Domain` type may be omitted, in which case it is derived from
+//! the fields present in `domain`.
+
+use super::Digest;
+use crate::{
+    error::{Result, SignerError},
+    hash::{keccak256, keccak256_iter},
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{I256, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Name of the domain struct type.
+pub const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Fields of the domain struct in their canonical order.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// Member of an EIP-712 struct type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypedField {
+    /// Member name.
+    pub name: String,
+    /// Member type, e.g. `address`, `uint256[]` or another struct name.
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+impl TypedField {
+    /// Create a new struct member.
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ty: ty.into(),
+        }
+    }
+}
+
+/// EIP-712 typed data: struct types, domain and the message to sign.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    /// Struct type definitions by name.
+    pub types: BTreeMap<String, Vec<TypedField>>,
+    /// Name of the message struct type.
+    pub primary_type: String,
+    /// Domain values.
+    pub domain: Value,
+    /// Message values.
+    pub message: Value,
+}
+
+impl TypedData {
+    /// Parse typed data from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let mut typed_data: Self = serde_json::from_str(json)?;
+
+        if !typed_data.types.contains_key(DOMAIN_TYPE) {
+            let domain = typed_data
+                .domain
+                .as_object()
+                .ok_or_else(|| invalid("domain must be an object"))?;
+            let fields = DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| domain.contains_key(*name))
+                .map(|(name, ty)| TypedField::new(*name, *ty))
+                .collect();
+            typed_data.types.insert(DOMAIN_TYPE.into(), fields);
+        }
+
+        Ok(typed_data)
+    }
+
+    /// Compute the domain separator.
+    pub fn domain_separator(&self) -> Result<Digest> {
+        self.hash_struct(DOMAIN_TYPE, &self.domain)
+    }
+
+    /// Compute the hash of the message struct.
+    pub fn struct_hash(&self) -> Result<Digest> {
+        self.hash_struct(&self.primary_type, &self.message)
+    }
+
+    /// Compute the digest to be signed:
+    /// `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    pub fn signing_hash(&self) -> Result<Digest> {
+        let domain_separator = self.domain_separator()?;
+
+        // Signing the domain itself commits to the domain separator only
+        if self.primary_type == DOMAIN_TYPE {
+            return Ok(Digest(keccak256_iter([
+                &[0x19, 0x01][..],
+                &domain_separator.0[..],
+            ])));
+        }
+
+        let struct_hash = self.struct_hash()?;
+        Ok(Digest(keccak256_iter([
+            &[0x19, 0x01][..],
+            &domain_separator.0[..],
+            &struct_hash.0[..],
+        ])))
+    }
+
+    /// Encode the struct type with all referenced types, e.g.
+    /// `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    pub fn encode_type(&self, name: &str) -> Result<String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(name, &mut dependencies)?;
+        dependencies.remove(name);
+
+        let mut encoded = self.encode_single_type(name)?;
+        for dependency in dependencies {
+            encoded.push_str(&self.encode_single_type(dependency)?);
+        }
+        Ok(encoded)
+    }
+
+    /// Compute the hash of the encoded struct type.
+    pub fn type_hash(&self, name: &str) -> Result<Digest> {
+        Ok(Digest(keccak256(self.encode_type(name)?.as_bytes())))
+    }
+
+    /// Encode the struct value: `typeHash ‖ encodeData(member)...`.
+    pub fn encode_data(&self, name: &str, value: &Value) -> Result<Vec<u8>> {
+        let fields = self.fields(name)?;
+        let object = value
+            .as_object()
+            .ok_or_else(|| invalid(format!("value of {name} must be an object")))?;
+
+        let mut encoded = Vec::with_capacity(32 * (fields.len() + 1));
+        encoded.extend_from_slice(&self.type_hash(name)?.0);
+        for field in fields {
+            let value = object
+                .get(&field.name)
+                .ok_or_else(|| invalid(format!("missing field {}.{}", name, field.name)))?;
+            encoded.extend_from_slice(&self.encode_value(&field.ty, value)?);
+        }
+        Ok(encoded)
+    }
+
+    /// Compute the hash of the struct value.
+    pub fn hash_struct(&self, name: &str, value: &Value) -> Result<Digest> {
+        Ok(Digest(keccak256(&self.encode_data(name, value)?)))
+    }
+
+    fn fields(&self, name: &str) -> Result<&[TypedField]> {
+        self.types
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| invalid(format!("unknown type {name}")))
+    }
+
+    fn encode_single_type(&self, name: &str) -> Result<String> {
+        let members = self
+            .fields(name)?
+            .iter()
+            .map(|field| format!("{} {}", field.ty, field.name))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(format!("{name}({members})"))
+    }
+
+    fn collect_dependencies<'a>(
+        &'a self,
+        name: &'a str,
+        found: &mut BTreeSet<&'a str>,
+    ) -> Result<()> {
+        if !found.insert(name) {
+            return Ok(());
+        }
+        for field in self.fields(name)? {
+            let base = base_type(&field.ty);
+            if self.types.contains_key(base) {
+                self.collect_dependencies(base, found)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_value(&self, ty: &str, value: &Value) -> Result<[u8; 32]> {
+        if let Some((element, len)) = split_array(ty)? {
+            let items = value
+                .as_array()
+                .ok_or_else(|| invalid(format!("value of {ty} must be an array")))?;
+            if let Some(len) = len
+                && items.len() != len
+            {
+                return Err(invalid(format!(
+                    "{ty} expects {len} elements, got {}",
+                    items.len()
+                )));
+            }
+
+            let mut encoded = Vec::with_capacity(32 * items.len());
+            for item in items {
+                encoded.extend_from_slice(&self.encode_value(element, item)?);
+            }
+            return Ok(keccak256(&encoded));
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value).map(|digest| digest.0);
+        }
+
+        encode_atomic(ty, value)
+    }
+}
+
+/// Strip array suffixes from the type, e.g. `Person[][2]` → `Person`.
+fn base_type(ty: &str) -> &str {
+    ty.split('[').next().unwrap_or(ty)
+}
+
+/// Split an array type into the element type and the fixed length, if any.
+fn split_array(ty: &str) -> Result<Option<(&str, Option<usize>)>> {
+    let Some(stripped) = ty.strip_suffix(']') else {
+        return Ok(None);
+    };
+    let open = stripped
+        .rfind('[')
+        .ok_or_else(|| invalid(format!("malformed array type {ty}")))?;
+    let (element, len) = (&stripped[..open], &stripped[open + 1..]);
+
+    let len = if len.is_empty() {
+        None
+    } else {
+        Some(
+            len.parse()
+                .map_err(|_| invalid(format!("malformed array length in {ty}")))?,
+        )
+    };
+    Ok(Some((element, len)))
+}
+
+fn encode_atomic(ty: &str, value: &Value) -> Result<[u8; 32]> {
+    let mut word = [0u8; 32];
+
+    match ty {
+        "string" => {
+            let value = value
+                .as_str()
+                .ok_or_else(|| invalid("string value expected"))?;
+            return Ok(keccak256(value.as_bytes()));
+        }
+        "bytes" => return Ok(keccak256(&decode_bytes(value)?)),
+        "bool" => {
+            let value = value
+                .as_bool()
+                .ok_or_else(|| invalid("bool value expected"))?;
+            word[31] = value as u8;
+        }
+        "address" => {
+            let bytes = decode_bytes(value)?;
+            if bytes.len() != 20 {
+                return Err(invalid(format!(
+                    "address must be 20 bytes, got {}",
+                    bytes.len()
+                )));
+            }
+            word[12..].copy_from_slice(&bytes);
+        }
+        _ => {
+            if let Some(size) = ty.strip_prefix("bytes") {
+                let size = parse_size(ty, size, 1..=32)?;
+                let bytes = decode_bytes(value)?;
+                if bytes.len() != size {
+                    return Err(invalid(format!(
+                        "{ty} expects {size} bytes, got {}",
+                        bytes.len()
+                    )));
+                }
+                word[..size].copy_from_slice(&bytes);
+            } else if let Some(bits) = ty.strip_prefix("uint") {
+                let bits = parse_bits(ty, bits)?;
+                let value = parse_uint(value)?;
+                if value.bit_len() > bits {
+                    return Err(invalid(format!("{value} does not fit into {ty}")));
+                }
+                word = value.to_be_bytes::<32>();
+            } else if let Some(bits) = ty.strip_prefix("int") {
+                let bits = parse_bits(ty, bits)?;
+                let value = parse_int(value)?;
+                let magnitude = value.unsigned_abs();
+                let magnitude = if value.is_negative() {
+                    magnitude - U256::from(1)
+                } else {
+                    magnitude
+                };
+                if magnitude.bit_len() >= bits {
+                    return Err(invalid(format!("{value} does not fit into {ty}")));
+                }
+                word = value.into_raw().to_be_bytes::<32>();
+            } else {
+                return Err(invalid(format!("unknown type {ty}")));
+            }
+        }
+    }
+
+    Ok(word)
+}
+
+fn parse_size(ty: &str, size: &str, range: core::ops::RangeInclusive<usize>) -> Result<usize> {
+    size.parse()
+        .ok()
+        .filter(|size| range.contains(size))
+        .ok_or_else(|| invalid(format!("unknown type {ty}")))
+}
+
+fn parse_bits(ty: &str, bits: &str) -> Result<usize> {
+    // Bare `uint`/`int` are aliases of the 256-bit types
+    if bits.is_empty() {
+        return Ok(256);
+    }
+    parse_size(ty, bits, 8..=256).and_then(|bits| {
+        bits.is_multiple_of(8)
+            .then_some(bits)
+            .ok_or_else(|| invalid(format!("unknown type {ty}")))
+    })
+}
+
+fn decode_bytes(value: &Value) -> Result<Vec<u8>> {
+    let value = value
+        .as_str()
+        .ok_or_else(|| invalid("hex string value expected"))?;
+    crate::utils::decode_hex(value).map_err(|e| invalid(format!("invalid hex {value}: {e}")))
+}
+
+fn parse_uint(value: &Value) -> Result<U256> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(U256::from)
+            .ok_or_else(|| invalid(format!("{number} is not an unsigned integer"))),
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex) => U256::from_str_radix(hex, 16),
+            None => U256::from_str_radix(s, 10),
+        }
+        .map_err(|e| invalid(format!("invalid integer {s}: {e}"))),
+        _ => Err(invalid("integer value expected")),
+    }
+}
+
+fn parse_int(value: &Value) -> Result<I256> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(I256::unchecked_from)
+            .ok_or_else(|| invalid(format!("{number} is not an integer"))),
+        Value::String(s) => if s.trim_start_matches('-').starts_with("0x") {
+            I256::from_hex_str(s)
+        } else {
+            I256::from_dec_str(s)
+        }
+        .map_err(|e| invalid(format!("invalid integer {s}: {e}"))),
+        _ => Err(invalid("integer value expected")),
+    }
+}
+
+fn invalid(message: impl ToString) -> SignerError {
+    SignerError::InvalidTypedData(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed_data(ty: &str, value: Value) -> TypedData {
+        TypedData::from_json(
+            &serde_json::json!({
+                "types": { "Value": [{ "name": "value", "type": ty }] },
+                "primaryType": "Value",
+                "domain": {},
+                "message": { "value": value },
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    fn encoded(ty: &str, value: Value) -> Result<Vec<u8>> {
+        typed_data(ty, value.clone()).encode_data("Value", &serde_json::json!({ "value": value }))
+    }
+
+    #[test]
+    fn domain_type_is_derived() {
+        let typed_data = TypedData::from_json(
+            r#"{"types":{},"primaryType":"EIP712Domain","domain":{"chainId":1,"name":"Test"},"message":{}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            typed_data.encode_type(DOMAIN_TYPE).unwrap(),
+            "EIP712Domain(string name,uint256 chainId)"
+        );
+    }
+
+    #[test]
+    fn integers_are_range_checked() {
+        assert!(encoded("uint8", 255.into()).is_ok());
+        assert!(encoded("uint8", 256.into()).is_err());
+        assert!(encoded("uint8", (-1).into()).is_err());
+        assert!(encoded("int8", 127.into()).is_ok());
+        assert!(encoded("int8", (-128).into()).is_ok());
+        assert!(encoded("int8", 128.into()).is_err());
+        assert!(encoded("int8", (-129).into()).is_err());
+        assert!(encoded("uint256", "0xff".into()).is_ok());
+        assert!(encoded("uint7", 1.into()).is_err());
+
+        // Negative values are sign extended
+        let data = encoded("int16", "-1".into()).unwrap();
+        assert_eq!(&data[32..], &[0xff; 32]);
+    }
+
+    #[test]
+    fn fixed_values_are_length_checked() {
+        assert!(encoded("bytes4", "0x01020304".into()).is_ok());
+        assert!(encoded("bytes4", "0x010203".into()).is_err());
+        assert!(encoded("address", "0x01".into()).is_err());
+        assert!(encoded("uint8[2]", serde_json::json!([1, 2])).is_ok());
+        assert!(encoded("uint8[2]", serde_json::json!([1])).is_err());
+        assert!(encoded("bytes33", "0x".into()).is_err());
+    }
+}
@@ -11,6 +11,8 @@ use alloc::{format, string::String, vec::Vec};
 
 pub mod address;
 pub mod digest;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod eip712;
 pub mod keys;
 pub mod signature;
 #[cfg(feature = "std")]
@@ -23,6 +25,8 @@ pub use keyring::{Keyring, Keystore, Secp256k1Codec};
 
 pub use address::{Address, FromActorIdError};
 pub use digest::{Digest, ToDigest};
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use eip712::TypedData;
 pub use keys::{PrivateKey, PublicKey, Seed};
 pub use signature::{ContractSignature, Signature, SignedData, SignedMessage, VerifiedData};
 
@@ -130,7 +130,7 @@ impl Signature {
         }
     }
 
-    fn eip191_hash(hash: [u8; 32]) -> [u8; 32] {
+    pub(crate) fn eip191_hash(hash: [u8; 32]) -> [u8; 32] {
         let mut hasher = Keccak256::new();
 
         hasher.update(b"\x19Ethereum Signed Message:\n");
@@ -362,6 +362,15 @@ where
         })
     }
 
+    /// Build signed data from a signature created by the given public key.
+    pub(crate) fn from_signer_parts(data: T, signature: Signature, public_key: PublicKey) -> Self {
+        Self {
+            data,
+            signature,
+            public_key,
+        }
+    }
+
     pub fn try_from_parts(data: T, signature: Signature) -> Result<Self, &'static str> {
         signature
             .validate(&data)
@@ -605,7 +614,7 @@ impl ContractSignature {
     }
 }
 
-fn contract_specific_digest(digest: Digest, contract_address: Address) -> Digest {
+pub(crate) fn contract_specific_digest(digest: Digest, contract_address: Address) -> Digest {
     Digest(keccak256_iter([
         &[0x19, 0x00],
         contract_address.0.as_ref(),
@@ -3,9 +3,11 @@
 
 //! Secp256k1-specific signer extensions.
 
+#[cfg(feature = "serde")]
+use super::TypedData;
 use super::{
     Address, ContractSignature, Digest, PrivateKey, PublicKey, Secp256k1, Signature, SignedData,
-    SignedMessage,
+    SignedMessage, signature::contract_specific_digest,
 };
 use crate::{
     Signer,
@@ -70,6 +72,24 @@ pub trait Secp256k1SignerExt {
         digest: Digest,
         password: Option<&str>,
     ) -> Result<ContractSignature>;
+
+    /// Create an EIP-712 signature of the typed data.
+    #[cfg(feature = "serde")]
+    fn sign_typed_data(
+        &self,
+        public_key: PublicKey,
+        typed_data: &TypedData,
+        password: Option<&str>,
+    ) -> Result<Signature>;
+
+    /// Verify an EIP-712 signature of the typed data.
+    #[cfg(feature = "serde")]
+    fn verify_typed_data(
+        &self,
+        public_key: PublicKey,
+        typed_data: &TypedData,
+        signature: &Signature,
+    ) -> Result<()>;
 }
 
 impl Secp256k1SignerExt for Signer<Secp256k1> {
@@ -86,9 +106,7 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
         data: &[u8],
         password: Option<&str>,
     ) -> Result<Signature> {
-        let private_key = self.get_private_key(public_key, password)?;
-        Signature::create(&private_key, data)
-            .map_err(|e| SignerError::Crypto(format!("Signature creation failed: {e}")))
+        self.sign_digest(public_key, Digest::from(data), password)
     }
 
     fn sign_digest(
@@ -97,6 +115,16 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
         digest: Digest,
         password: Option<&str>,
     ) -> Result<Signature> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            if password.is_some() {
+                return Err(SignerError::Remote(
+                    "Key passwords are managed by the remote signer".into(),
+                ));
+            }
+            return client.sign_prehashed::<Secp256k1>(&public_key, &digest.0);
+        }
+
         let private_key = self.get_private_key(public_key, password)?;
         Signature::create_from_digest(&private_key, digest)
             .map_err(|e| SignerError::Crypto(format!("Signature creation failed: {e}")))
@@ -111,9 +139,8 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
     where
         T: super::ToDigest,
     {
-        let private_key = self.get_private_key(public_key, password)?;
-        SignedData::create(&private_key, data)
-            .map_err(|e| SignerError::Crypto(format!("SignedData creation failed: {e}")))
+        let signature = self.sign_digest(public_key, Digest::from(&data), password)?;
+        Ok(SignedData::from_signer_parts(data, signature, public_key))
     }
 
     fn signed_message<T>(
@@ -125,9 +152,14 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
     where
         for<'a> Digest: From<&'a T>,
     {
-        let private_key = self.get_private_key(public_key, password)?;
-        SignedMessage::create(private_key, data)
-            .map_err(|e| SignerError::Crypto(format!("SignedMessage creation failed: {e}")))
+        let digest = Digest(Signature::eip191_hash(Digest::from(&data).0));
+        let signature = self.sign_digest(public_key, digest, password)?;
+        // SAFETY: the signature was just created for the data by the public key.
+        Ok(
+            unsafe {
+                SignedMessage::from_parts_unchecked(data, signature, public_key.to_address())
+            },
+        )
     }
 
     fn sign_for_contract(
@@ -137,9 +169,7 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
         data: &[u8],
         password: Option<&str>,
     ) -> Result<ContractSignature> {
-        let private_key = self.get_private_key(public_key, password)?;
-        ContractSignature::create(contract_address, &private_key, data)
-            .map_err(|e| SignerError::Crypto(format!("Contract signature creation failed: {e}")))
+        self.sign_for_contract_digest(contract_address, public_key, Digest::from(data), password)
     }
 
     fn sign_for_contract_digest(
@@ -149,8 +179,30 @@ impl Secp256k1SignerExt for Signer<Secp256k1> {
         digest: Digest,
         password: Option<&str>,
     ) -> Result<ContractSignature> {
-        let private_key = self.get_private_key(public_key, password)?;
-        ContractSignature::create_from_digest(contract_address, &private_key, digest)
-            .map_err(|e| SignerError::Crypto(format!("Contract signature creation failed: {e}")))
+        let digest = contract_specific_digest(digest, contract_address);
+        self.sign_digest(public_key, digest, password)
+            .map(ContractSignature::from_signature)
+    }
+
+    #[cfg(feature = "serde")]
+    fn sign_typed_data(
+        &self,
+        public_key: PublicKey,
+        typed_data: &TypedData,
+        password: Option<&str>,
+    ) -> Result<Signature> {
+        self.sign_digest(public_key, typed_data.signing_hash()?, password)
+    }
+
+    #[cfg(feature = "serde")]
+    fn verify_typed_data(
+        &self,
+        public_key: PublicKey,
+        typed_data: &TypedData,
+        signature: &Signature,
+    ) -> Result<()> {
+        signature
+            .verify_with_digest(public_key, &typed_data.signing_hash()?)
+            .map_err(|_| SignerError::VerificationFailed)
     }
 }
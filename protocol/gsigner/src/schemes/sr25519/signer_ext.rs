@@ -11,6 +11,8 @@ use schnorrkel::signing_context;
 /// Extension trait for Sr25519 signers.
 pub trait Sr25519SignerExt {
     /// Sign with a custom context. Pass `password: None` for unencrypted keys.
+    ///
+    /// A remote signer signs with the daemon keys and rejects a password.
     fn sign_with_context(
         &self,
         public_key: PublicKey,
@@ -40,6 +42,17 @@ impl Sr25519SignerExt for Signer<Sr25519> {
         data: &[u8],
         password: Option<&str>,
     ) -> Result<Signature> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            if password.is_some() {
+                return Err(crate::error::SignerError::Remote(
+                    "Key passwords are managed by the remote signer".into(),
+                )
+                .into());
+            }
+            return Ok(client.sign_with_context::<Sr25519>(&public_key, context, data)?);
+        }
+
         let private_key = match password {
             Some(pwd) => self.private_key_encrypted(public_key, pwd)?,
             None => self.private_key(public_key)?,
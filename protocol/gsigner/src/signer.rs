@@ -1,10 +1,12 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-//! Unified signer API backed by the JSON keyring.
+//! Unified signer API backed by the JSON keyring or a remote signer daemon.
 
 #![cfg(all(feature = "std", feature = "keyring", feature = "serde"))]
 
+#[cfg(all(unix, feature = "remote"))]
+use crate::remote::RemoteClient;
 use crate::{
     error::{Result, SignerError},
     keyring::{self, KeyringScheme, KeystoreEntry},
@@ -18,10 +20,19 @@ use std::{
 };
 use tempfile::TempDir;
 
+/// Where the signer keys live.
+enum Backend<S: KeyringScheme> {
+    /// Keys are stored in the keyring and used in-process.
+    Keyring(RwLock<keyring::Keyring<S::Keystore>>),
+    /// Keys are held by a remote signer daemon.
+    #[cfg(all(unix, feature = "remote"))]
+    Remote(RemoteClient),
+}
+
 /// Universal signer that works with any signature scheme by storing keys in the keyring.
 #[derive(Clone)]
 pub struct Signer<S: KeyringScheme> {
-    keyring: Arc<RwLock<keyring::Keyring<S::Keystore>>>,
+    backend: Arc<Backend<S>>,
     _tmp_dir: Option<Arc<TempDir>>,
     _phantom: PhantomData<S>,
 }
@@ -39,7 +50,7 @@ impl<S: KeyringScheme> Signer<S> {
     /// Create a signer backed by the provided keyring.
     pub fn new(keyring: keyring::Keyring<S::Keystore>) -> Self {
         Self {
-            keyring: Arc::new(RwLock::new(keyring)),
+            backend: Arc::new(Backend::Keyring(RwLock::new(keyring))),
             _tmp_dir: None,
             _phantom: PhantomData,
         }
@@ -47,7 +58,7 @@ impl<S: KeyringScheme> Signer<S> {
 
     fn with_tempdir(keyring: keyring::Keyring<S::Keystore>, tmp_dir: Option<TempDir>) -> Self {
         Self {
-            keyring: Arc::new(RwLock::new(keyring)),
+            backend: Arc::new(Backend::Keyring(RwLock::new(keyring))),
             _tmp_dir: tmp_dir.map(Arc::new),
             _phantom: PhantomData,
         }
@@ -79,14 +90,53 @@ impl<S: KeyringScheme> Signer<S> {
         Ok(Self::with_tempdir(keyring, Some(temp_dir)))
     }
 
+    /// Create a signer forwarding requests to the remote signer daemon
+    /// listening on the Unix socket.
+    ///
+    /// Only signing, verification and key listing are available; operations
+    /// requiring key material (generation, import, export) fail.
+    #[cfg(all(unix, feature = "remote"))]
+    pub fn remote(socket: impl Into<PathBuf>) -> Self {
+        Self::with_remote_client(RemoteClient::new(socket))
+    }
+
+    /// Create a signer forwarding requests through the provided client.
+    #[cfg(all(unix, feature = "remote"))]
+    pub fn with_remote_client(client: RemoteClient) -> Self {
+        Self {
+            backend: Arc::new(Backend::Remote(client)),
+            _tmp_dir: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the remote signer client, if the signer is remote.
+    #[cfg(all(unix, feature = "remote"))]
+    pub fn remote_client(&self) -> Option<&RemoteClient> {
+        match &*self.backend {
+            Backend::Remote(client) => Some(client),
+            Backend::Keyring(_) => None,
+        }
+    }
+
+    fn keyring_lock(&self) -> Result<&RwLock<keyring::Keyring<S::Keystore>>> {
+        match &*self.backend {
+            Backend::Keyring(keyring) => Ok(keyring),
+            #[cfg(all(unix, feature = "remote"))]
+            Backend::Remote(_) => Err(SignerError::Remote(
+                "Key material is not available through the remote signer".into(),
+            )),
+        }
+    }
+
     fn keyring(&self) -> Result<RwLockReadGuard<'_, keyring::Keyring<S::Keystore>>> {
-        self.keyring
+        self.keyring_lock()?
             .read()
             .map_err(|err| SignerError::Other(format!("Failed to acquire read lock: {err}")))
     }
 
     fn keyring_mut(&self) -> Result<RwLockWriteGuard<'_, keyring::Keyring<S::Keystore>>> {
-        self.keyring
+        self.keyring_lock()?
             .write()
             .map_err(|err| SignerError::Other(format!("Failed to acquire write lock: {err}")))
     }
@@ -151,6 +201,11 @@ impl<S: KeyringScheme> Signer<S> {
 
     /// Sign data with the specified public key.
     pub fn sign(&self, public_key: S::PublicKey, data: &[u8]) -> Result<S::Signature> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            return client.sign::<S>(&public_key, data);
+        }
+
         let private_key = self.private_key(public_key)?;
         S::sign(&private_key, data)
     }
@@ -183,6 +238,11 @@ impl<S: KeyringScheme> Signer<S> {
 
     /// Check if a key exists in storage.
     pub fn has_key(&self, public_key: S::PublicKey) -> Result<bool> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            return Ok(client.list_keys::<S>()?.contains(&public_key));
+        }
+
         let storage = self.keyring()?;
         for keystore in storage.list() {
             if S::keystore_public(keystore)? == public_key {
@@ -194,6 +254,11 @@ impl<S: KeyringScheme> Signer<S> {
 
     /// List all public keys in storage.
     pub fn list_keys(&self) -> Result<Vec<S::PublicKey>> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            return client.list_keys::<S>();
+        }
+
         let storage = self.keyring()?;
         storage.list().iter().map(S::keystore_public).collect()
     }
@@ -255,6 +320,14 @@ impl<S: KeyringScheme> Signer<S> {
 
     /// Try to find a public key associated with the provided address.
     pub fn get_key_by_address(&self, address: S::Address) -> Result<Option<S::PublicKey>> {
+        #[cfg(all(unix, feature = "remote"))]
+        if let Some(client) = self.remote_client() {
+            return Ok(client
+                .list_keys::<S>()?
+                .into_iter()
+                .find(|public_key| S::to_address(public_key) == address));
+        }
+
         let storage = self.keyring()?;
         for keystore in storage.list() {
             if S::keystore_address(keystore)? == address {
@@ -33,6 +33,100 @@ mod secp256k1_vectors {
 
         assert_eq!(address.to_hex().to_lowercase(), expected_address);
     }
+
+    /// EIP-712 specification example (`Mail` signed by "Cow")
+    /// Private key: keccak256("cow")
+    /// Address: 0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_eip712_mail_example() {
+        use gsigner::{
+            Signer,
+            schemes::secp256k1::{Secp256k1, Secp256k1SignerExt, eip712::TypedData},
+        };
+
+        let typed_data = TypedData::from_json(
+            r#"{
+                "types": {
+                    "EIP712Domain": [
+                        { "name": "name", "type": "string" },
+                        { "name": "version", "type": "string" },
+                        { "name": "chainId", "type": "uint256" },
+                        { "name": "verifyingContract", "type": "address" }
+                    ],
+                    "Person": [
+                        { "name": "name", "type": "string" },
+                        { "name": "wallet", "type": "address" }
+                    ],
+                    "Mail": [
+                        { "name": "from", "type": "Person" },
+                        { "name": "to", "type": "Person" },
+                        { "name": "contents", "type": "string" }
+                    ]
+                },
+                "primaryType": "Mail",
+                "domain": {
+                    "name": "Ether Mail",
+                    "version": "1",
+                    "chainId": 1,
+                    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+                },
+                "message": {
+                    "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                    "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                    "contents": "Hello, Bob!"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            typed_data.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        assert_eq!(
+            hex::encode(typed_data.type_hash("Mail").unwrap()),
+            "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
+        );
+        assert_eq!(
+            hex::encode(typed_data.domain_separator().unwrap()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            hex::encode(typed_data.struct_hash().unwrap()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex::encode(typed_data.signing_hash().unwrap()),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+
+        let private_hex = "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4";
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&hex::decode(private_hex).unwrap());
+
+        let signer = Signer::<Secp256k1>::memory();
+        let public_key = signer.import(PrivateKey::from_pair_seed(seed)).unwrap();
+        assert_eq!(
+            public_key.to_address().to_hex().to_lowercase(),
+            "cd2a3d9f938e13cd947ec05abc7fe734df8dd826"
+        );
+
+        let signature = signer
+            .sign_typed_data(public_key, &typed_data, None)
+            .unwrap();
+        signer
+            .verify_typed_data(public_key, &typed_data, &signature)
+            .unwrap();
+        assert_eq!(
+            hex::encode(signature.into_pre_eip155_bytes()),
+            concat!(
+                "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d",
+                "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562",
+                "1c"
+            )
+        );
+    }
 }
 
 // =============================================================================
@@ -191,6 +191,44 @@ pub(crate) struct LazyPagesExecutionContext {
     pub globals_context: Option<GlobalsContext>,
     /// Lazy-pages status: indicates in which mod lazy-pages works actually.
     pub status: Status,
+    /// Gas charged by lazy-pages during current execution.
+    pub charges: LazyPagesCharges,
+}
+
+/// Gas charged by lazy-pages during the program execution, by kind of the charge.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LazyPagesCharges {
+    /// Charged for pages read by wasm instructions.
+    pub signal_read: u64,
+    /// Charged for pages written by wasm instructions.
+    pub signal_write: u64,
+    /// Charged for pages written by wasm instructions after being read.
+    pub signal_write_after_read: u64,
+    /// Charged for pages read by host functions.
+    pub host_func_read: u64,
+    /// Charged for pages written by host functions.
+    pub host_func_write: u64,
+    /// Charged for pages written by host functions after being read.
+    pub host_func_write_after_read: u64,
+    /// Charged for loading pages data from storage.
+    pub load_page_storage_data: u64,
+}
+
+impl LazyPagesCharges {
+    /// Returns total amount of charged gas.
+    pub fn total(&self) -> u64 {
+        [
+            self.signal_read,
+            self.signal_write,
+            self.signal_write_after_read,
+            self.host_func_read,
+            self.host_func_write,
+            self.host_func_write_after_read,
+            self.load_page_storage_data,
+        ]
+        .into_iter()
+        .fold(0, u64::saturating_add)
+    }
 }
 
 /// Lazy-pages version.
@@ -267,36 +305,67 @@ pub(crate) struct GasCharger {
     pub write_cost: u64,
     pub write_after_read_cost: u64,
     pub load_data_cost: u64,
+    /// Gas charged by this charger.
+    pub charged: ChargedGas,
+}
+
+/// Gas charged by [`GasCharger`], by kind of the charge.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ChargedGas {
+    pub read: u64,
+    pub write: u64,
+    pub write_after_read: u64,
+    pub load_data: u64,
 }
 
 impl GasCharger {
-    fn sub_gas(gas_counter: &mut u64, amount: u64) -> Status {
+    pub fn new(exec_ctx: &LazyPagesExecutionContext, access_costs: [CostNo; 3]) -> Self {
+        let [read, write, write_after_read] = access_costs;
+        Self {
+            read_cost: exec_ctx.cost(read),
+            write_cost: exec_ctx.cost(write),
+            write_after_read_cost: exec_ctx.cost(write_after_read),
+            load_data_cost: exec_ctx.cost(CostNo::LoadPageDataFromStorage),
+            charged: Default::default(),
+        }
+    }
+
+    // Returns new status and actually charged amount of gas.
+    fn sub_gas(gas_counter: &mut u64, amount: u64) -> (Status, u64) {
+        let charged = amount.min(*gas_counter);
         let new_gas = gas_counter.checked_sub(amount);
         *gas_counter = new_gas.unwrap_or_default();
         match new_gas {
-            None => Status::GasLimitExceeded,
-            Some(_) => Status::Normal,
+            None => (Status::GasLimitExceeded, charged),
+            Some(_) => (Status::Normal, charged),
         }
     }
 
     pub fn charge_for_page_access(
-        &self,
+        &mut self,
         gas_counter: &mut u64,
         page: GearPage,
         is_write: bool,
         is_accessed: bool,
     ) -> Result<Status, Error> {
-        let amount = match (is_write, is_accessed) {
-            (true, true) => self.write_after_read_cost,
-            (true, false) => self.write_cost,
-            (false, false) => self.read_cost,
+        let (amount, charged) = match (is_write, is_accessed) {
+            (true, true) => (
+                self.write_after_read_cost,
+                &mut self.charged.write_after_read,
+            ),
+            (true, false) => (self.write_cost, &mut self.charged.write),
+            (false, false) => (self.read_cost, &mut self.charged.read),
             (false, true) => return Err(Error::DoubleReadCharge(page)),
         };
-        Ok(Self::sub_gas(gas_counter, amount))
+        let (status, amount) = Self::sub_gas(gas_counter, amount);
+        *charged = charged.saturating_add(amount);
+        Ok(status)
     }
 
     pub fn charge_for_page_data_load(&mut self, gas_counter: &mut u64) -> Status {
-        Self::sub_gas(gas_counter, self.load_data_cost)
+        let (status, amount) = Self::sub_gas(gas_counter, self.load_data_cost);
+        self.charged.load_data = self.charged.load_data.saturating_add(amount);
+        status
     }
 }
 
@@ -73,6 +73,17 @@ impl AccessHandler for HostFuncAccessHandler<'_> {
     }
 
     fn into_output(self, ctx: &mut LazyPagesExecutionContext) -> Result<Self::Output, Error> {
+        let charged = &self.gas_charger.charged;
+        let charges = &mut ctx.charges;
+        charges.host_func_read = charges.host_func_read.saturating_add(charged.read);
+        charges.host_func_write = charges.host_func_write.saturating_add(charged.write);
+        charges.host_func_write_after_read = charges
+            .host_func_write_after_read
+            .saturating_add(charged.write_after_read);
+        charges.load_page_storage_data = charges
+            .load_page_storage_data
+            .saturating_add(charged.load_data);
+
         Ok(ctx.status)
     }
 }
@@ -120,14 +131,14 @@ pub fn pre_process_memory_accesses(
             let mut ctx = ctx.borrow_mut();
             let (rt_ctx, exec_ctx) = ctx.contexts_mut()?;
 
-            let gas_charger = {
-                GasCharger {
-                    read_cost: exec_ctx.cost(CostNo::HostFuncRead),
-                    write_cost: exec_ctx.cost(CostNo::HostFuncWrite),
-                    write_after_read_cost: exec_ctx.cost(CostNo::HostFuncWriteAfterRead),
-                    load_data_cost: exec_ctx.cost(CostNo::LoadPageDataFromStorage),
-                }
-            };
+            let gas_charger = GasCharger::new(
+                exec_ctx,
+                [
+                    CostNo::HostFuncRead,
+                    CostNo::HostFuncWrite,
+                    CostNo::HostFuncWriteAfterRead,
+                ],
+            );
             let mut status = Status::Normal;
 
             if !reads.is_empty() {
@@ -29,7 +29,7 @@ mod sys;
 #[cfg(test)]
 mod tests;
 
-pub use common::{Error as LazyPagesError, LazyPagesStorage, LazyPagesVersion};
+pub use common::{Error as LazyPagesError, LazyPagesCharges, LazyPagesStorage, LazyPagesVersion};
 pub use host_func::pre_process_memory_accesses;
 pub use signal::{ExceptionInfo, UserSignalHandler};
 
@@ -194,6 +194,7 @@ pub fn initialize_for_program(
                 access_mod: cfg.access_mod,
             }),
             status: Status::Normal,
+            charges: Default::default(),
         };
 
         // Set protection if wasm memory exist.
@@ -333,6 +334,16 @@ pub fn write_accessed_pages() -> Result<Vec<u32>, Error> {
     })
 }
 
+/// Returns gas charged by lazy-pages during the current (or the last) execution.
+pub fn charges() -> Result<LazyPagesCharges, Error> {
+    LAZY_PAGES_CONTEXT.with(|ctx| {
+        ctx.borrow()
+            .execution_context()
+            .map(|ctx| ctx.charges)
+            .map_err(Into::into)
+    })
+}
+
 pub fn status() -> Result<Status, Error> {
     LAZY_PAGES_CONTEXT.with(|ctx| {
         ctx.borrow()
@@ -61,12 +61,14 @@ unsafe fn user_signal_handler_internal(
     let page = GearPage::from_offset(rt_ctx, offset);
 
     let gas_ctx = if let Some(globals_config) = exec_ctx.globals_context.as_ref() {
-        let gas_charger = GasCharger {
-            read_cost: exec_ctx.cost(CostNo::SignalRead),
-            write_cost: exec_ctx.cost(CostNo::SignalWrite),
-            write_after_read_cost: exec_ctx.cost(CostNo::SignalWriteAfterRead),
-            load_data_cost: exec_ctx.cost(CostNo::LoadPageDataFromStorage),
-        };
+        let gas_charger = GasCharger::new(
+            exec_ctx,
+            [
+                CostNo::SignalRead,
+                CostNo::SignalWrite,
+                CostNo::SignalWriteAfterRead,
+            ],
+        );
 
         let gas_counter = unsafe {
             globals::apply_for_global(
@@ -163,6 +165,19 @@ impl AccessHandler for SignalAccessHandler {
     }
 
     fn into_output(self, ctx: &mut LazyPagesExecutionContext) -> Result<Self::Output, Error> {
+        if let Some((_, gas_charger)) = self.gas_ctx.as_ref() {
+            let charged = &gas_charger.charged;
+            let charges = &mut ctx.charges;
+            charges.signal_read = charges.signal_read.saturating_add(charged.read);
+            charges.signal_write = charges.signal_write.saturating_add(charged.write);
+            charges.signal_write_after_read = charges
+                .signal_write_after_read
+                .saturating_add(charged.write_after_read);
+            charges.load_page_storage_data = charges
+                .load_page_storage_data
+                .saturating_add(charged.load_data);
+        }
+
         if let (Some((gas_counter, _)), Some(globals_config)) =
             (self.gas_ctx, ctx.globals_context.as_ref())
         {
@@ -37,3 +37,7 @@ pub use processing::{
 pub mod informational {
     pub use crate::executor::execute_for_reply;
 }
+
+/// Gas profiling of the program execution, used by `gtest`.
+#[cfg(feature = "gtest")]
+pub use gear_core_backend::profiling;
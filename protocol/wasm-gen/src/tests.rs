@@ -945,6 +945,72 @@ fn precise_syscalls_works() {
     }
 }
 
+#[test]
+fn hashing_syscalls_works() {
+    gear_utils::init_default_logger();
+
+    let mut rng = SmallRng::seed_from_u64(123);
+    let mut buf = vec![0; UNSTRUCTURED_SIZE];
+    rng.fill_bytes(&mut buf);
+    let mut unstructured = Unstructured::new(&buf);
+
+    for syscall in [
+        SyscallName::Blake2b256,
+        SyscallName::Keccak256,
+        SyscallName::Sha256,
+    ] {
+        let mut injection_types = SyscallsInjectionTypes::all_never();
+        injection_types.set(InvocableSyscall::Loose(syscall), 1, 3);
+        let syscalls_config = SyscallsConfigBuilder::new(injection_types).build();
+
+        let backend_report = execute_wasm_with_custom_configs(
+            &mut unstructured,
+            syscalls_config,
+            Default::default(),
+        );
+
+        assert_eq!(
+            backend_report.termination_reason,
+            TerminationReason::Actor(ActorTerminationReason::Success),
+            "syscall: {}",
+            syscall.to_str()
+        );
+    }
+}
+
+#[test]
+fn signature_verification_syscalls_works() {
+    gear_utils::init_default_logger();
+
+    let mut rng = SmallRng::seed_from_u64(123);
+    let mut buf = vec![0; UNSTRUCTURED_SIZE];
+    rng.fill_bytes(&mut buf);
+    let mut unstructured = Unstructured::new(&buf);
+
+    for syscall in [
+        SyscallName::Ed25519Verify,
+        SyscallName::Sr25519Verify,
+        SyscallName::Secp256k1Recover,
+    ] {
+        let mut injection_types = SyscallsInjectionTypes::all_never();
+        injection_types.set(InvocableSyscall::Loose(syscall), 1, 3);
+        let syscalls_config = SyscallsConfigBuilder::new(injection_types).build();
+
+        let backend_report = execute_wasm_with_custom_configs(
+            &mut unstructured,
+            syscalls_config,
+            Default::default(),
+        );
+
+        assert_eq!(
+            backend_report.termination_reason,
+            TerminationReason::Actor(ActorTerminationReason::Success),
+            "syscall: {}",
+            syscall.to_str()
+        );
+    }
+}
+
 #[derive(Clone)]
 struct MemoryWrite {
     offset: u32,
@@ -27,6 +27,7 @@ mod module;
 mod tests;
 
 pub mod gas_metering;
+pub mod profiler;
 pub mod stack_limiter;
 pub mod syscalls;
 
@@ -100,6 +101,9 @@ pub enum InstrumentationError {
         program contains unsupported instructions (memory grow, etc.)"
     )]
     GasInjection,
+    /// Error occurred during injecting gas profiler counters.
+    #[display("The WASM module isn't instrumented with the gas limiter")]
+    GasLimiterNotFound,
 }
 
 /// This is an auxiliary builder that allows to instrument WASM module.
@@ -0,0 +1,141 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Contains the code for the gas profiler instrumentation.
+//!
+//! The module instrumented with the gas limiter charges gas for the
+//! instructions of every metered block by calling the `gas_charge` function.
+//! This pass makes every function of the module also add the charged amount
+//! to its own mutable global, which is exported with the name returned by
+//! [`profiler_global_name`]. Reading these globals after the execution gives
+//! the gas burned by the instructions of each function.
+//!
+//! Injected instructions aren't metered, so the gas burned by the module
+//! stays the same. The pass is intended for testing environments only,
+//! as the instrumented module isn't a valid gear program anymore.
+
+use crate::{
+    GLOBAL_NAME_GAS, InstrumentationError, Module,
+    module::{Export, Global, Instruction, ModuleBuilder},
+};
+use alloc::{format, string::String, vec::Vec};
+use wasmparser::{ExternalKind, TypeRef, ValType};
+
+/// Prefix of the exported globals holding the gas burned by functions.
+pub const PROFILER_GLOBAL_PREFIX: &str = "__gear_profile_";
+
+/// Returns export name of the global holding the gas burned by the function
+/// with `func_index`.
+pub fn profiler_global_name(func_index: u32) -> String {
+    format!("{PROFILER_GLOBAL_PREFIX}{func_index}")
+}
+
+/// Injects gas counters into the functions of the module instrumented with
+/// the gas limiter.
+///
+/// Returns the instrumented module and indices of the functions having
+/// the counters, i.e. of all functions charging gas.
+pub fn inject(module: Module) -> Result<(Module, Vec<u32>), InstrumentationError> {
+    use Instruction::*;
+
+    let gas_index = module
+        .export_section
+        .as_ref()
+        .and_then(|section| {
+            section
+                .iter()
+                .find(|entry| entry.name == GLOBAL_NAME_GAS && entry.kind == ExternalKind::Global)
+        })
+        .map(|entry| entry.index)
+        .ok_or(InstrumentationError::GasLimiterNotFound)?;
+
+    let code_section = module
+        .code_section
+        .as_ref()
+        .ok_or(InstrumentationError::GasLimiterNotFound)?;
+
+    // `gas_charge` is the only function updating the gas global.
+    let gas_charge_pos = code_section
+        .iter()
+        .position(|func| func.instructions.contains(&GlobalSet(gas_index)))
+        .ok_or(InstrumentationError::GasLimiterNotFound)?;
+
+    // `gas_charge` charges its own cost in addition to the argument.
+    let gas_charge_cost = code_section[gas_charge_pos]
+        .instructions
+        .iter()
+        .find_map(|instruction| match instruction {
+            I64Const(cost) => Some(*cost),
+            _ => None,
+        })
+        .ok_or(InstrumentationError::GasLimiterNotFound)?;
+
+    let import_count = module.import_count(|ty| matches!(ty, TypeRef::Func(_))) as u32;
+    let gas_charge_index = import_count + gas_charge_pos as u32;
+    let mut global_index = module.globals_space() as u32;
+
+    let params_count = |pos: usize| {
+        module
+            .function_section
+            .as_ref()
+            .and_then(|section| section.get(pos))
+            .and_then(|&ty| module.type_section.as_ref()?.get(ty as usize))
+            .map(|ty| ty.params().len() as u32)
+            .ok_or(InstrumentationError::GasLimiterNotFound)
+    };
+
+    let mut profiled = Vec::new();
+    let mut functions = module.code_section.clone().unwrap_or_default();
+
+    for (pos, func) in functions.iter_mut().enumerate() {
+        if pos == gas_charge_pos || !func.instructions.contains(&Call(gas_charge_index)) {
+            continue;
+        }
+
+        // New local keeping the amount passed to `gas_charge`.
+        let amount_local = func
+            .locals
+            .iter()
+            .fold(params_count(pos)?, |count, (n, _)| count + n);
+        func.locals.push((1, ValType::I32));
+
+        let mut instructions = Vec::with_capacity(func.instructions.len());
+        for instruction in func.instructions.drain(..) {
+            if instruction != Call(gas_charge_index) {
+                instructions.push(instruction);
+                continue;
+            }
+
+            instructions.extend([
+                LocalTee(amount_local),
+                Call(gas_charge_index),
+                GlobalGet(global_index),
+                LocalGet(amount_local),
+                I64ExtendI32U,
+                I64Const(gas_charge_cost),
+                I64Add,
+                I64Add,
+                GlobalSet(global_index),
+            ]);
+        }
+        func.instructions = instructions;
+
+        profiled.push(import_count + pos as u32);
+        global_index += 1;
+    }
+
+    let mut module = module;
+    module.code_section = Some(functions);
+
+    let first_global_index = module.globals_space() as u32;
+    let mut builder = ModuleBuilder::from_module(module);
+    for (offset, &func_index) in profiled.iter().enumerate() {
+        builder.push_global(Global::i64_value_mut(0));
+        builder.push_export(Export::global(
+            profiler_global_name(func_index),
+            first_global_index + offset as u32,
+        ));
+    }
+
+    Ok((builder.build(), profiled))
+}
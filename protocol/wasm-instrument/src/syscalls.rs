@@ -91,6 +91,16 @@ pub enum SyscallName {
     ReserveGas,
     UnreserveGas,
     SystemReserveGas,
+
+    // Cryptographic hashing
+    Blake2b256,
+    Keccak256,
+    Sha256,
+
+    // Signature verification
+    Ed25519Verify,
+    Sr25519Verify,
+    Secp256k1Recover,
 }
 
 /// Runtime syscall set.
@@ -123,16 +133,19 @@ impl SyscallName {
             Self::EnvVars => "gr_env_vars",
             Self::BlockHeight => "gr_block_height",
             Self::BlockTimestamp => "gr_block_timestamp",
+            Self::Blake2b256 => "gr_blake2b_256",
             Self::CreateProgram => "gr_create_program",
             Self::CreateProgramWGas => "gr_create_program_wgas",
             Self::ReplyDeposit => "gr_reply_deposit",
             Self::Debug => "gr_debug",
+            Self::Ed25519Verify => "gr_ed25519_verify",
             Self::Panic => "gr_panic",
             Self::OomPanic => "gr_oom_panic",
             Self::Exit => "gr_exit",
             Self::Free => "free",
             Self::FreeRange => "free_range",
             Self::GasAvailable => "gr_gas_available",
+            Self::Keccak256 => "gr_keccak256",
             Self::Leave => "gr_leave",
             Self::MessageId => "gr_message_id",
             Self::SystemBreak => "gr_system_break",
@@ -140,6 +153,7 @@ impl SyscallName {
             Self::Random => "gr_random",
             Self::Read => "gr_read",
             Self::Reply => "gr_reply",
+            Self::Secp256k1Recover => "gr_secp256k1_recover",
             Self::ReplyCommit => "gr_reply_commit",
             Self::ReplyCommitWGas => "gr_reply_commit_wgas",
             Self::ReplyPush => "gr_reply_push",
@@ -163,8 +177,10 @@ impl SyscallName {
             Self::SendInput => "gr_send_input",
             Self::SendPushInput => "gr_send_push_input",
             Self::SendInputWGas => "gr_send_input_wgas",
+            Self::Sha256 => "gr_sha256",
             Self::Size => "gr_size",
             Self::Source => "gr_source",
+            Self::Sr25519Verify => "gr_sr25519_verify",
             Self::ReplyCode => "gr_reply_code",
             Self::SignalCode => "gr_signal_code",
             Self::SystemReserveGas => "gr_system_reserve_gas",
@@ -221,25 +237,14 @@ impl SyscallName {
         !matches!(
             self,
             Self::CreateProgramWGas
-                | Self::CreateProgram
-                | Self::ReplyDeposit
-                | Self::SignalCode
-                | Self::Random
                 | Self::ReplyCommitWGas
-                | Self::SignalFrom
                 | Self::ReplyInputWGas
                 | Self::ReplyWGas
-                | Self::ReservationReplyCommit
-                | Self::ReservationReply
-                | Self::ReservationSendCommit
-                | Self::ReservationSend
-                | Self::ReserveGas
                 | Self::SendCommitWGas
                 | Self::SendInputWGas
                 | Self::SendWGas
-                | Self::SystemReserveGas
-                | Self::UnreserveGas
-                | Self::Wait
+                | Self::Ed25519Verify
+                | Self::Sr25519Verify
         )
     }
 
@@ -527,6 +532,30 @@ impl SyscallName {
                 Ptr::Hash(HashType::SubjectId).into(),
                 Ptr::MutBlockNumberWithHash(HashType::SubjectId).into(),
             ]),
+            Self::Blake2b256 | Self::Keccak256 | Self::Sha256 => SyscallSignature::gr_infallible([
+                Ptr::SizedBufferStart {
+                    length_param_idx: 1,
+                }
+                .into(),
+                Length,
+                Ptr::MutHash(HashType::Digest).into(),
+            ]),
+            Self::Ed25519Verify | Self::Sr25519Verify => SyscallSignature::gr_infallible([
+                Ptr::Hash(HashType::PublicKey).into(),
+                Ptr::Signature.into(),
+                Ptr::SizedBufferStart {
+                    length_param_idx: 3,
+                }
+                .into(),
+                Length,
+                Ptr::MutBool.into(),
+            ]),
+            Self::Secp256k1Recover => SyscallSignature::gr_infallible([
+                Ptr::Hash(HashType::Digest).into(),
+                Ptr::EcdsaSignature.into(),
+                Ptr::MutEcdsaPublicKey.into(),
+                Ptr::MutBool.into(),
+            ]),
             Self::SystemBreak => unimplemented!("Unsupported syscall signature for system_break"),
         }
     }
@@ -592,6 +621,11 @@ pub enum HashType {
     ReservationId,
     /// This enum variant is used for the `gr_random` syscall.
     SubjectId,
+    /// This enum variant is used for the hashing syscalls output and
+    /// the message hash of `gr_secp256k1_recover`.
+    Digest,
+    /// This enum variant is used for the signature verification syscalls.
+    PublicKey,
 }
 
 impl From<ParamType> for ValType {
@@ -769,6 +803,8 @@ mod pointers {
         HashWithValue(HashType),
         TwoHashes(HashType, HashType),
         TwoHashesWithValue(HashType, HashType),
+        Signature,
+        EcdsaSignature,
         // Mutable ptrs.
         MutBlockNumber,
         MutBlockTimestamp,
@@ -779,6 +815,8 @@ mod pointers {
         MutLength,
         MutValue,
         MutBlockNumberWithHash(HashType),
+        MutEcdsaPublicKey,
+        MutBool,
     }
 
     impl Ptr {
@@ -791,7 +829,9 @@ mod pointers {
                 | Value
                 | HashWithValue(_)
                 | TwoHashes(_, _)
-                | TwoHashesWithValue(_, _) => false,
+                | TwoHashesWithValue(_, _)
+                | Signature
+                | EcdsaSignature => false,
                 MutBlockNumber
                 | MutBlockTimestamp
                 | MutSizedBufferStart { .. }
@@ -800,7 +840,9 @@ mod pointers {
                 | MutGas
                 | MutLength
                 | MutValue
-                | MutBlockNumberWithHash(_) => true,
+                | MutBlockNumberWithHash(_)
+                | MutEcdsaPublicKey
+                | MutBool => true,
             }
         }
     }
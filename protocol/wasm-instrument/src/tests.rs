@@ -5,10 +5,11 @@ use crate::{
     GLOBAL_NAME_GAS, InstrumentationBuilder, InstrumentationError, Module, Rules,
     gas_metering::ConstantCostRules,
     module::{Function, Global, Instruction, Instruction::*, ModuleBuilder},
+    profiler,
     syscalls::{ParamType::*, Ptr, RegularParamType::*, SyscallKind, SyscallName},
 };
-use alloc::format;
-use wasmparser::{BlockType, FuncType, ValType};
+use alloc::{format, vec::Vec};
+use wasmparser::{BlockType, ExternalKind, FuncType, ValType};
 
 pub(crate) fn parse_wat(source: &str) -> Module {
     let module_bytes = wat::parse_str(source).unwrap();
@@ -189,6 +190,91 @@ fn cost_overflow() {
     );
 }
 
+#[test]
+fn profiler_counters() {
+    let injected_module = inject(
+        prebuilt_simple_module(),
+        |_| ConstantCostRules::default(),
+        "env",
+    )
+    .unwrap();
+    let (profiled_module, functions) = profiler::inject(injected_module).unwrap();
+
+    let empty_func_index = 1;
+    let func_index = empty_func_index + 1;
+    let gas_charge_index = func_index + 1;
+    // Globals are the module's one and `gear_gas`.
+    let profile_global_index = 2;
+    // The function has a single parameter.
+    let amount_local = 1;
+
+    // The empty function doesn't charge gas, so it has no counter.
+    assert_eq!(functions, [func_index]);
+
+    let Some(&I64Const(gas_charge_cost)) = get_function_body(&profiled_module, 2)
+        .unwrap()
+        .iter()
+        .find(|instruction| matches!(instruction, I64Const(_)))
+    else {
+        panic!("`gas_charge` must have the cost constant");
+    };
+
+    let charge = |amount| {
+        [
+            I32Const(amount),
+            LocalTee(amount_local),
+            Call(gas_charge_index),
+            GlobalGet(profile_global_index),
+            LocalGet(amount_local),
+            I64ExtendI32U,
+            I64Const(gas_charge_cost),
+            I64Add,
+            I64Add,
+            GlobalSet(profile_global_index),
+        ]
+    };
+
+    let expected: Vec<_> = [
+        &charge(3)[..],
+        &[Call(empty_func_index), If(BlockType::Empty)],
+        &charge(3),
+        &[
+            Call(empty_func_index),
+            Call(empty_func_index),
+            Call(empty_func_index),
+            Else,
+        ],
+        &charge(2),
+        &[
+            Call(empty_func_index),
+            Call(empty_func_index),
+            End,
+            Call(empty_func_index),
+            End,
+        ],
+    ]
+    .concat();
+    assert_eq!(get_function_body(&profiled_module, 1).unwrap(), expected);
+
+    let export = profiled_module
+        .export_section
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|export| export.name == profiler::profiler_global_name(func_index))
+        .expect("counter must be exported");
+    assert_eq!(export.kind, ExternalKind::Global);
+    assert_eq!(export.index, profile_global_index);
+}
+
+#[test]
+fn profiler_requires_gas_limiter() {
+    assert_eq!(
+        profiler::inject(prebuilt_simple_module()).unwrap_err(),
+        InstrumentationError::GasLimiterNotFound
+    );
+}
+
 #[macro_export]
 macro_rules! test_gas_counter_injection {
     (name = $name:ident; input = $input:expr; expected = $expected:expr) => {
@@ -560,25 +646,14 @@ fn check_syscall_err_ptr_position() {
 fn ethexe_syscall_availability_matches_gsys_cfg_gates() {
     let unavailable = [
         SyscallName::CreateProgramWGas,
-        SyscallName::CreateProgram,
-        SyscallName::ReplyDeposit,
-        SyscallName::SignalCode,
-        SyscallName::Random,
         SyscallName::ReplyCommitWGas,
-        SyscallName::SignalFrom,
         SyscallName::ReplyInputWGas,
         SyscallName::ReplyWGas,
-        SyscallName::ReservationReplyCommit,
-        SyscallName::ReservationReply,
-        SyscallName::ReservationSendCommit,
-        SyscallName::ReservationSend,
-        SyscallName::ReserveGas,
         SyscallName::SendCommitWGas,
         SyscallName::SendInputWGas,
         SyscallName::SendWGas,
-        SyscallName::SystemReserveGas,
-        SyscallName::UnreserveGas,
-        SyscallName::Wait,
+        SyscallName::Ed25519Verify,
+        SyscallName::Sr25519Verify,
     ];
 
     for syscall in SyscallName::instrumentable(SyscallKind::Vara) {
@@ -0,0 +1,310 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! On-disk tier of the module cache.
+//!
+//! Every module is stored in its own file, named after its code id and the
+//! fingerprint of the engine configuration it was compiled with. A file starts
+//! with a magic and a checksum of the serialized module, so truncated or
+//! otherwise corrupted files are detected and removed instead of being
+//! deserialized.
+//!
+//! The total size of the cache is bounded: after a new module is stored, the
+//! least recently used files are evicted until the cache fits the limit.
+//! Temporary files left by interrupted writes are removed when the cache is
+//! opened.
+
+use gear_core::{ids::CodeId, utils};
+use std::{
+    fs::{self, File},
+    hash::{DefaultHasher, Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+use wasmtime::{Engine, Module};
+
+const MAGIC: &[u8; 8] = b"gearwmc1";
+const CHECKSUM_LEN: usize = 32;
+const HEADER_LEN: usize = MAGIC.len() + CHECKSUM_LEN;
+const EXTENSION: &str = "module";
+const TMP_EXTENSION: &str = "tmp";
+
+/// Configuration of the on-disk module cache.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskCacheConfig {
+    /// Directory to store compiled modules in.
+    pub directory: PathBuf,
+    /// Maximum total size of stored modules, in bytes.
+    pub max_size: u64,
+}
+
+pub(crate) struct DiskCache {
+    config: DiskCacheConfig,
+}
+
+impl DiskCache {
+    pub fn new(config: DiskCacheConfig) -> io::Result<Self> {
+        fs::create_dir_all(&config.directory)?;
+
+        let cache = Self { config };
+        cache.remove_temporary_files()?;
+
+        Ok(cache)
+    }
+
+    /// Loads the module, removing the file if it can't be used.
+    pub fn load(&self, engine: &Engine, code_id: CodeId) -> Option<Module> {
+        let path = self.path(engine, code_id);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                tracing::warn!("failed to read cached module {}: {err}", path.display());
+                return None;
+            }
+        };
+
+        let Some(serialized) = Self::verify(&bytes) else {
+            tracing::warn!("removing corrupted cached module {}", path.display());
+            let _ = fs::remove_file(&path);
+            return None;
+        };
+
+        // SAFETY: the file was written by `Module::serialize` of a compatible engine,
+        // unless someone else has write access to the cache directory. The checksum
+        // only detects accidental corruption: it doesn't authenticate the contents,
+        // so anyone able to write there can get arbitrary code executed by the node.
+        // The directory must be only writable by the node's user.
+        match unsafe { Module::deserialize(engine, serialized) } {
+            Ok(module) => {
+                // Bump the modification time, which is used as the last access time on eviction.
+                if let Err(err) = File::options()
+                    .write(true)
+                    .open(&path)
+                    .and_then(|file| file.set_modified(SystemTime::now()))
+                {
+                    tracing::trace!("failed to touch cached module {}: {err}", path.display());
+                }
+
+                Some(module)
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "removing incompatible cached module {}: {err:?}",
+                    path.display()
+                );
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Stores the module, evicting least recently used ones if the cache is full.
+    pub fn store(&self, engine: &Engine, code_id: CodeId, module: &Module) {
+        let path = self.path(engine, code_id);
+        if let Err(err) = self.write(&path, module) {
+            tracing::warn!("failed to store module {}: {err:?}", path.display());
+            return;
+        }
+
+        if let Err(err) = self.evict() {
+            tracing::warn!("failed to evict cached modules: {err}");
+        }
+    }
+
+    fn path(&self, engine: &Engine, code_id: CodeId) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        engine.precompile_compatibility_hash().hash(&mut hasher);
+        let fingerprint = hasher.finish();
+
+        self.config
+            .directory
+            .join(format!("{code_id}-{fingerprint:016x}.{EXTENSION}"))
+    }
+
+    /// Returns the serialized module, if the file is not corrupted.
+    fn verify(bytes: &[u8]) -> Option<&[u8]> {
+        if bytes.len() < HEADER_LEN || !bytes.starts_with(MAGIC) {
+            return None;
+        }
+
+        let (checksum, serialized) = bytes[MAGIC.len()..].split_at(CHECKSUM_LEN);
+        (utils::hash(serialized) == checksum).then_some(serialized)
+    }
+
+    fn write(&self, path: &Path, module: &Module) -> wasmtime::Result<()> {
+        let serialized = module.serialize()?;
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + serialized.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&utils::hash(&serialized));
+        bytes.extend_from_slice(&serialized);
+
+        // Write into a temporary file first, so readers never observe a partially written module.
+        let tmp_path = path.with_extension(format!("{}.{TMP_EXTENSION}", std::process::id()));
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(&tmp_path, path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp_path);
+        })?;
+
+        Ok(())
+    }
+
+    /// Removes temporary files left by writes interrupted before the rename.
+    fn remove_temporary_files(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.config.directory)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != TMP_EXTENSION) {
+                continue;
+            }
+
+            tracing::trace!("removing temporary file {}", path.display());
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                // Renamed concurrently by another writer.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn evict(&self) -> io::Result<()> {
+        let mut entries = Vec::new();
+        let mut total_size = 0;
+
+        for entry in fs::read_dir(&self.config.directory)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != EXTENSION) {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            total_size += metadata.len();
+            entries.push((metadata.modified()?, metadata.len(), path));
+        }
+
+        if total_size <= self.config.max_size {
+            return Ok(());
+        }
+
+        entries.sort_unstable_by_key(|(modified, ..)| *modified);
+
+        for (_, size, path) in entries {
+            if total_size <= self.config.max_size {
+                break;
+            }
+
+            tracing::trace!("evict cached module {}", path.display());
+            match fs::remove_file(&path) {
+                Ok(()) => total_size -= size,
+                // Evicted concurrently by another thread.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => total_size -= size,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(loom))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gear_core::ids::prelude::CodeIdExt;
+
+    const EMPTY_WASM: &[u8] = b"\x00asm\x01\x00\x00\x00";
+
+    fn cache(max_size: u64) -> (tempfile::TempDir, DiskCache) {
+        let dir = tempfile::tempdir().expect("temp dir is created");
+        let cache = DiskCache::new(DiskCacheConfig {
+            directory: dir.path().to_path_buf(),
+            max_size,
+        })
+        .expect("cache is created");
+
+        (dir, cache)
+    }
+
+    #[test]
+    fn store_and_load() {
+        let (_dir, cache) = cache(u64::MAX);
+        let engine = Engine::default();
+        let code_id = CodeId::generate(EMPTY_WASM);
+
+        assert!(cache.load(&engine, code_id).is_none());
+
+        let module = Module::new(&engine, EMPTY_WASM).expect("module compiles");
+        cache.store(&engine, code_id, &module);
+
+        assert!(cache.load(&engine, code_id).is_some());
+    }
+
+    #[test]
+    fn corrupted_module_is_removed() {
+        let (_dir, cache) = cache(u64::MAX);
+        let engine = Engine::default();
+        let code_id = CodeId::generate(EMPTY_WASM);
+
+        let module = Module::new(&engine, EMPTY_WASM).expect("module compiles");
+        cache.store(&engine, code_id, &module);
+
+        let path = cache.path(&engine, code_id);
+        let mut bytes = fs::read(&path).expect("module is stored");
+        *bytes.last_mut().unwrap() ^= 0xff;
+        fs::write(&path, bytes).expect("module is overwritten");
+
+        assert!(cache.load(&engine, code_id).is_none());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn temporary_files_are_removed_on_open() {
+        let dir = tempfile::tempdir().expect("temp dir is created");
+        let tmp_path = dir.path().join("module.42.tmp");
+        fs::write(&tmp_path, b"partially written").expect("temporary file is written");
+
+        let _cache = DiskCache::new(DiskCacheConfig {
+            directory: dir.path().to_path_buf(),
+            max_size: u64::MAX,
+        })
+        .expect("cache is created");
+
+        assert!(!tmp_path.exists());
+    }
+
+    #[test]
+    fn least_recently_used_modules_are_evicted() {
+        let engine = Engine::default();
+        let codes = [
+            EMPTY_WASM.to_vec(),
+            // Same empty module with an empty custom section appended.
+            [EMPTY_WASM, b"\x00\x02\x01a"].concat(),
+        ];
+        let code_ids = codes.each_ref().map(|code| CodeId::generate(code));
+
+        let module = Module::new(&engine, &codes[0]).expect("module compiles");
+        let module_size = module.serialize().unwrap().len() as u64 + HEADER_LEN as u64;
+
+        // Only one module fits.
+        let (_dir, cache) = cache(module_size + module_size / 2);
+
+        cache.store(&engine, code_ids[0], &module);
+        // Make the first module older, since modification times may be coarse.
+        File::options()
+            .write(true)
+            .open(cache.path(&engine, code_ids[0]))
+            .and_then(|file| file.set_modified(SystemTime::UNIX_EPOCH))
+            .expect("modification time is set");
+
+        let module = Module::new(&engine, &codes[1]).expect("module compiles");
+        cache.store(&engine, code_ids[1], &module);
+
+        assert!(cache.load(&engine, code_ids[0]).is_none());
+        assert!(cache.load(&engine, code_ids[1]).is_some());
+    }
+}
@@ -12,22 +12,32 @@
 //! A `CompilePermit` represents ownership of one in-progress compile. Dropping
 //! it always removes the hash from `compiling` and wakes waiters, so both
 //! successful compilation and early errors unblock the next thread.
+//!
+//! Optionally, compiled modules are also persisted on disk (see
+//! [`enable_disk_cache`]), so they survive node restarts. The disk is consulted
+//! by the permit owner before compiling, so it's accessed outside the mutex.
 
 #[cfg(all(loom, test))]
 use loom::sync::{Condvar, Mutex};
 #[cfg(not(all(loom, test)))]
 use std::sync::{Condvar, Mutex};
 
+pub use disk::DiskCacheConfig;
+
+use disk::DiskCache;
 use gear_core::ids::{CodeId, prelude::CodeIdExt};
 use lru::LruCache;
-use std::{collections::HashSet, num::NonZeroUsize, sync::OnceLock};
+use std::{collections::HashSet, io, num::NonZeroUsize, sync::OnceLock};
 use wasmtime::{Engine, Module, error::Context};
 
+mod disk;
+
 const MODULES_CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
 
 struct Cache {
     state: Mutex<CacheState>,
     module_ready: Condvar,
+    disk: OnceLock<DiskCache>,
 }
 
 struct CacheState {
@@ -45,6 +55,7 @@ impl Cache {
                 compiling: HashSet::new(),
             }),
             module_ready: Condvar::new(),
+            disk: OnceLock::new(),
         }
     }
 
@@ -56,17 +67,34 @@ impl Cache {
             Err(module) => return Ok(module),
         };
 
+        let disk = self.disk.get();
+
+        if let Some(module) = disk.and_then(|disk| disk.load(engine, code_id)) {
+            tracing::trace!("load wasmtime module from disk cache");
+
+            self.insert(code_id, module.clone());
+            return Ok(ModuleFrom::Disk(module));
+        }
+
         tracing::trace!("create wasmtime module because of missed LRU cache");
 
         let module = Module::new(engine, code).context("failed to create module")?;
 
-        let mut state = self.state.lock().unwrap();
-        let old_module = state.modules.put(code_id, module.clone());
-        debug_assert!(old_module.is_none());
+        if let Some(disk) = disk {
+            disk.store(engine, code_id, &module);
+        }
+
+        self.insert(code_id, module.clone());
 
         Ok(ModuleFrom::New(module))
     }
 
+    fn insert(&self, code_id: CodeId, module: Module) {
+        let mut state = self.state.lock().unwrap();
+        let old_module = state.modules.put(code_id, module);
+        debug_assert!(old_module.is_none());
+    }
+
     fn reserve_compile(
         &self,
         code_id: CodeId,
@@ -163,21 +191,44 @@ impl Drop for CompilePermit<'_> {
 enum ModuleFrom {
     Lru(Module),
     EngineChanged(Module),
+    Disk(Module),
     New(Module),
 }
 
+static CACHE: OnceLock<Cache> = OnceLock::new();
+
 /// Returns a compiled Wasmtime module, using an in-memory LRU cache on hits.
+///
+/// If the disk cache is enabled, it's checked on LRU misses and
+/// newly compiled modules are stored there.
 pub fn get(engine: &Engine, code: &[u8]) -> wasmtime::Result<Module> {
-    static CACHE: OnceLock<Cache> = OnceLock::new();
-
     let cache = CACHE.get_or_init(Cache::new);
     match cache.get(engine, code)? {
-        ModuleFrom::Lru(module) | ModuleFrom::EngineChanged(module) | ModuleFrom::New(module) => {
-            Ok(module)
-        }
+        ModuleFrom::Lru(module)
+        | ModuleFrom::EngineChanged(module)
+        | ModuleFrom::Disk(module)
+        | ModuleFrom::New(module) => Ok(module),
     }
 }
 
+/// Enables the on-disk tier of the module cache.
+///
+/// Stored modules are loaded as native code without authentication, so the
+/// directory must only be writable by the node's user.
+///
+/// Has no effect if the disk cache is already enabled.
+pub fn enable_disk_cache(config: DiskCacheConfig) -> io::Result<()> {
+    let cache = CACHE.get_or_init(Cache::new);
+    if cache.disk.get().is_some() {
+        tracing::warn!("disk module cache is already enabled");
+        return Ok(());
+    }
+
+    let _ = cache.disk.set(DiskCache::new(config)?);
+
+    Ok(())
+}
+
 #[cfg(not(loom))]
 #[cfg(test)]
 mod tests {
@@ -228,6 +279,36 @@ mod tests {
             .expect("module compiles after deserialize miss");
         assert!(matches!(module, ModuleFrom::New(_)));
     }
+
+    #[test]
+    fn loads_from_disk_cache() {
+        let dir = tempfile::tempdir().expect("temp dir is created");
+        let config = DiskCacheConfig {
+            directory: dir.path().to_path_buf(),
+            max_size: u64::MAX,
+        };
+        let engine = Engine::default();
+
+        let cache = Cache::new();
+        let _ = cache.disk.set(DiskCache::new(config.clone()).unwrap());
+
+        let module = cache.get(&engine, EMPTY_WASM).expect("module compiles");
+        assert!(matches!(module, ModuleFrom::New(_)));
+
+        // Simulate restart.
+        let cache = Cache::new();
+        let _ = cache.disk.set(DiskCache::new(config).unwrap());
+
+        let module = cache
+            .get(&engine, EMPTY_WASM)
+            .expect("module loads from disk");
+        assert!(matches!(module, ModuleFrom::Disk(_)));
+
+        let module = cache
+            .get(&engine, EMPTY_WASM)
+            .expect("module loads from cache");
+        assert!(matches!(module, ModuleFrom::Lru(_)));
+    }
 }
 
 #[cfg(loom)]
@@ -264,6 +345,7 @@ mod tests_loom {
                     ModuleFrom::New(_) => new += 1,
                     ModuleFrom::Lru(_) => lru += 1,
                     ModuleFrom::EngineChanged(_) => panic!("engine should not change"),
+                    ModuleFrom::Disk(_) => panic!("disk cache is not enabled"),
                 }
             }
 
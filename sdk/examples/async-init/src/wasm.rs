@@ -24,12 +24,6 @@ static mut ARGUMENTS: InputArgs = InputArgs {
 
 static mut RESPONSES: u8 = 0;
 
-#[cfg(feature = "ethexe")]
-fn ping_reply_fut(addr: ActorId) -> msg::MessageFuture {
-    msg::send_bytes_for_reply(addr, "PING", 0).expect("Failed to send message")
-}
-
-#[cfg(not(feature = "ethexe"))]
 fn ping_reply_fut(addr: ActorId) -> msg::MessageFuture {
     msg::send_bytes_for_reply(addr, "PING", 0, 0).expect("Failed to send message")
 }
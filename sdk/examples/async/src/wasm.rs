@@ -14,12 +14,8 @@ extern "C" fn init() {
 }
 
 async fn ping() -> Vec<u8> {
-    #[cfg(not(feature = "ethexe"))]
-    let fut = msg::send_bytes_for_reply(unsafe { DESTINATION }, "PING", 0, 0);
-    #[cfg(feature = "ethexe")]
-    let fut = msg::send_bytes_for_reply(unsafe { DESTINATION }, "PING", 0);
-
-    fut.expect("Failed to send message")
+    msg::send_bytes_for_reply(unsafe { DESTINATION }, "PING", 0, 0)
+        .expect("Failed to send message")
         .await
         .expect("Received error reply")
 }
@@ -0,0 +1,139 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Groth16 proof verifier built on top of the BN254 builtin actor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use gstd::ActorId;
+use parity_scale_codec::{Decode, Encode};
+
+#[cfg(feature = "std")]
+mod code {
+    include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+}
+
+#[cfg(feature = "std")]
+pub use code::WASM_BINARY_OPT as WASM_BINARY;
+
+/// Groth16 verifying key. Every point is encoded as `ArkScale<G1Affine>`
+/// or `ArkScale<G2Affine>` respectively.
+#[derive(Clone, Encode, Decode)]
+pub struct VerifyingKey {
+    pub alpha_g1: Vec<u8>,
+    pub beta_g2: Vec<u8>,
+    pub gamma_g2: Vec<u8>,
+    pub delta_g2: Vec<u8>,
+    pub ic: Vec<Vec<u8>>,
+}
+
+#[derive(Encode, Decode)]
+pub struct InitMessage {
+    /// Address of the BN254 builtin actor.
+    pub builtin: ActorId,
+    pub vk: VerifyingKey,
+}
+
+/// Groth16 proof, encoded the same way as [`VerifyingKey`].
+#[derive(Clone, Encode, Decode)]
+pub struct Proof {
+    pub a: Vec<u8>,
+    pub b: Vec<u8>,
+    pub c: Vec<u8>,
+}
+
+/// Request to verify the proof. Public inputs are the little-endian limbs
+/// of the scalar field elements. The program replies with `bool`.
+#[derive(Encode, Decode)]
+pub struct HandleMessage {
+    pub proof: Proof,
+    pub public_inputs: Vec<Vec<u64>>,
+}
+
+#[cfg(not(feature = "std"))]
+mod wasm;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gbuiltin_bn254::{
+        ark_bn254::{Fr, G1Projective as G1, G2Projective as G2},
+        ark_ec::{CurveGroup, Group},
+        ark_ff::PrimeField,
+        ark_scale,
+        ark_serialize::CanonicalSerialize,
+    };
+    use gtest::{BN254_ID, Log, Program, System, constants::DEFAULT_USER_ALICE};
+
+    type ArkScale<T> = ark_scale::ArkScale<T, { ark_scale::HOST_CALL }>;
+
+    fn encode<T: CanonicalSerialize>(value: T) -> Vec<u8> {
+        ArkScale::from(value).encode()
+    }
+
+    #[test]
+    fn groth16_verification() {
+        let system = System::new();
+        system.init_logger();
+
+        let [alpha, beta, gamma, delta, ic0, ic1, r, t] =
+            [2u64, 3, 5, 7, 11, 13, 17, 19].map(Fr::from);
+        let input = Fr::from(42u64);
+
+        // With `A = r * G1` and `C = t * G1` the proof is valid iff
+        // `r * s = alpha * beta + vk_x * gamma + t * delta`, where `B = s * G2`.
+        let vk_x = ic0 + input * ic1;
+        let s = (alpha * beta + vk_x * gamma + t * delta) / r;
+
+        let g1 = G1::generator();
+        let g2 = G2::generator();
+
+        let vk = VerifyingKey {
+            alpha_g1: encode((g1 * alpha).into_affine()),
+            beta_g2: encode((g2 * beta).into_affine()),
+            gamma_g2: encode((g2 * gamma).into_affine()),
+            delta_g2: encode((g2 * delta).into_affine()),
+            ic: vec![
+                encode((g1 * ic0).into_affine()),
+                encode((g1 * ic1).into_affine()),
+            ],
+        };
+        let proof = Proof {
+            a: encode((g1 * r).into_affine()),
+            b: encode((g2 * s).into_affine()),
+            c: encode((g1 * t).into_affine()),
+        };
+
+        let program = Program::current(&system);
+        let mid = program.send(
+            DEFAULT_USER_ALICE,
+            InitMessage {
+                builtin: BN254_ID,
+                vk,
+            },
+        );
+        let res = system.run_next_block();
+        assert!(res.succeed.contains(&mid));
+
+        for (input, verified) in [(input, true), (input + Fr::from(1u64), false)] {
+            let mid = program.send(
+                DEFAULT_USER_ALICE,
+                HandleMessage {
+                    proof: proof.clone(),
+                    public_inputs: vec![input.into_bigint().0.to_vec()],
+                },
+            );
+            let res = system.run_next_block();
+            assert!(res.succeed.contains(&mid));
+
+            let log = Log::builder()
+                .source(program.id())
+                .dest(DEFAULT_USER_ALICE)
+                .payload(verified);
+            assert!(res.contains(&log));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use super::*;
+use ark_bn254::{Bn254, G1Affine, G1Projective, G2Affine};
+use ark_ec::{CurveGroup, pairing::Pairing};
+use ark_ff::One;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use gbuiltin_bn254::*;
+use gstd::{msg, prelude::*};
+
+type ArkScale<T> = ark_scale::ArkScale<T, { ark_scale::HOST_CALL }>;
+type ArkScaleValidated<T> =
+    ark_scale::ArkScale<T, { ark_scale::make_usage(Compress::No, Validate::Yes) }>;
+
+struct Contract {
+    builtin: ActorId,
+    alpha_g1: G1Affine,
+    beta_g2: G2Affine,
+    gamma_g2: G2Affine,
+    delta_g2: G2Affine,
+    ic: Vec<G1Affine>,
+}
+
+static mut CONTRACT: Option<Contract> = None;
+
+/// Decodes a point, checking that it's on the curve and in the correct subgroup.
+fn decode<T: CanonicalDeserialize>(bytes: &[u8]) -> T {
+    ArkScaleValidated::<T>::decode(&mut &bytes[..])
+        .expect("Failed to decode point")
+        .0
+}
+
+async fn request(builtin: ActorId, request: Request) -> Response {
+    let reply = msg::send_bytes_for_reply(builtin, request.encode(), 0, 0)
+        .expect("Failed to send message")
+        .await
+        .expect("Received error reply");
+
+    Response::decode(&mut reply.as_slice()).expect("Failed to decode `Response`")
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn init() {
+    let InitMessage { builtin, vk } = msg::load().expect("Unable to decode `InitMessage`");
+
+    assert!(!vk.ic.is_empty(), "Verifying key has no IC points");
+
+    let contract = Contract {
+        builtin,
+        alpha_g1: decode(&vk.alpha_g1),
+        beta_g2: decode(&vk.beta_g2),
+        gamma_g2: decode(&vk.gamma_g2),
+        delta_g2: decode(&vk.delta_g2),
+        ic: vk.ic.iter().map(|point| decode(point)).collect(),
+    };
+
+    unsafe { CONTRACT = Some(contract) }
+}
+
+#[gstd::async_main]
+async fn main() {
+    let HandleMessage {
+        proof,
+        public_inputs,
+    } = msg::load().expect("Unable to decode `HandleMessage`");
+    let contract = unsafe {
+        static_mut!(CONTRACT)
+            .as_ref()
+            .expect("The contract is not initialized")
+    };
+
+    assert_eq!(
+        public_inputs.len() + 1,
+        contract.ic.len(),
+        "Invalid count of public inputs"
+    );
+
+    // vk_x = ic[0] + sum(public_inputs[i] * ic[i + 1])
+    let mut vk_x = ArkScaleProjective::from(G1Projective::from(contract.ic[0])).encode();
+    for (input, point) in public_inputs.into_iter().zip(&contract.ic[1..]) {
+        let base = ArkScaleProjective::from(G1Projective::from(*point)).encode();
+        let scalar = ArkScale::from(input).encode();
+
+        let Response::ProjectiveMultiplicationG1(product) = request(
+            contract.builtin,
+            Request::ProjectiveMultiplicationG1 { base, scalar },
+        )
+        .await
+        else {
+            unreachable!()
+        };
+
+        let Response::AdditionG1(sum) = request(
+            contract.builtin,
+            Request::AdditionG1 {
+                a: vk_x,
+                b: product,
+            },
+        )
+        .await
+        else {
+            unreachable!()
+        };
+
+        vk_x = sum;
+    }
+
+    let vk_x = ArkScaleProjective::<G1Projective>::decode(&mut vk_x.as_slice())
+        .expect("Failed to decode `vk_x`")
+        .0
+        .into_affine();
+
+    // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+    let a: ArkScale<Vec<G1Affine>> = vec![
+        -decode::<G1Affine>(&proof.a),
+        contract.alpha_g1,
+        vk_x,
+        decode(&proof.c),
+    ]
+    .into();
+    let b: ArkScale<Vec<G2Affine>> = vec![
+        decode(&proof.b),
+        contract.beta_g2,
+        contract.gamma_g2,
+        contract.delta_g2,
+    ]
+    .into();
+
+    let Response::MultiMillerLoop(f) = request(
+        contract.builtin,
+        Request::MultiMillerLoop {
+            a: a.encode(),
+            b: b.encode(),
+        },
+    )
+    .await
+    else {
+        unreachable!()
+    };
+
+    let Response::FinalExponentiation(result) =
+        request(contract.builtin, Request::FinalExponentiation { f }).await
+    else {
+        unreachable!()
+    };
+
+    let result = decode::<<Bn254 as Pairing>::TargetField>(&result);
+
+    msg::reply(result.is_one(), 0).expect("Failed to send reply");
+}
@@ -205,19 +205,19 @@ impl StakingBroker {
         .await
     }
 
-    async fn active_era(&mut self) {
+    async fn query(&mut self, request: Request) {
         debug!(
-            "[StakingBroker] Sending `active_era` message at broker's state {:?}",
-            self
+            "[StakingBroker] Sending query message {:?} at broker's state {:?}",
+            request, self
         );
 
-        match msg::send_for_reply(BUILTIN_ADDRESS, Request::ActiveEra, 0, 0)
+        match msg::send_for_reply(BUILTIN_ADDRESS, request, 0, 0)
             .expect("Error sending message")
             .await
         {
             Ok(reply) => {
-                debug!("[StakingBroker] ActiveEra reply from builtin actor received");
-                // Forward the ActiveEra response back to the user
+                debug!("[StakingBroker] Query reply from builtin actor received");
+                // Forward the query response back to the user
                 msg::reply_bytes(reply, 0).expect("Failed to send reply");
             }
             Err(e) => {
@@ -269,8 +269,13 @@ async fn main() {
         Request::SetPayee { payee } => {
             broker.set_payee(payee).await;
         }
-        Request::ActiveEra => {
-            broker.active_era().await;
+        request @ (Request::ActiveEra
+        | Request::Ledger
+        | Request::UnlockingChunks
+        | Request::Nominations
+        | Request::EraRewardPoints { .. }
+        | Request::SlashingSpans) => {
+            broker.query(request).await;
         }
     }
 }
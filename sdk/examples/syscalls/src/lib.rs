@@ -78,6 +78,18 @@ pub enum Kind {
     Unreserve(u64),
     // Param(salt), Expected(hash, block number)
     Random([u8; 32], ([u8; 32], u32)),
+    // Param(data), Expected(hash)
+    Blake2b256(Vec<u8>, [u8; 32]),
+    // Param(data), Expected(hash)
+    Keccak256(Vec<u8>, [u8; 32]),
+    // Param(data), Expected(hash)
+    Sha256(Vec<u8>, [u8; 32]),
+    // Param(public key, signature, message), Expected(verified)
+    Ed25519Verify([u8; 32], [u8; 64], Vec<u8>, bool),
+    // Param(public key, signature, message), Expected(verified)
+    Sr25519Verify([u8; 32], [u8; 64], Vec<u8>, bool),
+    // Param(message hash, signature), Expected(public key)
+    Secp256k1Recover([u8; 32], [u8; 65], Option<[u8; 64]>),
     // Expected(lower bound, upper bound )-> estimated gas level
     GasAvailable(u64, u64),
     // Expected(message id)
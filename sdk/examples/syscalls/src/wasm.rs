@@ -3,9 +3,9 @@
 
 use crate::Kind;
 use gstd::{
-    ActorId, CodeId, MessageId, ReservationId, Vec,
+    ActorId, CodeId, MessageId, ReservationId, Vec, crypto,
     errors::{ReplyCode, SignalCode, SimpleExecutionError},
-    exec, format,
+    exec, format, hash,
     msg::{self, MessageHandle},
     prelude::*,
     prog,
@@ -317,6 +317,45 @@ fn process(syscall_kind: Kind) {
             assert_eq!(expected_hash, actual_hash, "Kind::Random: hash test failed");
             assert_eq!(expected_bn, actual_bn, "Kind::Random: bn test failed");
         }
+        Kind::Blake2b256(data, expected_hash) => {
+            let actual_hash = hash::blake2b_256(&data);
+            assert_eq!(
+                expected_hash, actual_hash,
+                "Kind::Blake2b256: hash test failed"
+            );
+        }
+        Kind::Keccak256(data, expected_hash) => {
+            let actual_hash = hash::keccak256(&data);
+            assert_eq!(
+                expected_hash, actual_hash,
+                "Kind::Keccak256: hash test failed"
+            );
+        }
+        Kind::Sha256(data, expected_hash) => {
+            let actual_hash = hash::sha256(&data);
+            assert_eq!(expected_hash, actual_hash, "Kind::Sha256: hash test failed");
+        }
+        Kind::Ed25519Verify(public_key, signature, message, expected_verified) => {
+            let actual_verified = crypto::ed25519_verify(&public_key, &signature, &message);
+            assert_eq!(
+                expected_verified, actual_verified,
+                "Kind::Ed25519Verify: verification test failed"
+            );
+        }
+        Kind::Sr25519Verify(public_key, signature, message, expected_verified) => {
+            let actual_verified = crypto::sr25519_verify(&public_key, &signature, &message);
+            assert_eq!(
+                expected_verified, actual_verified,
+                "Kind::Sr25519Verify: verification test failed"
+            );
+        }
+        Kind::Secp256k1Recover(message_hash, signature, expected_public_key) => {
+            let actual_public_key = crypto::secp256k1_recover(&message_hash, &signature);
+            assert_eq!(
+                expected_public_key, actual_public_key,
+                "Kind::Secp256k1Recover: recovery test failed"
+            );
+        }
         Kind::GasAvailable(lower, upper) => {
             let gas_available = exec::gas_available();
             assert!(
@@ -33,6 +33,9 @@ pub fn default_wait_up_to_duration() -> u32 {
     gstd::Config::wait_up_to()
 }
 
+/// Number of permits of the semaphore used by the semaphore commands.
+pub const SEMAPHORE_PERMITS: u32 = 2;
+
 #[derive(Debug, Encode, Decode)]
 pub enum WaitSubcommand {
     Wait,
@@ -96,4 +99,24 @@ pub enum Command {
     MxLockStaticAccess(LockStaticAccessSubcommand),
     RwLock(RwLockType, RwLockContinuation),
     RwLockStaticAccess(RwLockType, LockStaticAccessSubcommand),
+    // Replies with whether the permit is acquired before the timeout.
+    SemaphoreAcquire(Option<BlockCount>),
+    SemaphoreRelease,
+    MpscSend(u32),
+    // Replies with the received value, or `None` on timeout.
+    MpscRecv(Option<BlockCount>),
+    // Sends the value and receives it in the same message.
+    MpscSendRecv(u32),
+    OneshotSend(u32),
+    // Replies with the received value, or `None` on timeout.
+    OneshotRecv(Option<BlockCount>),
+    // Sends `first` and `second` messages to the source, replies with the
+    // first reply, or `timeout` if no reply arrives in time.
+    SelectReplies(BlockCount),
+    // Sends `first` and `second` messages to the source, replies with both
+    // replies concatenated, or `timeout` if they don't arrive in time.
+    JoinReplies(BlockCount),
+    // Sends `first` message to the source, replies with its reply, or
+    // `slept` if the sleep for the given number of blocks ends earlier.
+    SelectReplyOrSleep(BlockCount),
 }
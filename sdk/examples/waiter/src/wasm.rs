@@ -3,14 +3,18 @@
 
 use crate::{
     Command, LockContinuation, LockStaticAccessSubcommand, MxLockContinuation, RwLockContinuation,
-    RwLockType, SleepForWaitType, WaitSubcommand,
+    RwLockType, SEMAPHORE_PERMITS, SleepForWaitType, WaitSubcommand,
 };
 use core::ops::{Deref, DerefMut};
 use futures::future;
 use gstd::{
-    exec, format, msg,
+    exec, format,
+    msg::{self, MessageFuture},
     prelude::*,
-    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    sync::{
+        Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, Semaphore, SemaphorePermit,
+        mpsc, oneshot,
+    },
 };
 
 static mut MUTEX: Mutex<()> = Mutex::new(());
@@ -18,6 +22,10 @@ static mut MUTEX_LOCK_GUARD: Option<MutexGuard<()>> = None;
 static mut RW_LOCK: RwLock<()> = RwLock::new(());
 static mut R_LOCK_GUARD: Option<RwLockReadGuard<()>> = None;
 static mut W_LOCK_GUARD: Option<RwLockWriteGuard<()>> = None;
+static SEMAPHORE: Semaphore = Semaphore::new(SEMAPHORE_PERMITS);
+static mut SEMAPHORE_PERMITS_HELD: Vec<SemaphorePermit> = Vec::new();
+static mut MPSC: Option<(mpsc::Sender<u32>, mpsc::Receiver<u32>)> = None;
+static mut ONESHOT_SENDER: Option<oneshot::Sender<u32>> = None;
 
 #[gstd::async_main]
 async fn main() {
@@ -151,9 +159,131 @@ async fn main() {
                 );
             }
         },
+        Command::SemaphoreAcquire(timeout) => {
+            let future = SEMAPHORE.acquire();
+            let future = match timeout {
+                Some(timeout) => future.up_to(timeout).expect("Invalid wait duration."),
+                None => future,
+            };
+            let acquired = match future.await {
+                Ok(permit) => {
+                    unsafe { static_mut!(SEMAPHORE_PERMITS_HELD) }.push(permit);
+                    true
+                }
+                Err(err) if err.timed_out() => false,
+                Err(err) => panic!("Failed to acquire permit: {err}"),
+            };
+            msg::reply(acquired, 0).expect("Failed to send reply");
+        }
+        Command::SemaphoreRelease => {
+            unsafe { static_mut!(SEMAPHORE_PERMITS_HELD) }
+                .pop()
+                .expect("No permits held");
+        }
+        Command::MpscSend(value) => {
+            mpsc_channel()
+                .0
+                .send(value)
+                .expect("Receiver is never dropped");
+        }
+        Command::MpscRecv(timeout) => {
+            let future = mpsc_channel().1.recv();
+            let future = match timeout {
+                Some(timeout) => future.up_to(timeout).expect("Invalid wait duration."),
+                None => future,
+            };
+            let value = match future.await {
+                Ok(value) => Some(value.expect("Sender is never dropped")),
+                Err(err) if err.timed_out() => None,
+                Err(err) => panic!("Failed to receive value: {err}"),
+            };
+            msg::reply(value, 0).expect("Failed to send reply");
+        }
+        Command::MpscSendRecv(value) => {
+            let (tx, mut rx) = mpsc::channel();
+            let (received, _) = future::join(rx.recv(), async {
+                tx.send(value).expect("Receiver is alive");
+            })
+            .await;
+            let received = received.expect("No timeout is set");
+            msg::reply(received, 0).expect("Failed to send reply");
+        }
+        Command::OneshotSend(value) => {
+            let tx = unsafe { static_mut!(ONESHOT_SENDER) }
+                .take()
+                .expect("Nobody waits for the value");
+            let _ = tx.send(value);
+        }
+        Command::OneshotRecv(timeout) => {
+            let (tx, rx) = oneshot::channel();
+            unsafe { *static_mut!(ONESHOT_SENDER) = Some(tx) };
+
+            let future = rx.recv();
+            let future = match timeout {
+                Some(timeout) => future.up_to(timeout).expect("Invalid wait duration."),
+                None => future,
+            };
+            let value = match future.await {
+                Ok(value) => Some(value.expect("Sender is never dropped")),
+                Err(err) if err.timed_out() => None,
+                Err(err) => panic!("Failed to receive value: {err}"),
+            };
+            msg::reply(value, 0).expect("Failed to send reply");
+        }
+        Command::SelectReplies(blocks) => {
+            let (first, second) = (send_with_hook(b"first"), send_with_hook(b"second"));
+            let reply = gstd::select! {
+                reply = first => reply.expect("Failed to receive reply"),
+                reply = second => reply.expect("Failed to receive reply"),
+                timeout(blocks) => b"timeout".to_vec(),
+            };
+            msg::reply_bytes(reply, 0).expect("Failed to send reply");
+        }
+        Command::JoinReplies(blocks) => {
+            let replies = gstd::join!(
+                send_with_hook(b"first"),
+                send_with_hook(b"second");
+                timeout(blocks)
+            );
+            let reply = match replies {
+                Ok((first, second)) => [
+                    first.expect("Failed to receive reply"),
+                    second.expect("Failed to receive reply"),
+                ]
+                .concat(),
+                Err(err) if err.timed_out() => b"timeout".to_vec(),
+                Err(err) => panic!("Failed to join replies: {err}"),
+            };
+            msg::reply_bytes(reply, 0).expect("Failed to send reply");
+        }
+        Command::SelectReplyOrSleep(blocks) => {
+            let reply = gstd::select! {
+                reply = send_with_hook(b"first") => reply.expect("Failed to receive reply"),
+                _ = exec::sleep_for(blocks) => b"slept".to_vec(),
+            };
+            msg::reply_bytes(reply, 0).expect("Failed to send reply");
+        }
     }
 }
 
+// Sends the `payload` to the source for reply, which is reported to the source
+// by the reply hook.
+fn send_with_hook(payload: &'static [u8]) -> MessageFuture {
+    let source = msg::source();
+
+    msg::send_bytes_for_reply(source, payload, 0, 1_000_000_000)
+        .expect("send message failed")
+        .handle_reply(move || {
+            msg::send_bytes(source, [b"hook: ", payload].concat(), 0)
+                .expect("Failed to send hook message");
+        })
+        .expect("Failed to set reply hook")
+}
+
+fn mpsc_channel() -> &'static mut (mpsc::Sender<u32>, mpsc::Receiver<u32>) {
+    unsafe { static_mut!(MPSC) }.get_or_insert_with(mpsc::channel)
+}
+
 fn process_wait_subcommand(subcommand: WaitSubcommand) {
     match subcommand {
         WaitSubcommand::Wait => exec::wait(),
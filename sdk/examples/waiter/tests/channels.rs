@@ -0,0 +1,123 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use demo_waiter::Command;
+use gear_core::ids::MessageId;
+use gtest::{Log, Program, System, constants::DEFAULT_USER_ALICE};
+
+pub const USER_ID: u64 = DEFAULT_USER_ALICE;
+
+#[test]
+fn mpsc_recv_waits_for_values() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let first = program.send(USER_ID, Command::MpscRecv(None));
+    let result = system.run_next_block();
+    assert!(!result.log().iter().any(|log| log.reply_to() == Some(first)));
+
+    program.send(USER_ID, Command::MpscSend(1));
+    program.send(USER_ID, Command::MpscSend(2));
+    let result = system.run_next_block();
+    assert!(result.contains(&received_log(first, Some(1))));
+
+    // The second value is already in the channel, so it's received at once.
+    let second = program.send(USER_ID, Command::MpscRecv(None));
+    let result = system.run_next_block();
+    assert!(result.contains(&received_log(second, Some(2))));
+}
+
+#[test]
+fn mpsc_recv_times_out() {
+    const TIMEOUT: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let receiver = program.send(USER_ID, Command::MpscRecv(Some(TIMEOUT)));
+    let result = system.run_next_block();
+    assert!(
+        !result
+            .log()
+            .iter()
+            .any(|log| log.reply_to() == Some(receiver))
+    );
+
+    let results = system.run_to_block(result.block_info.height + TIMEOUT);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&received_log(receiver, None)))
+    );
+
+    // The value sent after the timeout is kept for the next receiver.
+    program.send(USER_ID, Command::MpscSend(3));
+    let receiver = program.send(USER_ID, Command::MpscRecv(Some(TIMEOUT)));
+    let result = system.run_next_block();
+    assert!(result.contains(&received_log(receiver, Some(3))));
+}
+
+#[test]
+fn mpsc_send_and_recv_in_same_message() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::MpscSendRecv(4));
+    let result = system.run_next_block();
+    assert!(result.contains(&received_log(msg_id, Some(4))));
+}
+
+#[test]
+fn oneshot_recv_waits_for_value() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let receiver = program.send(USER_ID, Command::OneshotRecv(None));
+    let result = system.run_next_block();
+    assert!(
+        !result
+            .log()
+            .iter()
+            .any(|log| log.reply_to() == Some(receiver))
+    );
+
+    program.send(USER_ID, Command::OneshotSend(5));
+    let result = system.run_next_block();
+    assert!(result.contains(&received_log(receiver, Some(5))));
+}
+
+#[test]
+fn oneshot_recv_times_out() {
+    const TIMEOUT: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let receiver = program.send(USER_ID, Command::OneshotRecv(Some(TIMEOUT)));
+    let result = system.run_next_block();
+    assert!(
+        !result
+            .log()
+            .iter()
+            .any(|log| log.reply_to() == Some(receiver))
+    );
+
+    let results = system.run_to_block(result.block_info.height + TIMEOUT);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&received_log(receiver, None)))
+    );
+}
+
+fn received_log(msg_id: MessageId, value: Option<u32>) -> Log {
+    Log::builder().dest(USER_ID).reply_to(msg_id).payload(value)
+}
+
+fn init_fixture(system: &System) -> Program<'_> {
+    system.init_logger_with_default_filter("");
+    let program = Program::current(system);
+    program.send_bytes(USER_ID, []);
+    system.run_next_block();
+    program
+}
@@ -0,0 +1,186 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use demo_waiter::Command;
+use gear_core::ids::MessageId;
+use gtest::{BlockRunResult, Log, Program, System, constants::DEFAULT_USER_ALICE};
+
+pub const USER_ID: u64 = DEFAULT_USER_ALICE;
+
+#[test]
+fn select_uses_first_reply() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::SelectReplies(10));
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+
+    reply(&system, &program, b"second", b"2");
+    let result = system.run_next_block();
+    assert!(result.contains(&reply_log(msg_id, b"2")));
+    assert!(result.contains(&hook_log(b"second")));
+
+    // The reply to the cancelled branch doesn't execute its hook.
+    reply(&system, &program, b"first", b"1");
+    let result = system.run_next_block();
+    assert!(!result.contains(&hook_log(b"first")));
+    assert!(result.failed.is_empty());
+}
+
+#[test]
+fn select_times_out() {
+    const TIMEOUT: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::SelectReplies(TIMEOUT));
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+
+    let results = system.run_to_block(result.block_info.height + TIMEOUT);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&reply_log(msg_id, b"timeout")))
+    );
+
+    reply(&system, &program, b"first", b"1");
+    reply(&system, &program, b"second", b"2");
+    let result = system.run_next_block();
+    assert!(!result.contains(&hook_log(b"first")));
+    assert!(!result.contains(&hook_log(b"second")));
+    assert!(result.failed.is_empty());
+}
+
+#[test]
+fn select_cancels_sleep() {
+    const SLEEP: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::SelectReplyOrSleep(SLEEP));
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+
+    reply(&system, &program, b"first", b"1");
+    let result = system.run_next_block();
+    assert!(result.contains(&reply_log(msg_id, b"1")));
+
+    // The message isn't woken by the cancelled sleep.
+    let results = system.run_to_block(result.block_info.height + SLEEP);
+    assert!(results.iter().all(|result| result.log().is_empty()));
+}
+
+#[test]
+fn select_uses_sleep() {
+    const SLEEP: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::SelectReplyOrSleep(SLEEP));
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+
+    let results = system.run_to_block(result.block_info.height + SLEEP);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&reply_log(msg_id, b"slept")))
+    );
+
+    reply(&system, &program, b"first", b"1");
+    let result = system.run_next_block();
+    assert!(!result.contains(&hook_log(b"first")));
+    assert!(result.failed.is_empty());
+}
+
+#[test]
+fn join_waits_for_all_replies() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::JoinReplies(10));
+    system.run_next_block();
+
+    reply(&system, &program, b"second", b"2");
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+
+    reply(&system, &program, b"first", b"1");
+    let result = system.run_next_block();
+    assert!(result.contains(&reply_log(msg_id, b"12")));
+    assert!(result.contains(&hook_log(b"first")));
+}
+
+#[test]
+fn join_times_out() {
+    const TIMEOUT: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let msg_id = program.send(USER_ID, Command::JoinReplies(TIMEOUT));
+    let result = system.run_next_block();
+    let start = result.block_info.height;
+
+    reply(&system, &program, b"first", b"1");
+    let result = system.run_next_block();
+    assert!(!replied(&result, msg_id));
+    assert!(result.contains(&hook_log(b"first")));
+
+    let results = system.run_to_block(start + TIMEOUT);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&reply_log(msg_id, b"timeout")))
+    );
+
+    reply(&system, &program, b"second", b"2");
+    let result = system.run_next_block();
+    assert!(!result.contains(&hook_log(b"second")));
+    assert!(result.failed.is_empty());
+}
+
+// Replies from the user to the program's message with the `payload`.
+fn reply(system: &System, program: &Program<'_>, payload: &[u8], reply: &[u8]) {
+    let log = Log::builder()
+        .source(program.id())
+        .dest(USER_ID)
+        .payload_bytes(payload);
+    system
+        .get_mailbox(USER_ID)
+        .reply_bytes(log, reply, 0)
+        .expect("Message is in the mailbox");
+}
+
+fn replied(result: &BlockRunResult, msg_id: MessageId) -> bool {
+    result
+        .log()
+        .iter()
+        .any(|log| log.reply_to() == Some(msg_id))
+}
+
+fn reply_log(msg_id: MessageId, payload: &[u8]) -> Log {
+    Log::builder()
+        .dest(USER_ID)
+        .reply_to(msg_id)
+        .payload_bytes(payload)
+}
+
+fn hook_log(payload: &[u8]) -> Log {
+    Log::builder()
+        .dest(USER_ID)
+        .payload_bytes([b"hook: ", payload].concat())
+}
+
+fn init_fixture(system: &System) -> Program<'_> {
+    system.init_logger_with_default_filter("");
+    let program = Program::current(system);
+    program.send_bytes(USER_ID, []);
+    system.run_next_block();
+    program
+}
@@ -0,0 +1,83 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use demo_waiter::{Command, SEMAPHORE_PERMITS};
+use gear_core::ids::MessageId;
+use gtest::{Log, Program, System, constants::DEFAULT_USER_ALICE};
+
+pub const USER_ID: u64 = DEFAULT_USER_ALICE;
+
+#[test]
+fn semaphore_limits_permit_holders() {
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    let holders: Vec<_> = (0..SEMAPHORE_PERMITS)
+        .map(|_| program.send(USER_ID, Command::SemaphoreAcquire(None)))
+        .collect();
+    let waiter = program.send(USER_ID, Command::SemaphoreAcquire(None));
+    let result = system.run_next_block();
+
+    for holder in holders {
+        assert!(result.contains(&acquired_log(holder, true)));
+    }
+    assert!(
+        !result
+            .log()
+            .iter()
+            .any(|log| log.reply_to() == Some(waiter))
+    );
+
+    program.send(USER_ID, Command::SemaphoreRelease);
+    let result = system.run_next_block();
+
+    assert!(result.contains(&acquired_log(waiter, true)));
+}
+
+#[test]
+fn semaphore_acquire_times_out() {
+    const TIMEOUT: u32 = 5;
+
+    let system = System::new();
+    let program = init_fixture(&system);
+
+    for _ in 0..SEMAPHORE_PERMITS {
+        program.send(USER_ID, Command::SemaphoreAcquire(None));
+    }
+    let waiter = program.send(USER_ID, Command::SemaphoreAcquire(Some(TIMEOUT)));
+    let result = system.run_next_block();
+    assert!(
+        !result
+            .log()
+            .iter()
+            .any(|log| log.reply_to() == Some(waiter))
+    );
+
+    let results = system.run_to_block(result.block_info.height + TIMEOUT);
+    assert!(
+        results
+            .iter()
+            .any(|result| result.contains(&acquired_log(waiter, false)))
+    );
+
+    // The permit released after the timeout isn't taken by the timed out message.
+    program.send(USER_ID, Command::SemaphoreRelease);
+    let acquirer = program.send(USER_ID, Command::SemaphoreAcquire(Some(TIMEOUT)));
+    let result = system.run_next_block();
+    assert!(result.contains(&acquired_log(acquirer, true)));
+}
+
+fn acquired_log(msg_id: MessageId, acquired: bool) -> Log {
+    Log::builder()
+        .dest(USER_ID)
+        .reply_to(msg_id)
+        .payload(acquired)
+}
+
+fn init_fixture(system: &System) -> Program<'_> {
+    system.init_logger_with_default_filter("");
+    let program = Program::current(system);
+    program.send_bytes(USER_ID, []);
+    system.run_next_block();
+    program
+}
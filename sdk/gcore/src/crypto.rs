@@ -0,0 +1,140 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Signature verification functions.
+//!
+//! Verification is performed by the host, so programs don't need to compile
+//! cryptographic libraries into their code.
+
+use crate::hash;
+use gsys::EcdsaSignature;
+
+/// Verify the ed25519 `signature` of the `message` made by the `public_key`
+/// owner.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::crypto;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let public_key = [0; 32];
+///     let signature = [0; 64];
+///     assert!(!crypto::ed25519_verify(&public_key, &signature, b"message"));
+/// }
+/// ```
+#[cfg(not(feature = "ethexe"))]
+pub fn ed25519_verify(public_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> bool {
+    let mut verified = 0u8;
+    unsafe {
+        gsys::gr_ed25519_verify(
+            public_key,
+            signature,
+            message.as_ptr(),
+            message.len() as u32,
+            &mut verified,
+        )
+    };
+    verified != 0
+}
+
+/// Verify the sr25519 `signature` of the `message` made by the `public_key`
+/// owner.
+///
+/// The `substrate` signing context is used, so signatures made by Substrate
+/// accounts can be verified.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::crypto;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let public_key = [0; 32];
+///     let signature = [0; 64];
+///     assert!(!crypto::sr25519_verify(&public_key, &signature, b"message"));
+/// }
+/// ```
+#[cfg(not(feature = "ethexe"))]
+pub fn sr25519_verify(public_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> bool {
+    let mut verified = 0u8;
+    unsafe {
+        gsys::gr_sr25519_verify(
+            public_key,
+            signature,
+            message.as_ptr(),
+            message.len() as u32,
+            &mut verified,
+        )
+    };
+    verified != 0
+}
+
+/// Recover the uncompressed secp256k1 public key (without `0x04` prefix)
+/// from the `signature` of the `message_hash`.
+///
+/// The `signature` is 65 bytes of `r`, `s` and `v`, where `v` may be either
+/// `0`/`1` or Ethereum-style `27`/`28`.
+///
+/// Returns `None` if the signature is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::crypto;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let message_hash = [0; 32];
+///     let signature = [0; 65];
+///     assert!(crypto::secp256k1_recover(&message_hash, &signature).is_none());
+/// }
+/// ```
+pub fn secp256k1_recover(message_hash: &[u8; 32], signature: &[u8; 65]) -> Option<[u8; 64]> {
+    let mut rs = [0; 64];
+    rs.copy_from_slice(&signature[..64]);
+    let signature = EcdsaSignature {
+        rs,
+        v: signature[64],
+    };
+
+    let mut public_key = [0; 64];
+    let mut recovered = 0u8;
+    unsafe {
+        gsys::gr_secp256k1_recover(
+            message_hash,
+            signature.as_ptr(),
+            &mut public_key,
+            &mut recovered,
+        )
+    };
+
+    (recovered != 0).then_some(public_key)
+}
+
+/// Recover the Ethereum address of the `signature` author, as the `ecrecover`
+/// precompile does.
+///
+/// Returns `None` if the signature is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::{crypto, hash};
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let message_hash = hash::keccak256(b"message");
+///     let signature = [0; 65];
+///     assert!(crypto::ecrecover(&message_hash, &signature).is_none());
+/// }
+/// ```
+pub fn ecrecover(message_hash: &[u8; 32], signature: &[u8; 65]) -> Option<[u8; 20]> {
+    let public_key = secp256k1_recover(message_hash, signature)?;
+
+    let mut address = [0; 20];
+    address.copy_from_slice(&hash::keccak256(&public_key)[12..]);
+    Some(address)
+}
@@ -7,16 +7,12 @@
 //! This module also provides API for low-level async implementation.
 
 use crate::{
-    ActorId, EnvVars, MessageId,
+    ActorId, EnvVars, MessageId, ReservationId,
     errors::{Result, SyscallError},
     utils::AsRawPtr,
 };
 use core::mem::MaybeUninit;
-#[cfg(not(feature = "ethexe"))]
-use {
-    crate::ReservationId,
-    gsys::{BlockNumberWithHash, ErrorWithGas, ErrorWithHash},
-};
+use gsys::{BlockNumberWithHash, ErrorWithGas, ErrorWithHash};
 
 /// Get current version of environment variables.
 pub fn env_vars() -> EnvVars {
@@ -103,7 +99,6 @@ pub fn block_timestamp() -> u64 {
 ///     // I will be executed for pre-defined (deposited) 100_000 of gas!
 /// }
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_deposit(message_id: MessageId, amount: u64) -> Result<()> {
     let mut error_code = 0u32;
     unsafe { gsys::gr_reply_deposit(message_id.as_ptr(), amount, &mut error_code) };
@@ -175,7 +170,6 @@ pub fn exit(inheritor_id: ActorId) -> ! {
 ///
 /// - [`unreserve_gas`] function unreserves gas identified by [`ReservationId`].
 /// - [`system_reserve_gas`] function reserves gas for system usage.
-#[cfg(not(feature = "ethexe"))]
 pub fn reserve_gas(amount: u64, duration: u32) -> Result<ReservationId> {
     let mut res: ErrorWithHash = Default::default();
 
@@ -207,7 +201,6 @@ pub fn reserve_gas(amount: u64, duration: u32) -> Result<ReservationId> {
 /// # See also
 ///
 /// - [`reserve_gas`] function reserves gas for further usage.
-#[cfg(not(feature = "ethexe"))]
 pub fn system_reserve_gas(amount: u64) -> Result<()> {
     let mut error_code = 0u32;
     unsafe { gsys::gr_system_reserve_gas(amount, &mut error_code) };
@@ -225,7 +218,6 @@ pub fn system_reserve_gas(amount: u64) -> Result<()> {
 /// # See also
 ///
 /// - [`reserve_gas`] function reserves gas for further usage.
-#[cfg(not(feature = "ethexe"))]
 pub fn unreserve_gas(id: ReservationId) -> Result<u64> {
     let mut res: ErrorWithGas = Default::default();
 
@@ -336,19 +328,11 @@ pub fn value_available() -> u128 {
 ///     exec::wait();
 /// }
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub fn wait() -> ! {
     unsafe { gsys::gr_wait() }
 }
 
-#[cfg_attr(
-    not(feature = "ethexe"),
-    doc = "Same as [`wait`], but delays handling for a specific number of blocks."
-)]
-#[cfg_attr(
-    feature = "ethexe",
-    doc = "Same as `wait`, but delays handling for a specific number of blocks."
-)]
+/// Same as [`wait`], but delays handling for a specific number of blocks.
 ///
 /// # Panics
 ///
@@ -357,14 +341,8 @@ pub fn wait_for(duration: u32) -> ! {
     unsafe { gsys::gr_wait_for(duration) }
 }
 
-#[cfg_attr(
-    not(feature = "ethexe"),
-    doc = "Same as [`wait`], but delays handling for the maximum number of blocks that can be paid for and doesn't exceed the given `duration`."
-)]
-#[cfg_attr(
-    feature = "ethexe",
-    doc = "Same as `wait`, but delays handling for the maximum number of blocks that can be paid for and doesn't exceed the given `duration`."
-)]
+/// Same as [`wait`], but delays handling for the maximum number of blocks
+/// that can be paid for and doesn't exceed the given `duration`.
 pub fn wait_up_to(duration: u32) -> ! {
     unsafe { gsys::gr_wait_up_to(duration) }
 }
@@ -451,7 +429,6 @@ pub fn program_id() -> ActorId {
 ///     let (seed, block_number) = exec::random(subject).expect("Error in random");
 /// }
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub fn random(subject: [u8; 32]) -> Result<([u8; 32], u32)> {
     let mut res: BlockNumberWithHash = Default::default();
 
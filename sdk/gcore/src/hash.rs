@@ -0,0 +1,61 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Cryptographic hash functions.
+//!
+//! Hashing is performed by the host, which is much cheaper in terms of gas
+//! than hashing in the program's own code.
+
+/// Calculate the BLAKE2b-256 hash of the `data`.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::hash;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let hash = hash::blake2b_256(b"Hello, world!");
+/// }
+/// ```
+pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0; 32];
+    unsafe { gsys::gr_blake2b_256(data.as_ptr(), data.len() as u32, &mut hash) };
+    hash
+}
+
+/// Calculate the Keccak-256 hash of the `data`, as used by Ethereum.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::hash;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let hash = hash::keccak256(b"Hello, world!");
+/// }
+/// ```
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0; 32];
+    unsafe { gsys::gr_keccak256(data.as_ptr(), data.len() as u32, &mut hash) };
+    hash
+}
+
+/// Calculate the SHA-256 hash of the `data`.
+///
+/// # Examples
+///
+/// ```
+/// use gcore::hash;
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn handle() {
+///     let hash = hash::sha256(b"Hello, world!");
+/// }
+/// ```
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0; 32];
+    unsafe { gsys::gr_sha256(data.as_ptr(), data.len() as u32, &mut hash) };
+    hash
+}
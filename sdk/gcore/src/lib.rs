@@ -54,17 +54,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(test(attr(deny(warnings), allow(unused_variables, unused_assignments))))]
 
+pub mod crypto;
 #[cfg(target_arch = "wasm32")]
 pub mod ctor;
 pub mod errors;
 pub mod exec;
+pub mod hash;
 pub mod msg;
-#[cfg(not(feature = "ethexe"))]
 pub mod prog;
 pub use gear_stack_buffer as stack_buffer;
-#[cfg(not(feature = "ethexe"))]
-pub use gprimitives::ReservationId;
-pub use gprimitives::{ActorId, CodeId, MessageHandle, MessageId, Ss58Address};
+pub use gprimitives::{ActorId, CodeId, MessageHandle, MessageId, ReservationId, Ss58Address};
 
 mod utils;
 pub use utils::ext;
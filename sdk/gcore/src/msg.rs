@@ -26,18 +26,15 @@
 //! the program execution and enqueued after the execution successfully ends.
 
 use crate::{
-    ActorId, MessageHandle, MessageId,
+    ActorId, MessageHandle, MessageId, ReservationId,
     errors::{Error, Result, SyscallError},
     stack_buffer,
     utils::AsRawPtr,
 };
-use gear_core_errors::ReplyCode;
-use gsys::{ErrorWithHandle, ErrorWithHash, ErrorWithReplyCode, HashWithValue};
-#[cfg(not(feature = "ethexe"))]
-use {
-    crate::ReservationId,
-    gear_core_errors::SignalCode,
-    gsys::{ErrorWithSignalCode, TwoHashesWithValue},
+use gear_core_errors::{ReplyCode, SignalCode};
+use gsys::{
+    ErrorWithHandle, ErrorWithHash, ErrorWithReplyCode, ErrorWithSignalCode, HashWithValue,
+    TwoHashesWithValue,
 };
 
 const PTR_SPECIAL: *const u128 = u32::MAX as *const u128;
@@ -89,7 +86,6 @@ pub fn reply_code() -> Result<ReplyCode> {
 ///     let signal_code = msg::signal_code().expect("Unable to get signal code");
 /// }
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub fn signal_code() -> Result<Option<SignalCode>> {
     let mut res: ErrorWithSignalCode = Default::default();
 
@@ -314,7 +310,6 @@ pub fn reply(payload: &[u8], value: u128) -> Result<MessageId> {
 ///
 /// - [`send_from_reservation`] function sends a new message to the program or
 ///   user by using gas from a reservation.
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_from_reservation(id: ReservationId, payload: &[u8], value: u128) -> Result<MessageId> {
     let rid_value = HashWithValue {
         hash: id.into(),
@@ -472,7 +467,6 @@ pub fn reply_commit_with_gas(gas_limit: u64, value: u128) -> Result<MessageId> {
 /// # See also
 ///
 /// - [`reply_push`] function allows forming a reply message in parts.
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_commit_from_reservation(id: ReservationId, value: u128) -> Result<MessageId> {
     let rid_value = HashWithValue {
         hash: id.into(),
@@ -557,7 +551,6 @@ pub fn reply_to() -> Result<MessageId> {
 ///     let erroneous_message = msg::signal_from().unwrap();
 /// }
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub fn signal_from() -> Result<MessageId> {
     let mut res: ErrorWithHash = Default::default();
 
@@ -754,7 +747,6 @@ pub fn send(destination: ActorId, payload: &[u8], value: u128) -> Result<Message
 ///   by using gas from a reservation.
 /// - [`send_init`],[`send_push`], [`send_commit_from_reservation`] functions
 ///   allows forming a message to send in parts.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_from_reservation(
     reservation_id: ReservationId,
     destination: ActorId,
@@ -766,7 +758,6 @@ pub fn send_from_reservation(
 
 /// Same as [`send_from_reservation`], but sends the message after the`delay`
 /// expressed in block count.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_delayed_from_reservation(
     reservation_id: ReservationId,
     destination: ActorId,
@@ -901,7 +892,6 @@ pub fn send_input_with_gas_delayed(
 ///   reservation.
 /// - [`send_push`], [`send_init`] functions allows forming message to send in
 ///   parts.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_commit_from_reservation(
     reservation_id: ReservationId,
     handle: MessageHandle,
@@ -913,7 +903,6 @@ pub fn send_commit_from_reservation(
 
 /// Same as [`send_commit_from_reservation`], but sends the message after the
 /// `delay` expressed in block count.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_commit_delayed_from_reservation(
     reservation_id: ReservationId,
     handle: MessageHandle,
@@ -102,10 +102,11 @@ pub fn create_program(
     payload: &[u8],
     value: u128,
 ) -> Result<(MessageId, ActorId)> {
-    create_program_delayed(code_id, salt, payload, value, 0)
+    create_program_inner(code_id, salt, payload, value, 0)
 }
 
 /// Same as [`create_program`], but with an explicit gas limit.
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_with_gas(
     code_id: CodeId,
     salt: &[u8],
@@ -118,12 +119,23 @@ pub fn create_program_with_gas(
 
 /// Same as [`create_program`], but creates a new program after the `delay`
 /// expressed in block count.
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_delayed(
     code_id: CodeId,
     salt: &[u8],
     payload: &[u8],
     value: u128,
     delay: u32,
+) -> Result<(MessageId, ActorId)> {
+    create_program_inner(code_id, salt, payload, value, delay)
+}
+
+fn create_program_inner(
+    code_id: CodeId,
+    salt: &[u8],
+    payload: &[u8],
+    value: u128,
+    delay: u32,
 ) -> Result<(MessageId, ActorId)> {
     let cid_value = HashWithValue {
         hash: code_id.into(),
@@ -1,9 +1,7 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-#[cfg(not(feature = "ethexe"))]
-use gprimitives::ReservationId;
-use gprimitives::{ActorId, CodeId, MessageId};
+use gprimitives::{ActorId, CodeId, MessageId, ReservationId};
 
 pub(crate) trait AsRawPtr: AsRef<[u8]> + AsMut<[u8]> {
     fn as_ptr(&self) -> *const [u8; 32] {
@@ -18,7 +16,6 @@ pub(crate) trait AsRawPtr: AsRef<[u8]> + AsMut<[u8]> {
 impl AsRawPtr for ActorId {}
 impl AsRawPtr for CodeId {}
 impl AsRawPtr for MessageId {}
-#[cfg(not(feature = "ethexe"))]
 impl AsRawPtr for ReservationId {}
 
 /// Extensions for additional features.
@@ -21,12 +21,8 @@ mod utils;
 static mut HANDLE_REPLY_FLAG: Flag = Flag(false);
 
 /// A global flag, determining if `handle_signal` already was generated.
-#[cfg(not(feature = "ethexe"))]
 static mut HANDLE_SIGNAL_FLAG: Flag = Flag(false);
 
-#[cfg(feature = "ethexe")]
-static mut HANDLE_SIGNAL_FLAG: Flag = Flag(true);
-
 fn literal_to_actor_id(literal: syn::LitStr) -> syn::Result<TokenStream> {
     let actor_id: [u8; 32] = ActorId::from_str(&literal.value())
         .map_err(|err| syn::Error::new_spanned(literal, err))?
@@ -120,17 +116,9 @@ impl Parse for MainAttrs {
                 "handle_reply" => {
                     attrs.handle_reply = Some(path);
                 }
-                #[cfg(not(feature = "ethexe"))]
                 "handle_signal" => {
                     attrs.handle_signal = Some(path);
                 }
-                #[cfg(feature = "ethexe")]
-                "handle_signal" => {
-                    return Err(syn::Error::new_spanned(
-                        name,
-                        "`handle_signal` is forbidden with `ethexe` feature on",
-                    ));
-                }
                 _ => return Err(syn::Error::new_spanned(name, "unknown parameter")),
             }
 
@@ -223,6 +211,23 @@ fn generate_handle_signal_if_required(mut code: TokenStream, attr: Option<Path>)
     code
 }
 
+/// Code depositing gas for future reply handling in `*_for_reply` functions.
+///
+/// Replies are executed at the expense of the receiver's executable balance on
+/// ethexe, so the `reply_deposit` argument is accepted there, but ignored.
+fn reply_deposit_code() -> proc_macro2::TokenStream {
+    if cfg!(feature = "ethexe") {
+        return quote! {};
+    }
+
+    quote! {
+        // Depositing gas for future reply handling if not zero.
+        if reply_deposit != 0 {
+            crate::exec::reply_deposit(waiting_reply_to, reply_deposit)?;
+        }
+    }
+}
+
 fn generate_if_required(code: TokenStream, attrs: MainAttrs) -> TokenStream {
     let code = generate_handle_reply_if_required(code, attrs.handle_reply);
     generate_handle_signal_if_required(code, attrs.handle_signal)
@@ -451,13 +456,12 @@ pub fn wait_for_reply(attr: TokenStream, item: TokenStream) -> TokenStream {
     let (for_reply_docs, for_reply_as_docs) = utils::wait_for_reply_docs(ident.to_string(), style);
 
     // Generate arguments.
-    #[cfg_attr(feature = "ethexe", allow(unused_mut))]
     let (mut inputs, variadic) = (function.sig.inputs.clone(), function.sig.variadic.clone());
     let args = utils::get_args(&inputs);
 
     // Add `reply_deposit` argument.
-    #[cfg(not(feature = "ethexe"))]
     inputs.push(syn::parse_quote!(reply_deposit: u64));
+    let deposit_reply = reply_deposit_code();
 
     // Generate generics.
     let decodable_ty = utils::ident("D");
@@ -483,70 +487,36 @@ pub fn wait_for_reply(attr: TokenStream, item: TokenStream) -> TokenStream {
         quote! { #ident }
     };
 
-    match () {
-        #[cfg(not(feature = "ethexe"))]
-        () => quote! {
-            #function
+    quote! {
+        #function
 
-            #[doc = #for_reply_docs]
-            pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::MessageFuture> {
-                // Function call.
-                let waiting_reply_to = #ident #args ?;
+        #[doc = #for_reply_docs]
+        pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::MessageFuture> {
+            // Function call.
+            let waiting_reply_to = #ident #args ?;
 
-                // Depositing gas for future reply handling if not zero.
-                if reply_deposit != 0 {
-                    crate::exec::reply_deposit(waiting_reply_to, reply_deposit)?;
-                }
+            #deposit_reply
 
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
+            // Registering signal.
+            crate::async_runtime::signals().register_signal(waiting_reply_to);
 
             Ok(crate::msg::MessageFuture { waiting_reply_to, reply_deposit })
         }
 
-            #[doc = #for_reply_as_docs]
-            pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecMessageFuture<D>> {
-                // Function call.
-                let waiting_reply_to = #ident #args ?;
-
-                // Depositing gas for future reply handling if not zero.
-                if reply_deposit != 0 {
-                    crate::exec::reply_deposit(waiting_reply_to, reply_deposit)?;
-                }
-
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
-
-                Ok(crate::msg::CodecMessageFuture::<D> { waiting_reply_to, reply_deposit, _marker: Default::default() })
-            }
-        },
-        #[cfg(feature = "ethexe")]
-        () => quote! {
-            #function
-
-            #[doc = #for_reply_docs]
-            pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::MessageFuture> {
-                // Function call.
-                let waiting_reply_to = #ident #args ?;
-
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
-
-                Ok(crate::msg::MessageFuture { waiting_reply_to, reply_deposit: 0 })
-            }
+        #[doc = #for_reply_as_docs]
+        pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecMessageFuture<D>> {
+            // Function call.
+            let waiting_reply_to = #ident #args ?;
 
-            #[doc = #for_reply_as_docs]
-            pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecMessageFuture<D>> {
-                // Function call.
-                let waiting_reply_to = #ident #args ?;
+            #deposit_reply
 
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
+            // Registering signal.
+            crate::async_runtime::signals().register_signal(waiting_reply_to);
 
-                Ok(crate::msg::CodecMessageFuture::<D> { waiting_reply_to, reply_deposit: 0, _marker: Default::default() })
-            }
-        },
-    }.into()
+            Ok(crate::msg::CodecMessageFuture::<D> { waiting_reply_to, reply_deposit, _marker: Default::default() })
+        }
+    }
+    .into()
 }
 
 /// Similar to [`macro@wait_for_reply`], but works with functions that create
@@ -586,13 +556,12 @@ pub fn wait_create_program_for_reply(attr: TokenStream, item: TokenStream) -> To
         utils::wait_for_reply_docs(function_ident.to_string(), style);
 
     // Generate arguments.
-    #[cfg_attr(feature = "ethexe", allow(unused_mut))]
     let (mut inputs, variadic) = (function.sig.inputs.clone(), function.sig.variadic.clone());
     let args = utils::get_args(&inputs);
 
     // Add `reply_deposit` argument.
-    #[cfg(not(feature = "ethexe"))]
     inputs.push(syn::parse_quote!(reply_deposit: u64));
+    let deposit_reply = reply_deposit_code();
 
     // Generate generics.
     let decodable_ty = utils::ident("D");
@@ -606,70 +575,36 @@ pub fn wait_create_program_for_reply(attr: TokenStream, item: TokenStream) -> To
         ),
     );
 
-    match () {
-        #[cfg(not(feature = "ethexe"))]
-        () => quote! {
-            #function
+    quote! {
+        #function
 
-            #[doc = #for_reply_docs]
-            pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::CreateProgramFuture> {
-                // Function call.
-                let (waiting_reply_to, program_id) = #ident #args ?;
+        #[doc = #for_reply_docs]
+        pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::CreateProgramFuture> {
+            // Function call.
+            let (waiting_reply_to, program_id) = #ident #args ?;
 
-                // Depositing gas for future reply handling if not zero.
-                if reply_deposit != 0 {
-                    crate::exec::reply_deposit(waiting_reply_to, reply_deposit)?;
-                }
+            #deposit_reply
 
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
+            // Registering signal.
+            crate::async_runtime::signals().register_signal(waiting_reply_to);
 
             Ok(crate::msg::CreateProgramFuture { waiting_reply_to, program_id, reply_deposit })
         }
 
-            #[doc = #for_reply_as_docs]
-            pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecCreateProgramFuture<D>> {
-                // Function call.
-                let (waiting_reply_to, program_id) = #ident #args ?;
-
-                // Depositing gas for future reply handling if not zero.
-                if reply_deposit != 0 {
-                    crate::exec::reply_deposit(waiting_reply_to, reply_deposit)?;
-                }
-
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
+        #[doc = #for_reply_as_docs]
+        pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecCreateProgramFuture<D>> {
+            // Function call.
+            let (waiting_reply_to, program_id) = #ident #args ?;
 
-                Ok(crate::msg::CodecCreateProgramFuture::<D> { waiting_reply_to, program_id, reply_deposit, _marker: Default::default() })
-            }
-        },
-        #[cfg(feature = "ethexe")]
-        () => quote! {
-            #function
+            #deposit_reply
 
-            #[doc = #for_reply_docs]
-            pub fn #for_reply #for_reply_generics ( #inputs #variadic ) -> Result<crate::msg::CreateProgramFuture> {
-                // Function call.
-                let (waiting_reply_to, program_id) = #ident #args ?;
+            // Registering signal.
+            crate::async_runtime::signals().register_signal(waiting_reply_to);
 
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
-
-                Ok(crate::msg::CreateProgramFuture { waiting_reply_to, program_id, reply_deposit: 0 })
-            }
-
-            #[doc = #for_reply_as_docs]
-            pub fn #for_reply_as #for_reply_as_generics ( #inputs #variadic ) -> Result<crate::msg::CodecCreateProgramFuture<D>> {
-                // Function call.
-                let (waiting_reply_to, program_id) = #ident #args ?;
-
-                // Registering signal.
-                crate::async_runtime::signals().register_signal(waiting_reply_to);
-
-                Ok(crate::msg::CodecCreateProgramFuture::<D> { waiting_reply_to, program_id, reply_deposit: 0, _marker: Default::default() })
-            }
-        },
-    }.into()
+            Ok(crate::msg::CodecCreateProgramFuture::<D> { waiting_reply_to, program_id, reply_deposit, _marker: Default::default() })
+        }
+    }
+    .into()
 }
 
 #[cfg(test)]
@@ -678,17 +613,9 @@ mod tests {
     fn ui() {
         let t = trybuild::TestCases::new();
 
-        #[cfg(not(feature = "ethexe"))]
-        {
-            t.pass("tests/ui/async_init_works.rs");
-            t.pass("tests/ui/async_main_works.rs");
-            t.compile_fail("tests/ui/signal_double_definition_not_work.rs");
-            t.compile_fail("tests/ui/reply_double_definition_not_work.rs");
-        }
-
-        #[cfg(feature = "ethexe")]
-        {
-            t.compile_fail("tests/ui/signal_doesnt_work_with_ethexe.rs");
-        }
+        t.pass("tests/ui/async_init_works.rs");
+        t.pass("tests/ui/async_main_works.rs");
+        t.compile_fail("tests/ui/signal_double_definition_not_work.rs");
+        t.compile_fail("tests/ui/reply_double_definition_not_work.rs");
     }
 }
@@ -3,9 +3,7 @@
 
 //! Module for future-management.
 
-#[cfg(not(feature = "ethexe"))]
-use crate::critical;
-use crate::{MessageId, prelude::Box};
+use crate::{MessageId, critical, prelude::Box};
 use core::{
     future::Future,
     pin::Pin,
@@ -54,12 +52,10 @@ where
 {
     let msg_id = crate::msg::id();
     let task = super::futures().entry(msg_id).or_insert_with(|| {
-        #[cfg(not(feature = "ethexe"))]
-        {
-            let system_reserve_amount = crate::Config::system_reserve();
-            crate::exec::system_reserve_gas(system_reserve_amount)
-                .expect("Failed to reserve gas for system signal");
-        }
+        let system_reserve_amount = crate::Config::system_reserve();
+        crate::exec::system_reserve_gas(system_reserve_amount)
+            .expect("Failed to reserve gas for system signal");
+
         Task::new(future)
     });
 
@@ -74,10 +70,23 @@ where
 
     let mut cx = Context::from_waker(&task.waker);
 
-    if Pin::new(&mut task.future).poll(&mut cx).is_ready() {
+    // The future may be woken by the current message itself (e.g. when
+    // it's both sending to and receiving from a channel), so it's polled
+    // again instead of waiting for the wake that will never come.
+    let _ = super::take_repoll();
+    let is_ready = loop {
+        if Pin::new(&mut task.future).poll(&mut cx).is_ready() {
+            break true;
+        }
+
+        if !super::take_repoll() {
+            break false;
+        }
+    };
+
+    if is_ready {
         super::futures().remove(&msg_id);
         super::locks().remove_message_entry(msg_id);
-        #[cfg(not(feature = "ethexe"))]
         let _ = critical::take_hook();
     } else {
         super::locks().wait(msg_id);
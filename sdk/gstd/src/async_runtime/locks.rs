@@ -7,7 +7,7 @@ use crate::{
     config::WaitType,
     errors::{Error, Result, UsageError},
     exec,
-    sync::MutexId,
+    sync::{MutexId, WaiterId},
 };
 use core::cmp::Ordering;
 use hashbrown::HashMap;
@@ -128,6 +128,9 @@ enum LockContext {
     Sleep(BlockNumber),
     // Used for waking up a message for an attempt to seize lock for mutex 'MutexId'
     MxLockMonitor(MutexId),
+    // Used for waking up a message when waiting for a synchronization object
+    // by the future 'WaiterId' times out
+    SyncTimeout(WaiterId),
 }
 
 /// DoubleMap for wait locks.
@@ -218,6 +221,29 @@ impl LocksMap {
             .remove(&LockContext::MxLockMonitor(mutex_id));
     }
 
+    pub(crate) fn insert_sync_timeout(
+        &mut self,
+        message_id: MessageId,
+        waiter_id: WaiterId,
+        deadline: BlockNumber,
+    ) {
+        let locks = self.message_locks(message_id);
+        locks.insert(
+            LockContext::SyncTimeout(waiter_id),
+            Lock::exactly(
+                deadline
+                    .checked_sub(exec::block_height())
+                    .expect("Deadline must be greater than current block"),
+            )
+            .expect("Never fails with block count > 0"),
+        );
+    }
+
+    pub(crate) fn remove_sync_timeout(&mut self, message_id: MessageId, waiter_id: WaiterId) {
+        self.message_locks(message_id)
+            .remove(&LockContext::SyncTimeout(waiter_id));
+    }
+
     pub fn remove_message_entry(&mut self, message_id: MessageId) {
         // We're removing locks for the message to keep program's state clean.
         //
@@ -3,25 +3,22 @@
 
 mod futures;
 mod locks;
-mod signals;
-
-#[cfg(not(feature = "ethexe"))]
 mod reply_hooks;
+mod signals;
+mod tracker;
 
 pub use self::futures::message_loop;
 pub(crate) use locks::Lock;
-pub(crate) use signals::ReplyPoll;
-
-#[cfg(not(feature = "ethexe"))]
 pub(crate) use reply_hooks::HooksMap;
+pub(crate) use signals::ReplyPoll;
+pub(crate) use tracker::Wait;
 
 use self::futures::FuturesMap;
+use crate::critical;
 use hashbrown::HashMap;
 use locks::LocksMap;
 use signals::WakeSignals;
-
-#[cfg(not(feature = "ethexe"))]
-use crate::critical;
+use tracker::WaitTracker;
 
 static mut FUTURES: Option<FuturesMap> = None;
 
@@ -41,10 +38,45 @@ pub(crate) fn locks() -> &'static mut LocksMap {
     unsafe { crate::static_mut!(LOCKS).get_or_insert_with(LocksMap::default) }
 }
 
-#[cfg(not(feature = "ethexe"))]
+static mut TRACKER: Option<WaitTracker> = None;
+
+pub(crate) fn tracker() -> &'static mut WaitTracker {
+    unsafe { crate::static_mut!(TRACKER).get_or_insert_with(WaitTracker::default) }
+}
+
+/// Deregisters the waits of a cancelled future, so the reply or sleep
+/// doesn't wake the message and reply hooks aren't executed.
+pub(crate) fn cancel_waits(waits: &[Wait]) {
+    let msg_id = crate::msg::id();
+
+    for wait in waits {
+        match *wait {
+            Wait::Reply(waiting_reply_to) => {
+                if signals().waits_for(waiting_reply_to) {
+                    signals().remove(waiting_reply_to);
+                    locks().remove(msg_id, waiting_reply_to);
+                    reply_hooks().remove(waiting_reply_to);
+                }
+            }
+            Wait::Sleep(wake_up_at) => locks().remove_sleep(msg_id, wake_up_at),
+        }
+    }
+}
+
+static mut REPOLL: bool = false;
+
+/// Makes the future of the current message be polled again instead of
+/// waiting.
+pub(crate) fn repoll() {
+    unsafe { REPOLL = true };
+}
+
+fn take_repoll() -> bool {
+    unsafe { core::mem::take(crate::static_mut!(REPOLL)) }
+}
+
 static mut REPLY_HOOKS: Option<HooksMap> = None;
 
-#[cfg(not(feature = "ethexe"))]
 pub(crate) fn reply_hooks() -> &'static mut HooksMap {
     unsafe { crate::static_mut!(REPLY_HOOKS).get_or_insert_with(HooksMap::new) }
 }
@@ -57,15 +89,10 @@ pub fn handle_reply_with_hook() {
     let replied_to =
         crate::msg::reply_to().expect("`gstd::handle_reply_with_hook()` called in wrong context");
 
-    #[cfg(not(feature = "ethexe"))]
     reply_hooks().execute_and_remove(replied_to);
-
-    #[cfg(feature = "ethexe")]
-    let _ = replied_to;
 }
 
 /// Default signal handler.
-#[cfg(not(feature = "ethexe"))]
 pub fn handle_signal() {
     let msg_id = crate::msg::signal_from().expect(
         "`gstd::async_runtime::handle_signal()` must be called only in `handle_signal` entrypoint",
@@ -76,6 +76,10 @@ impl WakeSignals {
         self.signals.contains_key(&reply_to)
     }
 
+    pub fn remove(&mut self, reply_to: MessageId) {
+        self.signals.remove(&reply_to);
+    }
+
     pub fn poll(&mut self, reply_to: MessageId, cx: &mut Context<'_>) -> ReplyPoll {
         match self.signals.remove(&reply_to) {
             None => ReplyPoll::None,
@@ -0,0 +1,54 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Module for tracking waits of the futures polled by combinators, so they
+//! can be deregistered when the future is cancelled.
+
+use crate::{BlockNumber, MessageId, prelude::Vec};
+
+/// Wait registered in the async runtime by a future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Wait {
+    // Waiting for a reply to the message 'MessageId'
+    Reply(MessageId),
+    // Sleeping until the block 'BlockNumber'
+    Sleep(BlockNumber),
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct WaitTracker {
+    // Number of futures being polled by combinators at the moment.
+    depth: usize,
+    waits: Vec<Wait>,
+}
+
+impl WaitTracker {
+    /// Records the wait if some future is being polled by a combinator.
+    pub fn track(&mut self, wait: Wait) {
+        if self.depth > 0 {
+            self.waits.push(wait);
+        }
+    }
+
+    /// Starts tracking waits of the future, returning the tracking start.
+    pub fn begin(&mut self) -> usize {
+        self.depth += 1;
+        self.waits.len()
+    }
+
+    /// Stops tracking waits of the future, returning waits tracked since
+    /// the `start`.
+    ///
+    /// Waits are kept for the outer combinator, if any, because they belong
+    /// to its future as well.
+    pub fn end(&mut self, start: usize) -> Vec<Wait> {
+        let waits = self.waits.split_off(start);
+        self.depth -= 1;
+
+        if self.depth > 0 {
+            self.waits.extend_from_slice(&waits);
+        }
+
+        waits
+    }
+}
@@ -65,7 +65,8 @@ pub enum Error {
 
     /// Timeout reached while expecting for reply.
     ///
-    /// NOTE: this error could only be returned from async messaging.
+    /// NOTE: this error could only be returned from async messaging and
+    /// waiting for [`sync`](crate::sync) objects.
     #[error("Timeout has occurred: expected at {0}, now {1}")]
     Timeout(u32, u32),
 }
@@ -166,14 +167,8 @@ pub enum UsageError {
     EmptyWaitDuration,
     /// This error occurs when providing zero gas amount to system gas reserving
     /// function.
-    #[cfg_attr(
-        not(feature = "ethexe"),
-        doc = "\n\nSee [`Config::set_system_reserve`](crate::Config::set_system_reserve)."
-    )]
-    #[cfg_attr(
-        feature = "ethexe",
-        doc = "\n\nNever occurs under `ethexe` feature, since `Config::set_system_reserve` method is not available."
-    )]
+    ///
+    /// See [`Config::set_system_reserve`](crate::Config::set_system_reserve).
     #[error("System reservation amount can not be zero in config")]
     ZeroSystemReservationAmount,
     /// This error occurs when providing zero duration to mutex lock function
@@ -181,14 +176,8 @@ pub enum UsageError {
     ZeroMxLockDuration,
     /// This error occurs when handle_reply is called without (or with zero)
     /// reply deposit.
-    #[cfg_attr(
-        not(feature = "ethexe"),
-        doc = "\n\nSee [`MessageFuture::handle_reply`](crate::msg::MessageFuture::handle_reply)."
-    )]
-    #[cfg_attr(
-        feature = "ethexe",
-        doc = "\n\nNever occurs under `ethexe` feature, since `MessageFuture::handle_reply` method is not available."
-    )]
+    ///
+    /// See [`MessageFuture::handle_reply`](crate::msg::MessageFuture::handle_reply).
     #[error("Reply deposit can not be zero when setting reply hook")]
     ZeroReplyDeposit,
 }
@@ -5,5 +5,4 @@
 
 pub mod errors;
 mod handlers;
-#[cfg(not(feature = "ethexe"))]
 pub mod primitives_ext;
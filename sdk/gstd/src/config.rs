@@ -10,8 +10,7 @@ use crate::{
     errors::{Error, Result, UsageError},
 };
 
-/// Constant declaring default `Config::system_reserve()` in case of not
-/// "ethexe" feature.
+/// Constant declaring default `Config::system_reserve()`.
 pub const SYSTEM_RESERVE: u64 = 10_000_000_000;
 
 /// Wait types.
@@ -46,7 +45,6 @@ pub struct Config {
     /// Default gas amount reserved for system purposes.
     ///
     /// Initial value: **1_000_000_000**
-    #[cfg(not(feature = "ethexe"))]
     pub system_reserve: u64,
 
     pub(crate) wait_type: WaitType,
@@ -58,7 +56,6 @@ impl Config {
             wait_up_to: 100,
             wait_for: 100,
             mx_lock_duration: 100,
-            #[cfg(not(feature = "ethexe"))]
             system_reserve: SYSTEM_RESERVE,
             wait_type: WaitType::WaitUpTo,
         }
@@ -84,7 +81,6 @@ impl Config {
     }
 
     /// Get the `system_reserve` gas amount.
-    #[cfg(not(feature = "ethexe"))]
     pub fn system_reserve() -> u64 {
         unsafe { CONFIG.system_reserve }
     }
@@ -142,7 +138,6 @@ impl Config {
     }
 
     /// Set `system_reserve` gas amount.
-    #[cfg(not(feature = "ethexe"))]
     pub fn set_system_reserve(amount: u64) -> Result<()> {
         if amount == 0 {
             return Err(Error::Gstd(UsageError::ZeroSystemReservationAmount));
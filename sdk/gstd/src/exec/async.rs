@@ -4,7 +4,11 @@
 //! Module for asynchronous execution control functions which can be used
 //! during message handling.
 
-use crate::{MessageId, async_runtime, msg};
+use crate::{
+    MessageId,
+    async_runtime::{self, Wait},
+    msg,
+};
 use core::{
     future::Future,
     pin::Pin,
@@ -43,6 +47,7 @@ impl Future for MessageSleepFuture {
 
         if current_block_number < self.till_block_number {
             async_runtime::locks().insert_sleep(self.msg_id, self.till_block_number);
+            async_runtime::tracker().track(Wait::Sleep(self.till_block_number));
             Poll::Pending
         } else {
             async_runtime::locks().remove_sleep(self.msg_id, self.till_block_number);
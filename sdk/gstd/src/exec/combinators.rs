@@ -0,0 +1,203 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Support code of the [`select!`](crate::select) and [`join!`](crate::join)
+//! macros.
+//!
+//! Every future passed to the macros is polled as a [`Branch`], which
+//! remembers the replies and sleeps the future waits for. When the branch is
+//! cancelled (it loses the race or the timeout is reached), they are
+//! deregistered from the async runtime, so they neither wake the message
+//! nor execute reply hooks later.
+
+use crate::{
+    BlockCount, async_runtime,
+    async_runtime::Wait,
+    errors::Result,
+    msg,
+    prelude::{Box, Vec},
+    sync::WaitTimeout,
+};
+use core::{
+    future::{self, Future},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The future polled by the combinator.
+#[doc(hidden)]
+pub struct Branch<F: Future> {
+    future: Option<Pin<Box<F>>>,
+    output: Option<F::Output>,
+    waits: Vec<Wait>,
+}
+
+impl<F: Future> Branch<F> {
+    /// Creates a branch polling the `future`.
+    pub fn new(future: F) -> Self {
+        Self {
+            future: Some(Box::pin(future)),
+            output: None,
+            waits: Vec::new(),
+        }
+    }
+
+    /// Takes the output of the completed future.
+    pub fn take_output(&mut self) -> Option<F::Output> {
+        self.output.take()
+    }
+}
+
+#[doc(hidden)]
+pub trait PollBranch {
+    /// Polls the future if it isn't completed yet, returning whether it's
+    /// completed.
+    fn poll_branch(&mut self, cx: &mut Context<'_>) -> bool;
+
+    /// Drops the future if it isn't completed yet, deregistering its waits.
+    fn cancel(&mut self);
+}
+
+impl<F: Future> PollBranch for Branch<F> {
+    fn poll_branch(&mut self, cx: &mut Context<'_>) -> bool {
+        let Some(future) = self.future.as_mut() else {
+            return true;
+        };
+
+        let start = async_runtime::tracker().begin();
+        let poll = future.as_mut().poll(cx);
+        let waits = async_runtime::tracker().end(start);
+
+        match poll {
+            Poll::Ready(output) => {
+                self.future = None;
+                self.output = Some(output);
+                self.waits.clear();
+                true
+            }
+            Poll::Pending => {
+                for wait in waits {
+                    if !self.waits.contains(&wait) {
+                        self.waits.push(wait);
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    fn cancel(&mut self) {
+        if let Some(future) = self.future.take() {
+            drop(future);
+            async_runtime::cancel_waits(&self.waits);
+            self.waits.clear();
+        }
+    }
+}
+
+/// Tuple of [`Branch`]es.
+#[doc(hidden)]
+pub trait Branches {
+    /// Calls `f` for every branch.
+    fn for_each(&mut self, f: &mut dyn FnMut(&mut dyn PollBranch));
+
+    /// Cancels the branches which aren't completed yet.
+    fn cancel_all(&mut self) {
+        self.for_each(&mut |branch| branch.cancel());
+    }
+}
+
+macro_rules! impl_branches {
+    ($($branch:ident . $idx:tt),+) => {
+        impl<$($branch: PollBranch),+> Branches for ($($branch,)+) {
+            fn for_each(&mut self, f: &mut dyn FnMut(&mut dyn PollBranch)) {
+                $(f(&mut self.$idx);)+
+            }
+        }
+    };
+}
+
+impl_branches!(B0.0);
+impl_branches!(B0.0, B1.1);
+impl_branches!(B0.0, B1.1, B2.2);
+impl_branches!(B0.0, B1.1, B2.2, B3.3);
+impl_branches!(B0.0, B1.1, B2.2, B3.3, B4.4);
+impl_branches!(B0.0, B1.1, B2.2, B3.3, B4.4, B5.5);
+impl_branches!(B0.0, B1.1, B2.2, B3.3, B4.4, B5.5, B6.6);
+impl_branches!(B0.0, B1.1, B2.2, B3.3, B4.4, B5.5, B6.6, B7.7);
+
+fn wait_timeout<B: Branches>(
+    branches: &mut B,
+    block_count: Option<BlockCount>,
+) -> Result<WaitTimeout> {
+    let mut timeout = WaitTimeout::new();
+
+    if let Some(block_count) = block_count
+        && let Err(err) = timeout.up_to(block_count)
+    {
+        branches.cancel_all();
+        return Err(err);
+    }
+
+    Ok(timeout)
+}
+
+/// Waits until any of the `branches` completes, cancelling the rest of them.
+///
+/// Returns [`Error::Timeout`](crate::errors::Error::Timeout) and cancels all
+/// branches if none of them completes in `block_count` blocks.
+#[doc(hidden)]
+pub async fn select<B: Branches>(branches: &mut B, block_count: Option<BlockCount>) -> Result<()> {
+    let mut timeout = wait_timeout(branches, block_count)?;
+
+    future::poll_fn(|cx| {
+        // Only one branch completes, otherwise the outputs of the others
+        // would be lost.
+        let mut completed = false;
+        branches.for_each(&mut |branch| {
+            if !completed {
+                completed = branch.poll_branch(cx);
+            }
+        });
+
+        let result = if completed {
+            timeout.clear();
+            Ok(())
+        } else if let Err(err) = timeout.check(msg::id()) {
+            Err(err)
+        } else {
+            return Poll::Pending;
+        };
+
+        branches.cancel_all();
+        Poll::Ready(result)
+    })
+    .await
+}
+
+/// Waits until all of the `branches` complete.
+///
+/// Returns [`Error::Timeout`](crate::errors::Error::Timeout) and cancels the
+/// pending branches if they don't complete in `block_count` blocks.
+#[doc(hidden)]
+pub async fn join<B: Branches>(branches: &mut B, block_count: Option<BlockCount>) -> Result<()> {
+    let mut timeout = wait_timeout(branches, block_count)?;
+
+    future::poll_fn(|cx| {
+        let mut completed = true;
+        branches.for_each(&mut |branch| completed &= branch.poll_branch(cx));
+
+        if completed {
+            timeout.clear();
+            return Poll::Ready(Ok(()));
+        }
+
+        if let Err(err) = timeout.check(msg::id()) {
+            branches.cancel_all();
+            return Poll::Ready(Err(err));
+        }
+
+        Poll::Pending
+    })
+    .await
+}
@@ -5,6 +5,8 @@
 //! execution flow.
 
 pub use r#async::*;
+pub use combinators::*;
 pub use gcore::exec::*;
 
 mod r#async;
+mod combinators;
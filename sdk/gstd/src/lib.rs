@@ -135,35 +135,31 @@ extern crate galloc;
 mod async_runtime;
 mod common;
 mod config;
-#[cfg(not(feature = "ethexe"))]
 pub mod critical;
 pub mod exec;
 mod macros;
 pub mod msg;
 pub mod prelude;
-#[cfg(not(feature = "ethexe"))]
 pub mod prog;
-#[cfg(not(feature = "ethexe"))]
 mod reservations;
 pub mod sync;
 pub mod util;
 
-pub use async_runtime::{handle_reply_with_hook, message_loop};
+pub use async_runtime::{handle_reply_with_hook, handle_signal, message_loop};
 pub use common::errors;
 pub use config::{Config, SYSTEM_RESERVE};
 pub use gcore::{
     ActorId, BlockCount, BlockNumber, CodeId, EnvVars, Gas, GasMultiplier, MessageId, Percent,
-    Ss58Address, Value, debug, static_mut, static_ref,
+    Ss58Address, Value, crypto, debug, hash, static_mut, static_ref,
 };
 #[cfg(target_arch = "wasm32")]
 pub use gcore::{ctor, dtor};
 pub use gstd_codegen::{actor_id, async_init, async_main};
 pub use prelude::*;
 
-#[cfg(not(feature = "ethexe"))]
-pub use {
-    async_runtime::handle_signal, common::primitives_ext::*, gcore::ReservationId, reservations::*,
-};
+pub use common::primitives_ext::*;
+pub use gcore::ReservationId;
+pub use reservations::*;
 
 /// Extensions for additional features.
 pub mod ext {
@@ -0,0 +1,177 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+/// Waits on multiple futures at once, running the branch of the first one to
+/// complete.
+///
+/// Every branch has the `pattern = future => body` form. An optional last
+/// `timeout(block_count) => body` branch runs if none of the futures
+/// completes in `block_count` blocks. Up to 8 futures are supported.
+///
+/// Unlike [`futures::select!`](https://docs.rs/futures/latest/futures/macro.select.html),
+/// the futures that lose the race are cancelled: the replies and sleeps they
+/// have been polled for are deregistered, so they don't wake the message
+/// anymore and their reply hooks are never executed.
+///
+/// # Panics
+///
+/// Panics if `block_count` of the timeout branch is zero.
+///
+/// # Examples
+///
+/// Ask two programs for the price and use the first reply, waiting for it at
+/// most 10 blocks.
+///
+/// ```
+/// use gstd::{ActorId, msg};
+///
+/// #[gstd::async_main]
+/// async fn main() {
+///     # let (first, second) = (ActorId::from(1), ActorId::from(2));
+///     let price = gstd::select! {
+///         reply = msg::send_for_reply_as::<_, u128>(first, "PRICE", 0, 0).unwrap() => reply.ok(),
+///         reply = msg::send_for_reply_as::<_, u128>(second, "PRICE", 0, 0).unwrap() => reply.ok(),
+///         timeout(10) => None,
+///     };
+///
+///     msg::reply(price, 0).expect("Unable to reply");
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! select {
+    (@branches { $( { ($($skip:tt)*) $pat:pat = $fut:expr => $body:block } )+ } { $($unused:tt)* }) => {
+        $crate::select!(@expand
+            { $( { ($($skip)*) $pat = $fut => $body } )+ }
+            ::core::option::Option::None,
+            { ::core::unreachable!("No timeout is set") }
+        )
+    };
+    (@branches { $($done:tt)+ } { $($unused:tt)* } @timeout ($blocks:expr) $timeout_body:block) => {
+        $crate::select!(@expand
+            { $($done)+ }
+            ::core::option::Option::Some($blocks),
+            $timeout_body
+        )
+    };
+    (@expand { $( { ($($skip:tt)*) $pat:pat = $fut:expr => $body:block } )+ } $blocks:expr, $timeout_body:block) => {{
+        let mut __branches = ( $( $crate::exec::Branch::new($fut), )+ );
+
+        match $crate::exec::select(&mut __branches, $blocks).await {
+            ::core::result::Result::Ok(()) => $(
+                if let ::core::option::Option::Some(__output) = {
+                    let ( $($skip,)* __branch, .. ) = &mut __branches;
+                    __branch.take_output()
+                } {
+                    let $pat = __output;
+                    $body
+                } else
+            )+ {
+                ::core::unreachable!("One of the branches is completed")
+            },
+            ::core::result::Result::Err(__err) if __err.timed_out() => $timeout_body,
+            ::core::result::Result::Err(__err) => ::core::panic!("Invalid select timeout: {}", __err),
+        }
+    }};
+
+    // Normalization of branches: every branch gets `_` for each preceding
+    // branch, which is used to find its position in the tuple of futures.
+    (@branches { $($done:tt)* } { $($skip:tt)* } timeout($blocks:expr) => $body:block $(,)?) => {
+        $crate::select!(@branches { $($done)* } { $($skip)* } @timeout ($blocks) $body)
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } timeout($blocks:expr) => $body:expr $(,)?) => {
+        $crate::select!(@branches { $($done)* } { $($skip)* } @timeout ($blocks) { $body })
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } $pat:pat = $fut:expr => $body:block, $($rest:tt)*) => {
+        $crate::select!(@branches { $($done)* { ($($skip)*) $pat = $fut => $body } } { $($skip)* _ } $($rest)*)
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } $pat:pat = $fut:expr => $body:block $($rest:tt)*) => {
+        $crate::select!(@branches { $($done)* { ($($skip)*) $pat = $fut => $body } } { $($skip)* _ } $($rest)*)
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } $pat:pat = $fut:expr => $body:expr, $($rest:tt)*) => {
+        $crate::select!(@branches { $($done)* { ($($skip)*) $pat = $fut => { $body } } } { $($skip)* _ } $($rest)*)
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } $pat:pat = $fut:expr => $body:expr) => {
+        $crate::select!(@branches { $($done)* { ($($skip)*) $pat = $fut => { $body } } } { $($skip)* _ })
+    };
+
+    ($($branches:tt)+) => {
+        $crate::select!(@branches {} {} $($branches)+)
+    };
+}
+
+/// Waits on multiple futures at once, returning the tuple of their outputs
+/// when all of them complete.
+///
+/// If `; timeout(block_count)` follows the futures, the output is wrapped
+/// into [`Result`](crate::errors::Result), which is
+/// [`Error::Timeout`](crate::errors::Error::Timeout) if the futures don't
+/// complete in `block_count` blocks. The pending futures are cancelled then:
+/// the replies and sleeps they have been polled for are deregistered, so they
+/// don't wake the message anymore and their reply hooks are never executed.
+/// Up to 8 futures are supported.
+///
+/// # Examples
+///
+/// Send the request to two programs and wait for both replies at most 10
+/// blocks.
+///
+/// ```
+/// use gstd::{ActorId, msg};
+///
+/// #[gstd::async_main]
+/// async fn main() {
+///     # let (first, second) = (ActorId::from(1), ActorId::from(2));
+///     let (first, second) = gstd::join!(
+///         msg::send_bytes_for_reply(first, b"PING", 0, 0).unwrap(),
+///         msg::send_bytes_for_reply(second, b"PING", 0, 0).unwrap();
+///         timeout(10)
+///     )
+///     .expect("Replies haven't arrived in time");
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! join {
+    (@branches { $( { ($($skip:tt)*) $fut:expr } )+ } { $($unused:tt)* } @timeout) => {{
+        let mut __branches = ( $( $crate::exec::Branch::new($fut), )+ );
+
+        let _ = $crate::exec::join(&mut __branches, ::core::option::Option::None).await;
+
+        $crate::join!(@outputs __branches { $( { ($($skip)*) } )+ })
+    }};
+    (@branches { $( { ($($skip:tt)*) $fut:expr } )+ } { $($unused:tt)* } @timeout ($blocks:expr)) => {{
+        let mut __branches = ( $( $crate::exec::Branch::new($fut), )+ );
+
+        match $crate::exec::join(&mut __branches, ::core::option::Option::Some($blocks)).await {
+            ::core::result::Result::Ok(()) => ::core::result::Result::Ok(
+                $crate::join!(@outputs __branches { $( { ($($skip)*) } )+ })
+            ),
+            ::core::result::Result::Err(__err) => ::core::result::Result::Err(__err),
+        }
+    }};
+    (@outputs $branches:ident { $( { ($($skip:tt)*) } )+ }) => {
+        ( $(
+            {
+                let ( $($skip,)* __branch, .. ) = &mut $branches;
+                __branch.take_output().expect("All branches are completed")
+            },
+        )+ )
+    };
+
+    // Normalization of futures: every future gets `_` for each preceding
+    // future, which is used to find its position in the tuple of futures.
+    (@branches { $($done:tt)* } { $($skip:tt)* } @timeout $(($blocks:expr))?) => {
+        ::core::compile_error!("At least one future must be provided")
+    };
+    (@branches { $($done:tt)* } { $($skip:tt)* } $fut:expr, $($rest:tt)*) => {
+        $crate::join!(@branches { $($done)* { ($($skip)*) $fut } } { $($skip)* _ } $($rest)*)
+    };
+
+    ($($fut:expr),+ $(,)?) => {
+        $crate::join!(@branches {} {} $($fut,)+ @timeout)
+    };
+    ($($fut:expr),+ ; timeout($blocks:expr) $(,)?) => {
+        $crate::join!(@branches {} {} $($fut,)+ @timeout ($blocks))
+    };
+}
@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 mod bail;
+mod combinators;
 mod debug;
@@ -1,11 +1,9 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-#[cfg(not(feature = "ethexe"))]
-use crate::ActorId;
 use crate::{
-    Config, MessageId,
-    async_runtime::{self, Lock, ReplyPoll, signals},
+    ActorId, Config, MessageId,
+    async_runtime::{self, Lock, ReplyPoll, Wait, signals},
     errors::{Error, Result},
     prelude::Vec,
 };
@@ -37,7 +35,10 @@ where
         ReplyPoll::None => panic!(
             "Somebody created a future with the MessageId that never ended in static replies!"
         ),
-        ReplyPoll::Pending => Poll::Pending,
+        ReplyPoll::Pending => {
+            async_runtime::tracker().track(Wait::Reply(waiting_reply_to));
+            Poll::Pending
+        }
         ReplyPoll::Some((payload, reply_code)) => {
             // Remove lock after waking.
             async_runtime::locks().remove(msg_id, waiting_reply_to);
@@ -92,7 +93,6 @@ pub struct CodecMessageFuture<T> {
     pub waiting_reply_to: MessageId,
     /// Reply deposit that was allocated for this message. Checked in
     /// handle_reply.
-    #[cfg_attr(feature = "ethexe", allow(unused))]
     pub(crate) reply_deposit: u64,
     /// Marker
     ///
@@ -146,7 +146,6 @@ impl_futures!(
 /// }
 /// # fn main() {}
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub struct CodecCreateProgramFuture<T> {
     /// A message identifier for an expected reply.
     pub waiting_reply_to: MessageId,
@@ -154,7 +153,6 @@ pub struct CodecCreateProgramFuture<T> {
     pub program_id: ActorId,
     /// Reply deposit that was allocated for this message. Checked in
     /// handle_reply.
-    #[cfg_attr(feature = "ethexe", allow(unused))]
     pub(crate) reply_deposit: u64,
     /// Marker
     ///
@@ -165,7 +163,6 @@ pub struct CodecCreateProgramFuture<T> {
     pub(crate) _marker: PhantomData<T>,
 }
 
-#[cfg(not(feature = "ethexe"))]
 impl_futures!(
     CodecCreateProgramFuture,
     D,
@@ -217,7 +214,6 @@ pub struct MessageFuture {
     pub waiting_reply_to: MessageId,
     /// Reply deposit that was allocated for this message. Checked in
     /// handle_reply.
-    #[cfg_attr(feature = "ethexe", allow(unused))]
     pub(crate) reply_deposit: u64,
 }
 
@@ -261,7 +257,6 @@ impl_futures!(
 /// }
 /// # fn main() {}
 /// ```
-#[cfg(not(feature = "ethexe"))]
 pub struct CreateProgramFuture {
     /// A message identifier for an expected reply.
     pub waiting_reply_to: MessageId,
@@ -269,11 +264,9 @@ pub struct CreateProgramFuture {
     pub program_id: ActorId,
     /// Reply deposit that was allocated for this message. Checked in
     /// handle_reply.
-    #[cfg_attr(feature = "ethexe", allow(unused))]
     pub(crate) reply_deposit: u64,
 }
 
-#[cfg(not(feature = "ethexe"))]
 impl_futures!(
     CreateProgramFuture,
     (ActorId, Vec<u8>),
@@ -1,10 +1,8 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-#[cfg(not(feature = "ethexe"))]
-use crate::ReservationId;
 use crate::{
-    ActorId, MessageId,
+    ActorId, MessageId, ReservationId,
     msg::{self, utils},
     prelude::{Vec, ops::RangeBounds, vec},
 };
@@ -184,7 +182,6 @@ impl MessageHandle {
     ///         .expect("Unable to commit");
     /// }
     /// ```
-    #[cfg(not(feature = "ethexe"))]
     #[wait_for_reply(self)]
     pub fn commit_from_reservation(
         self,
@@ -197,7 +194,6 @@ impl MessageHandle {
 
     /// Same as [`commit_from_reservation`](Self::commit_from_reservation), but
     /// sends the message after the `delay` expressed in block count.
-    #[cfg(not(feature = "ethexe"))]
     pub fn commit_delayed_from_reservation(
         self,
         id: ReservationId,
@@ -319,7 +315,6 @@ pub fn reply_bytes(payload: impl AsRef<[u8]>, value: u128) -> Result<MessageId>
 ///
 /// - [`send_bytes_from_reservation`] function sends a new message to the
 ///   program or user by using gas from a reservation.
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_bytes_from_reservation(
     id: ReservationId,
     payload: impl AsRef<[u8]>,
@@ -410,7 +405,6 @@ pub fn reply_commit(value: u128) -> Result<MessageId> {
 ///
 /// - [`reply_push`] function allows forming a reply message in parts.
 /// - [`ReservationId`] struct allows reserve gas for later use.
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_commit_from_reservation(id: ReservationId, value: u128) -> Result<MessageId> {
     gcore::msg::reply_commit_from_reservation(id, value)
 }
@@ -625,7 +619,6 @@ pub fn send_bytes_with_gas_delayed<T: AsRef<[u8]>>(
 /// - [`MessageHandle::init`], [`MessageHandle::push`], and
 ///   [`MessageHandle::commit`] functions allow forming a message to send in
 ///   parts.
-#[cfg(not(feature = "ethexe"))]
 #[wait_for_reply]
 pub fn send_bytes_from_reservation<T: AsRef<[u8]>>(
     id: ReservationId,
@@ -638,7 +631,6 @@ pub fn send_bytes_from_reservation<T: AsRef<[u8]>>(
 
 /// Same as [`send_bytes_from_reservation`], but sends the message after the
 /// `delay` expressed in block count.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_bytes_delayed_from_reservation<T: AsRef<[u8]>>(
     id: ReservationId,
     program: ActorId,
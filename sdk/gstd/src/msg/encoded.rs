@@ -5,10 +5,8 @@
 //! with messages arguments as with data structure instead of bytes array
 //! decoded/encoded via SCALE Codec (<https://docs.substrate.io/v3/advanced/scale-codec/>).
 
-#[cfg(not(feature = "ethexe"))]
-use crate::ReservationId;
 use crate::{
-    ActorId, MessageId, errors::Error, msg::utils, prelude::ops::RangeBounds,
+    ActorId, MessageId, ReservationId, errors::Error, msg::utils, prelude::ops::RangeBounds,
     util::with_optimized_encode,
 };
 use gcore::errors::Result;
@@ -139,7 +137,6 @@ pub fn reply<E: Encode>(payload: E, value: u128) -> Result<MessageId> {
 ///
 /// - [`send_from_reservation`] function sends a new message to the program or
 ///   user by using gas from a reservation.
-#[cfg(not(feature = "ethexe"))]
 pub fn reply_from_reservation<E: Encode>(
     id: ReservationId,
     payload: E,
@@ -445,7 +442,6 @@ pub fn send_with_gas_delayed<E: Encode>(
 ///   [`MessageHandle::push`](super::MessageHandle::init), and
 ///   [`MessageHandle::commit`](super::MessageHandle::commit) functions allow
 ///   forming a message to send in parts.
-#[cfg(not(feature = "ethexe"))]
 #[wait_for_reply]
 pub fn send_from_reservation<E: Encode>(
     id: ReservationId,
@@ -460,7 +456,6 @@ pub fn send_from_reservation<E: Encode>(
 
 /// Same as [`send_from_reservation`], but sends the message after the `delay`
 /// expressed in block count.
-#[cfg(not(feature = "ethexe"))]
 pub fn send_delayed_from_reservation<E: Encode>(
     id: ReservationId,
     program: ActorId,
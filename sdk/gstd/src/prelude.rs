@@ -6,9 +6,7 @@
 
 // Reexports from Rust's libraries
 
-#[cfg(not(feature = "ethexe"))]
-pub use crate::ReservationIdExt;
-pub use crate::{dbg, static_mut, static_ref};
+pub use crate::{ReservationIdExt, dbg, static_mut, static_ref};
 pub use ::alloc::{
     borrow,
     borrow::ToOwned,
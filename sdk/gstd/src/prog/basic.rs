@@ -40,7 +40,7 @@ pub fn create_program_bytes(
     payload: impl AsRef<[u8]>,
     value: u128,
 ) -> Result<(MessageId, ActorId)> {
-    create_program_bytes_delayed(code_id, salt, payload, value, 0)
+    gcore::prog::create_program(code_id, salt.as_ref(), payload.as_ref(), value)
 }
 
 /// Same as [`create_program_bytes`], but creates a new program after the
@@ -63,6 +63,7 @@ pub fn create_program_bytes(
 ///     msg::send_bytes_delayed(new_program_id, b"PING", 0, 200).expect("Unable to send");
 /// }
 /// ```
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_bytes_delayed(
     code_id: CodeId,
     salt: impl AsRef<[u8]>,
@@ -74,6 +75,7 @@ pub fn create_program_bytes_delayed(
 }
 
 /// Same as [`create_program_bytes`], but with an explicit gas limit.
+#[cfg(not(feature = "ethexe"))]
 #[wait_create_program_for_reply]
 pub fn create_program_bytes_with_gas(
     code_id: CodeId,
@@ -87,6 +89,7 @@ pub fn create_program_bytes_with_gas(
 
 /// Same as [`create_program_bytes_with_gas`], but creates a new program after
 /// the `delay` expressed in block count.
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_bytes_with_gas_delayed(
     code_id: CodeId,
     salt: impl AsRef<[u8]>,
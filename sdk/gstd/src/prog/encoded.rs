@@ -22,6 +22,7 @@ pub fn create_program<E: Encode>(
 
 /// Same as [`create_program`], but creates a new program after the `delay`
 /// expressed in block count.
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_delayed<E: Encode>(
     code_id: CodeId,
     salt: impl AsRef<[u8]>,
@@ -35,6 +36,7 @@ pub fn create_program_delayed<E: Encode>(
 }
 
 /// Same as [`create_program`], but with an explicit gas limit.
+#[cfg(not(feature = "ethexe"))]
 #[wait_create_program_for_reply]
 pub fn create_program_with_gas<E: Encode>(
     code_id: CodeId,
@@ -50,6 +52,7 @@ pub fn create_program_with_gas<E: Encode>(
 
 /// Same as [`create_program_with_gas`], but creates a new program after the
 /// `delay` expressed in block count.
+#[cfg(not(feature = "ethexe"))]
 pub fn create_program_with_gas_delayed<E: Encode>(
     code_id: CodeId,
     salt: impl AsRef<[u8]>,
@@ -77,11 +77,12 @@ impl ProgramGenerator {
         payload: impl AsRef<[u8]>,
         value: u128,
     ) -> Result<(MessageId, ActorId)> {
-        Self::create_program_bytes_delayed(code_id, payload, value, 0)
+        prog::create_program_bytes(code_id, Self::get_salt(), payload, value)
     }
 
     /// Same as [`create_program_bytes`](Self::create_program_bytes), but
     /// creates a new program after the `delay` expressed in block count.
+    #[cfg(not(feature = "ethexe"))]
     pub fn create_program_bytes_delayed(
         code_id: CodeId,
         payload: impl AsRef<[u8]>,
@@ -93,6 +94,7 @@ impl ProgramGenerator {
 
     /// Same as [`create_program_bytes`](Self::create_program_bytes), but with
     /// an explicit gas limit.
+    #[cfg(not(feature = "ethexe"))]
     #[wait_create_program_for_reply(Self)]
     pub fn create_program_bytes_with_gas(
         code_id: CodeId,
@@ -105,6 +107,7 @@ impl ProgramGenerator {
 
     /// Same as [`create_program_bytes_with_gas`](Self::create_program_bytes_with_gas), but
     /// creates a new program after the `delay` expressed in block count.
+    #[cfg(not(feature = "ethexe"))]
     pub fn create_program_bytes_with_gas_delayed(
         code_id: CodeId,
         payload: impl AsRef<[u8]>,
@@ -137,6 +140,7 @@ impl ProgramGenerator {
 
     /// Same as [`create_program`](Self::create_program), but creates a new
     /// program after the `delay` expressed in block count.
+    #[cfg(not(feature = "ethexe"))]
     pub fn create_program_delayed<E: Encode>(
         code_id: CodeId,
         payload: E,
@@ -150,6 +154,7 @@ impl ProgramGenerator {
 
     /// Same as [`create_program`](Self::create_program), but with an explicit
     /// gas limit.
+    #[cfg(not(feature = "ethexe"))]
     #[wait_create_program_for_reply(Self)]
     pub fn create_program_with_gas<E: Encode>(
         code_id: CodeId,
@@ -164,6 +169,7 @@ impl ProgramGenerator {
 
     /// Same as [`create_program_with_gas`](Self::create_program_with_gas), but
     /// creates a new program after the `delay` expressed in block count.
+    #[cfg(not(feature = "ethexe"))]
     pub fn create_program_with_gas_delayed<E: Encode>(
         code_id: CodeId,
         payload: E,
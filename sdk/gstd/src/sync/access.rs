@@ -3,7 +3,12 @@
 
 //! This module gives mechanism of waking for async lockers.
 
-use crate::{MessageId, collections::VecDeque};
+use crate::{
+    BlockCount, BlockNumber, MessageId, async_runtime,
+    collections::VecDeque,
+    errors::{CoreError, Error, ExtError, MessageError, Result, UsageError},
+    exec, msg,
+};
 use core::cell::UnsafeCell;
 
 // Option<VecDeque> to make new `const fn`
@@ -23,6 +28,19 @@ impl AccessQueue {
         inner.as_mut().and_then(|v| v.pop_front())
     }
 
+    pub fn remove(&self, message_id: &MessageId) -> bool {
+        let inner = unsafe { &mut *self.0.get() };
+
+        inner
+            .as_mut()
+            .and_then(|v| {
+                v.iter()
+                    .position(|id| id == message_id)
+                    .map(|i| v.remove(i))
+            })
+            .is_some()
+    }
+
     pub fn contains(&self, message_id: &MessageId) -> bool {
         let inner = unsafe { &*self.0.get() };
 
@@ -45,3 +63,101 @@ impl AccessQueue {
         AccessQueue(UnsafeCell::new(None))
     }
 }
+
+/// Wakes the message waiting for a synchronization object.
+///
+/// The waiting message may be the current one, if it awaits several futures
+/// at once (e.g. with `join!`). It isn't in the waitlist then, so its future
+/// is polled again instead.
+pub fn wake(message_id: MessageId) {
+    if message_id == msg::id() {
+        async_runtime::repoll();
+        return;
+    }
+
+    match exec::wake(message_id) {
+        // The message has been already woken by another waiter.
+        Ok(()) | Err(CoreError::Ext(ExtError::Message(MessageError::DuplicateWaking))) => {}
+        Err(err) => panic!("Failed to wake the message: {err}"),
+    }
+}
+
+static mut NEXT_WAITER_ID: WaiterId = WaiterId(0);
+
+/// Identifier of a future waiting for a synchronization object.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub(crate) struct WaiterId(u32);
+
+impl WaiterId {
+    fn next() -> Self {
+        unsafe {
+            let id = NEXT_WAITER_ID;
+            NEXT_WAITER_ID = WaiterId(id.0.wrapping_add(1));
+            id
+        }
+    }
+}
+
+/// Timeout of a future waiting for a synchronization object.
+///
+/// Until the timeout is set, the future waits as long as it takes.
+pub struct WaitTimeout {
+    id: WaiterId,
+    deadline: Option<BlockNumber>,
+    // The message which will be woken up at the deadline.
+    locked: Option<MessageId>,
+}
+
+impl WaitTimeout {
+    pub fn new() -> Self {
+        Self {
+            id: WaiterId::next(),
+            deadline: None,
+            locked: None,
+        }
+    }
+
+    /// Sets the timeout in `block_count` blocks from the current one.
+    pub fn up_to(&mut self, block_count: BlockCount) -> Result<()> {
+        if block_count == 0 {
+            return Err(Error::Gstd(UsageError::EmptyWaitDuration));
+        }
+
+        self.deadline = Some(exec::block_height().saturating_add(block_count));
+        Ok(())
+    }
+
+    /// Returns [`Error::Timeout`] if the deadline is reached, otherwise
+    /// makes sure the waiting message will be woken up at the deadline.
+    pub fn check(&mut self, message_id: MessageId) -> Result<()> {
+        let Some(deadline) = self.deadline else {
+            return Ok(());
+        };
+
+        let now = exec::block_height();
+        if now >= deadline {
+            self.clear();
+            return Err(Error::Timeout(deadline, now));
+        }
+
+        if self.locked.is_none() {
+            async_runtime::locks().insert_sync_timeout(message_id, self.id, deadline);
+            self.locked = Some(message_id);
+        }
+
+        Ok(())
+    }
+
+    /// Stops waiting for the deadline.
+    pub fn clear(&mut self) {
+        if let Some(message_id) = self.locked.take() {
+            async_runtime::locks().remove_sync_timeout(message_id, self.id);
+        }
+    }
+}
+
+impl Drop for WaitTimeout {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -12,15 +12,32 @@
 //! - [`RwLock`]: Provides a mutual exclusion mechanism that allows multiple
 //!   readings by different actors while allowing only one writer at the
 //!   execution. In some cases, this can be more efficient than a mutex.
+//! - [`Semaphore`]: Limits the number of actors accessing some resource at
+//!   the same time, e.g. the number of messages awaiting replies from other
+//!   programs.
+//! - [`mpsc`]: A multi-producer, single-consumer channel for passing values
+//!   between messages being processed concurrently.
+//! - [`oneshot`]: A channel for passing a single value between messages.
+//!
+//! Waiting for a [`Semaphore`] permit or a channel value can be limited by
+//! a number of blocks, after which the future outputs
+//! [`Error::Timeout`](crate::errors::Error::Timeout).
 
 mod access;
 
+pub mod mpsc;
 mod mutex;
+pub mod oneshot;
 mod rwlock;
+mod semaphore;
 
 pub use self::{
     mutex::{Mutex, MutexGuard, MutexLockFuture},
     rwlock::{RwLock, RwLockReadFuture, RwLockReadGuard, RwLockWriteFuture, RwLockWriteGuard},
+    semaphore::{Semaphore, SemaphoreAcquireFuture, SemaphorePermit},
 };
 
-pub(crate) use self::mutex::MutexId;
+pub(crate) use self::{
+    access::{WaitTimeout, WaiterId},
+    mutex::MutexId,
+};
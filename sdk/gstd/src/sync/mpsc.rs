@@ -0,0 +1,252 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! A multi-producer, single-consumer queue for sending values between
+//! asynchronous tasks of the program.
+//!
+//! The channel is created with the [`channel`] function, which returns the
+//! [`Sender`] and [`Receiver`] halves. Senders can be cloned and used by
+//! any number of messages, while the receiver awaits values with
+//! [`Receiver::recv`]. Sending never waits, because the channel is unbounded.
+//!
+//! # Examples
+//!
+//! This program collects replies from several programs: every `PING`
+//! message waits for its reply and sends it to the channel, while the
+//! `COLLECT` message receives the replies in the order they arrive.
+//!
+//! ```ignored
+//! use gstd::{
+//!     msg,
+//!     prelude::*,
+//!     sync::mpsc::{self, Receiver, Sender},
+//!     ActorId,
+//! };
+//!
+//! static mut CHANNEL: Option<(Sender<Vec<u8>>, Receiver<Vec<u8>>)> = None;
+//!
+//! #[unsafe(no_mangle)]
+//! extern "C" fn init() {
+//!     unsafe { CHANNEL = Some(mpsc::channel()) };
+//! }
+//!
+//! #[gstd::async_main]
+//! async fn main() {
+//!     let (tx, rx) = unsafe { static_mut!(CHANNEL).as_mut().unwrap() };
+//!     let (command, destination): (String, ActorId) =
+//!         msg::load().expect("Unable to decode payload");
+//!
+//!     match command.as_str() {
+//!         "PING" => {
+//!             let reply = msg::send_bytes_for_reply(destination, b"PING", 0, 0)
+//!                 .expect("Unable to send bytes")
+//!                 .await
+//!                 .expect("Error in async message processing");
+//!             tx.send(reply).expect("Receiver is never dropped");
+//!         }
+//!         "COLLECT" => {
+//!             let reply = rx
+//!                 .recv()
+//!                 .up_to(10)
+//!                 .expect("Invalid wait duration")
+//!                 .await
+//!                 .expect("No reply in 10 blocks")
+//!                 .expect("Sender is never dropped");
+//!             msg::reply_bytes(reply, 0).expect("Unable to reply");
+//!         }
+//!         _ => unreachable!(),
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use super::access::{self, WaitTimeout};
+use crate::{BlockCount, MessageId, collections::VecDeque, errors::Result, msg, prelude::rc::Rc};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+struct Shared<T> {
+    values: RefCell<VecDeque<T>>,
+    senders: Cell<usize>,
+    receiver_alive: Cell<bool>,
+    // The message awaiting the receiver.
+    waiting: Cell<Option<MessageId>>,
+}
+
+impl<T> Shared<T> {
+    fn wake_receiver(&self) {
+        if let Some(message_id) = self.waiting.take() {
+            access::wake(message_id);
+        }
+    }
+}
+
+/// Creates a new unbounded channel, returning the sender and receiver
+/// halves.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(Shared {
+        values: RefCell::new(VecDeque::new()),
+        senders: Cell::new(1),
+        receiver_alive: Cell::new(true),
+        waiting: Cell::new(None),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending half of the channel, created by the [`channel`] function.
+///
+/// Can be cloned to send values from several places. The receiver gets
+/// `None` once all senders are dropped.
+pub struct Sender<T> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends the `value` to the channel, waking up the message awaiting the
+    /// receiver.
+    ///
+    /// Returns the `value` back if the receiver has been dropped.
+    pub fn send(&self, value: T) -> Result<(), T> {
+        if !self.shared.receiver_alive.get() {
+            return Err(value);
+        }
+
+        self.shared.values.borrow_mut().push_back(value);
+        self.shared.wake_receiver();
+
+        Ok(())
+    }
+
+    /// Checks whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.receiver_alive.get()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.set(self.shared.senders.get() + 1);
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let senders = self.shared.senders.get() - 1;
+        self.shared.senders.set(senders);
+
+        if senders == 0 {
+            self.shared.wake_receiver();
+        }
+    }
+}
+
+/// The receiving half of the channel, created by the [`channel`] function.
+pub struct Receiver<T> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the next value from the channel, waiting until it's sent if
+    /// the channel is empty.
+    ///
+    /// The future outputs `None` if the channel is empty and all senders
+    /// have been dropped.
+    pub fn recv(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture {
+            receiver: self,
+            timeout: WaitTimeout::new(),
+        }
+    }
+
+    /// Receives the next value from the channel if there is any, without
+    /// waiting.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.shared.values.borrow_mut().pop_front()
+    }
+
+    /// Returns the number of values in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.values.borrow().len()
+    }
+
+    /// Checks whether the channel is empty.
+    pub fn is_empty(&self) -> bool {
+        self.shared.values.borrow().is_empty()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_alive.set(false);
+        self.shared.values.borrow_mut().clear();
+    }
+}
+
+/// The future returned by the [`recv`](Receiver::recv) method.
+///
+/// The output of the future is the received value, `None` if all senders
+/// have been dropped, or [`Error::Timeout`](crate::errors::Error::Timeout)
+/// if the timeout set with [`up_to`](Self::up_to) is reached.
+pub struct RecvFuture<'a, T> {
+    receiver: &'a mut Receiver<T>,
+    timeout: WaitTimeout,
+}
+
+impl<T> RecvFuture<'_, T> {
+    /// Sets the maximum number of blocks to wait for a value.
+    pub fn up_to(mut self, block_count: BlockCount) -> Result<Self> {
+        self.timeout.up_to(block_count)?;
+        Ok(self)
+    }
+}
+
+impl<T> Future for RecvFuture<'_, T> {
+    type Output = Result<Option<T>>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let shared = &this.receiver.shared;
+
+        if let Some(value) = shared.values.borrow_mut().pop_front() {
+            shared.waiting.set(None);
+            this.timeout.clear();
+            return Poll::Ready(Ok(Some(value)));
+        }
+
+        if shared.senders.get() == 0 {
+            shared.waiting.set(None);
+            this.timeout.clear();
+            return Poll::Ready(Ok(None));
+        }
+
+        let current_msg_id = msg::id();
+        if let Err(err) = this.timeout.check(current_msg_id) {
+            shared.waiting.set(None);
+            return Poll::Ready(Err(err));
+        }
+
+        shared.waiting.set(Some(current_msg_id));
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for RecvFuture<'_, T> {
+    fn drop(&mut self) {
+        self.receiver.shared.waiting.set(None);
+    }
+}
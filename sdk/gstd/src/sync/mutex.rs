@@ -176,12 +176,7 @@ impl<T> MutexGuard<'_, T> {
 
 impl<T> Drop for MutexGuard<'_, T> {
     fn drop(&mut self) {
-        let is_holder_msg_signal_handler = match () {
-            #[cfg(not(feature = "ethexe"))]
-            () => msg::signal_from() == Ok(self.holder_msg_id),
-            #[cfg(feature = "ethexe")]
-            () => false,
-        };
+        let is_holder_msg_signal_handler = msg::signal_from() == Ok(self.holder_msg_id);
 
         if !is_holder_msg_signal_handler {
             self.ensure_access_by_holder();
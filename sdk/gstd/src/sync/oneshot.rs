@@ -0,0 +1,209 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! A channel for sending a single value between asynchronous tasks of the
+//! program.
+//!
+//! The channel is created with the [`channel`] function, which returns the
+//! [`Sender`] and [`Receiver`] halves. The sender is consumed by sending
+//! the value, and the receiver awaits it with [`Receiver::recv`].
+//!
+//! # Examples
+//!
+//! This program makes the `WAIT` message wait until the `NOTIFY` message
+//! provides a value for it, for at most 10 blocks.
+//!
+//! ```ignored
+//! use gstd::{
+//!     msg,
+//!     prelude::*,
+//!     sync::oneshot::{self, Sender},
+//! };
+//!
+//! static mut SENDER: Option<Sender<u32>> = None;
+//!
+//! #[gstd::async_main]
+//! async fn main() {
+//!     let command: String = msg::load().expect("Unable to decode payload");
+//!
+//!     match command.as_str() {
+//!         "WAIT" => {
+//!             let (tx, rx) = oneshot::channel();
+//!             unsafe { SENDER = Some(tx) };
+//!
+//!             let value = rx
+//!                 .recv()
+//!                 .up_to(10)
+//!                 .expect("Invalid wait duration")
+//!                 .await
+//!                 .expect("No value in 10 blocks")
+//!                 .expect("Sender is never dropped");
+//!             msg::reply(value, 0).expect("Unable to reply");
+//!         }
+//!         "NOTIFY" => {
+//!             let tx = unsafe { static_mut!(SENDER).take() }.expect("Nobody is waiting");
+//!             let _ = tx.send(42);
+//!         }
+//!         _ => unreachable!(),
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use super::access::{self, WaitTimeout};
+use crate::{BlockCount, MessageId, errors::Result, msg, prelude::rc::Rc};
+use core::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+struct Shared<T> {
+    value: Cell<Option<T>>,
+    sender_alive: Cell<bool>,
+    receiver_alive: Cell<bool>,
+    // The message awaiting the receiver.
+    waiting: Cell<Option<MessageId>>,
+}
+
+impl<T> Shared<T> {
+    fn wake_receiver(&self) {
+        if let Some(message_id) = self.waiting.take() {
+            access::wake(message_id);
+        }
+    }
+}
+
+/// Creates a new oneshot channel, returning the sender and receiver halves.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(Shared {
+        value: Cell::new(None),
+        sender_alive: Cell::new(true),
+        receiver_alive: Cell::new(true),
+        waiting: Cell::new(None),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending half of the channel, created by the [`channel`] function.
+pub struct Sender<T> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends the `value` to the channel, waking up the message awaiting the
+    /// receiver.
+    ///
+    /// Returns the `value` back if the receiver has been dropped.
+    pub fn send(self, value: T) -> Result<(), T> {
+        if !self.shared.receiver_alive.get() {
+            return Err(value);
+        }
+
+        self.shared.value.set(Some(value));
+
+        Ok(())
+    }
+
+    /// Checks whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.receiver_alive.get()
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // The receiver is woken either to take the value or to find out
+        // that it's never going to be sent.
+        self.shared.sender_alive.set(false);
+        self.shared.wake_receiver();
+    }
+}
+
+/// The receiving half of the channel, created by the [`channel`] function.
+pub struct Receiver<T> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the value, waiting until it's sent.
+    ///
+    /// The future outputs `None` if the sender has been dropped without
+    /// sending the value.
+    pub fn recv(self) -> RecvFuture<T> {
+        RecvFuture {
+            receiver: self,
+            timeout: WaitTimeout::new(),
+        }
+    }
+
+    /// Receives the value if it has been sent, without waiting.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.shared.value.take()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_alive.set(false);
+        self.shared.waiting.set(None);
+        self.shared.value.take();
+    }
+}
+
+/// The future returned by the [`recv`](Receiver::recv) method.
+///
+/// The output of the future is the received value, `None` if the sender
+/// has been dropped without sending it, or
+/// [`Error::Timeout`](crate::errors::Error::Timeout) if the timeout set with
+/// [`up_to`](Self::up_to) is reached.
+pub struct RecvFuture<T> {
+    receiver: Receiver<T>,
+    timeout: WaitTimeout,
+}
+
+impl<T> RecvFuture<T> {
+    /// Sets the maximum number of blocks to wait for the value.
+    pub fn up_to(mut self, block_count: BlockCount) -> Result<Self> {
+        self.timeout.up_to(block_count)?;
+        Ok(self)
+    }
+}
+
+impl<T> Future for RecvFuture<T> {
+    type Output = Result<Option<T>>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let shared = &this.receiver.shared;
+
+        if let Some(value) = shared.value.take() {
+            shared.waiting.set(None);
+            this.timeout.clear();
+            return Poll::Ready(Ok(Some(value)));
+        }
+
+        if !shared.sender_alive.get() {
+            shared.waiting.set(None);
+            this.timeout.clear();
+            return Poll::Ready(Ok(None));
+        }
+
+        let current_msg_id = msg::id();
+        if let Err(err) = this.timeout.check(current_msg_id) {
+            shared.waiting.set(None);
+            return Poll::Ready(Err(err));
+        }
+
+        shared.waiting.set(Some(current_msg_id));
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use super::access::{self, AccessQueue, WaitTimeout};
+use crate::{BlockCount, MessageId, errors::Result, msg};
+use core::{
+    cell::Cell,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A counting semaphore, limiting the number of actors accessing some
+/// resource at the same time.
+///
+/// The semaphore holds a number of permits, which can be acquired with the
+/// [`acquire`](Semaphore::acquire) method. If there are no permits left, the
+/// message goes to the wait state until another actor releases its permit by
+/// dropping the [`SemaphorePermit`].
+///
+/// # Examples
+///
+/// This program relays the `PING` message to another program, but allows
+/// at most 3 messages waiting for the reply at the same time. The rest of
+/// them wait for a free permit up to 10 blocks.
+///
+/// ```ignored
+/// use gstd::{msg, sync::Semaphore, ActorId};
+///
+/// static mut DEST: ActorId = ActorId::zero();
+/// static SEMAPHORE: Semaphore = Semaphore::new(3);
+///
+/// #[unsafe(no_mangle)]
+/// extern "C" fn init() {
+///     // `some_address` can be obtained from the init payload
+///     # let some_address = ActorId::zero();
+///     unsafe { DEST = some_address };
+/// }
+///
+/// #[gstd::async_main]
+/// async fn main() {
+///     let _permit = SEMAPHORE
+///         .acquire()
+///         .up_to(10)
+///         .expect("Invalid wait duration")
+///         .await
+///         .expect("Failed to acquire a permit in time");
+///
+///     let reply = msg::send_bytes_for_reply(unsafe { DEST }, b"PING", 0, 0)
+///         .expect("Unable to send bytes")
+///         .await
+///         .expect("Error in async message processing");
+///
+///     msg::reply_bytes(reply, 0).expect("Unable to reply");
+/// }
+/// # fn main() {}
+/// ```
+pub struct Semaphore {
+    permits: Cell<u32>,
+    queue: AccessQueue,
+}
+
+impl Semaphore {
+    /// Create a new semaphore with the given number of permits.
+    pub const fn new(permits: u32) -> Self {
+        Semaphore {
+            permits: Cell::new(permits),
+            queue: AccessQueue::new(),
+        }
+    }
+
+    /// Returns the number of permits which can be acquired right now.
+    pub fn available_permits(&self) -> u32 {
+        self.permits.get()
+    }
+
+    /// Adds `n` new permits to the semaphore, waking up the messages waiting
+    /// for them.
+    pub fn add_permits(&self, n: u32) {
+        self.permits.set(self.permits.get().saturating_add(n));
+        self.wake_waiters();
+    }
+
+    /// Acquire a permit, waiting until one is released if there are no
+    /// permits left.
+    ///
+    /// The permit is returned back to the semaphore when the
+    /// [`SemaphorePermit`] is dropped.
+    pub fn acquire(&self) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture {
+            semaphore: self,
+            waiting: None,
+            timeout: WaitTimeout::new(),
+        }
+    }
+
+    /// Acquire a permit if there is any left, without waiting.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.take_permit()
+    }
+
+    fn take_permit(&self) -> Option<SemaphorePermit<'_>> {
+        let permits = self.permits.get().checked_sub(1)?;
+        self.permits.set(permits);
+
+        Some(SemaphorePermit { semaphore: self })
+    }
+
+    // Wakes up as many waiting messages as there are available permits.
+    // Woken messages take the permits on their own when they are executed.
+    fn wake_waiters(&self) {
+        for _ in 0..self.permits.get() {
+            let Some(message_id) = self.queue.dequeue() else {
+                break;
+            };
+
+            access::wake(message_id);
+        }
+    }
+}
+
+// we are always single-threaded
+unsafe impl Sync for Semaphore {}
+
+/// A permit acquired from the [`Semaphore`]. When this structure is dropped
+/// (falls out of scope), the permit is returned back to the semaphore.
+///
+/// Unlike the [`MutexGuard`](super::MutexGuard), the permit isn't bound to
+/// the message that acquired it, so it may be stored and dropped by another
+/// message.
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl SemaphorePermit<'_> {
+    /// Consumes the permit without returning it back to the semaphore.
+    pub fn forget(self) {
+        mem::forget(self);
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(1);
+    }
+}
+
+/// The future returned by the [`acquire`](Semaphore::acquire) method.
+///
+/// The output of the future is the [`SemaphorePermit`] that can be obtained
+/// by using `await` syntax, or [`Error::Timeout`](crate::errors::Error::Timeout)
+/// if the timeout set with [`up_to`](Self::up_to) is reached.
+///
+/// # Examples
+///
+/// ```
+/// use gstd::sync::{Semaphore, SemaphoreAcquireFuture, SemaphorePermit};
+///
+/// #[gstd::async_main]
+/// async fn main() {
+///     let semaphore: Semaphore = Semaphore::new(1);
+///     let future: SemaphoreAcquireFuture = semaphore.acquire();
+///     let permit: SemaphorePermit = future.await.expect("No timeout is set");
+///     assert_eq!(semaphore.available_permits(), 0);
+///     drop(permit);
+///     assert_eq!(semaphore.available_permits(), 1);
+/// }
+/// # fn main() {}
+/// ```
+pub struct SemaphoreAcquireFuture<'a> {
+    semaphore: &'a Semaphore,
+    // The message that is in the semaphore queue on behalf of this future.
+    waiting: Option<MessageId>,
+    timeout: WaitTimeout,
+}
+
+impl SemaphoreAcquireFuture<'_> {
+    /// Sets the maximum number of blocks to wait for a permit.
+    pub fn up_to(mut self, block_count: BlockCount) -> Result<Self> {
+        self.timeout.up_to(block_count)?;
+        Ok(self)
+    }
+
+    fn stop_waiting(&mut self) {
+        if let Some(message_id) = self.waiting.take() {
+            self.semaphore.queue.remove(&message_id);
+        }
+        self.timeout.clear();
+    }
+}
+
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = Result<SemaphorePermit<'a>>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if let Some(permit) = this.semaphore.take_permit() {
+            this.stop_waiting();
+            return Poll::Ready(Ok(permit));
+        }
+
+        let current_msg_id = msg::id();
+        if let Err(err) = this.timeout.check(current_msg_id) {
+            this.stop_waiting();
+            return Poll::Ready(Err(err));
+        }
+
+        // The message may be already in the queue if it has been woken up by
+        // the timeout or by another waiter of the same message.
+        if !this.semaphore.queue.contains(&current_msg_id) {
+            this.semaphore.queue.enqueue(current_msg_id);
+        }
+        this.waiting = Some(current_msg_id);
+
+        Poll::Pending
+    }
+}
+
+impl Drop for SemaphoreAcquireFuture<'_> {
+    fn drop(&mut self) {
+        if let Some(message_id) = self.waiting.take()
+            && !self.semaphore.queue.remove(&message_id)
+        {
+            // The message has been woken up to take a permit, but it's not
+            // going to take it anymore, so somebody else should be woken.
+            self.semaphore.wake_waiters();
+        }
+    }
+}
@@ -51,6 +51,32 @@ pub type SignalCode = u32;
 /// Represents value type.
 pub type Value = u128;
 
+/// Represents ed25519 or sr25519 public key type.
+pub type PublicKey = [u8; 32];
+
+/// Represents ed25519 or sr25519 signature type.
+pub type Signature = [u8; 64];
+
+/// Represents uncompressed secp256k1 public key type, without `0x04` prefix.
+pub type EcdsaPublicKey = [u8; 64];
+
+/// Represents type defining recoverable secp256k1 signature: `r`, `s` and
+/// recovery id `v`. 65 bytes.
+///
+/// `v` may be either `0`/`1` or Ethereum-style `27`/`28`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+pub struct EcdsaSignature {
+    pub rs: Signature,
+    pub v: u8,
+}
+
+impl EcdsaSignature {
+    pub const fn as_ptr(&self) -> *const Self {
+        self as _
+    }
+}
+
 /// Represents type defining concatenated block number with hash. 36 bytes.
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy, Zeroable, Pod)]
@@ -478,7 +504,6 @@ syscalls! {
     /// - `delay`: `u32` amount of blocks to delay.
     /// - `err_mid_pid`: `mut ptr` for concatenated error code, message id
     ///   and program id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_create_program(
         cid_value: *const HashWithValue,
         salt: *const SizedBufferStart,
@@ -495,7 +520,6 @@ syscalls! {
     /// - `message_id`: `const ptr` for message id.
     /// - `gas`: `u64` defining gas limit to deposit.
     /// - `err`: `mut ptr` for error code.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reply_deposit(message_id: *const Hash, gas: Gas, err: *mut ErrorCode);
 
     /// Infallible `gr_debug` info syscall.
@@ -527,7 +551,6 @@ syscalls! {
     ///
     /// Arguments type:
     /// - `err_code`: `mut ptr` for concatenated error code and signal code.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_signal_code(err_code: *mut ErrorWithSignalCode);
 
     /// Infallible `gr_exit` control syscall.
@@ -562,9 +585,86 @@ syscalls! {
     /// Arguments type:
     /// - `subject`: `const ptr` for the subject.
     /// - `bn_random`: `mut ptr` for concatenated block number with hash.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_random(subject: *const Hash, bn_random: *mut BlockNumberWithHash);
 
+    /// Infallible `gr_blake2b_256` calculate syscall.
+    ///
+    /// Arguments type:
+    /// - `data`: `const ptr` for the begging of the data buffer.
+    /// - `len`: `u32` length of the data buffer.
+    /// - `hash`: `mut ptr` for the BLAKE2b-256 hash of the data.
+    pub fn gr_blake2b_256(data: *const SizedBufferStart, len: Length, hash: *mut Hash);
+
+    /// Infallible `gr_keccak256` calculate syscall.
+    ///
+    /// Arguments type:
+    /// - `data`: `const ptr` for the begging of the data buffer.
+    /// - `len`: `u32` length of the data buffer.
+    /// - `hash`: `mut ptr` for the Keccak-256 hash of the data.
+    pub fn gr_keccak256(data: *const SizedBufferStart, len: Length, hash: *mut Hash);
+
+    /// Infallible `gr_sha256` calculate syscall.
+    ///
+    /// Arguments type:
+    /// - `data`: `const ptr` for the begging of the data buffer.
+    /// - `len`: `u32` length of the data buffer.
+    /// - `hash`: `mut ptr` for the SHA-256 hash of the data.
+    pub fn gr_sha256(data: *const SizedBufferStart, len: Length, hash: *mut Hash);
+
+    /// Infallible `gr_ed25519_verify` calculate syscall.
+    ///
+    /// Arguments type:
+    /// - `public_key`: `const ptr` for the ed25519 public key.
+    /// - `signature`: `const ptr` for the ed25519 signature.
+    /// - `message`: `const ptr` for the begging of the message buffer.
+    /// - `message_len`: `u32` length of the message buffer.
+    /// - `verified`: `mut ptr` for `u8`, set to `1` if the signature is
+    ///   valid and to `0` otherwise.
+    #[cfg(not(feature = "ethexe"))]
+    pub fn gr_ed25519_verify(
+        public_key: *const PublicKey,
+        signature: *const Signature,
+        message: *const SizedBufferStart,
+        message_len: Length,
+        verified: *mut u8,
+    );
+
+    /// Infallible `gr_sr25519_verify` calculate syscall.
+    ///
+    /// Uses `substrate` signing context, same as Substrate accounts do.
+    ///
+    /// Arguments type:
+    /// - `public_key`: `const ptr` for the sr25519 public key.
+    /// - `signature`: `const ptr` for the sr25519 signature.
+    /// - `message`: `const ptr` for the begging of the message buffer.
+    /// - `message_len`: `u32` length of the message buffer.
+    /// - `verified`: `mut ptr` for `u8`, set to `1` if the signature is
+    ///   valid and to `0` otherwise.
+    #[cfg(not(feature = "ethexe"))]
+    pub fn gr_sr25519_verify(
+        public_key: *const PublicKey,
+        signature: *const Signature,
+        message: *const SizedBufferStart,
+        message_len: Length,
+        verified: *mut u8,
+    );
+
+    /// Infallible `gr_secp256k1_recover` calculate syscall.
+    ///
+    /// Arguments type:
+    /// - `message_hash`: `const ptr` for the 32 bytes hash of the signed message.
+    /// - `signature`: `const ptr` for the recoverable secp256k1 signature.
+    /// - `public_key`: `mut ptr` for the recovered uncompressed public key.
+    ///   Left untouched if recovery fails.
+    /// - `recovered`: `mut ptr` for `u8`, set to `1` if the public key was
+    ///   recovered and to `0` otherwise.
+    pub fn gr_secp256k1_recover(
+        message_hash: *const Hash,
+        signature: *const EcdsaSignature,
+        public_key: *mut EcdsaPublicKey,
+        recovered: *mut u8,
+    );
+
     // TODO: issue #1859
     /// Fallible `gr_read` get syscall.
     ///
@@ -619,7 +719,6 @@ syscalls! {
     ///
     /// Arguments type:
     /// - `err_mid`: `mut ptr` for concatenated error code and message id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_signal_from(err_mid: *mut ErrorWithHash);
 
     /// Fallible `gr_reply_input_wgas` send syscall.
@@ -693,7 +792,6 @@ syscalls! {
     /// Arguments type:
     /// - `rid_value`: `const ptr` for concatenated reservation id and value.
     /// - `err_mid`: `mut ptr` for concatenated error code and message id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reservation_reply_commit(
         rid_value: *const HashWithValue,
         err_mid: *mut ErrorWithHash,
@@ -706,7 +804,6 @@ syscalls! {
     /// - `payload`: `const ptr` for the begging of the payload buffer.
     /// - `len`: `u32` length of the payload buffer.
     /// - `err_mid`: `mut ptr` for concatenated error code and message id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reservation_reply(
         rid_value: *const HashWithValue,
         payload: *const SizedBufferStart,
@@ -722,7 +819,6 @@ syscalls! {
     ///   program id and value.
     /// - `delay`: `u32` amount of blocks to delay.
     /// - `err_mid`: `mut ptr` for concatenated error code and message id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reservation_send_commit(
         handle: Handle,
         rid_pid_value: *const TwoHashesWithValue,
@@ -739,7 +835,6 @@ syscalls! {
     /// - `len`: `u32` length of the payload buffer.
     /// - `delay`: `u32` amount of blocks to delay.
     /// - `err_mid`: `mut ptr` for concatenated error code and message id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reservation_send(
         rid_pid_value: *const TwoHashesWithValue,
         payload: *const SizedBufferStart,
@@ -754,7 +849,6 @@ syscalls! {
     /// - `gas`: `u64` defining amount of gas to reserve.
     /// - `duration`: `u32` reservation duration.
     /// - `err_rid`: `mut ptr` for concatenated error code and reservation id.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_reserve_gas(gas: Gas, duration: BlockNumber, err_rid: *mut ErrorWithHash);
 
     /// Fallible `gr_send_commit_wgas` send syscall.
@@ -904,7 +998,6 @@ syscalls! {
     /// Arguments type:
     /// - `gas`: `u64` defining amount of gas to reserve.
     /// - `err`: `mut ptr` for error code.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_system_reserve_gas(gas: Gas, err: *mut ErrorCode);
 
     /// Fallible `gr_unreserve_gas` control syscall.
@@ -913,7 +1006,6 @@ syscalls! {
     /// - `reservation_id`: `const ptr` for reservation id.
     /// - `err_unreserved`: `mut ptr` for concatenated error code and
     ///   unreserved gas amount.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_unreserve_gas(reservation_id: *const Hash, err_unreserved: *mut ErrorWithGas);
 
     /// Infallible `gr_value_available` get syscall.
@@ -941,7 +1033,6 @@ syscalls! {
     pub fn gr_wait_up_to(duration: BlockNumber) -> !;
 
     /// Infallible `gr_wait` control syscall.
-    #[cfg(not(feature = "ethexe"))]
     pub fn gr_wait() -> !;
 
     /// Fallible `gr_wake` control syscall.
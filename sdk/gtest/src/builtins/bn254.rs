@@ -0,0 +1,188 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! BN254 builtin actor implementation.
+//!
+//! Requests to the actor are processed by `builtins_common::bn254::execute_bn254_builtins`
+//! with the gas costs defined in this module.
+
+pub use builtins_common::bn254::{Request as Bn254Request, Response as Bn254Response};
+
+use builtins_common::bn254::Bn254OpsGasCost;
+use gear_core::ids::ActorId;
+
+/// The id of the BN254 builtin actor.
+pub const BN254_ID: ActorId = ActorId::new(*b"modl/bia/bn254/v-\x01\0/\0\0\0\0\0\0\0\0\0\0\0\0");
+
+pub(crate) struct Bn254OpsGasCostsImpl;
+
+impl Bn254OpsGasCost for Bn254OpsGasCostsImpl {
+    fn decode_bytes(_len: u32) -> u64 {
+        0
+    }
+
+    fn bn254_multi_miller_loop(_count: u32) -> u64 {
+        0
+    }
+
+    fn bn254_final_exponentiation() -> u64 {
+        0
+    }
+
+    fn bn254_addition_g1() -> u64 {
+        0
+    }
+
+    fn bn254_mul_projective_g1(_count: u32) -> u64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_USER_ALICE, Log, Program, System};
+    use ark_bn254::{Bn254, Fr, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2};
+    use ark_ec::{Group, pairing::Pairing, short_weierstrass::SWCurveConfig};
+    use ark_ff::{UniformRand, biginteger::BigInt};
+    use ark_scale::{
+        hazmat::ArkScaleProjective,
+        scale::{Decode, Encode},
+    };
+    use ark_std::test_rng;
+    use builtins_common::bn254::{ark_bn254, ark_ec, ark_ff, ark_scale};
+    use demo_constructor::{Arg, Call, Calls, Scheme, WASM_BINARY};
+    use std::ops::Mul;
+
+    type ArkScaleLocal<T> = ark_scale::ArkScale<T, { ark_scale::HOST_CALL }>;
+
+    // Sends the request to the builtin through a proxy program and returns the reply.
+    fn send_request(builtin_req: Bn254Request) -> Bn254Response {
+        let sys = System::new();
+
+        let alice_id = ActorId::from(DEFAULT_USER_ALICE);
+        let proxy_pid = ActorId::new([3; 32]);
+
+        let proxy_scheme = Scheme::predefined(
+            // init: do nothing
+            Calls::builder().noop(),
+            // handle: send message to bn254 builtin
+            Calls::builder().add_call(Call::Send(
+                Arg::new(BN254_ID.into_bytes()),
+                Arg::new(builtin_req.encode()),
+                None,
+                Arg::new(0u128),
+                Arg::new(0u32),
+            )),
+            // handle_reply: load reply payload and forward it to original sender
+            Calls::builder()
+                .add_call(Call::LoadBytes)
+                .add_call(Call::StoreVec("reply_payload".to_string()))
+                .add_call(Call::Send(
+                    Arg::new(alice_id.into_bytes()),
+                    Arg::get("reply_payload"),
+                    Some(Arg::new(0)),
+                    Arg::new(0u128),
+                    Arg::new(0u32),
+                )),
+            // handle_signal: noop
+            Calls::builder(),
+        );
+
+        let proxy_program = Program::from_binary_with_id(&sys, proxy_pid, WASM_BINARY);
+
+        let init_mid = proxy_program.send(alice_id, proxy_scheme);
+        let res = sys.run_next_block();
+        assert!(res.succeed.contains(&init_mid));
+
+        let mid = proxy_program.send_bytes(alice_id, b"");
+        let res = sys.run_next_block();
+        assert!(res.succeed.contains(&mid));
+        assert!(res.contains(&Log::builder().source(proxy_pid).dest(alice_id)));
+
+        let mut logs = res.decoded_log::<Bn254Response>();
+        logs.pop().expect("no log found").payload().clone()
+    }
+
+    #[test]
+    fn test_pairing() {
+        let mut rng = test_rng();
+
+        let a: G1Affine = G1::rand(&mut rng).into();
+        let scalar = Fr::rand(&mut rng);
+        let b: G2Affine = G2::generator().mul(scalar).into();
+
+        let loop_result = <Bn254 as Pairing>::multi_miller_loop(vec![a], vec![b]);
+        let expected = <Bn254 as Pairing>::final_exponentiation(loop_result);
+
+        let ark_a: ArkScaleLocal<Vec<G1Affine>> = vec![a].into();
+        let ark_b: ArkScaleLocal<Vec<G2Affine>> = vec![b].into();
+        let Bn254Response::MultiMillerLoop(f) = send_request(Bn254Request::MultiMillerLoop {
+            a: ark_a.encode(),
+            b: ark_b.encode(),
+        }) else {
+            panic!("unexpected response");
+        };
+
+        let actual = ArkScaleLocal::<<Bn254 as Pairing>::TargetField>::decode(&mut f.as_ref())
+            .expect("failed to decode result");
+        assert_eq!(actual.0, loop_result.0);
+
+        let Bn254Response::FinalExponentiation(result_bytes) =
+            send_request(Bn254Request::FinalExponentiation { f })
+        else {
+            panic!("unexpected response");
+        };
+
+        let actual =
+            ArkScaleLocal::<<Bn254 as Pairing>::TargetField>::decode(&mut result_bytes.as_ref())
+                .expect("failed to decode result");
+        assert!(matches!(expected, Some(inner) if inner.0 == actual.0));
+    }
+
+    #[test]
+    fn test_addition_g1() {
+        let mut rng = test_rng();
+
+        let a = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+
+        let ark_a: ArkScaleProjective<G1> = a.into();
+        let ark_b: ArkScaleProjective<G1> = b.into();
+        let Bn254Response::AdditionG1(result_bytes) = send_request(Bn254Request::AdditionG1 {
+            a: ark_a.encode(),
+            b: ark_b.encode(),
+        }) else {
+            panic!("unexpected response");
+        };
+
+        let actual = ArkScaleProjective::<G1>::decode(&mut result_bytes.as_ref())
+            .expect("failed to decode result");
+        assert_eq!(actual.0, a + b);
+    }
+
+    #[test]
+    fn test_projective_multiplication_g1() {
+        let mut rng = test_rng();
+
+        let bigint = BigInt::<4>::rand(&mut rng).0.to_vec();
+        let base = G1::rand(&mut rng);
+
+        let expected = <ark_bn254::g1::Config as SWCurveConfig>::mul_projective(&base, &bigint);
+
+        let ark_bigint: ArkScaleLocal<Vec<u64>> = bigint.into();
+        let ark_base: ArkScaleProjective<G1> = base.into();
+        let Bn254Response::ProjectiveMultiplicationG1(result_bytes) =
+            send_request(Bn254Request::ProjectiveMultiplicationG1 {
+                base: ark_base.encode(),
+                scalar: ark_bigint.encode(),
+            })
+        else {
+            panic!("unexpected response");
+        };
+
+        let actual = ArkScaleProjective::<G1>::decode(&mut result_bytes.as_ref())
+            .expect("failed to decode result");
+        assert_eq!(actual.0, expected);
+    }
+}
@@ -4,10 +4,16 @@
 //! Builtin actors implementations for gtest.
 
 mod bls12_381;
+mod bn254;
 mod eth_bridge;
+mod staking;
 
 pub use bls12_381::{BLS12_381_ID, Bls12_381Request, Bls12_381Response};
+pub use bn254::{BN254_ID, Bn254Request, Bn254Response};
 pub use eth_bridge::{ETH_BRIDGE_ID, EthBridgeRequest, EthBridgeResponse};
+pub use staking::{STAKING_ID, StakingRequest, StakingResponse};
 
 pub(crate) use bls12_381::BlsOpsGasCostsImpl;
+pub(crate) use bn254::Bn254OpsGasCostsImpl;
 pub(crate) use eth_bridge::process_eth_bridge_dispatch;
+pub(crate) use staking::process_staking_dispatch;
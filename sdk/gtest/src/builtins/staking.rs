@@ -0,0 +1,454 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Staking builtin actor implementation.
+//!
+//! The main function the module is `process_staking_dispatch` which
+//! processes incoming dispatches to the staking builtin actor.
+//!
+//! gtest has no validator set, so the staking state is a simplified emulation
+//! of the staking pallet: funds are bookkept in ledgers without being locked,
+//! eras are derived from the block height, any account can be nominated,
+//! no rewards are paid and no slashes happen.
+
+pub use gbuiltin_staking::{Request as StakingRequest, Response as StakingResponse};
+
+use crate::{
+    BLOCK_DURATION_IN_MSECS, BONDING_DURATION, ERA_DURATION_IN_BLOCKS, EXISTENTIAL_DEPOSIT,
+    MAX_NOMINATIONS, MAX_UNLOCKING_CHUNKS,
+    state::{accounts::Accounts, staking::StakingBuiltinStorage},
+};
+use builtins_common::BuiltinActorError;
+use gbuiltin_staking::{ActiveEraInfo, LedgerInfo, NominationsInfo, UnlockChunk};
+use gear_core::{ids::ActorId, limited::LimitedStr, message::StoredDispatch};
+use gear_core_processor::configs::BlockInfo;
+use parity_scale_codec::Decode;
+
+/// The id of the staking builtin actor.
+pub const STAKING_ID: ActorId = ActorId::new(*b"modl/bia/staking/v-\x01\0/\0\0\0\0\0\0\0\0\0\0");
+
+/// Processes a dispatch message sent to the staking builtin actor.
+///
+/// Returns `None` for requests that reply with an empty payload.
+pub(crate) fn process_staking_dispatch(
+    dispatch: &StoredDispatch,
+    block_info: BlockInfo,
+) -> Result<Option<StakingResponse>, BuiltinActorError> {
+    let source = dispatch.source();
+    let mut payload = dispatch.payload_bytes();
+    let request =
+        StakingRequest::decode(&mut payload).map_err(|_| BuiltinActorError::DecodingError)?;
+
+    let current_era = block_info.height / ERA_DURATION_IN_BLOCKS;
+
+    if request.is_query() {
+        return query(request, source, current_era, block_info).map(Some);
+    }
+
+    match request {
+        StakingRequest::Bond { value, .. } => {
+            if StakingBuiltinStorage::ledger(source).is_some() {
+                return Err(error("AlreadyBonded"));
+            }
+
+            let value = value.min(Accounts::balance(source));
+            if value < EXISTENTIAL_DEPOSIT {
+                return Err(error("InsufficientBond"));
+            }
+
+            StakingBuiltinStorage::set_ledger(
+                source,
+                LedgerInfo {
+                    stash: source,
+                    total: value,
+                    active: value,
+                    unlocking: vec![],
+                },
+            );
+        }
+        StakingRequest::BondExtra { value } => {
+            let mut ledger = ledger_of(source)?;
+
+            let extra = value.min(Accounts::balance(source).saturating_sub(ledger.total));
+            ledger.total += extra;
+            ledger.active += extra;
+
+            StakingBuiltinStorage::set_ledger(source, ledger);
+        }
+        StakingRequest::Unbond { value } => {
+            let mut ledger = ledger_of(source)?;
+
+            let mut value = value.min(ledger.active);
+            if value == 0 {
+                return Ok(None);
+            }
+
+            let era = current_era + BONDING_DURATION;
+            let unlocking = &mut ledger.unlocking;
+            let merge_into_last = unlocking.last().is_some_and(|chunk| chunk.era == era);
+            if !merge_into_last && unlocking.len() >= MAX_UNLOCKING_CHUNKS {
+                return Err(error("NoMoreChunks"));
+            }
+
+            ledger.active -= value;
+            // Avoid leaving a dust amount at stake
+            if ledger.active < EXISTENTIAL_DEPOSIT {
+                value += ledger.active;
+                ledger.active = 0;
+            }
+
+            match unlocking.last_mut() {
+                Some(chunk) if merge_into_last => chunk.value += value,
+                _ => unlocking.push(UnlockChunk { value, era }),
+            }
+
+            StakingBuiltinStorage::set_ledger(source, ledger);
+        }
+        StakingRequest::WithdrawUnbonded { .. } => {
+            let mut ledger = ledger_of(source)?;
+
+            let (withdrawn, unlocking): (Vec<_>, Vec<_>) = ledger
+                .unlocking
+                .into_iter()
+                .partition(|chunk| chunk.era <= current_era);
+            ledger.total -= withdrawn.iter().map(|chunk| chunk.value).sum::<u128>();
+            ledger.unlocking = unlocking;
+
+            if ledger.unlocking.is_empty() && ledger.active < EXISTENTIAL_DEPOSIT {
+                StakingBuiltinStorage::kill_stash(source);
+            } else {
+                StakingBuiltinStorage::set_ledger(source, ledger);
+            }
+        }
+        StakingRequest::Nominate { targets } => {
+            ledger_of(source)?;
+
+            if targets.is_empty() {
+                return Err(error("EmptyTargets"));
+            }
+            if targets.len() > MAX_NOMINATIONS {
+                return Err(error("TooManyTargets"));
+            }
+
+            let mut targets = targets;
+            targets.sort();
+            targets.dedup();
+
+            StakingBuiltinStorage::set_nominations(
+                source,
+                Some(NominationsInfo {
+                    targets,
+                    submitted_in: current_era,
+                    suppressed: false,
+                }),
+            );
+        }
+        StakingRequest::Chill => {
+            ledger_of(source)?;
+
+            StakingBuiltinStorage::set_nominations(source, None);
+        }
+        StakingRequest::PayoutStakers { .. } => {
+            // There are no validators in gtest.
+            return Err(error("NotStash"));
+        }
+        StakingRequest::Rebond { value } => {
+            let mut ledger = ledger_of(source)?;
+
+            if ledger.unlocking.is_empty() {
+                return Err(error("NoUnlockChunk"));
+            }
+
+            // Rebond the most recently unlocking funds first
+            let mut remaining = value;
+            while remaining > 0 {
+                let Some(chunk) = ledger.unlocking.last_mut() else {
+                    break;
+                };
+
+                let rebonded = chunk.value.min(remaining);
+                chunk.value -= rebonded;
+                ledger.active += rebonded;
+                remaining -= rebonded;
+
+                if chunk.value == 0 {
+                    ledger.unlocking.pop();
+                }
+            }
+
+            StakingBuiltinStorage::set_ledger(source, ledger);
+        }
+        StakingRequest::SetPayee { .. } => {
+            // Rewards are never paid in gtest, so the destination isn't stored.
+            ledger_of(source)?;
+        }
+        _ => unreachable!("Queries are handled separately"),
+    }
+
+    Ok(None)
+}
+
+/// Answers a read-only request to the staking builtin actor.
+fn query(
+    request: StakingRequest,
+    source: ActorId,
+    current_era: u32,
+    block_info: BlockInfo,
+) -> Result<StakingResponse, BuiltinActorError> {
+    let response = match request {
+        StakingRequest::ActiveEra => {
+            let era_start_offset =
+                (block_info.height % ERA_DURATION_IN_BLOCKS) as u64 * BLOCK_DURATION_IN_MSECS;
+
+            StakingResponse::ActiveEra {
+                info: ActiveEraInfo {
+                    index: current_era,
+                    start: Some(block_info.timestamp.saturating_sub(era_start_offset)),
+                },
+                executed_at: block_info.height,
+                executed_at_gear_block: block_info.height,
+            }
+        }
+        StakingRequest::Ledger => StakingResponse::Ledger {
+            ledger: StakingBuiltinStorage::ledger(source),
+        },
+        StakingRequest::UnlockingChunks => StakingResponse::UnlockingChunks {
+            chunks: StakingBuiltinStorage::ledger(source)
+                .map(|ledger| ledger.unlocking)
+                .unwrap_or_default(),
+            current_era: Some(current_era),
+        },
+        StakingRequest::Nominations => StakingResponse::Nominations {
+            nominations: StakingBuiltinStorage::nominations(source),
+        },
+        // No validators are rewarded in gtest.
+        StakingRequest::EraRewardPoints { era } => StakingResponse::EraRewardPoints {
+            era,
+            total: 0,
+            individual: vec![],
+        },
+        // No one is slashed in gtest.
+        StakingRequest::SlashingSpans => StakingResponse::SlashingSpans { spans: None },
+        _ => unreachable!("Only queries are handled here"),
+    };
+
+    Ok(response)
+}
+
+fn ledger_of(stash: ActorId) -> Result<LedgerInfo, BuiltinActorError> {
+    StakingBuiltinStorage::ledger(stash).ok_or_else(|| error("NotController"))
+}
+
+fn error(msg: &'static str) -> BuiltinActorError {
+    BuiltinActorError::Custom(LimitedStr::from_small_str(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_USER_ALICE, Program, System, UNITS};
+    use demo_constructor::{Arg, Call, Calls, Scheme, WASM_BINARY};
+    use parity_scale_codec::Encode;
+
+    fn forwarding_proxy(sys: &System) -> Program<'_> {
+        let alice_actor_id = ActorId::from(DEFAULT_USER_ALICE);
+        let proxy_program_id = ActorId::new([4; 32]);
+
+        let proxy_scheme = Scheme::predefined(
+            // init: do nothing
+            Calls::builder().noop(),
+            // handle: forward the payload to the staking builtin
+            Calls::builder()
+                .add_call(Call::LoadBytes)
+                .add_call(Call::StoreVec("request".to_string()))
+                .add_call(Call::Send(
+                    Arg::new(STAKING_ID.into_bytes()),
+                    Arg::get("request"),
+                    None,
+                    Arg::new(0u128),
+                    Arg::new(0u32),
+                )),
+            // handle_reply: load reply payload and forward it to original sender
+            Calls::builder()
+                .add_call(Call::LoadBytes)
+                .add_call(Call::StoreVec("reply_payload".to_string()))
+                .add_call(Call::Send(
+                    Arg::new(alice_actor_id.into_bytes()),
+                    Arg::get("reply_payload"),
+                    Some(Arg::new(0)),
+                    Arg::new(0u128),
+                    Arg::new(0u32),
+                )),
+            // handle_signal: noop
+            Calls::builder(),
+        );
+
+        let proxy_program = Program::from_binary_with_id(sys, proxy_program_id, WASM_BINARY);
+        let init_mid = proxy_program.send(alice_actor_id, proxy_scheme);
+        let res = sys.run_next_block();
+        assert!(res.succeed.contains(&init_mid));
+
+        sys.transfer(alice_actor_id, proxy_program_id, 1_000 * UNITS, true);
+
+        proxy_program
+    }
+
+    fn send_request(sys: &System, proxy: &Program<'_>, request: StakingRequest) -> Vec<u8> {
+        let mid = proxy.send_bytes(DEFAULT_USER_ALICE, request.encode());
+        let res = sys.run_next_block();
+        assert!(res.succeed.contains(&mid));
+
+        res.log().last().expect("no log found").payload().to_vec()
+    }
+
+    fn query(sys: &System, proxy: &Program<'_>, request: StakingRequest) -> StakingResponse {
+        let payload = send_request(sys, proxy, request);
+        StakingResponse::decode(&mut payload.as_slice()).expect("failed to decode response")
+    }
+
+    #[test]
+    fn test_staking_ledger_queries() {
+        let sys = System::new();
+        let proxy = forwarding_proxy(&sys);
+
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::Ledger),
+            StakingResponse::Ledger { ledger: None }
+        );
+
+        let payload = send_request(
+            &sys,
+            &proxy,
+            StakingRequest::Bond {
+                value: 100 * UNITS,
+                payee: gbuiltin_staking::RewardAccount::Program,
+            },
+        );
+        assert!(payload.is_empty());
+
+        send_request(&sys, &proxy, StakingRequest::Unbond { value: 30 * UNITS });
+
+        let current_era = sys.block_height() / ERA_DURATION_IN_BLOCKS;
+        let unlocking = vec![UnlockChunk {
+            value: 30 * UNITS,
+            era: current_era + BONDING_DURATION,
+        }];
+
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::Ledger),
+            StakingResponse::Ledger {
+                ledger: Some(LedgerInfo {
+                    stash: proxy.id(),
+                    total: 100 * UNITS,
+                    active: 70 * UNITS,
+                    unlocking: unlocking.clone(),
+                })
+            }
+        );
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::UnlockingChunks),
+            StakingResponse::UnlockingChunks {
+                chunks: unlocking,
+                current_era: Some(current_era),
+            }
+        );
+
+        // Rebonding moves the unlocking funds back at stake
+        send_request(&sys, &proxy, StakingRequest::Rebond { value: 10 * UNITS });
+        let StakingResponse::Ledger {
+            ledger: Some(ledger),
+        } = query(&sys, &proxy, StakingRequest::Ledger)
+        else {
+            panic!("ledger should exist");
+        };
+        assert_eq!(ledger.active, 80 * UNITS);
+        assert_eq!(ledger.unlocking[0].value, 20 * UNITS);
+    }
+
+    #[test]
+    fn test_staking_nominations_queries() {
+        let sys = System::new();
+        let proxy = forwarding_proxy(&sys);
+        let targets = vec![ActorId::new([1; 32]), ActorId::new([2; 32])];
+
+        // Nominating requires bonding first
+        let payload = send_request(
+            &sys,
+            &proxy,
+            StakingRequest::Nominate {
+                targets: targets.clone(),
+            },
+        );
+        assert_eq!(payload, b"NotController");
+
+        send_request(
+            &sys,
+            &proxy,
+            StakingRequest::Bond {
+                value: 100 * UNITS,
+                payee: gbuiltin_staking::RewardAccount::Program,
+            },
+        );
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::Nominations),
+            StakingResponse::Nominations { nominations: None }
+        );
+
+        send_request(
+            &sys,
+            &proxy,
+            StakingRequest::Nominate {
+                targets: targets.clone(),
+            },
+        );
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::Nominations),
+            StakingResponse::Nominations {
+                nominations: Some(NominationsInfo {
+                    targets,
+                    submitted_in: sys.block_height() / ERA_DURATION_IN_BLOCKS,
+                    suppressed: false,
+                })
+            }
+        );
+
+        send_request(&sys, &proxy, StakingRequest::Chill);
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::Nominations),
+            StakingResponse::Nominations { nominations: None }
+        );
+    }
+
+    #[test]
+    fn test_staking_era_queries() {
+        let sys = System::new();
+        let proxy = forwarding_proxy(&sys);
+
+        sys.run_to_block(ERA_DURATION_IN_BLOCKS + 5);
+
+        let StakingResponse::ActiveEra {
+            info,
+            executed_at,
+            executed_at_gear_block,
+        } = query(&sys, &proxy, StakingRequest::ActiveEra)
+        else {
+            panic!("unexpected response");
+        };
+        assert_eq!(info.index, 1);
+        assert_eq!(executed_at, executed_at_gear_block);
+        assert_eq!(executed_at, sys.block_height());
+
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::EraRewardPoints { era: 0 }),
+            StakingResponse::EraRewardPoints {
+                era: 0,
+                total: 0,
+                individual: vec![],
+            }
+        );
+        assert_eq!(
+            query(&sys, &proxy, StakingRequest::SlashingSpans),
+            StakingResponse::SlashingSpans { spans: None }
+        );
+    }
+}
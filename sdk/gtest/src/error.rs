@@ -66,6 +66,10 @@ pub enum TestError {
     /// Searching gbuild artifact failed.
     #[error("Reading of program state failed: `{0}`")]
     GbuildArtifactNotFound(String),
+
+    /// Writing of the gas profile failed.
+    #[error("Writing of the gas profile failed: `{0}`")]
+    GasProfileWriteError(String),
 }
 
 macro_rules! usage_panic {
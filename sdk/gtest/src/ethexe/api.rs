@@ -11,6 +11,7 @@ use crate::{
         accounts::Accounts,
         bridge::BridgeBuiltinStorage,
         programs::{GTestProgram, PLACEHOLDER_MESSAGE_ID, ProgramsStorageManager},
+        staking::StakingBuiltinStorage,
     },
 };
 use gear_common::Origin;
@@ -34,6 +35,15 @@ use std::{
 use tracing_subscriber::EnvFilter;
 
 fn store_original_code(manager: &mut ExtManager, code_id: CodeId, code: Vec<u8>) {
+    // Valid codes are also instrumented for ethexe, so programs could create programs with them.
+    if let Some((instrumented_code, code_metadata)) =
+        ProgramBuilder::try_build_ethexe_instrumented_code(code.clone())
+    {
+        manager
+            .ethexe_mut()
+            .store_code(code_id, instrumented_code, code_metadata);
+    }
+
     manager.opt_binaries.insert(code_id, code);
 }
 
@@ -353,6 +363,7 @@ impl Drop for System {
         ProgramsStorageManager::clear();
         Accounts::clear();
         BridgeBuiltinStorage::clear();
+        StakingBuiltinStorage::clear();
     }
 }
 
@@ -482,6 +493,13 @@ impl ProgramBuilder {
     pub(crate) fn build_ethexe_instrumented_code(
         original_code: Vec<u8>,
     ) -> (InstrumentedCode, CodeMetadata) {
+        Self::try_build_ethexe_instrumented_code(original_code)
+            .expect("Failed to create ethexe Program from provided code")
+    }
+
+    pub(crate) fn try_build_ethexe_instrumented_code(
+        original_code: Vec<u8>,
+    ) -> Option<(InstrumentedCode, CodeMetadata)> {
         let schedule = Schedule::default();
         let code = Code::try_new(
             original_code,
@@ -493,10 +511,10 @@ impl ProgramBuilder {
             schedule.limits.parameters.into(),
             SyscallKind::Eth,
         )
-        .expect("Failed to create ethexe Program from provided code");
+        .ok()?;
 
         let (_, instrumented_code, code_metadata) = code.into_parts();
-        (instrumented_code, code_metadata)
+        Some((instrumented_code, code_metadata))
     }
 }
 
@@ -699,12 +717,21 @@ impl<'a> Program<'a> {
         self.id
     }
 
-    /// Ethexe gtest currently does not support state reads.
-    pub fn read_state_bytes(&self, _payload: Vec<u8>) -> Result<Vec<u8>> {
-        usage_panic!("Program state reads are not supported in ethexe gtest mode");
+    /// Reads the program's state by calling its `state` export with the
+    /// given payload.
+    ///
+    /// The export is executed against the program's current ethexe state
+    /// without modifying it.
+    pub fn read_state_bytes(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
+        let manager = self.manager.borrow();
+        manager
+            .ethexe()
+            .read_state_bytes(self.id, payload, manager.blocks_manager.get())
     }
 
-    /// Ethexe gtest currently does not support state reads.
+    /// Reads the program's state and decodes it into `D`.
+    ///
+    /// See [`Program::read_state_bytes`] for details.
     pub fn read_state<D: Decode, P: Encode>(&self, payload: P) -> Result<D> {
         let state_bytes = self.read_state_bytes(payload.encode())?;
         D::decode(&mut state_bytes.as_ref()).map_err(Into::into)
@@ -57,6 +57,15 @@ impl EthexeBackend {
             },
         );
         self.code_ids.insert(program_id, code_id);
+        self.store_code(code_id, instrumented_code, code_metadata);
+    }
+
+    pub(crate) fn store_code(
+        &mut self,
+        code_id: CodeId,
+        instrumented_code: InstrumentedCode,
+        code_metadata: CodeMetadata,
+    ) {
         self.instrumented_codes.insert(code_id, instrumented_code);
         self.code_metadata.insert(code_id, code_metadata);
     }
@@ -3,21 +3,24 @@
 
 use super::{backend::EthexeBackend, runtime::GTestEthexeRuntime};
 use crate::{
-    Gas,
+    Gas, MAX_USER_GAS_LIMIT, Result, TestError,
     log::{BlockRunResult, CoreLog},
+    state::blocks,
 };
 use ethexe_common::{
     PromisePolicy, StateHashWithQueueSize,
     gear::{CHUNK_PROCESSING_GAS_LIMIT, MessageType},
 };
 use ethexe_runtime_common::{
-    BlockInfo, InBlockTransitions, JournalHandler, MAX_CALL_REPLIES_PER_RUN,
+    BlockInfo, Ext, InBlockTransitions, JournalHandler, MAX_CALL_REPLIES_PER_RUN,
     MAX_OUTGOING_MESSAGES_BYTES_PER_RUN, MAX_OUTGOING_MESSAGES_PER_RUN, ProcessQueueContext,
-    RuntimeQueueReport, ScheduleHandler, TransitionController, TransitionsConfig,
-    process_queue_with_report, state::Storage,
+    RuntimeInterface, RuntimeQueueReport, ScheduleHandler, TransitionController, TransitionsConfig,
+    process_queue_with_report,
+    state::{ActiveProgram, Program, Storage},
 };
-use gear_core::{gas::GasAllowanceCounter, ids::ActorId};
+use gear_core::{code::SyscallKind, gas::GasAllowanceCounter, ids::ActorId};
 use gear_core_processor::common::DispatchOutcome;
+use gprimitives::H256;
 
 // Keep this local to avoid depending on `ethexe-processor`.
 const DEFAULT_CHUNK_SIZE: usize = 16;
@@ -30,6 +33,8 @@ impl EthexeBackend {
         allowance: Gas,
     ) -> BlockRunResult {
         let block_info = BlockInfo { height, timestamp };
+        // Reuse the epoch randomness managed by `BlocksManager` as the block seed.
+        let random_seed = H256::from_slice(&blocks::current_epoch_random());
         let cfg = TransitionsConfig {
             block_height: height,
             ..Default::default()
@@ -47,6 +52,7 @@ impl EthexeBackend {
             &mut transitions,
             MessageType::Injected,
             block_info,
+            random_seed,
             &mut gas_allowance,
             &mut result,
         );
@@ -56,6 +62,7 @@ impl EthexeBackend {
                 &mut transitions,
                 MessageType::Canonical,
                 block_info,
+                random_seed,
                 &mut gas_allowance,
                 &mut result,
             );
@@ -71,6 +78,7 @@ impl EthexeBackend {
         let finalized = transitions.finalize();
         self.states = finalized.states;
         self.schedule = finalized.schedule;
+        self.code_ids.extend(finalized.program_creations);
         result.gas_allowance_spent = allowance.saturating_sub(gas_allowance.left());
 
         result
@@ -104,6 +112,73 @@ impl EthexeBackend {
         result
     }
 
+    /// Executes the program's `state` export against its latest ethexe state.
+    ///
+    /// Memory pages are read through the lazy pages bound to the program
+    /// state hash; any changes made during execution are discarded.
+    pub(crate) fn read_state_bytes(
+        &self,
+        program_id: ActorId,
+        payload: Vec<u8>,
+        block_info: BlockInfo,
+    ) -> Result<Vec<u8>> {
+        let state_hash = self
+            .states
+            .get(&program_id)
+            .ok_or(TestError::ActorNotFound(program_id))?
+            .hash;
+        let state = self
+            .storage
+            .program_state(state_hash)
+            .unwrap_or_else(|| panic!("ethexe state for {program_id:?} is missing"));
+
+        let Program::Active(ActiveProgram {
+            allocations_hash,
+            memory_infix,
+            initialized: true,
+            ..
+        }) = state.program
+        else {
+            return Err(TestError::ActorIsNotExecutable(program_id));
+        };
+
+        let code_id = self
+            .code_ids
+            .get(&program_id)
+            .unwrap_or_else(|| panic!("missing ethexe code id for program {program_id:?}"));
+        let instrumented_code = self
+            .instrumented_codes
+            .get(code_id)
+            .unwrap_or_else(|| panic!("missing ethexe instrumented code {code_id:?}"))
+            .clone();
+        let code_metadata = self
+            .code_metadata
+            .get(code_id)
+            .unwrap_or_else(|| panic!("missing ethexe code metadata {code_id:?}"))
+            .clone();
+
+        let runtime = GTestEthexeRuntime::new(&self.storage, state_hash);
+        let allocations = allocations_hash.map_or_default(|hash| {
+            runtime
+                .allocations(hash)
+                .expect("missing ethexe program allocations")
+        });
+        runtime.init_lazy_pages();
+
+        gear_core_processor::informational::execute_for_reply::<Ext<GTestEthexeRuntime>, _>(
+            String::from("state"),
+            instrumented_code,
+            code_metadata,
+            Some(allocations.into()),
+            Some((program_id, memory_infix)),
+            payload,
+            MAX_USER_GAS_LIMIT,
+            block_info,
+            SyscallKind::Eth,
+        )
+        .map_err(TestError::ReadStateError)
+    }
+
     fn process_scheduled_tasks(&self, transitions: &mut InBlockTransitions) {
         let tasks = transitions.take_actual_tasks();
         let mut handler = ScheduleHandler {
@@ -123,6 +198,7 @@ impl EthexeBackend {
         transitions: &mut InBlockTransitions,
         queue_type: MessageType,
         block_info: BlockInfo,
+        random_seed: H256,
         gas_allowance: &mut GasAllowanceCounter,
         result: &mut BlockRunResult,
     ) -> bool {
@@ -147,7 +223,8 @@ impl EthexeBackend {
                 let mut reports_empty = true;
 
                 for (program_id, state) in chunk {
-                    let (instrumented_code, code_metadata) = self.program_code(program_id);
+                    let (instrumented_code, code_metadata) =
+                        self.program_code(transitions, program_id);
                     let runtime = GTestEthexeRuntime::new(&self.storage, state.hash);
                     let (journals, gas_spent, report) = process_queue_with_report(
                         ProcessQueueContext {
@@ -159,8 +236,10 @@ impl EthexeBackend {
                             code: Some((instrumented_code, code_metadata)),
                             gas_allowance: GasAllowanceCounter::new(chunk_allowance),
                             block_info,
+                            random_seed,
                             // gtest currently models promise syscalls as unavailable in ethexe mode.
                             promise_policy: PromisePolicy::Disabled,
+                            record_messages: false,
                         },
                         &runtime,
                     );
@@ -204,6 +283,9 @@ impl EthexeBackend {
                             outgoing_messages_limiter: &mut outgoing_messages_limiter,
                             outgoing_messages_bytes_limiter: &mut outgoing_messages_bytes_limiter,
                             call_reply_limiter: &mut call_reply_limiter,
+                            is_code_valid: Box::new(|code_id| {
+                                self.instrumented_codes.contains_key(&code_id)
+                            }),
                         };
 
                         gear_core_processor::handle_journal(journal, &mut journal_handler);
@@ -231,14 +313,16 @@ impl EthexeBackend {
 
     fn program_code(
         &self,
+        transitions: &InBlockTransitions,
         program_id: ActorId,
     ) -> (
         gear_core::code::InstrumentedCode,
         gear_core::code::CodeMetadata,
     ) {
-        let code_id = self
-            .code_ids
+        let code_id = transitions
+            .registered_programs()
             .get(&program_id)
+            .or_else(|| self.code_ids.get(&program_id))
             .unwrap_or_else(|| panic!("missing ethexe code id for program {program_id:?}"));
         let instrumented_code = self
             .instrumented_codes
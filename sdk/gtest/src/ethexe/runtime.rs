@@ -5,9 +5,9 @@ use ethexe_common::{HashOf, injected::Promise};
 use ethexe_runtime_common::{
     RuntimeInterface,
     state::{
-        ActiveProgram, Allocations, DispatchStash, Mailbox, MemStorage, MemoryPages,
-        MemoryPagesRegion, MessageQueue, Program, ProgramState, QueryableStorage, Storage,
-        UserMailbox, Waitlist,
+        ActiveProgram, Allocations, DispatchStash, GasReservations, Mailbox, MemStorage,
+        MemoryPages, MemoryPagesRegion, MessageQueue, Program, ProgramState, QueryableStorage,
+        Storage, UserMailbox, Waitlist,
     },
 };
 use gear_core::{buffer::Payload, ids::ActorId, memory::PageBuf, pages::GearPage};
@@ -57,11 +57,6 @@ impl RuntimeInterface for GTestEthexeRuntime {
         LAZY_PAGES_STATE_HASH.set(self.state_hash());
     }
 
-    fn random_data(&self) -> (Vec<u8>, u32) {
-        // Deterministic test entropy; wire this to BlocksManager when a test needs variability.
-        (vec![42; 32], 0)
-    }
-
     fn update_state_hash(&self, state_hash: &H256) {
         self.state_hash.set(*state_hash);
         LAZY_PAGES_STATE_HASH.set(*state_hash);
@@ -146,6 +141,14 @@ impl Storage for GTestEthexeRuntime {
         self.storage().write_allocations(allocations)
     }
 
+    fn gas_reservations(&self, hash: HashOf<GasReservations>) -> Option<GasReservations> {
+        self.storage().gas_reservations(hash)
+    }
+
+    fn write_gas_reservations(&self, reservations: GasReservations) -> HashOf<GasReservations> {
+        self.storage().write_gas_reservations(reservations)
+    }
+
     fn payload(&self, hash: HashOf<Payload>) -> Option<Payload> {
         self.storage().payload(hash)
     }
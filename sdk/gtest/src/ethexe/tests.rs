@@ -1,10 +1,10 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-use crate::{DEFAULT_USER_ALICE, UNITS};
+use crate::{DEFAULT_USER_ALICE, TestError, UNITS};
 use ethexe_common::gear::MessageType;
 use ethexe_runtime_common::{
-    RuntimeInterface,
+    RuntimeInterface, VALUE_PER_GAS,
     state::{MemStorage, ProgramState, Storage},
 };
 use gear_core::ids::{ActorId, MessageId, prelude::MessageIdExt};
@@ -296,15 +296,89 @@ fn ethexe_run_to_block_uses_ethexe_execution() {
 }
 
 #[test]
-fn ethexe_read_state_is_rejected() {
+fn ethexe_gas_reservation_holds_executable_balance_until_expiry() {
+    const RESERVE_GAS_PROGRAM: &str = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_reserve_gas" (func $reserve_gas (param i64 i32 i32)))
+            (export "init" (func $init))
+            (export "handle" (func $handle))
+            (func $init)
+            (func $handle
+                (call $reserve_gas (i64.const 1000000) (i32.const 5) (i32.const 0x200))
+            )
+        )
+    "#;
+    const RESERVED_GAS: u64 = 1_000_000;
+
     let system = System::new();
-    let program = Program::from_binary_with_id(&system, 112, demo_ping::WASM_BINARY);
+    let code = wat::parse_str(RESERVE_GAS_PROGRAM).expect("failed to parse wat");
+    let program = Program::from_binary_with_id(&system, 114, code);
 
-    let rejected = catch_unwind(AssertUnwindSafe(|| {
-        let _ = program.read_state_bytes(Vec::new());
-    }));
+    system.top_up_executable_balance(program.id(), ETHEXE_EXECUTABLE_BALANCE);
+    let init = program.send_bytes(1, b"");
+    assert!(system.run_next_block().succeed.contains(&init));
 
-    assert!(rejected.is_err());
+    let initial_executable_balance = program.executable_balance();
+    let message_id = program.send_bytes(1, b"");
+    let result = system.run_next_block();
+
+    assert!(result.succeed.contains(&message_id));
+    let gas_burned = result.gas_burned[&message_id];
+    assert_eq!(
+        program.executable_balance(),
+        initial_executable_balance - u128::from(gas_burned + RESERVED_GAS) * VALUE_PER_GAS
+    );
+
+    // Expired reservation returns its value back to the executable balance.
+    system.run_scheduled_tasks(5);
+    assert_eq!(
+        program.executable_balance(),
+        initial_executable_balance - u128::from(gas_burned) * VALUE_PER_GAS
+    );
+}
+
+#[test]
+fn ethexe_read_state_reads_program_memory() {
+    const COUNTER_PROGRAM: &str = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_reply" (func $reply (param i32 i32 i32 i32)))
+            (export "init" (func $init))
+            (export "handle" (func $handle))
+            (export "state" (func $state))
+            (func $init)
+            (func $handle
+                (i32.store (i32.const 0x200) (i32.add (i32.load (i32.const 0x200)) (i32.const 1)))
+            )
+            (func $state
+                (i32.store (i32.const 0x200) (i32.add (i32.load (i32.const 0x200)) (i32.const 1)))
+                (call $reply (i32.const 0x200) (i32.const 4) (i32.const 0x400) (i32.const 0x600))
+            )
+        )
+    "#;
+
+    let system = System::new();
+    let code = wat::parse_str(COUNTER_PROGRAM).expect("failed to parse wat");
+    let program = Program::from_binary_with_id(&system, 112, code);
+
+    assert!(matches!(
+        program.read_state_bytes(Vec::new()),
+        Err(TestError::ActorIsNotExecutable(id)) if id == program.id()
+    ));
+
+    system.top_up_executable_balance(program.id(), ETHEXE_EXECUTABLE_BALANCE);
+    for _ in 0..3 {
+        let message_id = program.send_bytes(1, b"");
+        let result = system.run_next_block();
+
+        assert!(result.succeed.contains(&message_id));
+    }
+
+    // Init doesn't touch the counter, two handles do; the `state` call sees
+    // the stored value and its own write is discarded.
+    assert_eq!(program.read_state::<u32, _>(()).unwrap(), 3);
+    assert_eq!(program.read_state::<u32, _>(()).unwrap(), 3);
 }
 
 fn initialize_ping_program(system: &System, program: &Program<'_>) {
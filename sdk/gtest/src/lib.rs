@@ -504,14 +504,16 @@ mod error;
 pub mod ethexe;
 mod log;
 mod manager;
+pub mod profiler;
 mod program;
 mod state;
 mod system;
 
 pub use crate::log::{BlockRunResult, CoreLog, Log};
 pub use builtins::{
-    BLS12_381_ID, Bls12_381Request, Bls12_381Response, ETH_BRIDGE_ID, EthBridgeRequest,
-    EthBridgeResponse,
+    BLS12_381_ID, BN254_ID, Bls12_381Request, Bls12_381Response, Bn254Request, Bn254Response,
+    ETH_BRIDGE_ID, EthBridgeRequest, EthBridgeResponse, STAKING_ID, StakingRequest,
+    StakingResponse,
 };
 pub use error::{Result, TestError};
 pub use parity_scale_codec;
@@ -566,6 +568,19 @@ pub mod constants {
     /// Duration of one epoch.
     pub const EPOCH_DURATION_IN_BLOCKS: BlockNumber = 600;
 
+    /* Staking-related constants */
+
+    /// Number of epochs (sessions) in one era.
+    pub const SESSIONS_PER_ERA: BlockNumber = 6;
+    /// Duration of one era.
+    pub const ERA_DURATION_IN_BLOCKS: BlockNumber = EPOCH_DURATION_IN_BLOCKS * SESSIONS_PER_ERA;
+    /// Number of eras bonded funds stay locked after unbonding.
+    pub const BONDING_DURATION: u32 = 14;
+    /// Maximal amount of unlocking chunks a ledger may have.
+    pub const MAX_UNLOCKING_CHUNKS: usize = 32;
+    /// Maximal amount of validators a nominator may nominate.
+    pub const MAX_NOMINATIONS: usize = 16;
+
     /* Storage-related constants */
     /// Extra amount of blocks must be reserved for storing in storage.
     pub const RESERVE_FOR: BlockNumber = 1;
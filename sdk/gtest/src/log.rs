@@ -1,7 +1,10 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-use crate::{GAS_MULTIPLIER, Gas, Value, error::usage_panic, program::ProgramIdWrapper};
+use crate::{
+    GAS_MULTIPLIER, Gas, Value, error::usage_panic, profiler::MessageGasProfile,
+    program::ProgramIdWrapper,
+};
 use gear_core::{
     buffer::Payload,
     ids::{ActorId, MessageId},
@@ -428,6 +431,9 @@ pub struct BlockRunResult {
     /// Mapping gas burned for each message during
     /// the current block execution.
     pub gas_burned: BTreeMap<MessageId, Gas>,
+    /// Gas profiles of the messages executed during the current block
+    /// execution, if the gas profiler is enabled.
+    pub gas_profiles: BTreeMap<MessageId, MessageGasProfile>,
 }
 
 impl BlockRunResult {
@@ -6,10 +6,11 @@ use crate::ethexe::EthexeBackend;
 use crate::{
     EXISTENTIAL_DEPOSIT, GAS_ALLOWANCE, GAS_MULTIPLIER, MAX_RESERVATIONS, MAX_USER_GAS_LIMIT,
     ProgramBuilder, RESERVE_FOR, Result, TestError, VALUE_PER_GAS,
-    builtins::{BLS12_381_ID, ETH_BRIDGE_ID},
+    builtins::{BLS12_381_ID, BN254_ID, ETH_BRIDGE_ID, STAKING_ID},
     constants::{BlockNumber, Gas, Value},
     error::usage_panic,
     log::{BlockRunResult, CoreLog},
+    profiler::GasProfiler,
     state::{
         self,
         accounts::Accounts,
@@ -92,6 +93,7 @@ pub(crate) struct ExtManager {
     pub(crate) builtins: BTreeSet<ActorId>,
     #[cfg(feature = "ethexe")]
     pub(crate) ethexe: EthexeBackend,
+    pub(crate) gas_profiler: Option<GasProfiler>,
 
     // Last block execution info
     pub(crate) succeed: BTreeSet<MessageId>,
@@ -104,7 +106,7 @@ pub(crate) struct ExtManager {
 
 impl ExtManager {
     pub(crate) fn new() -> Self {
-        let builtins = BTreeSet::from([BLS12_381_ID, ETH_BRIDGE_ID]);
+        let builtins = BTreeSet::from([BLS12_381_ID, BN254_ID, ETH_BRIDGE_ID, STAKING_ID]);
         Self {
             blocks_manager: BlocksManager,
             messages_processing_enabled: true,
@@ -4,7 +4,11 @@
 use super::*;
 use crate::{
     WasmProgram,
-    builtins::{self, BLS12_381_ID, BlsOpsGasCostsImpl, ETH_BRIDGE_ID},
+    builtins::{
+        self, BLS12_381_ID, BN254_ID, BlsOpsGasCostsImpl, Bn254OpsGasCostsImpl, ETH_BRIDGE_ID,
+        STAKING_ID,
+    },
+    profiler::Precharge,
     state::{
         blocks,
         programs::{GTestProgram, PLACEHOLDER_MESSAGE_ID},
@@ -13,6 +17,7 @@ use crate::{
 use builtins_common::{
     BuiltinActorError, BuiltinContext,
     bls12_381::{self, Bls12_381OpsLowLevel},
+    bn254::{self, Bn254OpsLowLevel},
 };
 use gear_core::{
     code::{InstrumentedCodeAndMetadata, MAX_WASM_PAGES_AMOUNT, SyscallKind},
@@ -157,6 +162,11 @@ impl ExtManager {
                 .map(CoreLog::from)
                 .collect(),
             gas_burned: mem::take(&mut self.gas_burned),
+            gas_profiles: self
+                .gas_profiler
+                .as_mut()
+                .map(GasProfiler::take_block)
+                .unwrap_or_default(),
         }
     }
 
@@ -272,7 +282,18 @@ impl ExtManager {
                 None => break,
             };
 
+            if !self.is_builtin(dispatch.destination())
+                && let Some(profiler) = self.gas_profiler.as_mut()
+            {
+                profiler.begin_message(dispatch.id(), dispatch.destination(), dispatch.kind());
+            }
+
             let journal = self.process_dispatch(&block_config, dispatch);
+
+            if let Some(profiler) = self.gas_profiler.as_mut() {
+                profiler.end_message();
+            }
+
             gear_core_processor::handle_journal(journal, self);
 
             total_processed += 1;
@@ -314,12 +335,14 @@ impl ExtManager {
             self.gas_allowance,
         );
 
+        let gas_burned = context.gas_burned();
         let context = match context.charge_for_program(block_config) {
             Ok(context) => context,
             Err(journal) => {
                 return journal;
             }
         };
+        self.record_precharge(Precharge::ProgramData, gas_burned, &context);
 
         ProgramsStorageManager::modify_program(destination_id, |program| {
             let Some(program) = program else {
@@ -428,6 +451,18 @@ impl ExtManager {
                     .try_into()
                     .unwrap_or_else(|_| unreachable!("Failed to encode BLS12-381 builtin reply"))
             }),
+            BN254_ID => bn254::execute_bn254_builtins::<Bn254OpsGasCostsImpl, Bn254OpsLowLevel>(
+                dispatch.payload_bytes(),
+                &mut mock_builtin_context,
+            )
+            .map(|response| {
+                log::debug!("BN254 response: {response:?}");
+
+                response
+                    .encode()
+                    .try_into()
+                    .unwrap_or_else(|_| unreachable!("Failed to encode BN254 builtin reply"))
+            }),
             ETH_BRIDGE_ID => builtins::process_eth_bridge_dispatch(&dispatch, self.block_height())
                 .map(|response| {
                     log::debug!("Eth-bridge response: {response:?}");
@@ -436,6 +471,16 @@ impl ExtManager {
                         unreachable!("Failed to encode eth-bridge builtin reply")
                     })
                 }),
+            STAKING_ID => builtins::process_staking_dispatch(&dispatch, self.blocks_manager.get())
+                .map(|response| {
+                    log::debug!("Staking response: {response:?}");
+
+                    response
+                        .map(|response| response.encode())
+                        .unwrap_or_default()
+                        .try_into()
+                        .unwrap_or_else(|_| unreachable!("Failed to encode staking builtin reply"))
+                }),
             id => unimplemented!("Unknown builtin program id: {id}"),
         };
 
@@ -507,12 +552,14 @@ impl ExtManager {
         dispatch_kind: DispatchKind,
         destination_id: ActorId,
     ) -> Vec<JournalNote> {
+        let gas_burned = context.gas_burned();
         let context = match context.charge_for_code_metadata(block_config) {
             Ok(context) => context,
             Err(journal) => {
                 return journal;
             }
         };
+        self.record_precharge(Precharge::CodeMetadata, gas_burned, &context);
 
         let code_id = active_program.code_id;
         let code_metadata = self.code_metadata(code_id).cloned().unwrap_or_else(|| {
@@ -550,11 +597,13 @@ impl ExtManager {
         });
 
         // Adjust gas counters for fetching instrumented binary code.
+        let gas_burned = context.gas_burned();
         let context =
             match context.charge_for_instrumented_code(block_config, instrumented_code_len) {
                 Ok(context) => context,
                 Err(journal) => return journal,
             };
+        self.record_precharge(Precharge::InstrumentedCode, gas_burned, &context);
 
         let instrumented_code = self.instrumented_code(code_id).cloned().unwrap_or_else(|| {
             let err_msg = format!(
@@ -566,6 +615,7 @@ impl ExtManager {
             unreachable!("{err_msg}");
         });
 
+        let gas_burned = context.gas_burned();
         let context = match context
             .charge_for_allocations(block_config, active_program.allocations_tree_len)
         {
@@ -574,6 +624,7 @@ impl ExtManager {
                 return journal;
             }
         };
+        self.record_precharge(Precharge::Allocations, gas_burned, &context);
 
         let allocations = if active_program.allocations_tree_len != 0 {
             ProgramsStorageManager::allocations(destination_id).unwrap_or_else(||
@@ -592,6 +643,7 @@ impl ExtManager {
             gas_reservation_map: active_program.gas_reservation_map.clone(),
         };
 
+        let gas_burned = context.gas_burned();
         let context = match context.charge_for_module_instantiation(
             block_config,
             actor_data,
@@ -603,10 +655,18 @@ impl ExtManager {
                 return journal;
             }
         };
+        self.record_precharge(Precharge::ModuleInstantiation, gas_burned, &context);
+
+        // The profiled code burns the same amount of gas as the original one.
+        let instrumented_code = self
+            .gas_profiler
+            .as_mut()
+            .and_then(|profiler| profiler.begin_execution(code_id, &instrumented_code))
+            .unwrap_or(instrumented_code);
 
         let balance = Accounts::reducible_balance(destination_id);
 
-        gear_core_processor::process::<Ext<LazyPagesNative>>(
+        let journal = gear_core_processor::process::<Ext<LazyPagesNative>>(
             block_config,
             ProcessExecutionContext::new(
                 context,
@@ -622,7 +682,24 @@ impl ExtManager {
                 block_config.block_info.height,
             ),
         )
-        .unwrap_or_else(|e| unreachable!("core-processor logic violated: {}", e))
+        .unwrap_or_else(|e| unreachable!("core-processor logic violated: {}", e));
+
+        if let Some(profiler) = self.gas_profiler.as_mut() {
+            profiler.end_execution(code_id);
+        }
+
+        journal
+    }
+
+    fn record_precharge<T>(
+        &mut self,
+        precharge: Precharge,
+        gas_burned: Gas,
+        context: &ContextCharged<T>,
+    ) {
+        if let Some(profiler) = self.gas_profiler.as_mut() {
+            profiler.record_precharge(precharge, context.gas_burned() - gas_burned);
+        }
     }
 
     fn process_mock_program(
@@ -0,0 +1,483 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Gas profiler.
+//!
+//! The profiler is enabled with
+//! [`System::enable_gas_profiler`](crate::System::enable_gas_profiler).
+//! Since then, the gas burned by every executed message is broken down by
+//! precharges, syscalls, lazy-pages charges and wasm functions (named by the
+//! name section of the program code, if any). Profiles of the messages
+//! executed in the block are returned in
+//! [`BlockRunResult::gas_profiles`](crate::BlockRunResult::gas_profiles),
+//! while profiles of all executed messages are accumulated into
+//! [`GasProfile`], which can be written in the folded stacks format accepted
+//! by `flamegraph.pl` and `inferno-flamegraph`.
+//!
+//! Programs are executed with the code having additional gas counters, so
+//! the profiler doesn't change the amount of burned gas.
+
+use crate::{
+    Gas,
+    error::{Result, TestError},
+};
+use gear_core::{
+    code::InstrumentedCode,
+    ids::{ActorId, CodeId, MessageId},
+    message::DispatchKind,
+};
+use gear_core_processor::profiling::{self, ExecutionProfile};
+use gear_wasm_instrument::{Module, Name, profiler};
+use std::{collections::BTreeMap, fmt::Write as _, fs, path::Path};
+
+pub use gear_core_processor::profiling::SyscallProfile;
+pub use gear_lazy_pages::LazyPagesCharges;
+pub use gear_wasm_instrument::SyscallName;
+
+/// Gas charge made before the program execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precharge {
+    /// Charge for loading the program data.
+    ProgramData,
+    /// Charge for loading the code metadata.
+    CodeMetadata,
+    /// Charge for loading the instrumented code.
+    InstrumentedCode,
+    /// Charge for loading the memory allocations.
+    Allocations,
+    /// Charge for the module instantiation.
+    ModuleInstantiation,
+}
+
+impl Precharge {
+    /// Returns the name of the precharge used in the folded stacks.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ProgramData => "program_data",
+            Self::CodeMetadata => "code_metadata",
+            Self::InstrumentedCode => "instrumented_code",
+            Self::Allocations => "allocations",
+            Self::ModuleInstantiation => "module_instantiation",
+        }
+    }
+}
+
+/// Gas burned by the message execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageGasProfile {
+    /// Program executing the message.
+    pub program_id: ActorId,
+    /// Kind of the executed message.
+    pub kind: DispatchKind,
+    /// Gas burned by the precharges.
+    pub precharges: BTreeMap<Precharge, Gas>,
+    /// Gas burned by syscalls.
+    pub syscalls: BTreeMap<SyscallName, SyscallProfile>,
+    /// Gas burned by lazy-pages for the memory accesses.
+    pub lazy_pages: LazyPagesCharges,
+    /// Gas burned by the instructions of wasm functions, by function name.
+    pub functions: BTreeMap<String, Gas>,
+}
+
+impl MessageGasProfile {
+    pub(crate) fn new(program_id: ActorId, kind: DispatchKind) -> Self {
+        Self {
+            program_id,
+            kind,
+            precharges: Default::default(),
+            syscalls: Default::default(),
+            lazy_pages: Default::default(),
+            functions: Default::default(),
+        }
+    }
+
+    /// Returns total amount of the profiled gas.
+    pub fn total(&self) -> Gas {
+        self.precharges
+            .values()
+            .chain(self.syscalls.values().map(|syscall| &syscall.gas))
+            .chain(self.functions.values())
+            .fold(self.lazy_pages.total(), |total, &gas| {
+                total.saturating_add(gas)
+            })
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (precharge, gas) in other.precharges {
+            let total = self.precharges.entry(precharge).or_default();
+            *total = total.saturating_add(gas);
+        }
+
+        for (name, syscall) in other.syscalls {
+            let total = self.syscalls.entry(name).or_default();
+            total.calls = total.calls.saturating_add(syscall.calls);
+            total.gas = total.gas.saturating_add(syscall.gas);
+        }
+
+        let LazyPagesCharges {
+            signal_read,
+            signal_write,
+            signal_write_after_read,
+            host_func_read,
+            host_func_write,
+            host_func_write_after_read,
+            load_page_storage_data,
+        } = other.lazy_pages;
+        let lazy_pages = &mut self.lazy_pages;
+        lazy_pages.signal_read = lazy_pages.signal_read.saturating_add(signal_read);
+        lazy_pages.signal_write = lazy_pages.signal_write.saturating_add(signal_write);
+        lazy_pages.signal_write_after_read = lazy_pages
+            .signal_write_after_read
+            .saturating_add(signal_write_after_read);
+        lazy_pages.host_func_read = lazy_pages.host_func_read.saturating_add(host_func_read);
+        lazy_pages.host_func_write = lazy_pages.host_func_write.saturating_add(host_func_write);
+        lazy_pages.host_func_write_after_read = lazy_pages
+            .host_func_write_after_read
+            .saturating_add(host_func_write_after_read);
+        lazy_pages.load_page_storage_data = lazy_pages
+            .load_page_storage_data
+            .saturating_add(load_page_storage_data);
+
+        for (name, gas) in other.functions {
+            let total = self.functions.entry(name).or_default();
+            *total = total.saturating_add(gas);
+        }
+    }
+
+    fn write_folded(&self, out: &mut String) {
+        let lazy_pages = [
+            ("signal_read", self.lazy_pages.signal_read),
+            ("signal_write", self.lazy_pages.signal_write),
+            (
+                "signal_write_after_read",
+                self.lazy_pages.signal_write_after_read,
+            ),
+            ("host_func_read", self.lazy_pages.host_func_read),
+            ("host_func_write", self.lazy_pages.host_func_write),
+            (
+                "host_func_write_after_read",
+                self.lazy_pages.host_func_write_after_read,
+            ),
+            (
+                "load_page_storage_data",
+                self.lazy_pages.load_page_storage_data,
+            ),
+        ];
+
+        let frames = self
+            .precharges
+            .iter()
+            .map(|(precharge, &gas)| ("precharge", String::from(precharge.name()), gas))
+            .chain(
+                self.syscalls
+                    .iter()
+                    .map(|(name, syscall)| ("syscall", String::from(name.to_str()), syscall.gas)),
+            )
+            .chain(
+                lazy_pages
+                    .into_iter()
+                    .map(|(name, gas)| ("lazy_pages", String::from(name), gas)),
+            )
+            .chain(
+                self.functions
+                    .iter()
+                    .map(|(name, &gas)| ("wasm", folded_frame(name), gas)),
+            );
+
+        for (category, name, gas) in frames {
+            if gas != 0 {
+                let _ = writeln!(
+                    out,
+                    "{};{:?};{category};{name} {gas}",
+                    self.program_id, self.kind
+                );
+            }
+        }
+    }
+}
+
+// Frames of the folded stacks are separated with `;`, while the sample count
+// follows the last space.
+fn folded_frame(name: &str) -> String {
+    name.replace(';', ":").replace(' ', "_")
+}
+
+/// Gas burned by all messages executed since the profiler has been enabled.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GasProfile {
+    /// Profiles of the executed messages.
+    pub messages: BTreeMap<MessageId, MessageGasProfile>,
+}
+
+impl GasProfile {
+    /// Returns total amount of the profiled gas.
+    pub fn total(&self) -> Gas {
+        self.messages
+            .values()
+            .fold(0, |total, message| total.saturating_add(message.total()))
+    }
+
+    /// Returns the profile in the folded stacks format.
+    ///
+    /// Every line has the `program;kind;category;name gas` form, where
+    /// `category` is one of `precharge`, `syscall`, `lazy_pages` or `wasm`.
+    pub fn to_folded(&self) -> String {
+        let mut out = String::new();
+        for message in self.messages.values() {
+            message.write_folded(&mut out);
+        }
+
+        out
+    }
+
+    /// Writes the profile in the folded stacks format to the file at `path`.
+    ///
+    /// The file can be converted into the flamegraph with `flamegraph.pl` or
+    /// `inferno-flamegraph`.
+    pub fn write_folded(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_folded())
+            .map_err(|err| TestError::GasProfileWriteError(err.to_string()))
+    }
+
+    pub(crate) fn add(&mut self, message_id: MessageId, profile: MessageGasProfile) {
+        match self.messages.get_mut(&message_id) {
+            Some(message) => message.merge(profile),
+            None => {
+                self.messages.insert(message_id, profile);
+            }
+        }
+    }
+}
+
+// Program code with the gas counters.
+#[derive(Debug)]
+struct ProfiledCode {
+    code: InstrumentedCode,
+    functions: Vec<u32>,
+    names: BTreeMap<u32, String>,
+}
+
+impl ProfiledCode {
+    fn new(code: &InstrumentedCode) -> Self {
+        let module = Module::new(code.bytes()).expect("Instrumented code is valid");
+
+        let names = module
+            .name_section
+            .iter()
+            .flatten()
+            .filter_map(|name| match name {
+                Name::Function(map) => Some(map),
+                _ => None,
+            })
+            .flatten()
+            .map(|naming| (naming.index, naming.name.to_string()))
+            .collect();
+
+        let (module, functions) = profiler::inject(module)
+            .unwrap_or_else(|err| unreachable!("Failed to inject gas counters: {err}"));
+        let bytes = module
+            .serialize()
+            .unwrap_or_else(|err| unreachable!("Failed to serialize profiled code: {err}"));
+
+        Self {
+            code: InstrumentedCode::new(bytes, code.instantiated_section_sizes().clone()),
+            functions,
+            names,
+        }
+    }
+
+    fn function_name(&self, index: u32) -> String {
+        self.names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| format!("func[{index}]"))
+    }
+}
+
+/// State of the enabled profiler.
+#[derive(Debug, Default)]
+pub(crate) struct GasProfiler {
+    codes: BTreeMap<CodeId, ProfiledCode>,
+    // Profile of the message being executed.
+    current: Option<(MessageId, MessageGasProfile)>,
+    // Profiles of the messages executed in the current block.
+    block: BTreeMap<MessageId, MessageGasProfile>,
+    pub(crate) profile: GasProfile,
+}
+
+impl GasProfiler {
+    pub(crate) fn begin_message(
+        &mut self,
+        message_id: MessageId,
+        program_id: ActorId,
+        kind: DispatchKind,
+    ) {
+        self.current = Some((message_id, MessageGasProfile::new(program_id, kind)));
+    }
+
+    pub(crate) fn record_precharge(&mut self, precharge: Precharge, gas: Gas) {
+        if let Some((_, profile)) = self.current.as_mut()
+            && gas != 0
+        {
+            profile.precharges.insert(precharge, gas);
+        }
+    }
+
+    /// Returns the code with the gas counters to be executed instead of
+    /// `code` and enables profiling of the execution.
+    ///
+    /// Returns `None` if the message isn't profiled.
+    pub(crate) fn begin_execution(
+        &mut self,
+        code_id: CodeId,
+        code: &InstrumentedCode,
+    ) -> Option<InstrumentedCode> {
+        self.current.as_ref()?;
+
+        let profiled = self
+            .codes
+            .entry(code_id)
+            .or_insert_with(|| ProfiledCode::new(code));
+        profiling::enable(profiled.functions.clone());
+
+        Some(profiled.code.clone())
+    }
+
+    pub(crate) fn end_execution(&mut self, code_id: CodeId) {
+        let Some((_, profile)) = self.current.as_mut() else {
+            return;
+        };
+
+        // If the program hasn't been executed, lazy-pages charges belong to
+        // the previous execution.
+        let Some(ExecutionProfile {
+            syscalls,
+            functions,
+        }) = profiling::take()
+        else {
+            return;
+        };
+
+        let profiled = &self.codes[&code_id];
+        profile.syscalls = syscalls;
+        profile.functions = functions
+            .into_iter()
+            .map(|(index, gas)| (profiled.function_name(index), gas))
+            .collect();
+        profile.lazy_pages = gear_lazy_pages::charges()
+            .unwrap_or_else(|err| unreachable!("Failed to get lazy-pages charges: {err}"));
+    }
+
+    pub(crate) fn end_message(&mut self) {
+        if let Some((message_id, profile)) = self.current.take() {
+            self.profile.add(message_id, profile.clone());
+            match self.block.get_mut(&message_id) {
+                Some(message) => message.merge(profile),
+                None => {
+                    self.block.insert(message_id, profile);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn take_block(&mut self) -> BTreeMap<MessageId, MessageGasProfile> {
+        std::mem::take(&mut self.block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_USER_ALICE, Program, System};
+
+    const PROGRAM: &str = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "gr_reply" (func $reply (param i32 i32 i32 i32)))
+            (export "init" (func $init))
+            (export "handle" (func $handle))
+            (func $init)
+            (func $handle
+                (call $compute (i32.const 100))
+                (i32.store (i32.const 0x200) (i32.const 42))
+                (call $reply (i32.const 0x200) (i32.const 4) (i32.const 0x400) (i32.const 0x600))
+            )
+            (func $compute (param $n i32)
+                (loop $loop
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $loop (local.get $n))
+                )
+            )
+        )
+    "#;
+
+    #[test]
+    fn gas_profile_breakdown() {
+        let system = System::new();
+        let code = wat::parse_str(PROGRAM).expect("failed to parse wat");
+        let program = Program::from_binary_with_id(&system, 42, code);
+
+        let init = program.send_bytes(DEFAULT_USER_ALICE, b"");
+        assert!(system.run_next_block().succeed.contains(&init));
+
+        // The first message stores the memory page, so the next ones burn
+        // the same amount of gas.
+        for _ in 0..2 {
+            program.send_bytes(DEFAULT_USER_ALICE, b"");
+            system.run_next_block();
+        }
+
+        let message_id = program.send_bytes(DEFAULT_USER_ALICE, b"");
+        let gas_burned = system.run_next_block().gas_burned[&message_id];
+
+        assert!(system.gas_profile().is_none());
+        system.enable_gas_profiler();
+
+        let message_id = program.send_bytes(DEFAULT_USER_ALICE, b"");
+        let res = system.run_next_block();
+        assert!(res.succeed.contains(&message_id));
+
+        // Profiled code burns the same amount of gas.
+        assert_eq!(res.gas_burned[&message_id], gas_burned);
+
+        let profile = &res.gas_profiles[&message_id];
+        assert_eq!(profile.program_id, program.id());
+        assert_eq!(profile.kind, DispatchKind::Handle);
+        assert!(profile.total() <= gas_burned);
+
+        assert!(profile.precharges[&Precharge::ProgramData] > 0);
+        assert!(profile.precharges[&Precharge::ModuleInstantiation] > 0);
+
+        let reply = profile.syscalls[&SyscallName::Reply];
+        assert_eq!(reply.calls, 1);
+        assert!(reply.gas > 0);
+
+        assert!(profile.lazy_pages.signal_write > 0);
+        assert_eq!(profile.lazy_pages.signal_read, 0);
+
+        assert!(profile.functions["handle"] > 0);
+        assert!(profile.functions["compute"] > profile.functions["handle"]);
+        assert!(!profile.functions.contains_key("init"));
+
+        let gas_profile = system.gas_profile().expect("profiler is enabled");
+        assert_eq!(gas_profile.messages.len(), 1);
+        assert_eq!(&gas_profile.messages[&message_id], profile);
+
+        let folded = gas_profile.to_folded();
+        let prefix = format!("{};Handle;", program.id());
+        assert!(folded.lines().all(|line| line.starts_with(&prefix)));
+        assert!(folded.contains(&format!(
+            "{prefix}wasm;compute {}\n",
+            profile.functions["compute"]
+        )));
+        assert!(folded.contains(&format!("{prefix}syscall;gr_reply {}\n", reply.gas)));
+
+        assert_eq!(system.disable_gas_profiler(), Some(gas_profile));
+        assert!(system.gas_profile().is_none());
+
+        let message_id = program.send_bytes(DEFAULT_USER_ALICE, b"");
+        let res = system.run_next_block();
+        assert_eq!(res.gas_burned[&message_id], gas_burned);
+        assert!(res.gas_profiles.is_empty());
+    }
+}
@@ -12,6 +12,7 @@ pub(crate) mod mailbox;
 pub(crate) mod nonce;
 pub(crate) mod programs;
 pub(crate) mod queue;
+pub(crate) mod staking;
 pub(crate) mod stash;
 pub(crate) mod task_pool;
 pub(crate) mod waitlist;
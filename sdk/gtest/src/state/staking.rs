@@ -0,0 +1,71 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Staking builtin storage manager.
+
+use std::{collections::BTreeMap, thread::LocalKey};
+
+use crate::state::WithOverlay;
+use gbuiltin_staking::{LedgerInfo, NominationsInfo};
+use gear_core::ids::ActorId;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StakingState {
+    pub ledgers: BTreeMap<ActorId, LedgerInfo>,
+    pub nominators: BTreeMap<ActorId, NominationsInfo>,
+}
+
+thread_local! {
+    pub(super) static STAKING_STATE: WithOverlay<StakingState> = Default::default();
+}
+
+fn storage() -> &'static LocalKey<WithOverlay<StakingState>> {
+    &STAKING_STATE
+}
+
+pub(crate) struct StakingBuiltinStorage;
+
+impl StakingBuiltinStorage {
+    /// Get the staking ledger of the stash.
+    pub(crate) fn ledger(stash: ActorId) -> Option<LedgerInfo> {
+        storage().with(|state| state.data().ledgers.get(&stash).cloned())
+    }
+
+    /// Insert or update the staking ledger of the stash.
+    pub(crate) fn set_ledger(stash: ActorId, ledger: LedgerInfo) {
+        storage().with(|state| {
+            state.data_mut().ledgers.insert(stash, ledger);
+        })
+    }
+
+    /// Remove all staking data of the stash.
+    pub(crate) fn kill_stash(stash: ActorId) {
+        storage().with(|state| {
+            let mut state = state.data_mut();
+            state.ledgers.remove(&stash);
+            state.nominators.remove(&stash);
+        })
+    }
+
+    /// Get the nominations of the stash.
+    pub(crate) fn nominations(stash: ActorId) -> Option<NominationsInfo> {
+        storage().with(|state| state.data().nominators.get(&stash).cloned())
+    }
+
+    /// Insert or remove the nominations of the stash.
+    pub(crate) fn set_nominations(stash: ActorId, nominations: Option<NominationsInfo>) {
+        storage().with(|state| {
+            let mut state = state.data_mut();
+            match nominations {
+                Some(nominations) => state.nominators.insert(stash, nominations),
+                None => state.nominators.remove(&stash),
+            };
+        })
+    }
+
+    pub(crate) fn clear() {
+        storage().with(|state| {
+            *state.data_mut() = Default::default();
+        });
+    }
+}
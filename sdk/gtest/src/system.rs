@@ -6,10 +6,11 @@ use crate::{
     error::usage_panic,
     log::{BlockRunResult, CoreLog},
     manager::ExtManager,
+    profiler::{GasProfile, GasProfiler},
     program::{Program, ProgramIdWrapper},
     state::{
         accounts::Accounts, bridge::BridgeBuiltinStorage, mailbox::ActorMailbox,
-        programs::ProgramsStorageManager,
+        programs::ProgramsStorageManager, staking::StakingBuiltinStorage,
     },
 };
 use gear_common::MessageId;
@@ -233,6 +234,41 @@ impl System {
             .collect()
     }
 
+    /// Enables the gas profiler.
+    ///
+    /// Gas burned by every message executed since then is broken down by
+    /// precharges, syscalls, lazy-pages charges and wasm functions. Profiles
+    /// of the messages executed in the block are returned in
+    /// [`BlockRunResult::gas_profiles`], while [`Self::gas_profile`] returns
+    /// profiles of all messages executed since the profiler was enabled.
+    ///
+    /// Does nothing if the profiler is already enabled.
+    pub fn enable_gas_profiler(&self) {
+        self.0
+            .borrow_mut()
+            .gas_profiler
+            .get_or_insert_with(GasProfiler::default);
+    }
+
+    /// Disables the gas profiler, returning the profile collected so far.
+    pub fn disable_gas_profiler(&self) -> Option<GasProfile> {
+        self.0
+            .borrow_mut()
+            .gas_profiler
+            .take()
+            .map(|profiler| profiler.profile)
+    }
+
+    /// Returns profiles of all messages executed since the gas profiler was
+    /// enabled, or `None` if it isn't enabled.
+    pub fn gas_profile(&self) -> Option<GasProfile> {
+        self.0
+            .borrow()
+            .gas_profiler
+            .as_ref()
+            .map(|profiler| profiler.profile.clone())
+    }
+
     /// Return the current block height of the testing environment.
     pub fn block_height(&self) -> u32 {
         self.0.borrow().block_height()
@@ -523,8 +559,9 @@ impl Drop for System {
         ProgramsStorageManager::clear();
         Accounts::clear();
 
-        // Clear bridge-builtins state
+        // Clear builtins state
         BridgeBuiltinStorage::clear();
+        StakingBuiltinStorage::clear();
     }
 }
 
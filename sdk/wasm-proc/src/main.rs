@@ -11,7 +11,7 @@ use std::{collections::HashSet, fs, path::PathBuf};
 use tracing_subscriber::EnvFilter;
 use wasmparser::{Parser as WasmParser, Payload, TypeRef};
 
-const RT_ALLOWED_IMPORTS: [&str; 78] = [
+const RT_ALLOWED_IMPORTS: [&str; 82] = [
     // From `Allocator` (substrate/primitives/io/src/lib.rs)
     "ext_allocator_free_version_1",
     "ext_allocator_malloc_version_1",
@@ -103,6 +103,11 @@ const RT_ALLOWED_IMPORTS: [&str; 78] = [
     // From `GearBls12_381`
     "ext_gear_bls_12_381_aggregate_g1_version_1",
     "ext_gear_bls_12_381_map_to_g2affine_version_1",
+    // From `GearBn254`
+    "ext_gear_bn_254_addition_g1_version_1",
+    "ext_gear_bn_254_final_exponentiation_version_1",
+    "ext_gear_bn_254_mul_projective_g1_version_1",
+    "ext_gear_bn_254_multi_miller_loop_version_1",
 ];
 
 #[derive(Debug, clap::Parser)]
@@ -48,6 +48,7 @@ pub const SAFE_DEPENDENCIES: &[&str] = &[
     "actor-system-error",
     "galloc",
     "gbuiltin-bls381",
+    "gbuiltin-bn254",
     "gear-common-codegen",
     "gear-core-errors",
     "gear-runtime-primitives",
@@ -68,6 +69,7 @@ pub const STACKED_DEPENDENCIES: &[&str] = &[
     "gprimitives",
     "gbuiltin-eth-bridge",
     "pallet-gear-eth-bridge-primitives",
+    "gbuiltin-governance",
     "gbuiltin-proxy",
     "gbuiltin-staking",
     "gstd-codegen",
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use clap::Parser;
-use std::str::FromStr;
+use std::{num::NonZeroU64, str::FromStr};
 
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Parser, derive_more::Display)]
@@ -44,6 +44,12 @@ pub struct RunCmd {
     #[arg(long, default_value_t = 50)]
     pub sandbox_store_clear_counter_limit: u32,
 
+    /// Size limit of the on-disk cache of compiled program modules, in MiB (applies only to the Wasmtime sandbox backend).
+    ///
+    /// The cache is stored in the base path and survives node restarts. It's disabled if not set.
+    #[arg(long)]
+    pub sandbox_module_cache_size: Option<NonZeroU64>,
+
     /// The upper limit for the amount of gas a validator can burn in one block.
     #[arg(long)]
     pub max_gas: Option<u64>,
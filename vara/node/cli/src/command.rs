@@ -5,6 +5,7 @@ use crate::{
     SandboxBackend,
     cli::{Cli, Subcommand},
 };
+use gear_wasmtime_cache::DiskCacheConfig;
 use runtime_primitives::Block;
 use sc_cli::{ChainSpec, SubstrateCli};
 use sc_service::config::BasePath;
@@ -335,6 +336,14 @@ pub fn run() -> sc_cli::Result<()> {
             };
 
             runner.run_node_until_exit(|config| async move {
+                if let Some(size) = cli.run.sandbox_module_cache_size {
+                    gear_wasmtime_cache::enable_disk_cache(DiskCacheConfig {
+                        directory: config.base_path.path().join("sandbox-module-cache"),
+                        max_size: size.get().saturating_mul(1024 * 1024),
+                    })
+                    .map_err(|err| sc_cli::Error::Application(err.into()))?;
+                }
+
                 service::new_full(
                     config,
                     cli.no_hardware_benchmarks,
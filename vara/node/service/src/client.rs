@@ -38,6 +38,7 @@ pub type ExtendHostFunctions = (
     gear_ri::sandbox::HostFunctions,
     sp_crypto_ec_utils::bls12_381::host_calls::HostFunctions,
     gear_ri::gear_bls_12_381::HostFunctions,
+    gear_ri::gear_bn_254::HostFunctions,
 );
 /// Otherwise we only use the default Substrate host functions.
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -46,6 +47,7 @@ pub type ExtendHostFunctions = (
     gear_ri::sandbox::HostFunctions,
     sp_crypto_ec_utils::bls12_381::host_calls::HostFunctions,
     gear_ri::gear_bls_12_381::HostFunctions,
+    gear_ri::gear_bn_254::HostFunctions,
 );
 
 /// A set of APIs that polkadot-like runtimes must implement.
@@ -7,11 +7,19 @@
 use crate::Pallet as BuiltinActorPallet;
 use crate::*;
 use ark_std::{UniformRand, ops::Mul};
-use builtins_common::bls12_381::{
-    ark_bls12_381::{self, Bls12_381, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2},
-    ark_ec::{Group, ScalarMul, pairing::Pairing, short_weierstrass::SWCurveConfig},
-    ark_ff::biginteger::BigInt,
-    ark_scale::{self, hazmat::ArkScaleProjective},
+use builtins_common::{
+    bls12_381::{
+        ark_bls12_381::{
+            self, Bls12_381, G1Affine, G1Projective as G1, G2Affine, G2Projective as G2,
+        },
+        ark_ec::{Group, ScalarMul, pairing::Pairing, short_weierstrass::SWCurveConfig},
+        ark_ff::biginteger::BigInt,
+        ark_scale::{self, hazmat::ArkScaleProjective},
+    },
+    bn254::ark_bn254::{
+        self, Bn254, Fr as Bn254ScalarField, G1Affine as Bn254G1Affine, G1Projective as Bn254G1,
+        G2Affine as Bn254G2Affine, G2Projective as Bn254G2,
+    },
 };
 use common::Origin;
 use frame_benchmarking::benchmarks;
@@ -278,6 +286,98 @@ benchmarks! {
         assert!(ArkScale::<G2Affine>::decode(&mut &_result.unwrap()[..]).is_ok())
     }
 
+    bn254_multi_miller_loop {
+        let c in 0 .. 100;
+
+        let count = c as usize;
+
+        let mut rng = ark_std::test_rng();
+
+        let a = (0..count).map(|_| Bn254G1::rand(&mut rng).into()).collect::<Vec<Bn254G1Affine>>();
+        let a: ArkScale<Vec<<Bn254 as Pairing>::G1Affine>> = a.into();
+        let encoded_a = a.encode();
+
+        let b = (0..count).map(|_| Bn254G2::rand(&mut rng).into()).collect::<Vec<Bn254G2Affine>>();
+        let b: ArkScale<Vec<<Bn254 as Pairing>::G2Affine>> = b.into();
+        let encoded_b = b.encode();
+
+        // Custom error by default.
+        let mut _result = Err(3);
+    }: {
+        _result = gear_runtime_interface::gear_bn_254::multi_miller_loop(encoded_a, encoded_b);
+    } verify {
+        assert!(_result.is_ok());
+    }
+
+    bn254_final_exponentiation {
+        let mut rng = ark_std::test_rng();
+
+        let a: Bn254G1Affine = Bn254G1::rand(&mut rng).into();
+        let a: ArkScale<Vec<<Bn254 as Pairing>::G1Affine>> = vec![a].into();
+
+        let priv_key: Bn254ScalarField = UniformRand::rand(&mut rng);
+        let b: Bn254G2Affine = Bn254G2::generator().mul(priv_key).into();
+        let b: ArkScale<Vec<<Bn254 as Pairing>::G2Affine>> = vec![b].into();
+
+        let miller_loop = gear_runtime_interface::gear_bn_254::multi_miller_loop(
+            a.encode(),
+            b.encode(),
+        ).unwrap();
+
+        // Custom error by default.
+        let mut _result = Err(3);
+    }: {
+        _result = gear_runtime_interface::gear_bn_254::final_exponentiation(miller_loop);
+    } verify {
+        assert!(_result.is_ok());
+    }
+
+    bn254_addition_g1 {
+        let mut rng = ark_std::test_rng();
+
+        let a = Bn254G1::rand(&mut rng);
+        let ark_a: ArkScaleProjective<Bn254G1> = a.into();
+        let encoded_a = ark_a.encode();
+
+        let b = Bn254G1::rand(&mut rng);
+        let ark_b: ArkScaleProjective<Bn254G1> = b.into();
+        let encoded_b = ark_b.encode();
+
+        // Custom error by default.
+        let mut _result = Err(3);
+    }: {
+        _result = gear_runtime_interface::gear_bn_254::addition_g1(encoded_a, encoded_b);
+    } verify {
+        let encoded = _result.unwrap();
+        let result = ArkScaleProjective::<Bn254G1>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(a + b, result.0);
+    }
+
+    bn254_mul_projective_g1 {
+        let c in 1 .. MAX_BIG_INT;
+
+        let mut rng = ark_std::test_rng();
+
+        let bigint = BigInt::<{ MAX_BIG_INT as usize }>::rand(&mut rng);
+        let bigint = bigint.as_ref()[..c as usize].to_vec();
+        let ark_bigint: ArkScale<Vec<u64>> = bigint.clone().into();
+        let encoded_bigint = ark_bigint.encode();
+
+        let base = Bn254G1::rand(&mut rng);
+        let ark_base: ArkScaleProjective<Bn254G1> = base.into();
+        let encoded_base = ark_base.encode();
+
+        // Custom error by default.
+        let mut _result = Err(3);
+    }: {
+        _result = gear_runtime_interface::gear_bn_254::mul_projective_g1(encoded_base, encoded_bigint);
+    } verify {
+        let encoded = _result.unwrap();
+        let result = ArkScaleProjective::<Bn254G1>::decode(&mut &encoded[..]).unwrap();
+        let standard = <ark_bn254::g1::Config as SWCurveConfig>::mul_projective(&base, &bigint);
+        assert_eq!(standard, result.0);
+    }
+
     impl_benchmark_test_suite!(
         BuiltinActorPallet,
         crate::mock::new_test_ext(),
@@ -0,0 +1,89 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use super::*;
+use builtins_common::bn254::{self, Bn254Ops, Bn254OpsGasCost};
+use core::marker::PhantomData;
+use gear_runtime_interface::gear_bn_254 as gear_ri_bn254;
+
+pub struct Actor<T: Config>(PhantomData<T>);
+
+impl<T: Config> BuiltinActor for Actor<T> {
+    fn handle(
+        dispatch: &StoredDispatch,
+        context: &mut BuiltinContext,
+    ) -> Result<BuiltinReply, BuiltinActorError> {
+        bn254::execute_bn254_builtins::<Bn254OpsGasCostsImpl<T>, Bn254OpsRi>(
+            dispatch.payload_bytes(),
+            context,
+        )
+        .map(|response| BuiltinReply {
+            payload: response.encode().try_into().unwrap_or_else(|err| {
+                let err_msg = format!(
+                    "Actor::handle: Response message is too large. \
+                        Response - {response:X?}. Got error - {err:?}"
+                );
+
+                log::error!("{err_msg}");
+                unreachable!("{err_msg}")
+            }),
+            // The value is not used in the bn254 actor, it will be fully returned to the caller.
+            value: dispatch.value(),
+        })
+    }
+
+    fn max_gas() -> u64 {
+        Default::default()
+    }
+}
+
+struct Bn254OpsGasCostsImpl<T: Config>(PhantomData<T>);
+
+impl<T: Config> Bn254OpsGasCost for Bn254OpsGasCostsImpl<T> {
+    fn decode_bytes(len: u32) -> u64 {
+        <T as Config>::WeightInfo::decode_bytes(len).ref_time()
+    }
+
+    fn bn254_multi_miller_loop(count: u32) -> u64 {
+        <T as Config>::WeightInfo::bn254_multi_miller_loop(count).ref_time()
+    }
+
+    fn bn254_final_exponentiation() -> u64 {
+        <T as Config>::WeightInfo::bn254_final_exponentiation().ref_time()
+    }
+
+    fn bn254_addition_g1() -> u64 {
+        <T as Config>::WeightInfo::bn254_addition_g1().ref_time()
+    }
+
+    fn bn254_mul_projective_g1(count: u32) -> u64 {
+        <T as Config>::WeightInfo::bn254_mul_projective_g1(count).ref_time()
+    }
+}
+
+struct Bn254OpsRi;
+
+impl Bn254Ops for Bn254OpsRi {
+    fn multi_miller_loop(g1: Vec<u8>, g2: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        gear_ri_bn254::multi_miller_loop(g1, g2).map_err(|err_code| {
+            BuiltinActorError::from_u32(err_code, Some("Multi Miller loop host-call failed"))
+        })
+    }
+
+    fn final_exponentiation(f: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        gear_ri_bn254::final_exponentiation(f).map_err(|err_code| {
+            BuiltinActorError::from_u32(err_code, Some("Final exponentiation host-call failed"))
+        })
+    }
+
+    fn addition_g1(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        gear_ri_bn254::addition_g1(a, b)
+            .map_err(|err_code| BuiltinActorError::from_u32(err_code, Some("Addition G1 failed")))
+    }
+
+    fn projective_mul_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        gear_ri_bn254::mul_projective_g1(base, scalar).map_err(|err_code| {
+            BuiltinActorError::from_u32(err_code, Some("Projective multiplication G1 failed"))
+        })
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Governance builtin actor implementation
+
+use super::*;
+use common::Origin;
+use frame_support::traits::{Get, Polling};
+use gbuiltin_governance::{
+    AccountVote as BuiltinAccountVote, Conviction as BuiltinConviction, Request, Response,
+};
+use pallet_conviction_voting::{
+    AccountVote, Config as ConvictionVotingConfig, Conviction, TallyOf, Vote,
+};
+use parity_scale_codec::Decode;
+use sp_runtime::traits::{StaticLookup, UniqueSaturatedInto};
+
+type PollsOf<T> = <T as ConvictionVotingConfig>::Polls;
+type ClassOf<T> = <PollsOf<T> as Polling<TallyOf<T>>>::Class;
+type PollIndexOf<T> = <PollsOf<T> as Polling<TallyOf<T>>>::Index;
+
+/// Governance builtin actor.
+pub struct Actor<T: Config + ConvictionVotingConfig>(PhantomData<T>);
+
+impl<T: Config + ConvictionVotingConfig> Actor<T>
+where
+    T::AccountId: Origin,
+    ClassOf<T>: From<u16> + Into<u16>,
+    PollIndexOf<T>: From<u32>,
+    CallOf<T>: From<pallet_conviction_voting::Call<T>>,
+{
+    /// Casts received request to a runtime call.
+    fn cast(request: Request) -> CallOf<T> {
+        match request {
+            Request::Vote { poll_index, vote } => pallet_conviction_voting::Call::<T>::vote {
+                poll_index: poll_index.into(),
+                vote: match vote {
+                    BuiltinAccountVote::Standard {
+                        aye,
+                        conviction,
+                        balance,
+                    } => AccountVote::Standard {
+                        vote: Vote {
+                            aye,
+                            conviction: Self::cast_conviction(conviction),
+                        },
+                        balance: balance.unique_saturated_into(),
+                    },
+                    BuiltinAccountVote::Split { aye, nay } => AccountVote::Split {
+                        aye: aye.unique_saturated_into(),
+                        nay: nay.unique_saturated_into(),
+                    },
+                    BuiltinAccountVote::SplitAbstain { aye, nay, abstain } => {
+                        AccountVote::SplitAbstain {
+                            aye: aye.unique_saturated_into(),
+                            nay: nay.unique_saturated_into(),
+                            abstain: abstain.unique_saturated_into(),
+                        }
+                    }
+                },
+            },
+            Request::Delegate {
+                class,
+                to,
+                conviction,
+                balance,
+            } => pallet_conviction_voting::Call::<T>::delegate {
+                class: class.into(),
+                to: T::Lookup::unlookup(to.cast()),
+                conviction: Self::cast_conviction(conviction),
+                balance: balance.unique_saturated_into(),
+            },
+            Request::Undelegate { class } => pallet_conviction_voting::Call::<T>::undelegate {
+                class: class.into(),
+            },
+            Request::RemoveVote { class, index } => {
+                pallet_conviction_voting::Call::<T>::remove_vote {
+                    class: class.map(Into::into),
+                    index: index.into(),
+                }
+            }
+            Request::Unlock { class, target } => pallet_conviction_voting::Call::<T>::unlock {
+                class: class.into(),
+                target: T::Lookup::unlookup(target.cast()),
+            },
+            Request::ClassLocks => unreachable!("ClassLocks is handled separately"),
+        }
+        .into()
+    }
+
+    fn cast_conviction(conviction: BuiltinConviction) -> Conviction {
+        match conviction {
+            BuiltinConviction::None => Conviction::None,
+            BuiltinConviction::Locked1x => Conviction::Locked1x,
+            BuiltinConviction::Locked2x => Conviction::Locked2x,
+            BuiltinConviction::Locked3x => Conviction::Locked3x,
+            BuiltinConviction::Locked4x => Conviction::Locked4x,
+            BuiltinConviction::Locked5x => Conviction::Locked5x,
+            BuiltinConviction::Locked6x => Conviction::Locked6x,
+        }
+    }
+}
+
+impl<T: Config + ConvictionVotingConfig> BuiltinActor for Actor<T>
+where
+    T::AccountId: Origin,
+    ClassOf<T>: From<u16> + Into<u16>,
+    PollIndexOf<T>: From<u32>,
+    CallOf<T>: From<pallet_conviction_voting::Call<T>>,
+{
+    fn handle(
+        dispatch: &StoredDispatch,
+        context: &mut BuiltinContext,
+    ) -> Result<BuiltinReply, BuiltinActorError> {
+        let request = Request::decode(&mut dispatch.payload_bytes())
+            .map_err(|_| BuiltinActorError::DecodingError)?;
+
+        let origin = dispatch.source();
+
+        // Read-only requests are served from storage directly
+        if let Request::ClassLocks = request {
+            let read_cost = <T as frame_system::Config>::DbWeight::get()
+                .reads(1)
+                .ref_time();
+            context.try_charge_gas(read_cost)?;
+
+            let who: T::AccountId = origin.cast();
+            let locks = pallet_conviction_voting::ClassLocksFor::<T>::get(who)
+                .into_iter()
+                .map(|(class, amount)| (class.into(), amount.unique_saturated_into()))
+                .collect();
+            let payload = Response::ClassLocks { locks }
+                .encode()
+                .try_into()
+                .expect("Small vector");
+            return Ok(BuiltinReply {
+                payload,
+                value: dispatch.value(),
+            });
+        }
+
+        let call = Self::cast(request);
+
+        Ok(BuiltinReply {
+            payload: Pallet::<T>::dispatch_call(origin, call, context)
+                .map(|_| Default::default())?,
+            value: dispatch.value(),
+        })
+    }
+
+    fn max_gas() -> u64 {
+        Default::default()
+    }
+}
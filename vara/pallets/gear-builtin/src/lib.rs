@@ -21,6 +21,8 @@ extern crate alloc;
 pub mod benchmarking;
 
 pub mod bls12_381;
+pub mod bn254;
+pub mod governance;
 pub mod proxy;
 pub mod staking;
 pub mod weights;
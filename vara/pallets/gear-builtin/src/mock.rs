@@ -3,7 +3,7 @@
 
 use crate::{
     self as pallet_gear_builtin, ActorWithId, BuiltinActor, BuiltinReply, GasAllowanceOf,
-    bls12_381, proxy,
+    bls12_381, bn254, governance, proxy,
 };
 use builtins_common::{BuiltinActorError, BuiltinContext};
 use common::{GasProvider, GasTree, storage::Limiter};
@@ -12,19 +12,26 @@ use frame_support::{
     PalletId, construct_runtime,
     pallet_prelude::{DispatchClass, Weight},
     parameter_types,
-    traits::{ConstU32, ConstU64, FindAuthor, Get, InstanceFilter, OnFinalize, OnInitialize},
+    traits::{
+        ConstU32, ConstU64, FindAuthor, Get, InstanceFilter, OnFinalize, OnInitialize, PollStatus,
+        Polling,
+    },
 };
 use frame_support_test::TestRandomness;
 use frame_system::{self as system, limits::BlockWeights, pallet_prelude::BlockNumberFor};
 use gbuiltin_proxy::ProxyType as BuiltinProxyType;
 use gear_core::{ids::ActorId, message::StoredDispatch};
+use pallet_conviction_voting::{Tally, TallyOf};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_core::H256;
 use sp_runtime::{
-    BuildStorage, Perbill, Permill, RuntimeDebug,
+    BuildStorage, DispatchError, Perbill, Permill, RuntimeDebug,
     traits::{BlakeTwo256, IdentityLookup},
 };
-use sp_std::convert::{TryFrom, TryInto};
+use sp_std::{
+    collections::btree_map::BTreeMap,
+    convert::{TryFrom, TryInto},
+};
 
 type AccountId = u64;
 type BlockNumber = u32;
@@ -71,6 +78,7 @@ construct_runtime!(
         Timestamp: pallet_timestamp,
         Staking: pallet_staking,
         Proxy: pallet_proxy,
+        ConvictionVoting: pallet_conviction_voting,
         GearProgram: pallet_gear_program,
         GearMessenger: pallet_gear_messenger,
         GearScheduler: pallet_gear_scheduler,
@@ -135,7 +143,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 )
             }
             ProxyType::Staking => matches!(c, RuntimeCall::Staking(..)),
-            ProxyType::Governance | ProxyType::IdentityJudgement => {
+            ProxyType::Governance => matches!(c, RuntimeCall::ConvictionVoting(..)),
+            ProxyType::IdentityJudgement => {
                 unimplemented!("No pallets defined in test runtime")
             }
         }
@@ -181,6 +190,114 @@ impl pallet_proxy::Config for Test {
     type AnnouncementDepositFactor = AnnouncementDepositBase;
 }
 
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+    Ongoing(TallyOf<Test>, u16),
+    Completed(BlockNumber, bool),
+}
+
+parameter_types! {
+    pub static Polls: BTreeMap<u32, TestPollState> = [
+        (0, TestPollState::Ongoing(Tally::from_parts(0, 0, 0), 0)),
+        (1, TestPollState::Completed(1, true)),
+    ]
+    .into_iter()
+    .collect();
+}
+
+// Minimal polling source standing in for the referenda pallet.
+pub struct TestPolls;
+impl Polling<TallyOf<Test>> for TestPolls {
+    type Index = u32;
+    type Votes = Balance;
+    type Class = u16;
+    type Moment = BlockNumber;
+
+    fn classes() -> Vec<u16> {
+        vec![0, 1]
+    }
+
+    fn as_ongoing(index: u32) -> Option<(TallyOf<Test>, u16)> {
+        Polls::get().remove(&index).and_then(|state| match state {
+            TestPollState::Ongoing(tally, class) => Some((tally, class)),
+            _ => None,
+        })
+    }
+
+    fn access_poll<R>(
+        index: u32,
+        f: impl FnOnce(PollStatus<&mut TallyOf<Test>, BlockNumber, u16>) -> R,
+    ) -> R {
+        let mut polls = Polls::get();
+        let r = match polls.get_mut(&index) {
+            Some(TestPollState::Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+            Some(TestPollState::Completed(when, approved)) => {
+                f(PollStatus::Completed(*when, *approved))
+            }
+            None => f(PollStatus::None),
+        };
+        Polls::set(polls);
+        r
+    }
+
+    fn try_access_poll<R>(
+        index: u32,
+        f: impl FnOnce(PollStatus<&mut TallyOf<Test>, BlockNumber, u16>) -> Result<R, DispatchError>,
+    ) -> Result<R, DispatchError> {
+        let mut polls = Polls::get();
+        let r = match polls.get_mut(&index) {
+            Some(TestPollState::Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+            Some(TestPollState::Completed(when, approved)) => {
+                f(PollStatus::Completed(*when, *approved))
+            }
+            None => f(PollStatus::None),
+        }?;
+        Polls::set(polls);
+        Ok(r)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_ongoing(class: u16) -> Result<u32, ()> {
+        let mut polls = Polls::get();
+        let index = polls.keys().next_back().map_or(0, |x| x + 1);
+        polls.insert(
+            index,
+            TestPollState::Ongoing(Tally::from_parts(0, 0, 0), class),
+        );
+        Polls::set(polls);
+        Ok(index)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn end_ongoing(index: u32, approved: bool) -> Result<(), ()> {
+        let mut polls = Polls::get();
+        if !matches!(polls.get(&index), Some(TestPollState::Ongoing(..))) {
+            return Err(());
+        }
+        polls.insert(
+            index,
+            TestPollState::Completed(System::block_number(), approved),
+        );
+        Polls::set(polls);
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn max_ongoing() -> (u16, u32) {
+        (0, 1_000)
+    }
+}
+
+impl pallet_conviction_voting::Config for Test {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type VoteLockingPeriod = ConstU32<3>;
+    type MaxVotes = ConstU32<3>;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, AccountId>;
+    type Polls = TestPolls;
+}
+
 parameter_types! {
     pub const BlockGasLimit: u64 = 100_000_000_000;
     pub const OutgoingLimit: u32 = 1024;
@@ -318,6 +435,8 @@ impl pallet_gear_builtin::Config for Test {
         ActorWithId<HONEST_ACTOR_ID, HonestBuiltinActor>,
         ActorWithId<1, bls12_381::Actor<Self>>,
         ActorWithId<4, proxy::Actor<Self>>,
+        ActorWithId<5, bn254::Actor<Self>>,
+        ActorWithId<6, governance::Actor<Self>>,
     );
     type BlockLimiter = GearGas;
     type WeightInfo = ();
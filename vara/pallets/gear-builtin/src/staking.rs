@@ -6,6 +6,7 @@
 use super::*;
 use common::Origin;
 use core::marker::PhantomData;
+use frame_support::traits::Get;
 use gbuiltin_staking::*;
 use gear_core::limited::LimitedStr;
 use pallet_staking::{Config as StakingConfig, NominationsQuota, RewardDestination};
@@ -84,11 +85,136 @@ where
                 };
                 pallet_staking::Call::<T>::set_payee { payee }.into()
             }
-            _ => unreachable!("ActiveEra is handled separately"),
+            _ => unreachable!("Queries are handled separately"),
         }
     }
 }
 
+impl<T: Config + StakingConfig + pallet_gear::Config> Actor<T>
+where
+    T::AccountId: Origin,
+{
+    /// Answers a read-only request from the staking pallet storage.
+    pub(crate) fn query(
+        request: Request,
+        who: T::AccountId,
+        context: &mut BuiltinContext,
+    ) -> Result<Response, BuiltinActorError> {
+        let charge_reads = |context: &mut BuiltinContext, reads: u64| {
+            context.try_charge_gas(
+                <T as frame_system::Config>::DbWeight::get()
+                    .reads(reads)
+                    .ref_time(),
+            )
+        };
+        let ledger_of = |who: &T::AccountId| {
+            pallet_staking::Bonded::<T>::get(who)
+                .and_then(|controller| pallet_staking::Ledger::<T>::get(controller))
+        };
+        let unlocking_of = |unlocking: &[pallet_staking::UnlockChunk<_>]| -> Vec<UnlockChunk> {
+            unlocking
+                .iter()
+                .map(|chunk| UnlockChunk {
+                    value: chunk.value.unique_saturated_into(),
+                    era: chunk.era,
+                })
+                .collect()
+        };
+
+        let response = match request {
+            Request::ActiveEra => {
+                let executed_at = frame_system::Pallet::<T>::block_number().saturated_into::<u32>();
+                let executed_at_gear_block =
+                    pallet_gear::Pallet::<T>::block_number().saturated_into::<u32>();
+                let Some(era_info) = pallet_staking::ActiveEra::<T>::get() else {
+                    return Err(BuiltinActorError::Custom(LimitedStr::from_small_str(
+                        "Active era is not set",
+                    )));
+                };
+                Response::ActiveEra {
+                    info: ActiveEraInfo {
+                        index: era_info.index,
+                        start: era_info.start,
+                    },
+                    executed_at,
+                    executed_at_gear_block,
+                }
+            }
+            Request::Ledger => {
+                charge_reads(context, 2)?;
+                Response::Ledger {
+                    ledger: ledger_of(&who).map(|ledger| LedgerInfo {
+                        stash: ledger.stash.cast(),
+                        total: ledger.total.unique_saturated_into(),
+                        active: ledger.active.unique_saturated_into(),
+                        unlocking: unlocking_of(&ledger.unlocking),
+                    }),
+                }
+            }
+            Request::UnlockingChunks => {
+                charge_reads(context, 3)?;
+                Response::UnlockingChunks {
+                    chunks: ledger_of(&who)
+                        .map(|ledger| unlocking_of(&ledger.unlocking))
+                        .unwrap_or_default(),
+                    current_era: pallet_staking::CurrentEra::<T>::get(),
+                }
+            }
+            Request::Nominations => {
+                charge_reads(context, 1)?;
+                Response::Nominations {
+                    nominations: pallet_staking::Nominators::<T>::get(&who).map(|nominations| {
+                        NominationsInfo {
+                            targets: nominations
+                                .targets
+                                .into_iter()
+                                .map(|account_id| account_id.cast())
+                                .collect(),
+                            submitted_in: nominations.submitted_in,
+                            suppressed: nominations.suppressed,
+                        }
+                    }),
+                }
+            }
+            Request::EraRewardPoints { era } => {
+                charge_reads(context, 1)?;
+                let points = pallet_staking::ErasRewardPoints::<T>::get(era);
+                // The value grows with the number of validators, so they are charged
+                // as separate reads.
+                charge_reads(context, points.individual.len() as u64)?;
+                Response::EraRewardPoints {
+                    era,
+                    total: points.total,
+                    individual: points
+                        .individual
+                        .into_iter()
+                        .map(|(account_id, points)| (account_id.cast(), points))
+                        .collect(),
+                }
+            }
+            Request::SlashingSpans => {
+                charge_reads(context, 1)?;
+                // The pallet type keeps its fields private, but shares the encoding.
+                // Its layout isn't a part of the public API, so a mismatch is reported
+                // as an error reply instead of a panic.
+                let spans = pallet_staking::SlashingSpans::<T>::get(&who)
+                    .map(|spans| SlashingSpansInfo::decode(&mut &spans.encode()[..]))
+                    .transpose()
+                    .map_err(|_| {
+                        log::error!("Failed to decode slashing spans of {who:?}");
+                        BuiltinActorError::Custom(LimitedStr::from_small_str(
+                            "Failed to decode slashing spans",
+                        ))
+                    })?;
+                Response::SlashingSpans { spans }
+            }
+            _ => unreachable!("Only queries are handled here"),
+        };
+
+        Ok(response)
+    }
+}
+
 impl<T: Config + StakingConfig + pallet_gear::Config> BuiltinActor for Actor<T>
 where
     T::AccountId: Origin,
@@ -120,26 +246,15 @@ where
             Request::decode(&mut payload).map_err(|_| BuiltinActorError::DecodingError)?;
 
         // Handle special cases that return custom response instead of just dispatching calls
-        if let Request::ActiveEra = request {
-            let executed_at = frame_system::Pallet::<T>::block_number().saturated_into::<u32>();
-            let executed_at_gear_block =
-                pallet_gear::Pallet::<T>::block_number().saturated_into::<u32>();
-            let Some(era_info) = pallet_staking::ActiveEra::<T>::get() else {
-                return Err(BuiltinActorError::Custom(LimitedStr::from_small_str(
-                    "Active era is not set",
-                )));
-            };
-            let payload = Response::ActiveEra {
-                info: ActiveEraInfo {
-                    index: era_info.index,
-                    start: era_info.start,
-                },
-                executed_at,
-                executed_at_gear_block,
-            }
-            .encode()
-            .try_into()
-            .expect("Small vector");
+        if request.is_query() {
+            let payload = Self::query(request, origin.cast(), context)?
+                .encode()
+                .try_into()
+                .map_err(|_| {
+                    BuiltinActorError::Custom(LimitedStr::from_small_str(
+                        "Response message is too large",
+                    ))
+                })?;
             return Ok(BuiltinReply {
                 payload,
                 value: dispatch.value(),
@@ -0,0 +1,314 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use crate::{mock::*, tests::DEFAULT_GAS_LIMIT};
+use ark_std::{UniformRand, ops::Mul};
+use builtins_common::bn254::{
+    Request, Response,
+    ark_bn254::{
+        self, Bn254, Fq, Fr as ScalarField, G1Affine, G1Projective as G1, G2Affine,
+        G2Projective as G2,
+    },
+    ark_ec::{Group, pairing::Pairing, short_weierstrass::SWCurveConfig},
+    ark_ff::biginteger::BigInt,
+    ark_scale::{self, hazmat::ArkScaleProjective},
+};
+use common::Origin;
+use frame_support::assert_ok;
+use gear_core::ids::ActorId;
+use gear_core_errors::{ErrorReplyReason, ReplyCode, SimpleExecutionError};
+use pallet_gear::GasInfo;
+use parity_scale_codec::{Decode, Encode};
+use primitive_types::H256;
+
+type ArkScale<T> = ark_scale::ArkScale<T, { ark_scale::HOST_CALL }>;
+
+const ACTOR_ID: [u8; 32] =
+    hex_literal::hex!("13483c43cc2e4ebc4e7f79ac1f8e66c149a41ad11e483a6e2de2c12f535af06e");
+
+pub(crate) fn init_logger() {
+    let _ = tracing_subscriber::fmt::try_init();
+}
+
+fn get_gas_info(builtin_id: ActorId, payload: Vec<u8>) -> GasInfo {
+    start_transaction();
+    let res = Gear::calculate_gas_info(
+        SIGNER.into_origin(),
+        pallet_gear::manager::HandleKind::Handle(builtin_id),
+        payload,
+        0,
+        true,
+        None,
+        None,
+    )
+    .expect("calculate_gas_info failed");
+    rollback_transaction();
+
+    assert_ne!(res.min_limit, 0);
+    assert_ne!(res.burned, 0);
+    // < 90% * block_gas_limit
+    assert!(res.burned < BlockGasLimit::get() / 10 * 9);
+
+    res
+}
+
+fn assert_error_reply(reason: SimpleExecutionError) {
+    assert!(System::events().into_iter().any(|e| match e.event {
+        RuntimeEvent::Gear(pallet_gear::Event::<Test>::UserMessageSent { message, .. }) => {
+            message.destination() == SIGNER.cast()
+                && matches!(message.details(), Some(details) if details.to_reply_code()
+                == ReplyCode::Error(ErrorReplyReason::Execution(reason)))
+        }
+        _ => false,
+    }));
+}
+
+fn send_and_get_response(payload: Vec<u8>) -> Response {
+    let builtin_id: ActorId = H256::from(ACTOR_ID).cast();
+    let gas_info = get_gas_info(builtin_id, payload.clone());
+
+    // Check the case of insufficient gas
+    System::reset_events();
+
+    assert_ok!(Gear::send_message(
+        RuntimeOrigin::signed(SIGNER),
+        builtin_id,
+        payload.clone(),
+        gas_info.min_limit / 2,
+        0,
+        false,
+    ));
+
+    run_to_next_block();
+
+    assert_error_reply(SimpleExecutionError::RanOutOfGas);
+
+    // Check the computations are correct
+    System::reset_events();
+
+    assert_ok!(Gear::send_message(
+        RuntimeOrigin::signed(SIGNER),
+        builtin_id,
+        payload,
+        gas_info.min_limit,
+        0,
+        false,
+    ));
+
+    run_to_next_block();
+
+    let response = System::events()
+        .into_iter()
+        .find_map(|e| match e.event {
+            RuntimeEvent::Gear(pallet_gear::Event::<Test>::UserMessageSent { message, .. }) => {
+                assert_eq!(message.destination(), SIGNER.cast());
+                assert!(
+                    matches!(message.details(), Some(details) if matches!(details.to_reply_code(), ReplyCode::Success(..)))
+                );
+
+                Some(message.payload_bytes().to_vec())
+            }
+            _ => None,
+        })
+        .expect("reply should have been sent");
+
+    Response::decode(&mut response.as_slice()).expect("failed to decode response")
+}
+
+#[test]
+fn decoding_error() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let builtin_actor_id: ActorId = H256::from(ACTOR_ID).cast();
+
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            builtin_actor_id,
+            vec![255u8; 10],
+            1_000_000_000,
+            0,
+            false,
+        ));
+
+        run_to_next_block();
+
+        // An error reply should have been sent.
+        assert_error_reply(SimpleExecutionError::UserspacePanic);
+    });
+}
+
+#[test]
+fn multi_miller_loop() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let mut rng = ark_std::test_rng();
+
+        let message: G1Affine = G1::rand(&mut rng).into();
+        let priv_key: ScalarField = UniformRand::rand(&mut rng);
+        let pub_key: G2Affine = G2::generator().mul(priv_key).into();
+
+        let a: ArkScale<Vec<<Bn254 as Pairing>::G1Affine>> = vec![message].into();
+        let b: ArkScale<Vec<<Bn254 as Pairing>::G2Affine>> = vec![].into();
+        let payload = Request::MultiMillerLoop {
+            a: a.encode(),
+            b: b.encode(),
+        }
+        .encode();
+
+        // Case of the incorrect arguments
+        let builtin_id: ActorId = H256::from(ACTOR_ID).cast();
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            builtin_id,
+            payload,
+            DEFAULT_GAS_LIMIT,
+            0,
+            false,
+        ));
+
+        run_to_next_block();
+
+        assert_error_reply(SimpleExecutionError::UserspacePanic);
+
+        let result = <Bn254 as Pairing>::multi_miller_loop(vec![message], vec![pub_key]);
+
+        let b: ArkScale<Vec<<Bn254 as Pairing>::G2Affine>> = vec![pub_key].into();
+        let payload = Request::MultiMillerLoop {
+            a: a.encode(),
+            b: b.encode(),
+        }
+        .encode();
+
+        let Response::MultiMillerLoop(builtin_result) = send_and_get_response(payload) else {
+            unreachable!()
+        };
+
+        let builtin_result =
+            ArkScale::<<Bn254 as Pairing>::TargetField>::decode(&mut builtin_result.as_slice())
+                .unwrap();
+        assert_eq!(result.0, builtin_result.0);
+    });
+}
+
+#[test]
+fn final_exponentiation() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let mut rng = ark_std::test_rng();
+
+        let message: G1Affine = G1::rand(&mut rng).into();
+        let priv_key: ScalarField = UniformRand::rand(&mut rng);
+        let pub_key: G2Affine = G2::generator().mul(priv_key).into();
+
+        let loop_result = <Bn254 as Pairing>::multi_miller_loop(vec![message], vec![pub_key]);
+        let result = <Bn254 as Pairing>::final_exponentiation(loop_result);
+
+        let f: ArkScale<<Bn254 as Pairing>::TargetField> = loop_result.0.into();
+        let payload = Request::FinalExponentiation { f: f.encode() }.encode();
+
+        let Response::FinalExponentiation(builtin_result) = send_and_get_response(payload) else {
+            unreachable!()
+        };
+
+        let builtin_result =
+            ArkScale::<<Bn254 as Pairing>::TargetField>::decode(&mut builtin_result.as_slice())
+                .unwrap();
+        assert!(matches!(result, Some(r) if r.0 == builtin_result.0));
+    });
+}
+
+#[test]
+fn addition_g1() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let mut rng = ark_std::test_rng();
+
+        let a = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+
+        let ark_a: ArkScaleProjective<G1> = a.into();
+        let ark_b: ArkScaleProjective<G1> = b.into();
+        let payload = Request::AdditionG1 {
+            a: ark_a.encode(),
+            b: ark_b.encode(),
+        }
+        .encode();
+
+        let Response::AdditionG1(builtin_result) = send_and_get_response(payload) else {
+            unreachable!()
+        };
+
+        let builtin_result =
+            ArkScaleProjective::<G1>::decode(&mut builtin_result.as_slice()).unwrap();
+        assert_eq!(a + b, builtin_result.0);
+    });
+}
+
+#[test]
+fn point_not_on_curve() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let mut rng = ark_std::test_rng();
+
+        // y^2 != x^3 + 3
+        let a = G1::new_unchecked(Fq::from(1), Fq::from(1), Fq::from(1));
+        let b = G1::rand(&mut rng);
+
+        let ark_a: ArkScaleProjective<G1> = a.into();
+        let ark_b: ArkScaleProjective<G1> = b.into();
+        let payload = Request::AdditionG1 {
+            a: ark_a.encode(),
+            b: ark_b.encode(),
+        }
+        .encode();
+
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            H256::from(ACTOR_ID).cast(),
+            payload,
+            1_000_000_000,
+            0,
+            false,
+        ));
+
+        run_to_next_block();
+
+        assert_error_reply(SimpleExecutionError::UserspacePanic);
+    });
+}
+
+#[test]
+fn mul_projective_g1() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let mut rng = ark_std::test_rng();
+
+        let bigint = BigInt::<4>::rand(&mut rng);
+        let bigint = bigint.as_ref().to_vec();
+        let base = G1::rand(&mut rng);
+
+        let ark_bigint: ArkScale<Vec<u64>> = bigint.clone().into();
+        let ark_base: ArkScaleProjective<G1> = base.into();
+        let payload = Request::ProjectiveMultiplicationG1 {
+            base: ark_base.encode(),
+            scalar: ark_bigint.encode(),
+        }
+        .encode();
+
+        let Response::ProjectiveMultiplicationG1(builtin_result) = send_and_get_response(payload)
+        else {
+            unreachable!()
+        };
+
+        let builtin_result =
+            ArkScaleProjective::<G1>::decode(&mut builtin_result.as_slice()).unwrap();
+        let expected = <ark_bn254::g1::Config as SWCurveConfig>::mul_projective(&base, &bigint);
+        assert_eq!(expected, builtin_result.0);
+    });
+}
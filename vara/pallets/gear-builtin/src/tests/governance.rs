@@ -0,0 +1,274 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Governance builtin tests.
+
+use super::basic::init_logger;
+use crate::{mock::*, tests::DEFAULT_GAS_LIMIT};
+use common::Origin;
+use frame_support::assert_ok;
+use gbuiltin_governance::{AccountVote, Conviction, Request, Response};
+use gear_core::ids::ActorId;
+use gear_core_errors::{ErrorReplyReason, ReplyCode, SimpleExecutionError};
+use pallet_conviction_voting::{Casting, ClassLocksFor, Delegating, Voting, VotingFor};
+use parity_scale_codec::{Decode, Encode};
+use primitive_types::H256;
+
+const ACTOR_ID: [u8; 32] =
+    hex_literal::hex!("b46c6ff21dbea667fbf19e377da9a27c8accaafdd9f450241d225105e380a944");
+
+const ONGOING_POLL: u32 = 0;
+const COMPLETED_POLL: u32 = 1;
+const CLASS: u16 = 0;
+
+#[test]
+fn decoding_error() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        utils::send_payload(vec![255u8; 10]);
+
+        // An error reply should have been sent.
+        utils::assert_error_reply(SimpleExecutionError::UserspacePanic);
+    });
+}
+
+#[test]
+fn vote_and_remove_vote_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        utils::send_request(Request::Vote {
+            poll_index: ONGOING_POLL,
+            vote: AccountVote::Standard {
+                aye: true,
+                conviction: Conviction::Locked1x,
+                balance: 100 * UNITS,
+            },
+        });
+        utils::assert_success_reply();
+
+        let tally = utils::ongoing_tally(ONGOING_POLL);
+        assert_eq!(tally.ayes, 100 * UNITS);
+        assert_eq!(tally.nays, 0);
+        assert_eq!(tally.support, 100 * UNITS);
+
+        let Voting::Casting(Casting { votes, .. }) = VotingFor::<Test>::get(SIGNER, CLASS) else {
+            panic!("voting should be casting");
+        };
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].0, ONGOING_POLL);
+
+        // Funds used for voting are locked for the class
+        assert_eq!(
+            ClassLocksFor::<Test>::get(SIGNER).into_inner(),
+            vec![(CLASS, 100 * UNITS)]
+        );
+
+        System::reset_events();
+
+        utils::send_request(Request::RemoveVote {
+            class: Some(CLASS),
+            index: ONGOING_POLL,
+        });
+        utils::assert_success_reply();
+
+        let tally = utils::ongoing_tally(ONGOING_POLL);
+        assert_eq!(tally.ayes, 0);
+        assert_eq!(tally.support, 0);
+
+        let Voting::Casting(Casting { votes, .. }) = VotingFor::<Test>::get(SIGNER, CLASS) else {
+            panic!("voting should be casting");
+        };
+        assert!(votes.is_empty());
+    });
+}
+
+#[test]
+fn split_abstain_vote_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        utils::send_request(Request::Vote {
+            poll_index: ONGOING_POLL,
+            vote: AccountVote::SplitAbstain {
+                aye: 10 * UNITS,
+                nay: 20 * UNITS,
+                abstain: 30 * UNITS,
+            },
+        });
+        utils::assert_success_reply();
+
+        // Split votes are counted without conviction
+        let tally = utils::ongoing_tally(ONGOING_POLL);
+        assert_eq!(tally.ayes, UNITS);
+        assert_eq!(tally.nays, 2 * UNITS);
+        assert_eq!(tally.support, 40 * UNITS);
+    });
+}
+
+#[test]
+fn vote_on_completed_poll_fails() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        utils::send_request(Request::Vote {
+            poll_index: COMPLETED_POLL,
+            vote: AccountVote::Standard {
+                aye: true,
+                conviction: Conviction::None,
+                balance: 100 * UNITS,
+            },
+        });
+
+        utils::assert_error_reply(SimpleExecutionError::UserspacePanic);
+        assert!(ClassLocksFor::<Test>::get(SIGNER).is_empty());
+    });
+}
+
+#[test]
+fn delegate_and_undelegate_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        utils::send_request(Request::Delegate {
+            class: CLASS,
+            to: VAL_1_STASH.cast(),
+            conviction: Conviction::Locked2x,
+            balance: 50 * UNITS,
+        });
+        utils::assert_success_reply();
+
+        let Voting::Delegating(Delegating {
+            balance, target, ..
+        }) = VotingFor::<Test>::get(SIGNER, CLASS)
+        else {
+            panic!("voting should be delegating");
+        };
+        assert_eq!(balance, 50 * UNITS);
+        assert_eq!(target, VAL_1_STASH);
+
+        let Voting::Casting(Casting { delegations, .. }) =
+            VotingFor::<Test>::get(VAL_1_STASH, CLASS)
+        else {
+            panic!("target voting should be casting");
+        };
+        assert_eq!(delegations.votes, 100 * UNITS);
+
+        System::reset_events();
+
+        utils::send_request(Request::Undelegate { class: CLASS });
+        utils::assert_success_reply();
+
+        assert!(matches!(
+            VotingFor::<Test>::get(SIGNER, CLASS),
+            Voting::Casting(..)
+        ));
+        let Voting::Casting(Casting { delegations, .. }) =
+            VotingFor::<Test>::get(VAL_1_STASH, CLASS)
+        else {
+            panic!("target voting should be casting");
+        };
+        assert_eq!(delegations.votes, 0);
+    });
+}
+
+#[test]
+fn class_locks_query_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        // No locks yet
+        utils::send_request(Request::ClassLocks);
+        assert_eq!(utils::response(), Response::ClassLocks { locks: vec![] });
+
+        System::reset_events();
+
+        utils::send_request(Request::Vote {
+            poll_index: ONGOING_POLL,
+            vote: AccountVote::Standard {
+                aye: false,
+                conviction: Conviction::Locked3x,
+                balance: 70 * UNITS,
+            },
+        });
+        utils::assert_success_reply();
+
+        System::reset_events();
+
+        utils::send_request(Request::ClassLocks);
+        assert_eq!(
+            utils::response(),
+            Response::ClassLocks {
+                locks: vec![(CLASS, 70 * UNITS)]
+            }
+        );
+    });
+}
+
+mod utils {
+    use super::*;
+
+    pub(super) fn builtin_id() -> ActorId {
+        H256::from(ACTOR_ID).cast()
+    }
+
+    pub(super) fn send_payload(payload: Vec<u8>) {
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            builtin_id(),
+            payload,
+            DEFAULT_GAS_LIMIT,
+            0,
+            false,
+        ));
+
+        run_to_next_block();
+    }
+
+    pub(super) fn send_request(request: Request) {
+        send_payload(request.encode())
+    }
+
+    pub(super) fn ongoing_tally(index: u32) -> pallet_conviction_voting::TallyOf<Test> {
+        match Polls::get().get(&index) {
+            Some(TestPollState::Ongoing(tally, _)) => tally.clone(),
+            _ => panic!("poll {index} should be ongoing"),
+        }
+    }
+
+    fn reply() -> (ReplyCode, Vec<u8>) {
+        System::events()
+            .into_iter()
+            .find_map(|e| match e.event {
+                RuntimeEvent::Gear(pallet_gear::Event::<Test>::UserMessageSent {
+                    message, ..
+                }) if message.destination() == SIGNER.cast() => message
+                    .details()
+                    .map(|details| (details.to_reply_code(), message.payload_bytes().to_vec())),
+                _ => None,
+            })
+            .expect("reply should have been sent")
+    }
+
+    #[track_caller]
+    pub(super) fn assert_success_reply() {
+        assert!(matches!(reply().0, ReplyCode::Success(..)));
+    }
+
+    #[track_caller]
+    pub(super) fn assert_error_reply(reason: SimpleExecutionError) {
+        assert_eq!(
+            reply().0,
+            ReplyCode::Error(ErrorReplyReason::Execution(reason))
+        );
+    }
+
+    #[track_caller]
+    pub(super) fn response() -> Response {
+        let (code, payload) = reply();
+        assert!(matches!(code, ReplyCode::Success(..)));
+
+        Response::decode(&mut payload.as_slice()).expect("failed to decode response")
+    }
+}
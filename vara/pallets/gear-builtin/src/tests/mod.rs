@@ -8,5 +8,7 @@ pub const DEFAULT_GAS_LIMIT: u64 = 20_000_000_000;
 mod bad_builtin_ids;
 mod basic;
 mod bls381;
+mod bn254;
+mod governance;
 mod proxy;
 mod staking;
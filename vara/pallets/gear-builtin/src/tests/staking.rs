@@ -1,9 +1,12 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-use crate::tests::DEFAULT_GAS_LIMIT;
-use frame_support::assert_ok;
-use gbuiltin_staking::{ActiveEraInfo, Response};
+use crate::{staking::Actor as StakingBuiltin, tests::DEFAULT_GAS_LIMIT};
+use builtins_common::BuiltinContext;
+use frame_support::{assert_ok, traits::Get};
+use gbuiltin_staking::{
+    ActiveEraInfo, LedgerInfo, NominationsInfo, Response, SlashingSpansInfo, UnlockChunk,
+};
 use gprimitives::ActorId;
 use parity_scale_codec::Decode;
 use sp_staking::StakingAccount;
@@ -715,6 +718,218 @@ fn active_era_query_without_active_era_errors() {
     });
 }
 
+#[test]
+fn ledger_and_unlocking_chunks_queries_work() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let contract_id = ActorId::generate_from_user(CodeId::generate(WASM_BINARY), b"contract");
+
+        deploy_broker_contract();
+        run_to_next_block();
+
+        // Nothing is bonded yet
+        assert_eq!(
+            query_via_contract(contract_id, Request::Ledger),
+            Response::Ledger { ledger: None }
+        );
+
+        send_bond_message(contract_id, 100 * UNITS, None);
+        run_to_next_block();
+
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            contract_id,
+            Request::Unbond { value: 30 * UNITS }.encode(),
+            DEFAULT_GAS_LIMIT,
+            0,
+            false,
+        ));
+        run_to_next_block();
+
+        let current_era = pallet_staking::CurrentEra::<Test>::get();
+        let unlocking = vec![UnlockChunk {
+            value: 30 * UNITS,
+            era: current_era.unwrap_or_default()
+                + <Test as pallet_staking::Config>::BondingDuration::get(),
+        }];
+
+        assert_eq!(
+            query_via_contract(contract_id, Request::Ledger),
+            Response::Ledger {
+                ledger: Some(LedgerInfo {
+                    stash: contract_id,
+                    total: 100 * UNITS,
+                    active: 70 * UNITS,
+                    unlocking: unlocking.clone(),
+                })
+            }
+        );
+        assert_eq!(
+            query_via_contract(contract_id, Request::UnlockingChunks),
+            Response::UnlockingChunks {
+                chunks: unlocking,
+                current_era,
+            }
+        );
+    });
+}
+
+#[test]
+fn nominations_query_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let contract_id = ActorId::generate_from_user(CodeId::generate(WASM_BINARY), b"contract");
+
+        deploy_broker_contract();
+        run_to_next_block();
+
+        send_bond_message(contract_id, 100 * UNITS, None);
+        run_to_next_block();
+
+        assert_eq!(
+            query_via_contract(contract_id, Request::Nominations),
+            Response::Nominations { nominations: None }
+        );
+
+        let targets: Vec<ActorId> = vec![VAL_1_STASH, VAL_2_STASH]
+            .into_iter()
+            .map(|x| x.cast())
+            .collect();
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            contract_id,
+            Request::Nominate {
+                targets: targets.clone()
+            }
+            .encode(),
+            DEFAULT_GAS_LIMIT,
+            0,
+            false,
+        ));
+        run_to_next_block();
+
+        assert_eq!(
+            query_via_contract(contract_id, Request::Nominations),
+            Response::Nominations {
+                nominations: Some(NominationsInfo {
+                    targets,
+                    submitted_in: pallet_staking::CurrentEra::<Test>::get().unwrap_or_default(),
+                    suppressed: false,
+                })
+            }
+        );
+    });
+}
+
+#[test]
+fn era_reward_points_query_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let contract_id = ActorId::generate_from_user(CodeId::generate(WASM_BINARY), b"contract");
+
+        deploy_broker_contract();
+        run_to_next_block();
+
+        let era = 3;
+        pallet_staking::ErasRewardPoints::<Test>::insert(
+            era,
+            pallet_staking::EraRewardPoints {
+                total: 60,
+                individual: [(VAL_1_STASH, 20), (VAL_2_STASH, 40)].into_iter().collect(),
+            },
+        );
+
+        assert_eq!(
+            query_via_contract(contract_id, Request::EraRewardPoints { era }),
+            Response::EraRewardPoints {
+                era,
+                total: 60,
+                individual: vec![(VAL_1_STASH.cast(), 20), (VAL_2_STASH.cast(), 40)],
+            }
+        );
+
+        // Unknown era has no points
+        assert_eq!(
+            query_via_contract(contract_id, Request::EraRewardPoints { era: era + 1 }),
+            Response::EraRewardPoints {
+                era: era + 1,
+                total: 0,
+                individual: vec![],
+            }
+        );
+    });
+}
+
+#[test]
+fn era_reward_points_query_charges_per_validator() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let era = 3;
+        pallet_staking::ErasRewardPoints::<Test>::insert(
+            era,
+            pallet_staking::EraRewardPoints {
+                total: 60,
+                individual: [(VAL_1_STASH, 20), (VAL_2_STASH, 40)].into_iter().collect(),
+            },
+        );
+
+        let gas_burned = |era| {
+            let mut context = BuiltinContext::new(DEFAULT_GAS_LIMIT, DEFAULT_GAS_LIMIT);
+            StakingBuiltin::<Test>::query(Request::EraRewardPoints { era }, SIGNER, &mut context)
+                .unwrap();
+            context.to_gas_amount().burned()
+        };
+
+        let read = <Test as frame_system::Config>::DbWeight::get()
+            .reads(1)
+            .ref_time();
+        assert_eq!(gas_burned(era), gas_burned(era + 1) + 2 * read);
+    });
+}
+
+#[test]
+fn slashing_spans_query_works() {
+    init_logger();
+
+    new_test_ext().execute_with(|| {
+        let contract_id = ActorId::generate_from_user(CodeId::generate(WASM_BINARY), b"contract");
+        let contract_account_id = AccountId::from_origin(contract_id.into_origin());
+
+        deploy_broker_contract();
+        run_to_next_block();
+
+        assert_eq!(
+            query_via_contract(contract_id, Request::SlashingSpans),
+            Response::SlashingSpans { spans: None }
+        );
+
+        let spans = SlashingSpansInfo {
+            span_index: 2,
+            last_start: 7,
+            last_nonzero_slash: 7,
+            prior: vec![3, 1],
+        };
+        // The pallet type has private fields, so it's built from the shared encoding
+        pallet_staking::SlashingSpans::<Test>::insert(
+            contract_account_id,
+            pallet_staking::slashing::SlashingSpans::decode(&mut &spans.encode()[..]).unwrap(),
+        );
+
+        let response = query_via_contract(contract_id, Request::SlashingSpans);
+        assert_eq!(
+            response,
+            Response::SlashingSpans {
+                spans: Some(spans.clone())
+            }
+        );
+        assert_eq!(spans.num_slashing_spans(), 3);
+    });
+}
+
 mod util {
     pub(super) use crate::mock::{
         BLOCK_AUTHOR, ENDOWMENT, EXISTENTIAL_DEPOSIT, MILLISECS_PER_BLOCK, SIGNER, UNITS,
@@ -740,10 +955,12 @@ mod util {
     };
     use frame_support_test::TestRandomness;
     use frame_system::{self as system, limits::BlockWeights, pallet_prelude::BlockNumberFor};
+    use gbuiltin_staking::Response;
     pub(super) use gbuiltin_staking::{Request, RewardAccount};
     pub(super) use gear_core::ids::{ActorId, CodeId, prelude::*};
     use gear_core_errors::{ErrorReplyReason, ReplyCode, SimpleExecutionError};
     use pallet_session::historical as pallet_session_historical;
+    use parity_scale_codec::Decode;
     pub(super) use parity_scale_codec::Encode;
     use sp_core::{H256, crypto::key_types};
     use sp_runtime::{
@@ -1143,6 +1360,34 @@ mod util {
         )
     }
 
+    #[track_caller]
+    pub(super) fn query_via_contract(contract_id: ActorId, request: Request) -> Response {
+        System::reset_events();
+
+        assert_ok!(Gear::send_message(
+            RuntimeOrigin::signed(SIGNER),
+            contract_id,
+            request.encode(),
+            DEFAULT_GAS_LIMIT,
+            0,
+            false,
+        ));
+
+        run_to_next_block();
+
+        System::events()
+            .into_iter()
+            .find_map(|e| match e.event {
+                RuntimeEvent::Gear(pallet_gear::Event::UserMessageSent { message, .. })
+                    if message.destination() == ActorId::from(SIGNER.into_origin()) =>
+                {
+                    Response::decode(&mut message.payload_bytes()).ok()
+                }
+                _ => None,
+            })
+            .expect("query response should have been sent")
+    }
+
     #[track_caller]
     pub(super) fn assert_error_message_sent() {
         assert!(System::events().into_iter().any(|e| {
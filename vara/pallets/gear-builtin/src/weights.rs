@@ -8,6 +8,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! CPU: `INTEL(R) XEON(R) GOLD 6526Y`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! NOTE: the `bn254_*` weights were not produced by the run above. They are
+//! provisional estimates scaled from the `bls12_381_*` weights and must be
+//! replaced by a benchmark run before the runtime is released.
 
 // Executed Command:
 // ./target/production/gear benchmark pallet --runtime=./target/production/wbuild/vara-runtime/vara_runtime.compact.compressed.wasm --genesis-builder=runtime --genesis-builder-preset=development --steps=50 --repeat=20 --pallet=pallet_gear_builtin --extrinsic=* --heap-pages=16384 --output=./scripts/benchmarking/weights-output/pallet_gear_builtin.rs --template=scripts/benchmarking/frame-weight-template.hbs
@@ -34,6 +38,10 @@ pub trait WeightInfo {
     fn bls12_381_mul_projective_g2(c: u32, ) -> Weight;
     fn bls12_381_aggregate_g1(c: u32, ) -> Weight;
     fn bls12_381_map_to_g2affine(c: u32, ) -> Weight;
+    fn bn254_multi_miller_loop(c: u32, ) -> Weight;
+    fn bn254_final_exponentiation() -> Weight;
+    fn bn254_addition_g1() -> Weight;
+    fn bn254_mul_projective_g1(c: u32, ) -> Weight;
 }
 
 /// Weights for pallet_gear_builtin using the Gear node and recommended hardware.
@@ -140,6 +148,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             // Standard Error: 1
             .saturating_add(Weight::from_parts(681, 0).saturating_mul(c.into()))
     }
+    /// The range of component `c` is `[0, 100]`.
+    fn bn254_multi_miller_loop(c: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(287_415_220, 0)
+            .saturating_add(Weight::from_parts(74_930_518, 0).saturating_mul(c.into()))
+    }
+    fn bn254_final_exponentiation() -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(248_903_000, 0)
+    }
+    fn bn254_addition_g1() -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(243_000, 0)
+    }
+    /// The range of component `c` is `[1, 100]`.
+    fn bn254_mul_projective_g1(c: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(16_092_000, 0)
+            .saturating_add(Weight::from_parts(17_240_873, 0).saturating_mul(c.into()))
+    }
 }
 
 // For backwards compatibility and tests
@@ -245,4 +273,24 @@ impl WeightInfo for () {
             // Standard Error: 1
             .saturating_add(Weight::from_parts(681, 0).saturating_mul(c.into()))
     }
+    /// The range of component `c` is `[0, 100]`.
+    fn bn254_multi_miller_loop(c: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(287_415_220, 0)
+            .saturating_add(Weight::from_parts(74_930_518, 0).saturating_mul(c.into()))
+    }
+    fn bn254_final_exponentiation() -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(248_903_000, 0)
+    }
+    fn bn254_addition_g1() -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(243_000, 0)
+    }
+    /// The range of component `c` is `[1, 100]`.
+    fn bn254_mul_projective_g1(c: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(16_092_000, 0)
+            .saturating_add(Weight::from_parts(17_240_873, 0).saturating_mul(c.into()))
+    }
 }
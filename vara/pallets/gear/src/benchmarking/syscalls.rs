@@ -48,6 +48,16 @@ const PID_SIZE: u32 = size_of::<ActorId>() as u32;
 const MID_SIZE: u32 = size_of::<MessageId>() as u32;
 /// Random subject size
 const RANDOM_SUBJECT_SIZE: u32 = 32;
+/// Hash size
+const HASH_SIZE: u32 = 32;
+/// Ed25519 or sr25519 public key size
+const PUBLIC_KEY_SIZE: u32 = 32;
+/// Ed25519 or sr25519 signature size
+const SIGNATURE_SIZE: u32 = 64;
+/// Recoverable secp256k1 signature size
+const ECDSA_SIGNATURE_SIZE: u32 = 65;
+/// Uncompressed secp256k1 public key size
+const ECDSA_PUBLIC_KEY_SIZE: u32 = 64;
 
 /// Size of struct with fields: error len and handle
 const ERR_HANDLE_SIZE: u32 = ERR_LEN_SIZE + HANDLE_SIZE;
@@ -1373,6 +1383,168 @@ where
         Self::prepare_handle(module, 0)
     }
 
+    pub fn gr_hash(name: SyscallName, r: u32) -> Result<Exec<T>, &'static str> {
+        let repetitions = r * API_BENCHMARK_BATCH_SIZE;
+        let hash_offset = COMMON_OFFSET;
+        let data_offset = hash_offset + HASH_SIZE;
+        let data_len = COMMON_PAYLOAD_LEN;
+
+        let module = ModuleDefinition {
+            memory: Some(ImportedMemory::new(SMALL_MEM_SIZE)),
+            imported_functions: vec![name],
+            handle_body: Some(body::syscall(
+                repetitions,
+                &[
+                    // data ptr
+                    InstrI32Const(data_offset),
+                    // data len
+                    InstrI32Const(data_len),
+                    // hash offset
+                    InstrI32Const(hash_offset),
+                ],
+            )),
+            ..Default::default()
+        };
+
+        Self::prepare_handle(module, 0)
+    }
+
+    pub fn gr_hash_per_kb(name: SyscallName, n: u32) -> Result<Exec<T>, &'static str> {
+        let repetitions = API_BENCHMARK_BATCH_SIZE;
+        let hash_offset = COMMON_OFFSET;
+        let data_offset = hash_offset + HASH_SIZE;
+        let data_len = n * 1024;
+
+        let module = ModuleDefinition {
+            memory: Some(ImportedMemory::max::<T>()),
+            imported_functions: vec![name],
+            handle_body: Some(body::syscall(
+                repetitions,
+                &[
+                    // data ptr
+                    InstrI32Const(data_offset),
+                    // data len
+                    InstrI32Const(data_len),
+                    // hash offset
+                    InstrI32Const(hash_offset),
+                ],
+            )),
+            ..Default::default()
+        };
+
+        Self::prepare_handle(module, 0)
+    }
+
+    fn signature_verification_data(name: SyscallName) -> Vec<u8> {
+        match name {
+            SyscallName::Ed25519Verify => {
+                [&utils::ED25519_PUBLIC_KEY[..], &utils::ED25519_SIGNATURE].concat()
+            }
+            // Identity public key and zero signature with the schnorrkel marker bit
+            // are decoded successfully, so the whole verification is performed.
+            SyscallName::Sr25519Verify => {
+                let mut data = vec![0; (PUBLIC_KEY_SIZE + SIGNATURE_SIZE) as usize];
+                *data.last_mut().expect("data is not empty") = 0x80;
+                data
+            }
+            _ => unreachable!("not a signature verification syscall: {name:?}"),
+        }
+    }
+
+    fn gr_verify_inner(
+        name: SyscallName,
+        repetitions: u32,
+        message_len: u32,
+        memory: ImportedMemory,
+    ) -> Result<Exec<T>, &'static str> {
+        let public_key_offset = COMMON_OFFSET;
+        let signature_offset = public_key_offset + PUBLIC_KEY_SIZE;
+        let verified_offset = signature_offset + SIGNATURE_SIZE;
+        let message_offset = verified_offset + 1;
+
+        let module = ModuleDefinition {
+            memory: Some(memory),
+            imported_functions: vec![name],
+            data_segments: vec![DataSegment {
+                offset: public_key_offset,
+                value: Self::signature_verification_data(name),
+            }],
+            handle_body: Some(body::syscall(
+                repetitions,
+                &[
+                    // public key offset
+                    InstrI32Const(public_key_offset),
+                    // signature offset
+                    InstrI32Const(signature_offset),
+                    // message ptr
+                    InstrI32Const(message_offset),
+                    // message len
+                    InstrI32Const(message_len),
+                    // verified offset
+                    InstrI32Const(verified_offset),
+                ],
+            )),
+            ..Default::default()
+        };
+
+        Self::prepare_handle(module, 0)
+    }
+
+    pub fn gr_verify(name: SyscallName, r: u32) -> Result<Exec<T>, &'static str> {
+        Self::gr_verify_inner(
+            name,
+            r * API_BENCHMARK_BATCH_SIZE,
+            COMMON_PAYLOAD_LEN,
+            ImportedMemory::new(SMALL_MEM_SIZE),
+        )
+    }
+
+    pub fn gr_verify_per_kb(name: SyscallName, n: u32) -> Result<Exec<T>, &'static str> {
+        Self::gr_verify_inner(
+            name,
+            API_BENCHMARK_BATCH_SIZE,
+            n * 1024,
+            ImportedMemory::max::<T>(),
+        )
+    }
+
+    pub fn gr_secp256k1_recover(r: u32) -> Result<Exec<T>, &'static str> {
+        let repetitions = r * API_BENCHMARK_BATCH_SIZE;
+        let message_hash_offset = COMMON_OFFSET;
+        let signature_offset = message_hash_offset + HASH_SIZE;
+        let public_key_offset = signature_offset + ECDSA_SIGNATURE_SIZE;
+        let recovered_offset = public_key_offset + ECDSA_PUBLIC_KEY_SIZE;
+
+        let module = ModuleDefinition {
+            memory: Some(ImportedMemory::new(SMALL_MEM_SIZE)),
+            imported_functions: vec![SyscallName::Secp256k1Recover],
+            data_segments: vec![DataSegment {
+                offset: message_hash_offset,
+                value: [
+                    &utils::SECP256K1_MESSAGE_HASH[..],
+                    &utils::SECP256K1_SIGNATURE,
+                ]
+                .concat(),
+            }],
+            handle_body: Some(body::syscall(
+                repetitions,
+                &[
+                    // message hash offset
+                    InstrI32Const(message_hash_offset),
+                    // signature offset
+                    InstrI32Const(signature_offset),
+                    // public key offset
+                    InstrI32Const(public_key_offset),
+                    // recovered offset
+                    InstrI32Const(recovered_offset),
+                ],
+            )),
+            ..Default::default()
+        };
+
+        Self::prepare_handle(module, 0)
+    }
+
     pub fn termination_bench(
         name: SyscallName,
         param: Option<u32>,
@@ -250,6 +250,12 @@ where
             | SyscallName::FreeRange => check_mem::<T>(),
             SyscallName::SystemBreak => {/* no need for tests because tested in other bench test */}
             SyscallName::Random => check_gr_random::<T>(),
+            SyscallName::Blake2b256
+            | SyscallName::Keccak256
+            | SyscallName::Sha256 => check_gr_hash::<T>(syscall),
+            SyscallName::Ed25519Verify => check_gr_ed25519_verify::<T>(),
+            SyscallName::Sr25519Verify => check_gr_sr25519_verify::<T>(),
+            SyscallName::Secp256k1Recover => check_gr_secp256k1_recover::<T>(),
             SyscallName::ReserveGas => check_gr_reserve_gas::<T>(),
             SyscallName::UnreserveGas => check_gr_unreserve_gas::<T>(),
             SyscallName::ReservationSend => check_gr_reservation_send::<T>(),
@@ -1008,6 +1014,125 @@ where
     })
 }
 
+fn check_gr_hash<T>(syscall: SyscallName)
+where
+    T: Config,
+    T::AccountId: Origin,
+{
+    run_tester::<T, _, _, T::AccountId>(|_, _| {
+        let data = b"Hello, world!".to_vec();
+        let kind = match syscall {
+            SyscallName::Blake2b256 => {
+                let expected_hash = sp_io::hashing::blake2_256(&data);
+                Kind::Blake2b256(data, expected_hash)
+            }
+            SyscallName::Keccak256 => {
+                let expected_hash = sp_io::hashing::keccak_256(&data);
+                Kind::Keccak256(data, expected_hash)
+            }
+            SyscallName::Sha256 => {
+                let expected_hash = sp_io::hashing::sha2_256(&data);
+                Kind::Sha256(data, expected_hash)
+            }
+            _ => unreachable!("not a hashing syscall: {syscall:?}"),
+        };
+        let mp = vec![kind].encode().into();
+
+        (TestCall::send_message(mp), None::<DefaultPostCheck>)
+    })
+}
+
+fn check_gr_ed25519_verify<T>()
+where
+    T: Config,
+    T::AccountId: Origin,
+{
+    run_tester::<T, _, _, T::AccountId>(|_, _| {
+        let message = common_utils::SIGNED_MESSAGE.to_vec();
+        let mut corrupted_message = message.clone();
+        corrupted_message[0] ^= 1;
+
+        let mp = vec![
+            Kind::Ed25519Verify(
+                common_utils::ED25519_PUBLIC_KEY,
+                common_utils::ED25519_SIGNATURE,
+                message,
+                true,
+            ),
+            Kind::Ed25519Verify(
+                common_utils::ED25519_PUBLIC_KEY,
+                common_utils::ED25519_SIGNATURE,
+                corrupted_message,
+                false,
+            ),
+        ]
+        .encode()
+        .into();
+
+        (TestCall::send_message(mp), None::<DefaultPostCheck>)
+    })
+}
+
+fn check_gr_sr25519_verify<T>()
+where
+    T: Config,
+    T::AccountId: Origin,
+{
+    run_tester::<T, _, _, T::AccountId>(|_, _| {
+        let message = common_utils::SIGNED_MESSAGE.to_vec();
+
+        // sr25519 signatures are randomized, so they can only be made on the fly.
+        #[cfg(feature = "std")]
+        let mut kinds = {
+            use sp_core::{Pair, sr25519};
+
+            let pair = sr25519::Pair::from_seed(&[1; 32]);
+            let signature = pair.sign(&message);
+            vec![Kind::Sr25519Verify(
+                pair.public().0,
+                signature.0,
+                message.clone(),
+                true,
+            )]
+        };
+        #[cfg(not(feature = "std"))]
+        let mut kinds = vec![];
+
+        kinds.push(Kind::Sr25519Verify([0; 32], [0; 64], message, false));
+        let mp = kinds.encode().into();
+
+        (TestCall::send_message(mp), None::<DefaultPostCheck>)
+    })
+}
+
+fn check_gr_secp256k1_recover<T>()
+where
+    T: Config,
+    T::AccountId: Origin,
+{
+    run_tester::<T, _, _, T::AccountId>(|_, _| {
+        let mut invalid_signature = common_utils::SECP256K1_SIGNATURE;
+        invalid_signature[64] = 4;
+
+        let mp = vec![
+            Kind::Secp256k1Recover(
+                common_utils::SECP256K1_MESSAGE_HASH,
+                common_utils::SECP256K1_SIGNATURE,
+                Some(common_utils::SECP256K1_PUBLIC_KEY),
+            ),
+            Kind::Secp256k1Recover(
+                common_utils::SECP256K1_MESSAGE_HASH,
+                invalid_signature,
+                None,
+            ),
+        ]
+        .encode()
+        .into();
+
+        (TestCall::send_message(mp), None::<DefaultPostCheck>)
+    })
+}
+
 // TODO: although we do not want to test the business logic,
 // this test is still unstable due to constants #4030
 fn check_gr_gas_available<T>()
@@ -286,3 +286,44 @@ where
         random_data: (vec![0u8; 32], 0),
     })
 }
+
+/// Message signed by the test signatures below.
+pub const SIGNED_MESSAGE: &[u8] = b"Hello, world!";
+
+/// Ed25519 public key made from the `[1; 32]` seed.
+pub const ED25519_PUBLIC_KEY: [u8; 32] = [
+    0x8a, 0x88, 0xe3, 0xdd, 0x74, 0x09, 0xf1, 0x95, 0xfd, 0x52, 0xdb, 0x2d, 0x3c, 0xba, 0x5d, 0x72,
+    0xca, 0x67, 0x09, 0xbf, 0x1d, 0x94, 0x12, 0x1b, 0xf3, 0x74, 0x88, 0x01, 0xb4, 0x0f, 0x6f, 0x5c,
+];
+
+/// Ed25519 signature of [`SIGNED_MESSAGE`] made by [`ED25519_PUBLIC_KEY`] owner.
+pub const ED25519_SIGNATURE: [u8; 64] = [
+    0xb3, 0x88, 0xd9, 0x8a, 0x8f, 0x22, 0x22, 0xf2, 0x5c, 0x34, 0x95, 0xbd, 0x6b, 0x97, 0x5a, 0xcd,
+    0x73, 0x8a, 0x48, 0xd1, 0x8c, 0xb3, 0x3f, 0xeb, 0xeb, 0x56, 0x22, 0xaf, 0xb8, 0xba, 0x99, 0xec,
+    0x18, 0xa6, 0x4a, 0xa1, 0x6b, 0x6e, 0xb3, 0x54, 0xbb, 0x6f, 0x62, 0x3a, 0x36, 0xac, 0x76, 0x6a,
+    0xd2, 0x0c, 0x0d, 0xf5, 0xeb, 0x18, 0xcb, 0xce, 0xad, 0x4e, 0x46, 0x13, 0x14, 0xa0, 0xd8, 0x0c,
+];
+
+/// SHA-256 hash of [`SIGNED_MESSAGE`].
+pub const SECP256K1_MESSAGE_HASH: [u8; 32] = [
+    0x31, 0x5f, 0x5b, 0xdb, 0x76, 0xd0, 0x78, 0xc4, 0x3b, 0x8a, 0xc0, 0x06, 0x4e, 0x4a, 0x01, 0x64,
+    0x61, 0x2b, 0x1f, 0xce, 0x77, 0xc8, 0x69, 0x34, 0x5b, 0xfc, 0x94, 0xc7, 0x58, 0x94, 0xed, 0xd3,
+];
+
+/// Recoverable secp256k1 signature of [`SECP256K1_MESSAGE_HASH`] with
+/// Ethereum-style `v`.
+pub const SECP256K1_SIGNATURE: [u8; 65] = [
+    0xc9, 0xc9, 0xca, 0xc9, 0xbf, 0xf2, 0x8c, 0xbc, 0x58, 0xb5, 0x1c, 0xb8, 0x40, 0x51, 0x9b, 0x9d,
+    0x07, 0xe1, 0x95, 0x09, 0x78, 0x85, 0x85, 0xb2, 0x32, 0x6e, 0xd5, 0x66, 0x54, 0x00, 0x74, 0x2f,
+    0x7c, 0x9c, 0xbd, 0x80, 0x3c, 0x72, 0x95, 0x62, 0x35, 0xd2, 0xe8, 0x0d, 0x05, 0x41, 0x34, 0x11,
+    0x44, 0x73, 0xf0, 0xc1, 0x03, 0x2e, 0x3f, 0xfd, 0x32, 0x14, 0xe5, 0x61, 0xc4, 0x17, 0x47, 0xa7,
+    0x1b,
+];
+
+/// Uncompressed public key of the [`SECP256K1_SIGNATURE`] author.
+pub const SECP256K1_PUBLIC_KEY: [u8; 64] = [
+    0x4b, 0xc2, 0xa3, 0x12, 0x65, 0x15, 0x3f, 0x07, 0xe7, 0x0e, 0x0b, 0xab, 0x08, 0x72, 0x4e, 0x6b,
+    0x85, 0xe2, 0x17, 0xf8, 0xcd, 0x62, 0x8c, 0xeb, 0x62, 0x97, 0x42, 0x47, 0xbb, 0x49, 0x33, 0x82,
+    0xce, 0x28, 0xca, 0xb7, 0x9a, 0xd7, 0x11, 0x9e, 0xe1, 0xad, 0x3e, 0xbc, 0xdb, 0x98, 0xa1, 0x68,
+    0x05, 0x21, 0x15, 0x30, 0xec, 0xc6, 0xcf, 0xef, 0xa1, 0xb8, 0x8e, 0x6d, 0xff, 0x99, 0x23, 0x2a,
+];
@@ -402,6 +402,39 @@ pub struct SyscallWeights<T: Config> {
     /// Weight of calling `gr_random`.
     pub gr_random: Weight,
 
+    /// Weight of calling `gr_blake2b_256`.
+    pub gr_blake2b_256: Weight,
+
+    /// Weight per hashed byte by `gr_blake2b_256`.
+    pub gr_blake2b_256_per_byte: Weight,
+
+    /// Weight of calling `gr_keccak256`.
+    pub gr_keccak256: Weight,
+
+    /// Weight per hashed byte by `gr_keccak256`.
+    pub gr_keccak256_per_byte: Weight,
+
+    /// Weight of calling `gr_sha256`.
+    pub gr_sha256: Weight,
+
+    /// Weight per hashed byte by `gr_sha256`.
+    pub gr_sha256_per_byte: Weight,
+
+    /// Weight of calling `gr_ed25519_verify`.
+    pub gr_ed25519_verify: Weight,
+
+    /// Weight per message byte by `gr_ed25519_verify`.
+    pub gr_ed25519_verify_per_byte: Weight,
+
+    /// Weight of calling `gr_sr25519_verify`.
+    pub gr_sr25519_verify: Weight,
+
+    /// Weight per message byte by `gr_sr25519_verify`.
+    pub gr_sr25519_verify_per_byte: Weight,
+
+    /// Weight of calling `gr_secp256k1_recover`.
+    pub gr_secp256k1_recover: Weight,
+
     /// Weight of calling `gr_reply_deposit`.
     pub gr_reply_deposit: Weight,
 
@@ -1174,6 +1207,17 @@ impl<T: Config> Default for SyscallWeights<T> {
             gr_block_height: cost_batched(W::<T>::gr_block_height),
             gr_block_timestamp: cost_batched(W::<T>::gr_block_timestamp),
             gr_random: cost_batched(W::<T>::gr_random),
+            gr_blake2b_256: cost_batched(W::<T>::gr_blake2b_256),
+            gr_blake2b_256_per_byte: cost_byte_batched(W::<T>::gr_blake2b_256_per_kb),
+            gr_keccak256: cost_batched(W::<T>::gr_keccak256),
+            gr_keccak256_per_byte: cost_byte_batched(W::<T>::gr_keccak256_per_kb),
+            gr_sha256: cost_batched(W::<T>::gr_sha256),
+            gr_sha256_per_byte: cost_byte_batched(W::<T>::gr_sha256_per_kb),
+            gr_ed25519_verify: cost_batched(W::<T>::gr_ed25519_verify),
+            gr_ed25519_verify_per_byte: cost_byte_batched(W::<T>::gr_ed25519_verify_per_kb),
+            gr_sr25519_verify: cost_batched(W::<T>::gr_sr25519_verify),
+            gr_sr25519_verify_per_byte: cost_byte_batched(W::<T>::gr_sr25519_verify_per_kb),
+            gr_secp256k1_recover: cost_batched(W::<T>::gr_secp256k1_recover),
             gr_debug: cost_batched(W::<T>::gr_debug),
             gr_debug_per_byte: cost_byte_batched(W::<T>::gr_debug_per_kb),
             gr_reply_to: cost_batched(W::<T>::gr_reply_to),
@@ -1237,6 +1281,17 @@ impl<T: Config> From<SyscallWeights<T>> for SyscallCosts {
             gr_block_height: val.gr_block_height.ref_time().into(),
             gr_block_timestamp: val.gr_block_timestamp.ref_time().into(),
             gr_random: val.gr_random.ref_time().into(),
+            gr_blake2b_256: val.gr_blake2b_256.ref_time().into(),
+            gr_blake2b_256_per_byte: val.gr_blake2b_256_per_byte.ref_time().into(),
+            gr_keccak256: val.gr_keccak256.ref_time().into(),
+            gr_keccak256_per_byte: val.gr_keccak256_per_byte.ref_time().into(),
+            gr_sha256: val.gr_sha256.ref_time().into(),
+            gr_sha256_per_byte: val.gr_sha256_per_byte.ref_time().into(),
+            gr_ed25519_verify: val.gr_ed25519_verify.ref_time().into(),
+            gr_ed25519_verify_per_byte: val.gr_ed25519_verify_per_byte.ref_time().into(),
+            gr_sr25519_verify: val.gr_sr25519_verify.ref_time().into(),
+            gr_sr25519_verify_per_byte: val.gr_sr25519_verify_per_byte.ref_time().into(),
+            gr_secp256k1_recover: val.gr_secp256k1_recover.ref_time().into(),
             gr_reply_deposit: val.gr_reply_deposit.ref_time().into(),
             gr_send: val.gr_send.ref_time().into(),
             gr_send_per_byte: val.gr_send_per_byte.ref_time().into(),
@@ -8,6 +8,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! CPU: `INTEL(R) XEON(R) GOLD 6526Y`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! NOTE: the `gr_blake2b_256*`, `gr_keccak256*`, `gr_sha256*`, `gr_ed25519_verify*`,
+//! `gr_sr25519_verify*` and `gr_secp256k1_recover` weights were not produced by the
+//! run above. They are provisional estimates and must be replaced by a benchmark run
+//! before the runtime is released, as well as the costs derived from them in
+//! `protocol/core/src/gas_metering/schedule.rs`.
 
 // Executed Command:
 // ./target/production/gear benchmark pallet --runtime=./target/production/wbuild/vara-runtime/vara_runtime.compact.compressed.wasm --genesis-builder=runtime --genesis-builder-preset=development --steps=50 --repeat=20 --pallet=pallet_gear --extrinsic=alloc,claim_value,claim_value_to_inheritor,create_program,db_read_per_kb,db_write_per_kb,free,free_range,free_range_per_page,gr_block_height,gr_block_timestamp,gr_create_program,gr_create_program_per_kb,gr_create_program_wgas,gr_create_program_wgas_per_kb,gr_debug,gr_debug_per_kb,gr_env_vars,gr_gas_available,gr_message_id,gr_program_id,gr_random,gr_read,gr_read_per_kb,gr_reply_code,gr_reply_deposit,gr_reply_per_kb,gr_reply_push,gr_reply_push_input,gr_reply_push_input_per_kb,gr_reply_push_per_kb,gr_reply_to,gr_reply_wgas_per_kb,gr_reservation_reply_commit_per_kb,gr_reservation_reply_per_kb,gr_reservation_send,gr_reservation_send_commit,gr_reservation_send_per_kb,gr_reserve_gas,gr_send,gr_send_commit,gr_send_commit_wgas,gr_send_init,gr_send_input,gr_send_input_wgas,gr_send_per_kb,gr_send_push,gr_send_push_input,gr_send_push_input_per_kb,gr_send_push_per_kb,gr_send_wgas,gr_send_wgas_per_kb,gr_signal_code,gr_signal_from,gr_size,gr_source,gr_system_reserve_gas,gr_unreserve_gas,gr_value,gr_value_available,gr_wake,instantiate_module_code_section_per_kb,instantiate_module_data_section_per_kb,instantiate_module_element_section_per_kb,instantiate_module_global_section_per_kb,instantiate_module_table_section_per_kb,instantiate_module_type_section_per_kb,instr_br,instr_br_if,instr_br_table,instr_br_table_per_entry,instr_call,instr_call_const,instr_call_indirect,instr_call_indirect_per_param,instr_call_per_local,instr_global_get,instr_global_set,instr_i32add,instr_i32and,instr_i32clz,instr_i32ctz,instr_i32divs,instr_i32divu,instr_i32eq,instr_i32eqz,instr_i32extend16s,instr_i32extend8s,instr_i32ges,instr_i32geu,instr_i32gts,instr_i32gtu,instr_i32les,instr_i32leu,instr_i32load,instr_i32lts,instr_i32ltu,instr_i32mul,instr_i32ne,instr_i32or,instr_i32popcnt,instr_i32rems,instr_i32remu,instr_i32rotl,instr_i32rotr,instr_i32shl,instr_i32shrs,instr_i32shru,instr_i32store,instr_i32sub,instr_i32wrapi64,instr_i32xor,instr_i64add,instr_i64and,instr_i64clz,instr_i64ctz,instr_i64divs,instr_i64divu,instr_i64eq,instr_i64eqz,instr_i64extend16s,instr_i64extend32s,instr_i64extend8s,instr_i64extendsi32,instr_i64extendui32,instr_i64ges,instr_i64geu,instr_i64gts,instr_i64gtu,instr_i64les,instr_i64leu,instr_i64load,instr_i64lts,instr_i64ltu,instr_i64mul,instr_i64ne,instr_i64or,instr_i64popcnt,instr_i64rems,instr_i64remu,instr_i64rotl,instr_i64rotr,instr_i64shl,instr_i64shrs,instr_i64shru,instr_i64store,instr_i64sub,instr_i64xor,instr_if,instr_local_get,instr_local_set,instr_local_tee,instr_memory_current,instr_select,lazy_pages_host_func_read,lazy_pages_host_func_write,lazy_pages_host_func_write_after_read,lazy_pages_load_page_storage_data,lazy_pages_signal_read,lazy_pages_signal_write,lazy_pages_signal_write_after_read,load_allocations_per_interval,mem_grow,mem_grow_per_page,reinstrument_per_kb,send_message,send_reply,tasks_remove_from_mailbox,tasks_remove_from_waitlist,tasks_remove_gas_reservation,tasks_send_dispatch,tasks_send_user_message,tasks_send_user_message_to_mailbox,tasks_wake_message,tasks_wake_message_no_wake,upload_code,upload_program --heap-pages=16384 --output=./scripts/benchmarking/weights-output/pallet_gear.rs --template=scripts/benchmarking/frame-weight-template.hbs
@@ -75,6 +81,17 @@ pub trait WeightInfo {
     fn gr_block_height(r: u32, ) -> Weight;
     fn gr_block_timestamp(r: u32, ) -> Weight;
     fn gr_random(n: u32, ) -> Weight;
+    fn gr_blake2b_256(r: u32, ) -> Weight;
+    fn gr_blake2b_256_per_kb(n: u32, ) -> Weight;
+    fn gr_keccak256(r: u32, ) -> Weight;
+    fn gr_keccak256_per_kb(n: u32, ) -> Weight;
+    fn gr_sha256(r: u32, ) -> Weight;
+    fn gr_sha256_per_kb(n: u32, ) -> Weight;
+    fn gr_ed25519_verify(r: u32, ) -> Weight;
+    fn gr_ed25519_verify_per_kb(n: u32, ) -> Weight;
+    fn gr_sr25519_verify(r: u32, ) -> Weight;
+    fn gr_sr25519_verify_per_kb(n: u32, ) -> Weight;
+    fn gr_secp256k1_recover(r: u32, ) -> Weight;
     fn gr_reply_deposit(r: u32, ) -> Weight;
     fn gr_send(r: u32, ) -> Weight;
     fn gr_send_per_kb(n: u32, ) -> Weight;
@@ -771,6 +788,72 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(Weight::from_parts(133_452_122, 0).saturating_mul(n.into()))
     }
     /// The range of component `r` is `[0, 20]`.
+    fn gr_blake2b_256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(79_862_461, 0)
+            .saturating_add(Weight::from_parts(93_876_160, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_blake2b_256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_355_000, 0)
+            .saturating_add(Weight::from_parts(94_699_520, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_keccak256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(80_415_922, 0)
+            .saturating_add(Weight::from_parts(95_259_040, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_keccak256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(102_047_000, 0)
+            .saturating_add(Weight::from_parts(190_627_840, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_sha256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(80_106_517, 0)
+            .saturating_add(Weight::from_parts(94_769_360, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_sha256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_790_000, 0)
+            .saturating_add(Weight::from_parts(226_754_560, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_ed25519_verify(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(97_203_614, 0)
+            .saturating_add(Weight::from_parts(3_792_974_880, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_ed25519_verify_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(3_855_117_000, 0)
+            .saturating_add(Weight::from_parts(93_224_960, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_sr25519_verify(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_447_305, 0)
+            .saturating_add(Weight::from_parts(4_248_673_520, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_sr25519_verify_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(4_318_604_000, 0)
+            .saturating_add(Weight::from_parts(146_718_720, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_secp256k1_recover(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(112_835_740, 0)
+            .saturating_add(Weight::from_parts(5_029_920_960, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
     fn gr_reply_deposit(r: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
@@ -2718,6 +2801,72 @@ impl WeightInfo for () {
             .saturating_add(Weight::from_parts(133_452_122, 0).saturating_mul(n.into()))
     }
     /// The range of component `r` is `[0, 20]`.
+    fn gr_blake2b_256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(79_862_461, 0)
+            .saturating_add(Weight::from_parts(93_876_160, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_blake2b_256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_355_000, 0)
+            .saturating_add(Weight::from_parts(94_699_520, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_keccak256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(80_415_922, 0)
+            .saturating_add(Weight::from_parts(95_259_040, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_keccak256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(102_047_000, 0)
+            .saturating_add(Weight::from_parts(190_627_840, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_sha256(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(80_106_517, 0)
+            .saturating_add(Weight::from_parts(94_769_360, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_sha256_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_790_000, 0)
+            .saturating_add(Weight::from_parts(226_754_560, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_ed25519_verify(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(97_203_614, 0)
+            .saturating_add(Weight::from_parts(3_792_974_880, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_ed25519_verify_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(3_855_117_000, 0)
+            .saturating_add(Weight::from_parts(93_224_960, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_sr25519_verify(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(101_447_305, 0)
+            .saturating_add(Weight::from_parts(4_248_673_520, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `n` is `[0, 8192]`.
+    fn gr_sr25519_verify_per_kb(n: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(4_318_604_000, 0)
+            .saturating_add(Weight::from_parts(146_718_720, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
+    fn gr_secp256k1_recover(r: u32, ) -> Weight {
+        // Not benchmarked: provisional estimate, see the note at the top of the file.
+        Weight::from_parts(112_835_740, 0)
+            .saturating_add(Weight::from_parts(5_029_920_960, 0).saturating_mul(r.into()))
+    }
+    /// The range of component `r` is `[0, 20]`.
     fn gr_reply_deposit(r: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
@@ -22,7 +22,10 @@ use sp_runtime_interface::{
 use sp_std::{result::Result, vec::Vec};
 #[cfg(feature = "std")]
 use {
-    builtins_common::bls12_381::{Bls12_381Ops, Bls12_381OpsLowLevel},
+    builtins_common::{
+        bls12_381::{Bls12_381Ops, Bls12_381OpsLowLevel},
+        bn254::{Bn254Ops, Bn254OpsLowLevel},
+    },
     gear_lazy_pages::LazyPagesStorage,
     gear_lazy_pages_common::ProcessAccessError,
 };
@@ -342,3 +345,34 @@ pub trait GearBls12_381 {
         Bls12_381OpsLowLevel::map_to_g2affine(message).map_err(|e| e.as_u32())
     }
 }
+
+#[runtime_interface]
+pub trait GearBn254 {
+    /// Pairing multi Miller loop for BN254.
+    /// Accepts scale-encoded `ArkScale<Vec<G1Affine>>` and `ArkScale<Vec<G2Affine>>`.
+    /// Result is scale-encoded `ArkScale<TargetField>`.
+    fn multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, u32> {
+        Bn254OpsLowLevel::multi_miller_loop(a, b).map_err(|e| e.as_u32())
+    }
+
+    /// Pairing final exponentiation for BN254.
+    /// Accepts scale-encoded `ArkScale<TargetField>`.
+    /// Result is scale-encoded `ArkScale<TargetField>`.
+    fn final_exponentiation(f: Vec<u8>) -> Result<Vec<u8>, u32> {
+        Bn254OpsLowLevel::final_exponentiation(f).map_err(|e| e.as_u32())
+    }
+
+    /// Addition of two G1-points for BN254.
+    /// Accepts scale-encoded `ArkScaleProjective<G1Projective>` points.
+    /// Result is scale-encoded `ArkScaleProjective<G1Projective>`.
+    fn addition_g1(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, u32> {
+        Bn254OpsLowLevel::addition_g1(a, b).map_err(|e| e.as_u32())
+    }
+
+    /// Projective multiplication on G1 for BN254.
+    /// Accepts scale-encoded `ArkScaleProjective<G1Projective>` and `ArkScale<Vec<u64>>`.
+    /// Result is scale-encoded `ArkScaleProjective<G1Projective>`.
+    fn mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, u32> {
+        Bn254OpsLowLevel::projective_mul_g1(base, scalar).map_err(|e| e.as_u32())
+    }
+}
@@ -1157,6 +1157,8 @@ impl pallet_gear_messenger::Config for Runtime {
 }
 
 const ETH_BRIDGE_BUILTIN_ID: u64 = 3;
+const BN254_BUILTIN_ID: u64 = 5;
+const GOVERNANCE_BUILTIN_ID: u64 = 6;
 
 /// Builtin actors arranged in a tuple.
 ///
@@ -1168,6 +1170,8 @@ pub type BuiltinActors = (
     ActorWithId<2, pallet_gear_builtin::staking::Actor<Runtime>>,
     ActorWithId<{ ETH_BRIDGE_BUILTIN_ID }, pallet_gear_eth_bridge::Actor<Runtime>>,
     ActorWithId<4, pallet_gear_builtin::proxy::Actor<Runtime>>,
+    ActorWithId<{ BN254_BUILTIN_ID }, pallet_gear_builtin::bn254::Actor<Runtime>>,
+    ActorWithId<{ GOVERNANCE_BUILTIN_ID }, pallet_gear_builtin::governance::Actor<Runtime>>,
 );
 
 impl pallet_gear_builtin::Config for Runtime {
@@ -1187,6 +1191,10 @@ parameter_types! {
 parameter_types! {
     pub GearEthBridgeBuiltinAddress: AccountId
         = GearBuiltin::generate_actor_id(ETH_BRIDGE_BUILTIN_ID).into_bytes().into();
+    pub GearBn254BuiltinAddress: AccountId
+        = GearBuiltin::generate_actor_id(BN254_BUILTIN_ID).into_bytes().into();
+    pub GearGovernanceBuiltinAddress: AccountId
+        = GearBuiltin::generate_actor_id(GOVERNANCE_BUILTIN_ID).into_bytes().into();
 }
 
 pub struct GearEthBridgeAdminAccounts;
@@ -16,6 +16,8 @@ pub type Migrations = (
 pub type Migrations = (
     pallet_gear_eth_bridge::migrations::set_hash::Migration<Runtime>,
 	LockEdForBuiltin<crate::GearEthBridgeBuiltinAddress>,
+	LockEdForBuiltin<crate::GearBn254BuiltinAddress>,
+	LockEdForBuiltin<crate::GearGovernanceBuiltinAddress>,
 	// migrate to v3 of the Gear Scheduler with removal of program pause tasks
     pallet_gear_scheduler::migrations::v3_remove_program_pause_tasks::MigrateRemoveProgramPauseTasks<Runtime>,
 );
@@ -271,6 +271,17 @@ fn syscall_weights_test() {
         gr_block_height: 1_000_000.into(),
         gr_block_timestamp: 960_000.into(),
         gr_random: 1_700_000.into(),
+        gr_blake2b_256: 1_200_000.into(),
+        gr_blake2b_256_per_byte: 1_200.into(),
+        gr_keccak256: 1_200_000.into(),
+        gr_keccak256_per_byte: 2_300.into(),
+        gr_sha256: 1_200_000.into(),
+        gr_sha256_per_byte: 2_800.into(),
+        gr_ed25519_verify: 47_000_000.into(),
+        gr_ed25519_verify_per_byte: 1_100.into(),
+        gr_sr25519_verify: 53_000_000.into(),
+        gr_sr25519_verify_per_byte: 1_800.into(),
+        gr_secp256k1_recover: 63_000_000.into(),
         gr_reply_deposit: 4_100_000.into(),
         gr_send: 2_500_000.into(),
         gr_send_per_byte: 300.into(),
@@ -186,6 +186,17 @@ pub(super) fn expected_syscall_weights_count() -> usize {
         gr_block_height: _,
         gr_block_timestamp: _,
         gr_random: _,
+        gr_blake2b_256: _,
+        gr_blake2b_256_per_byte: _,
+        gr_keccak256: _,
+        gr_keccak256_per_byte: _,
+        gr_sha256: _,
+        gr_sha256_per_byte: _,
+        gr_ed25519_verify: _,
+        gr_ed25519_verify_per_byte: _,
+        gr_sr25519_verify: _,
+        gr_sr25519_verify_per_byte: _,
+        gr_secp256k1_recover: _,
         gr_reply_deposit: _,
         gr_send: _,
         gr_send_per_byte: _,
@@ -476,6 +487,17 @@ pub(super) fn check_syscall_weights<T: pallet_gear::Config>(
         expectation!(gr_block_height),
         expectation!(gr_block_timestamp),
         expectation!(gr_random),
+        expectation!(gr_blake2b_256),
+        expectation!(gr_blake2b_256_per_byte),
+        expectation!(gr_keccak256),
+        expectation!(gr_keccak256_per_byte),
+        expectation!(gr_sha256),
+        expectation!(gr_sha256_per_byte),
+        expectation!(gr_ed25519_verify),
+        expectation!(gr_ed25519_verify_per_byte),
+        expectation!(gr_sr25519_verify),
+        expectation!(gr_sr25519_verify_per_byte),
+        expectation!(gr_secp256k1_recover),
         expectation!(gr_reply_deposit),
         expectation!(gr_send),
         expectation!(gr_send_per_byte),
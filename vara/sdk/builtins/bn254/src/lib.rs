@@ -0,0 +1,135 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+pub use ark_bn254;
+pub use ark_ec;
+pub use ark_ff;
+pub use ark_scale;
+pub use ark_serialize;
+
+/// Constant defines codec index of [`Request::MultiMillerLoop`].
+pub const REQUEST_MULTI_MILLER_LOOP: u8 = 0;
+/// Constant defines codec index of [`Request::FinalExponentiation`].
+pub const REQUEST_FINAL_EXPONENTIATION: u8 = 1;
+/// Constant defines codec index of [`Request::AdditionG1`].
+pub const REQUEST_ADDITION_G1: u8 = 2;
+/// Constant defines codec index of [`Request::ProjectiveMultiplicationG1`].
+pub const REQUEST_PROJECTIVE_MULTIPLICATION_G1: u8 = 3;
+
+/// Type that should be used to create a message to the bn254 builtin actor.
+/// Use the following crates to construct a request:
+///  - `ark-scale`: <https://docs.rs/ark-scale/>;
+///  - `ark-bn254`: <https://docs.rs/ark-bn254/>.
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub enum Request {
+    /// Request to pairing multi Miller loop for *BN254*.
+    ///
+    /// Encoded:
+    ///   - `a`: [`ArkScale<Vec<G1Affine>>`](https://docs.rs/ark-scale/).
+    ///   - `b`: [`ArkScale<Vec<G2Affine>>`](https://docs.rs/ark-scale/).
+    #[codec(index = 0)]
+    MultiMillerLoop { a: Vec<u8>, b: Vec<u8> },
+
+    /// Request to pairing final exponentiation for *BN254*.
+    ///
+    /// Encoded: [`ArkScale<<Bn254::TargetField>`](https://docs.rs/ark-scale/).
+    #[codec(index = 1)]
+    FinalExponentiation { f: Vec<u8> },
+
+    /// Request to addition of *G1* points for *BN254*.
+    ///
+    /// Encoded:
+    ///   - `a`: [`ArkScaleProjective<G1Projective>`](https://docs.rs/ark-scale/).
+    ///   - `b`: [`ArkScaleProjective<G1Projective>`](https://docs.rs/ark-scale/).
+    #[codec(index = 2)]
+    AdditionG1 { a: Vec<u8>, b: Vec<u8> },
+
+    /// Request to projective multiplication on *G1* for *BN254*.
+    ///
+    /// Encoded:
+    ///   - `base`: [`ArkScaleProjective<G1Projective>`](https://docs.rs/ark-scale/).
+    ///   - `scalar`: [`ArkScale<Vec<u64>>`](https://docs.rs/ark-scale/).
+    #[codec(index = 3)]
+    ProjectiveMultiplicationG1 { base: Vec<u8>, scalar: Vec<u8> },
+}
+
+/// The enumeration contains result to a request.
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub enum Response {
+    /// Result of the multi Miller loop, encoded: [`ArkScale<Bn254::TargetField>`](https://docs.rs/ark-scale/).
+    #[codec(index = 0)]
+    MultiMillerLoop(Vec<u8>),
+    /// Result of the final exponentiation, encoded: [`ArkScale<Bn254::TargetField>`](https://docs.rs/ark-scale/).
+    #[codec(index = 1)]
+    FinalExponentiation(Vec<u8>),
+    /// Result of the addition, encoded: [`ArkScaleProjective<G1Projective>`](https://docs.rs/ark-scale/).
+    #[codec(index = 2)]
+    AdditionG1(Vec<u8>),
+    /// Result of the projective multiplication, encoded: [`ArkScaleProjective<G1Projective>`](https://docs.rs/ark-scale/).
+    #[codec(index = 3)]
+    ProjectiveMultiplicationG1(Vec<u8>),
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+
+    // The standard Decode implementation cannot be used for precise gas charging.
+    // The following test checks that scale codec indexes of variants are set correctly.
+    #[test]
+    fn codec_enum_indexes() {
+        for (index, (variant, request, response)) in [
+            (
+                REQUEST_MULTI_MILLER_LOOP,
+                Request::MultiMillerLoop {
+                    a: vec![],
+                    b: vec![],
+                },
+                Response::MultiMillerLoop(vec![]),
+            ),
+            (
+                REQUEST_FINAL_EXPONENTIATION,
+                Request::FinalExponentiation { f: vec![] },
+                Response::FinalExponentiation(vec![]),
+            ),
+            (
+                REQUEST_ADDITION_G1,
+                Request::AdditionG1 {
+                    a: vec![],
+                    b: vec![],
+                },
+                Response::AdditionG1(vec![]),
+            ),
+            (
+                REQUEST_PROJECTIVE_MULTIPLICATION_G1,
+                Request::ProjectiveMultiplicationG1 {
+                    base: vec![],
+                    scalar: vec![],
+                },
+                Response::ProjectiveMultiplicationG1(vec![]),
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(index, variant.into());
+
+            let request = request.encode();
+            assert!(matches!(request.first().copied(), Some(v) if v == variant));
+
+            let response = response.encode();
+            assert!(matches!(response.first().copied(), Some(v) if v == variant));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+mod high_level;
+mod low_level;
+
+pub use gbuiltin_bn254::{Request, Response, ark_bn254, ark_ec, ark_ff, ark_scale, ark_serialize};
+pub use high_level::*;
+pub use low_level::Bn254OpsLowLevel;
+
+use super::{BuiltinActorError, BuiltinContext};
+use alloc::{vec, vec::Vec};
+use gbuiltin_bn254::{
+    REQUEST_ADDITION_G1, REQUEST_FINAL_EXPONENTIATION, REQUEST_MULTI_MILLER_LOOP,
+    REQUEST_PROJECTIVE_MULTIPLICATION_G1,
+};
+
+/// Executes BN254 built-in functions.
+///
+/// Checks the first byte of the input to determine which BN254 operation to perform,
+/// and then calls the corresponding function with the remaining input bytes.
+pub fn execute_bn254_builtins<Gas: Bn254OpsGasCost, Ops: Bn254Ops>(
+    input: &[u8],
+    context: &mut BuiltinContext,
+) -> Result<Response, BuiltinActorError> {
+    match input.first().copied() {
+        Some(REQUEST_MULTI_MILLER_LOOP) => {
+            high_level::multi_miller_loop::<Gas, Ops>(&input[1..], context)
+        }
+        Some(REQUEST_FINAL_EXPONENTIATION) => {
+            high_level::final_exponentiation::<Gas, Ops>(&input[1..], context)
+        }
+        Some(REQUEST_ADDITION_G1) => high_level::addition_g1::<Gas, Ops>(&input[1..], context),
+        Some(REQUEST_PROJECTIVE_MULTIPLICATION_G1) => {
+            high_level::projective_multiplication_g1::<Gas, Ops>(&input[1..], context)
+        }
+        _ => Err(BuiltinActorError::DecodingError),
+    }
+}
+
+/// BN254 operations gas cost trait.
+///
+/// Depending on the environment (e.g., runtime or tests), different values for gas costs
+/// can be provided by implementing this trait accordingly.
+pub trait Bn254OpsGasCost {
+    /// Returns gas cost for decoding bytes.
+    fn decode_bytes(len: u32) -> u64;
+    /// Returns gas cost for BN254 multi Miller loop operation.
+    fn bn254_multi_miller_loop(count: u32) -> u64;
+    /// Returns gas cost for BN254 final exponentiation operation.
+    fn bn254_final_exponentiation() -> u64;
+    /// Returns gas cost for BN254 G1 addition operation.
+    fn bn254_addition_g1() -> u64;
+    /// Returns gas cost for BN254 projective multiplication G1 operation.
+    fn bn254_mul_projective_g1(count: u32) -> u64;
+}
+
+/// BN254 operations trait.
+///
+/// The trait abstracts the actual implementation of BN254 operations. Depending
+/// on the environment (e.g., runtime or tests), the operations can be implemented
+/// as host calls from the runtime, or directly using the `ark`s crates.
+///
+/// Implementations must reject points, which are not on the curve or not in
+/// the correct subgroup, since programs encode them without validation.
+pub trait Bn254Ops {
+    /// Performs the multi Miller loop operation on pairs of G1 and G2 points.
+    fn multi_miller_loop(g1: Vec<u8>, g2: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError>;
+    /// Performs the final exponentiation operation.
+    fn final_exponentiation(f: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError>;
+    /// Performs the addition operation on G1 points.
+    fn addition_g1(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError>;
+    /// Performs the projective multiplication operation on G1 points.
+    fn projective_mul_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError>;
+}
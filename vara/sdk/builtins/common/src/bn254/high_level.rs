@@ -0,0 +1,139 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use super::*;
+use ark_scale::{
+    HOST_CALL,
+    rw::InputAsRead,
+    scale::{Compact, Decode, Input},
+};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use gear_core::limited::LimitedStr;
+
+const IS_COMPRESSED: Compress = ark_scale::is_compressed(HOST_CALL);
+const IS_VALIDATED: Validate = ark_scale::is_validated(HOST_CALL);
+
+/// High-level BN254 Multi-miller loop op hiding under `Bn254Ops` trait
+/// parameter actual implementation details. For more info about the abstraction
+/// requirement see the `Bn254Ops` trait documentation.
+///
+/// The function performs preparation steps to call the actual implementation.
+/// Also it performs gas charging using the provided `Gas` trait parameter.
+pub fn multi_miller_loop<Gas: Bn254OpsGasCost, Ops: Bn254Ops>(
+    mut payload: &[u8],
+    context: &mut BuiltinContext,
+) -> Result<Response, BuiltinActorError> {
+    let a = decode_vec::<Gas, _>(&mut payload, context)?;
+    let b = decode_vec::<Gas, _>(&mut payload, context)?;
+
+    // Decode the items count from 'a'
+    let count = decode_count(&a).inspect_err(|_| {
+        log::debug!("Failed to decode items count in a");
+    })?;
+
+    // Decode the items count from 'b' and verify they match
+    match decode_count(&b) {
+        Ok(count_b) if count_b != count => {
+            return Err(BuiltinActorError::Custom(LimitedStr::from_small_str(
+                "Multi Miller loop: uneven item count",
+            )));
+        }
+        Err(e) => return Err(e),
+        Ok(_) => (),
+    }
+
+    let gas_cost = Gas::bn254_multi_miller_loop(count as u32);
+    context.try_charge_gas(gas_cost)?;
+
+    Ops::multi_miller_loop(a, b).map(Response::MultiMillerLoop)
+}
+
+/// High-level BN254 Final exponentiation op hiding under `Bn254Ops` trait
+/// parameter actual implementation details. For more info about the abstraction
+/// requirement see the `Bn254Ops` trait documentation.
+///
+/// The function performs preparation steps to call the actual implementation.
+/// Also it performs gas charging using the provided `Gas` trait parameter.
+pub fn final_exponentiation<Gas: Bn254OpsGasCost, Ops: Bn254Ops>(
+    mut payload: &[u8],
+    context: &mut BuiltinContext,
+) -> Result<Response, BuiltinActorError> {
+    let f = decode_vec::<Gas, _>(&mut payload, context)?;
+
+    let to_spend = Gas::bn254_final_exponentiation();
+    context.try_charge_gas(to_spend)?;
+
+    Ops::final_exponentiation(f).map(Response::FinalExponentiation)
+}
+
+/// High-level BN254 G1 addition op hiding under `Bn254Ops` trait
+/// parameter actual implementation details. For more info about the abstraction
+/// requirement see the `Bn254Ops` trait documentation.
+///
+/// The function performs preparation steps to call the actual implementation.
+/// Also it performs gas charging using the provided `Gas` trait parameter.
+pub fn addition_g1<Gas: Bn254OpsGasCost, Ops: Bn254Ops>(
+    mut payload: &[u8],
+    context: &mut BuiltinContext,
+) -> Result<Response, BuiltinActorError> {
+    let a = decode_vec::<Gas, _>(&mut payload, context)?;
+    let b = decode_vec::<Gas, _>(&mut payload, context)?;
+
+    let to_spend = Gas::bn254_addition_g1();
+    context.try_charge_gas(to_spend)?;
+
+    Ops::addition_g1(a, b).map(Response::AdditionG1)
+}
+
+/// High-level BN254 G1 projective multiplication op hiding under `Bn254Ops` trait
+/// parameter actual implementation details. For more info about the abstraction
+/// requirement see the `Bn254Ops` trait documentation.
+///
+/// The function performs preparation steps to call the actual implementation.
+/// Also it performs gas charging using the provided `Gas` trait parameter.
+pub fn projective_multiplication_g1<Gas: Bn254OpsGasCost, Ops: Bn254Ops>(
+    mut payload: &[u8],
+    context: &mut BuiltinContext,
+) -> Result<Response, BuiltinActorError> {
+    let base = decode_vec::<Gas, _>(&mut payload, context)?;
+    let scalar = decode_vec::<Gas, _>(&mut payload, context)?;
+
+    // decode the count of items
+    let count = decode_count(&scalar).inspect_err(|_| {
+        log::debug!("Failed to decode count of items in scalar");
+    })?;
+
+    let to_spend = Gas::bn254_mul_projective_g1(count as u32);
+    context.try_charge_gas(to_spend)?;
+
+    Ops::projective_mul_g1(base, scalar).map(Response::ProjectiveMultiplicationG1)
+}
+
+fn decode_count(items: &[u8]) -> Result<u64, BuiltinActorError> {
+    let mut slice = items;
+    let mut reader = InputAsRead(&mut slice);
+
+    u64::deserialize_with_mode(&mut reader, IS_COMPRESSED, IS_VALIDATED)
+        .map_err(|_| BuiltinActorError::DecodingError)
+}
+
+fn decode_vec<Gas: Bn254OpsGasCost, I: Input>(
+    input: &mut I,
+    context: &mut BuiltinContext,
+) -> Result<Vec<u8>, BuiltinActorError> {
+    let len = Compact::<u32>::decode(input).map(u32::from).map_err(|_| {
+        log::debug!("Failed to scale-decode length of the vector");
+        BuiltinActorError::DecodingError
+    })?;
+
+    let to_spend = Gas::decode_bytes(len);
+    context.try_charge_gas(to_spend)?;
+
+    let mut items = vec![0u8; len as usize];
+    let bytes_slice = items.as_mut_slice();
+
+    input.read(bytes_slice).map(|_| items).map_err(|_| {
+        log::debug!("Failed to scale-decode vector data");
+        BuiltinActorError::DecodingError
+    })
+}
@@ -0,0 +1,98 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use super::*;
+use ark_bn254::{Bn254, g1::Config as G1Config};
+use ark_ec::{
+    pairing::{MillerLoopOutput, Pairing},
+    short_weierstrass::{Projective as SWProjective, SWCurveConfig},
+};
+use ark_scale::{
+    HOST_CALL,
+    scale::{Decode, Encode},
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Valid, Validate};
+use gear_core::limited::LimitedStr;
+
+type ArkScaleLocal<T> = ark_scale::ArkScale<T, HOST_CALL>;
+const _: () = assert!(HOST_CALL == ark_scale::make_usage(Compress::No, Validate::No));
+type ArkScaleProjective<T> = ark_scale::hazmat::ArkScaleProjective<T>;
+
+// Programs encode points without validation, so inputs are checked to be on the
+// curve and in the correct subgroup here, on the host side.
+const VALIDATED: ark_scale::Usage = ark_scale::make_usage(Compress::No, Validate::Yes);
+type ArkScaleValidated<T> = ark_scale::ArkScale<T, VALIDATED>;
+
+/// Implementation of the bn254 operations, intended to be used
+/// directly.
+///
+/// Current impl is also used as a base impl for host-call based impl.
+/// The methods impl are considered to be low-level. To actually execute
+/// bn254 operations separate functions defined in the `builtins_common::bn254`
+/// must be used.
+pub struct Bn254OpsLowLevel;
+
+impl Bn254Ops for Bn254OpsLowLevel {
+    fn multi_miller_loop(g1: Vec<u8>, g2: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        let a = Self::decode::<Vec<<Bn254 as Pairing>::G1Affine>>(g1)?;
+        let b = Self::decode::<Vec<<Bn254 as Pairing>::G2Affine>>(g2)?;
+        let res = Bn254::multi_miller_loop(a, b);
+
+        Ok(Self::encode(res.0))
+    }
+
+    fn final_exponentiation(f: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        let f = Self::decode::<<Bn254 as Pairing>::TargetField>(f)?;
+        let res = Bn254::final_exponentiation(MillerLoopOutput(f)).ok_or(
+            BuiltinActorError::Custom(LimitedStr::from_small_str("Final exponentiation failed")),
+        )?;
+
+        Ok(Self::encode(res.0))
+    }
+
+    fn addition_g1(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        let a = Self::decode_proj_sw::<G1Config>(a)?;
+        let b = Self::decode_proj_sw::<G1Config>(b)?;
+
+        Ok(Self::encode_proj_sw(&(a + b)))
+    }
+
+    fn projective_mul_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, BuiltinActorError> {
+        let base = Self::decode_proj_sw::<G1Config>(base)?;
+        let scalar = Self::decode::<Vec<u64>>(scalar)?;
+        let res = <G1Config as SWCurveConfig>::mul_projective(&base, &scalar);
+
+        Ok(Self::encode_proj_sw(&res))
+    }
+}
+
+impl Bn254OpsLowLevel {
+    fn encode<T: CanonicalSerialize>(val: T) -> Vec<u8> {
+        ArkScaleLocal::from(val).encode()
+    }
+
+    fn decode<T: CanonicalDeserialize>(buf: Vec<u8>) -> Result<T, BuiltinActorError> {
+        ArkScaleValidated::<T>::decode(&mut &buf[..])
+            .map(|v| v.0)
+            .map_err(|_| BuiltinActorError::DecodingError)
+    }
+
+    fn encode_proj_sw<T: SWCurveConfig>(val: &SWProjective<T>) -> Vec<u8> {
+        ArkScaleProjective::from(val).encode()
+    }
+
+    fn decode_proj_sw<T: SWCurveConfig>(
+        buf: Vec<u8>,
+    ) -> Result<SWProjective<T>, BuiltinActorError> {
+        let point = ArkScaleProjective::<SWProjective<T>>::decode(&mut &buf[..])
+            .map(|v| v.0)
+            .map_err(|_| BuiltinActorError::DecodingError)?;
+
+        point.check().map_err(|_| {
+            log::debug!("Projective point is not on the curve or not in the subgroup");
+            BuiltinActorError::DecodingError
+        })?;
+
+        Ok(point)
+    }
+}
@@ -11,6 +11,10 @@ extern crate alloc;
 #[cfg(any(feature = "bls12-381", feature = "bls12-381-std"))]
 pub mod bls12_381;
 
+/// Common utilities for gear protocol BN254 built-in.
+#[cfg(any(feature = "bn254", feature = "bn254-std"))]
+pub mod bn254;
+
 /// Common utilities for gear protocol eth-bridge built-in.
 #[cfg(any(feature = "eth-bridge", feature = "eth-bridge-std"))]
 pub mod eth_bridge;
@@ -0,0 +1,133 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Types used to communicate with governance built-in.
+//!
+//! The governance built-in actor lets programs take part in on-chain referenda
+//! on their own behalf: vote, delegate their voting power and remove votes
+//! in the runtime's conviction-voting pallet.
+//!
+//! # Examples
+//!
+//! The following example shows how a contract can vote "aye" in the referendum
+//! with `poll_index` using the `value` sent along with the message.
+//!
+//! ```ignore
+//! use gstd::{msg, ActorId};
+//! use gbuiltin_governance::{AccountVote, Conviction, Request};
+//! use parity_scale_codec::Encode;
+//!
+//! const BUILTIN_ADDRESS: ActorId = ActorId::new(hex_literal::hex!(
+//!     "b46c6ff21dbea667fbf19e377da9a27c8accaafdd9f450241d225105e380a944"
+//! ));
+//!
+//! #[gstd::async_main]
+//! async fn main() {
+//!     let poll_index: u32 = msg::load().expect("Failed to load poll index");
+//!     let vote = AccountVote::Standard {
+//!         aye: true,
+//!         conviction: Conviction::Locked1x,
+//!         balance: msg::value(),
+//!     };
+//!     let payload = Request::Vote { poll_index, vote }.encode();
+//!     let _ = msg::send_bytes_for_reply(BUILTIN_ADDRESS, &payload[..], 0, 0)
+//!         .expect("Error sending message")
+//!         .await;
+//! }
+//! # fn main() {}
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use gprimitives::ActorId;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Type that should be used to create a message to the governance built-in actor.
+///
+/// A `partial` mirror of the conviction-voting pallet interface.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Request {
+    /// Vote in the poll with `poll_index` or update the existing vote.
+    #[codec(index = 0)]
+    Vote { poll_index: u32, vote: AccountVote },
+
+    /// Delegate the voting power (with some given conviction) of the sender
+    /// to `to` for the polls of the given `class`.
+    #[codec(index = 1)]
+    Delegate {
+        class: u16,
+        to: ActorId,
+        conviction: Conviction,
+        balance: u128,
+    },
+
+    /// Undelegate the voting power for the polls of the given `class`.
+    #[codec(index = 2)]
+    Undelegate { class: u16 },
+
+    /// Remove the sender's vote from the poll with `index`.
+    ///
+    /// `class` is optional only if the poll is still ongoing.
+    #[codec(index = 3)]
+    RemoveVote { class: Option<u16>, index: u32 },
+
+    /// Remove the lock caused by prior voting or delegating in the given `class`,
+    /// which has since expired, from the `target` account.
+    #[codec(index = 4)]
+    Unlock { class: u16, target: ActorId },
+
+    /// Get the amounts locked by the sender's voting per class.
+    #[codec(index = 5)]
+    ClassLocks,
+}
+
+/// A vote of the account.
+///
+/// A "mirror" of the conviction-voting pallet's `AccountVote` enum.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum AccountVote {
+    /// A standard vote, one-way (approve or reject) with a given amount of conviction.
+    Standard {
+        aye: bool,
+        conviction: Conviction,
+        balance: u128,
+    },
+    /// A split vote with balances given for both ways, and with no conviction.
+    Split { aye: u128, nay: u128 },
+    /// A split vote with balances given for both ways as well as abstentions,
+    /// and with no conviction.
+    SplitAbstain { aye: u128, nay: u128, abstain: u128 },
+}
+
+/// A value denoting the strength of conviction of a vote.
+///
+/// A "mirror" of the conviction-voting pallet's `Conviction` enum.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Conviction {
+    /// 0.1x votes, unlocked.
+    #[default]
+    None,
+    /// 1x votes, locked for an enactment period following a successful vote.
+    Locked1x,
+    /// 2x votes, locked for 2x enactment periods following a successful vote.
+    Locked2x,
+    /// 3x votes, locked for 4x...
+    Locked3x,
+    /// 4x votes, locked for 8x...
+    Locked4x,
+    /// 5x votes, locked for 16x...
+    Locked5x,
+    /// 6x votes, locked for 32x...
+    Locked6x,
+}
+
+/// Response type for governance built-in actor operations.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Response {
+    /// Amounts locked by the sender's voting, as `(class, amount)` pairs.
+    ClassLocks { locks: Vec<(u16, u128)> },
+}
@@ -92,6 +92,41 @@ pub enum Request {
     /// Get the active era.
     #[codec(index = 9)]
     ActiveEra,
+
+    /// Get the sender's staking ledger.
+    #[codec(index = 10)]
+    Ledger,
+
+    /// Get the sender's unlocking chunks along with the current era.
+    #[codec(index = 11)]
+    UnlockingChunks,
+
+    /// Get the sender's nominations.
+    #[codec(index = 12)]
+    Nominations,
+
+    /// Get the reward points earned by validators in the given era.
+    #[codec(index = 13)]
+    EraRewardPoints { era: u32 },
+
+    /// Get the sender's slashing spans.
+    #[codec(index = 14)]
+    SlashingSpans,
+}
+
+impl Request {
+    /// Returns `true` if the request only reads the staking state.
+    pub fn is_query(&self) -> bool {
+        matches!(
+            self,
+            Self::ActiveEra
+                | Self::Ledger
+                | Self::UnlockingChunks
+                | Self::Nominations
+                | Self::EraRewardPoints { .. }
+                | Self::SlashingSpans
+        )
+    }
 }
 
 /// An account where the rewards should accumulate on.
@@ -122,6 +157,32 @@ pub enum Response {
         /// Gear block number when the request was executed.
         executed_at_gear_block: u32,
     },
+    /// Response containing the sender's staking ledger, `None` if not bonded.
+    Ledger { ledger: Option<LedgerInfo> },
+    /// Response containing the sender's unlocking chunks.
+    UnlockingChunks {
+        /// Chunks of funds being unlocked.
+        chunks: Vec<UnlockChunk>,
+        /// Current era index, if set.
+        ///
+        /// A chunk can be withdrawn once its `era` is not greater than the current era.
+        current_era: Option<u32>,
+    },
+    /// Response containing the sender's nominations, `None` if not nominating.
+    Nominations {
+        nominations: Option<NominationsInfo>,
+    },
+    /// Response containing the reward points of validators in the requested era.
+    EraRewardPoints {
+        /// Requested era index.
+        era: u32,
+        /// Total number of points.
+        total: u32,
+        /// Points earned by each validator.
+        individual: Vec<(ActorId, u32)>,
+    },
+    /// Response containing the sender's slashing spans, `None` if never slashed.
+    SlashingSpans { spans: Option<SlashingSpansInfo> },
 }
 
 /// Information about the active era.
@@ -135,3 +196,64 @@ pub struct ActiveEraInfo {
     /// Start is set on the first `on_finalize` of the era to guarantee usage of `Time`.
     pub start: Option<u64>,
 }
+
+/// Information about a staking ledger.
+///
+/// A "mirror" of the staking pallet's `StakingLedger` struct.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct LedgerInfo {
+    /// The stash account whose balance is actually locked and at stake.
+    pub stash: ActorId,
+    /// The total amount of the stash's balance that is locked, including unlocking funds.
+    pub total: u128,
+    /// The amount of the stash's balance that is at stake in any forthcoming rounds.
+    pub active: u128,
+    /// Chunks of funds being unlocked.
+    pub unlocking: Vec<UnlockChunk>,
+}
+
+/// A chunk of funds scheduled to be unlocked.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct UnlockChunk {
+    /// Amount of funds to be unlocked.
+    pub value: u128,
+    /// Era number at which point the funds can be withdrawn.
+    pub era: u32,
+}
+
+/// Information about nominations.
+///
+/// A "mirror" of the staking pallet's `Nominations` struct.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct NominationsInfo {
+    /// The targets of the nomination.
+    pub targets: Vec<ActorId>,
+    /// The era the nominations were submitted.
+    pub submitted_in: u32,
+    /// Whether the nominations have been suppressed.
+    pub suppressed: bool,
+}
+
+/// Information about slashing spans of a stash.
+///
+/// A "mirror" of the staking pallet's `slashing::SlashingSpans` struct
+/// sharing the same encoding.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct SlashingSpansInfo {
+    /// Index of the current slashing span.
+    pub span_index: u32,
+    /// Start era of the most recent (ongoing) slashing span.
+    pub last_start: u32,
+    /// The last era at which a non-zero slash occurred.
+    pub last_nonzero_slash: u32,
+    /// Start eras of all prior slashing spans in reverse order (most recent first),
+    /// encoded as offsets relative to the slashing span after it.
+    pub prior: Vec<u32>,
+}
+
+impl SlashingSpansInfo {
+    /// Returns the number of slashing spans to pass to [`Request::WithdrawUnbonded`].
+    pub fn num_slashing_spans(&self) -> u32 {
+        self.prior.len() as u32 + 1
+    }
+}
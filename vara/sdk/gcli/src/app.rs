@@ -8,10 +8,16 @@ use crate::{
     },
     utils::HexBytes,
 };
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result, anyhow, ensure};
 use clap::Parser;
-use gsdk::{Api, SignedApi, ext::sp_core};
-use gsigner::schemes::sr25519::{Keyring, Keystore};
+use gsdk::{
+    Api, SignedApi, TxSigner,
+    ext::{
+        sp_core,
+        sp_runtime::{AccountId32, MultiSignature},
+    },
+};
+use gsigner::schemes::sr25519::{Keyring, Keystore, PublicKey, Sr25519, Sr25519SignerExt};
 use std::{env, fs, io, path::PathBuf, time::Duration};
 use tracing_subscriber::EnvFilter;
 
@@ -34,6 +40,13 @@ pub struct Opts {
     /// Password for the signer account, as hex string.
     #[arg(short, long)]
     pub passwd: Option<HexBytes>,
+
+    /// Unix socket of a remote signer daemon (`gsigner sr25519 server`).
+    ///
+    /// Transactions of the current account are signed by the daemon instead
+    /// of with the key from the local keyring.
+    #[arg(long)]
+    pub signer_socket: Option<PathBuf>,
 }
 
 /// Application state.
@@ -118,6 +131,21 @@ impl App {
 
     /// Returns a signed Gear node API wrapper.
     pub async fn signed_api(&self) -> Result<SignedApi> {
+        if let Some(socket) = &self.opts.signer_socket {
+            let public_key = self.keystore()?.public_key()?;
+            let signer = gsigner::Signer::<Sr25519>::remote(socket);
+            ensure!(
+                signer.has_key(public_key)?,
+                "key {} is not served by the remote signer",
+                self.ss58_address()?
+            );
+
+            return Ok(SignedApi::with_tx_signer(
+                self.api().await?,
+                RemoteSigner { signer, public_key },
+            ));
+        }
+
         let passwd_str = self
             .opts
             .passwd
@@ -134,6 +162,27 @@ impl App {
     }
 }
 
+/// Transaction signer backed by a remote signer daemon.
+struct RemoteSigner {
+    signer: gsigner::Signer<Sr25519>,
+    public_key: PublicKey,
+}
+
+impl TxSigner for RemoteSigner {
+    fn account_id(&self) -> AccountId32 {
+        self.public_key.to_bytes().into()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> gsdk::Result<MultiSignature> {
+        let signature = self
+            .signer
+            .sign_with_context(self.public_key, b"substrate", signer_payload, None)
+            .map_err(|e| gsdk::Error::Signer(e.into()))?;
+
+        Ok(sp_core::sr25519::Signature::from(signature).into())
+    }
+}
+
 fn store_path() -> Result<PathBuf> {
     let data_dir = dirs::data_dir().ok_or_else(|| anyhow!("Failed to locate app directory."))?;
     let store = gsigner::keyring::resolve_namespaced_path(
@@ -0,0 +1,162 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Command `calc-gas`
+use super::create_program::{Code, CodeArgs};
+use crate::{app::App, utils::HexBytes};
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use gear_core::{
+    ids::{ActorId, MessageId},
+    rpc::ReplyInfo,
+};
+use gsdk::GasInfo;
+
+/// Calculate gas required for an operation, without submitting it.
+#[derive(Clone, Debug, Parser)]
+pub enum CalcGas {
+    /// Calculate gas for sending a message.
+    ///
+    /// Also calculates the reply of the program.
+    Send {
+        /// Destination address, in SS58 or hex format.
+        destination: ActorId,
+
+        /// Message payload, as hex string.
+        #[arg(short, long, default_value = "0x")]
+        payload: HexBytes,
+
+        /// Value to send with the message.
+        #[arg(short, long, default_value = "0")]
+        value: u128,
+
+        /// Ignore traps during the program's execution.
+        #[arg(short, long)]
+        allow_other_panics: bool,
+    },
+    /// Calculate gas for replying to a message.
+    Reply {
+        /// Message to reply to.
+        message_id: MessageId,
+
+        /// Reply payload, as hex string.
+        #[arg(short, long, default_value = "0x")]
+        payload: HexBytes,
+
+        /// Value to send with the reply.
+        #[arg(short, long, default_value = "0")]
+        value: u128,
+
+        /// Ignore traps during the program's execution.
+        #[arg(short, long)]
+        allow_other_panics: bool,
+    },
+    /// Calculate gas for deploying a program.
+    CreateProgram {
+        /// Initial message payload, as hex string.
+        #[arg(short, long, default_value = "0x")]
+        init_payload: HexBytes,
+
+        /// Initial program balance.
+        #[arg(short, long, default_value = "0")]
+        value: u128,
+
+        /// Ignore traps during the program's execution.
+        #[arg(short, long)]
+        allow_other_panics: bool,
+
+        #[clap(flatten)]
+        code_args: CodeArgs,
+    },
+}
+
+impl CalcGas {
+    pub async fn exec(self, app: &mut App) -> Result<()> {
+        let api = app.signed_api().await?;
+
+        match self {
+            CalcGas::Send {
+                destination,
+                payload,
+                value,
+                allow_other_panics,
+            } => {
+                let gas_info = api
+                    .calculate_handle_gas(destination, &payload, value, allow_other_panics)
+                    .await?;
+                print_gas_info(&gas_info);
+
+                let reply = api
+                    .calculate_reply_for_handle(destination, &payload, gas_info.min_limit, value)
+                    .await?;
+                println!();
+                print_reply(&reply);
+            }
+            CalcGas::Reply {
+                message_id,
+                payload,
+                value,
+                allow_other_panics,
+            } => {
+                let gas_info = api
+                    .calculate_reply_gas(message_id, &payload, value, allow_other_panics)
+                    .await?;
+                print_gas_info(&gas_info);
+            }
+            CalcGas::CreateProgram {
+                init_payload,
+                value,
+                allow_other_panics,
+                code_args,
+            } => {
+                let gas_info = match code_args.into_code().await? {
+                    Code::Uploaded(code_id) => {
+                        api.calculate_create_gas(code_id, &init_payload, value, allow_other_panics)
+                            .await?
+                    }
+                    Code::Binary(code) => {
+                        api.calculate_upload_gas(code, &init_payload, value, allow_other_panics)
+                            .await?
+                    }
+                };
+                print_gas_info(&gas_info);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds `margin` percents to the calculated minimal gas limit.
+pub(super) fn with_margin(min_limit: u64, margin: u64) -> u64 {
+    min_limit.saturating_add(min_limit.saturating_mul(margin) / 100)
+}
+
+/// Prints the calculated gas.
+pub(super) fn print_gas_info(gas_info: &GasInfo) {
+    println!("{} {}", "Min limit:".bold(), gas_info.min_limit);
+    println!("{} {}", "Burned:".bold(), gas_info.burned);
+    println!("{} {}", "Reserved:".bold(), gas_info.reserved);
+    println!("{} {}", "May be returned:".bold(), gas_info.may_be_returned);
+    if gas_info.waited {
+        println!(
+            "{}",
+            "Message was put into the waitlist, min limit only covers the first execution".yellow()
+        );
+    }
+}
+
+/// Prints the calculated reply.
+pub(super) fn print_reply(reply: &ReplyInfo) {
+    println!("{} {}", "Reply code:".bold(), reply.code);
+    println!(
+        "{} {}",
+        "Reply payload:".bold(),
+        HexBytes::from(reply.payload.clone())
+    );
+    if let Ok(payload) = std::str::from_utf8(&reply.payload) {
+        println!("{} {payload:?}", "Reply payload (utf-8):".bold());
+    }
+    println!("{} {}", "Reply value:".bold(), reply.value);
+}
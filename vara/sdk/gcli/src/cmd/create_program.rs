@@ -3,6 +3,7 @@
 
 //! Command `create-program`.
 
+use super::calc_gas;
 use crate::{app::App, utils::HexBytes};
 use anyhow::{Context, Result};
 use clap::{Args, Parser};
@@ -36,17 +37,28 @@ pub struct CreateProgram {
     #[arg(short, long)]
     gas_limit: Option<u64>,
 
+    /// Safety margin added to the estimated gas limit, in percents.
+    ///
+    /// Ignored if `--gas-limit` is provided.
+    #[arg(long, default_value = "0")]
+    gas_margin: u64,
+
     /// Initial program balance.
     #[arg(short, long, default_value = "0")]
     value: u128,
 
+    /// Only calculate gas and print the results,
+    /// without submitting the transaction.
+    #[arg(long)]
+    dry_run: bool,
+
     #[clap(flatten)]
     code_args: CodeArgs,
 }
 
 #[derive(Debug, Clone, Args)]
 #[group(required = true, multiple = false)]
-struct CodeArgs {
+pub struct CodeArgs {
     /// ID of a previously uploaded code.
     ///
     /// Mutually exclusive with `--path` and `--stdin`.
@@ -66,13 +78,13 @@ struct CodeArgs {
     stdin: bool,
 }
 
-enum Code {
+pub enum Code {
     Uploaded(CodeId),
     Binary(Vec<u8>),
 }
 
 impl CodeArgs {
-    async fn into_code(self) -> Result<Code> {
+    pub async fn into_code(self) -> Result<Code> {
         match self {
             Self {
                 code_id: Some(code_id),
@@ -111,20 +123,28 @@ impl CreateProgram {
 
         let code = self.code_args.into_code().await?;
 
-        let gas_limit = if let Some(gas_limit) = self.gas_limit {
-            gas_limit
-        } else {
-            match &code {
-                Code::Uploaded(code_id) => {
-                    api.calculate_create_gas(*code_id, &self.init_payload, self.value, false)
-                        .await?
-                }
-                Code::Binary(code) => {
-                    api.calculate_upload_gas(code, &self.init_payload, self.value, false)
-                        .await?
+        let gas_limit = match self.gas_limit {
+            Some(gas_limit) if !self.dry_run => gas_limit,
+            gas_limit => {
+                let gas_info = match &code {
+                    Code::Uploaded(code_id) => {
+                        api.calculate_create_gas(*code_id, &self.init_payload, self.value, false)
+                            .await?
+                    }
+                    Code::Binary(code) => {
+                        api.calculate_upload_gas(code, &self.init_payload, self.value, false)
+                            .await?
+                    }
+                };
+
+                if self.dry_run {
+                    calc_gas::print_gas_info(&gas_info);
+                    return Ok(());
                 }
+
+                gas_limit
+                    .unwrap_or_else(|| calc_gas::with_margin(gas_info.min_limit, self.gas_margin))
             }
-            .min_limit
         };
 
         let (message_id, program_id) = match code {
@@ -3,6 +3,7 @@
 
 //! commands
 
+mod calc_gas;
 mod claim;
 pub mod config;
 mod create_program;
@@ -11,18 +12,21 @@ mod new;
 mod read_state;
 mod reply;
 mod send;
+mod trace;
 mod transfer;
 #[cfg(feature = "self-update")]
 mod update;
 mod upload_code;
+mod voucher;
 mod wallet;
+mod watch;
 
 #[cfg(feature = "self-update")]
 use self::update::Update;
 use self::{
-    claim::Claim, config::Config, create_program::CreateProgram, info::Info, new::New,
-    read_state::ReadState, reply::Reply, send::Send, transfer::Transfer, upload_code::UploadCode,
-    wallet::Wallet,
+    calc_gas::CalcGas, claim::Claim, config::Config, create_program::CreateProgram, info::Info,
+    new::New, read_state::ReadState, reply::Reply, send::Send, trace::Trace, transfer::Transfer,
+    upload_code::UploadCode, voucher::Voucher, wallet::Wallet, watch::Watch,
 };
 use crate::app::App;
 use anyhow::Result;
@@ -41,10 +45,18 @@ pub enum Command {
 
     Send(Send),
     Reply(Reply),
+    #[clap(subcommand)]
+    CalcGas(CalcGas),
+
+    Watch(Watch),
+    Trace(Trace),
 
     Transfer(Transfer),
     Claim(Claim),
 
+    #[clap(subcommand)]
+    Voucher(Voucher),
+
     Config(Config),
     #[clap(subcommand)]
     Wallet(Wallet),
@@ -67,6 +79,10 @@ impl Command {
             Command::UploadCode(upload) => upload.exec(app).await?,
             Command::Transfer(transfer) => transfer.exec(app).await?,
             Command::Reply(reply) => reply.exec(app).await?,
+            Command::CalcGas(calc_gas) => calc_gas.exec(app).await?,
+            Command::Watch(watch) => watch.exec(app).await?,
+            Command::Trace(trace) => trace.exec(app).await?,
+            Command::Voucher(voucher) => voucher.exec(app).await?,
             Command::Wallet(wallet) => wallet.exec(app)?,
             #[cfg(feature = "self-update")]
             Command::Update(update) => update.exec().await?,
@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 //! Command `send`
+use super::calc_gas;
 use crate::{app::App, utils::HexBytes};
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 use gear_core::ids::ActorId;
+use gsdk::gear::runtime_types::pallet_gear_voucher::internal::VoucherId;
 
 /// Send a message.
 #[derive(Clone, Debug, Parser)]
@@ -25,31 +27,79 @@ pub struct Send {
     #[arg(short, long)]
     gas_limit: Option<u64>,
 
+    /// Safety margin added to the estimated gas limit, in percents.
+    ///
+    /// Ignored if `--gas-limit` is provided.
+    #[arg(long, default_value = "0")]
+    gas_margin: u64,
+
     /// Value to send with the message.
     #[arg(short, long, default_value = "0")]
     value: u128,
+
+    /// Only calculate gas and print the results,
+    /// without submitting the transaction.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Voucher to pay for the message with, in SS58 or hex format.
+    #[arg(long)]
+    voucher: Option<ActorId>,
 }
 
 impl Send {
     pub async fn exec(self, app: &mut App) -> Result<()> {
         let api = app.signed_api().await?;
-        let gas_limit = if let Some(gas_limit) = self.gas_limit {
-            gas_limit
-        } else {
-            api.calculate_handle_gas(self.destination, &self.payload, self.value, false)
-                .await?
-                .min_limit
+        let gas_limit = match self.gas_limit {
+            Some(gas_limit) if !self.dry_run => gas_limit,
+            gas_limit => {
+                let gas_info = api
+                    .calculate_handle_gas(self.destination, &self.payload, self.value, false)
+                    .await?;
+                let gas_limit = gas_limit
+                    .unwrap_or_else(|| calc_gas::with_margin(gas_info.min_limit, self.gas_margin));
+
+                if self.dry_run {
+                    let reply = api
+                        .calculate_reply_for_handle(
+                            self.destination,
+                            &self.payload,
+                            gas_limit,
+                            self.value,
+                        )
+                        .await?;
+
+                    calc_gas::print_gas_info(&gas_info);
+                    println!();
+                    calc_gas::print_reply(&reply);
+                    return Ok(());
+                }
+
+                gas_limit
+            }
         };
 
-        let message_id = api
-            .send_message_bytes(
+        let message_id = if let Some(voucher) = self.voucher {
+            api.send_message_bytes_with_voucher(
+                VoucherId(voucher.into_bytes()),
                 self.destination,
                 self.payload.clone(),
                 gas_limit,
                 self.value,
+                false,
             )
             .await?
-            .value;
+            .value
+        } else {
+            api.send_message_bytes(
+                self.destination,
+                self.payload.clone(),
+                gas_limit,
+                self.value,
+            )
+            .await?
+            .value
+        };
 
         println!("Successfully sent the message");
         println!();
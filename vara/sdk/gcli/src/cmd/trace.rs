@@ -0,0 +1,438 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Command `trace`
+use crate::app::App;
+use anyhow::{Result, bail};
+use clap::Parser;
+use colored::Colorize;
+use futures::prelude::*;
+use gear_core::ids::{ActorId, MessageId, prelude::MessageIdExt};
+use gsdk::gear::{
+    gear::Event,
+    runtime_types::gear_common::event::{DispatchStatus, MessageEntry},
+};
+use std::{collections::HashMap, fmt, pin::pin};
+
+/// Follow a message through the queue, rendering the whole message tree.
+///
+/// Outgoing messages, replies and signals are recognized by their ids,
+/// which are derived from the id of the message that produced them.
+/// Delayed messages aren't awaited.
+#[derive(Clone, Debug, Parser)]
+pub struct Trace {
+    /// Message to trace.
+    message_id: MessageId,
+
+    /// Start from the given past block.
+    ///
+    /// Allows tracing messages that are already sent.
+    #[arg(long)]
+    from_block: Option<u32>,
+
+    /// Maximum amount of outgoing messages to look for per traced message.
+    #[arg(long, default_value = "64")]
+    max_outgoing: u32,
+
+    /// Only follow finalized blocks.
+    #[arg(long)]
+    finalized: bool,
+
+    /// Give up if the message isn't seen within the given amount of blocks.
+    ///
+    /// Messages processed before the first followed block are never seen,
+    /// use `--from-block` to trace them.
+    #[arg(long, default_value = "20")]
+    timeout: u32,
+}
+
+impl Trace {
+    pub async fn exec(self, app: &mut App) -> Result<()> {
+        let api = app.api().await?;
+        let mut blocks = pin!(
+            api.subscribe_gear_events(self.from_block, self.finalized)
+                .await?
+        );
+
+        let mut tree = MessageTree::new(self.message_id, self.max_outgoing);
+        let mut blocks_followed = 0;
+
+        while let Some(block) = blocks.try_next().await? {
+            let mut queue_processed = true;
+            blocks_followed += 1;
+
+            for event in block.events {
+                if matches!(event, Event::QueueNotProcessed) {
+                    queue_processed = false;
+                }
+
+                for id in tree.apply(event) {
+                    let node = &tree.nodes[&id];
+                    println!(
+                        "{} {} {id}: {}",
+                        format!("#{}", block.number).dimmed(),
+                        node.relation,
+                        node.status
+                    );
+                }
+            }
+
+            if queue_processed && tree.is_settled() {
+                break;
+            }
+
+            if !tree.is_seen(self.message_id) && blocks_followed >= self.timeout {
+                bail!(
+                    "Message {} isn't seen within {} blocks, \
+                    it may be already processed, try `--from-block`",
+                    self.message_id,
+                    self.timeout
+                );
+            }
+        }
+
+        println!();
+        tree.render(self.message_id, "", true);
+
+        Ok(())
+    }
+}
+
+/// How the message relates to its parent.
+#[derive(Clone, Copy, Debug)]
+enum Relation {
+    Root,
+    Outgoing(u32),
+    Reply,
+    Signal,
+    UserReply,
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Root => write!(f, "{}", "message".bold()),
+            Self::Outgoing(nonce) => write!(f, "{}", format!("outgoing #{nonce}").bold()),
+            Self::Reply => write!(f, "{}", "reply".bold()),
+            Self::Signal => write!(f, "{}", "signal".bold()),
+            Self::UserReply => write!(f, "{}", "user reply".bold()),
+        }
+    }
+}
+
+/// Last known status of the message.
+#[derive(Clone, Debug)]
+enum Status {
+    Unknown,
+    Queued,
+    Waiting { expiration: u32 },
+    Woken,
+    Dispatched(DispatchStatus),
+    Sent { expiration: Option<u32> },
+    Read(String),
+}
+
+impl Status {
+    /// Whether the message won't be processed anymore.
+    fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Dispatched(_) | Self::Sent { .. } | Self::Read(_)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "{}", "unknown".dimmed()),
+            Self::Queued => write!(f, "queued"),
+            Self::Waiting { expiration } => write!(f, "waiting until #{expiration}"),
+            Self::Woken => write!(f, "woken"),
+            Self::Dispatched(DispatchStatus::Success) => write!(f, "{}", "success".green()),
+            Self::Dispatched(DispatchStatus::Failed) => write!(f, "{}", "failed".red()),
+            Self::Dispatched(DispatchStatus::NotExecuted) => {
+                write!(f, "{}", "not executed".yellow())
+            }
+            Self::Sent {
+                expiration: Some(expiration),
+            } => write!(f, "sent to mailbox until #{expiration}"),
+            Self::Sent { expiration: None } => write!(f, "sent to user"),
+            Self::Read(reason) => write!(f, "read from mailbox, reason: {reason}"),
+        }
+    }
+}
+
+struct Node {
+    relation: Relation,
+    route: Option<(ActorId, ActorId)>,
+    status: Status,
+    children: Vec<MessageId>,
+}
+
+/// Tree of the traced message and messages produced by it.
+struct MessageTree {
+    nodes: HashMap<MessageId, Node>,
+    /// Ids of messages the traced ones may produce, along with their parents.
+    expected: HashMap<MessageId, (MessageId, Relation)>,
+    max_outgoing: u32,
+}
+
+impl MessageTree {
+    fn new(root: MessageId, max_outgoing: u32) -> Self {
+        let mut tree = Self {
+            nodes: HashMap::new(),
+            expected: HashMap::new(),
+            max_outgoing,
+        };
+        tree.insert(root, None, Relation::Root);
+
+        tree
+    }
+
+    fn insert(&mut self, id: MessageId, parent: Option<MessageId>, relation: Relation) {
+        self.nodes.insert(
+            id,
+            Node {
+                relation,
+                route: None,
+                status: Status::Unknown,
+                children: Vec::new(),
+            },
+        );
+
+        if let Some(parent) = parent
+            && let Some(parent) = self.nodes.get_mut(&parent)
+        {
+            parent.children.push(id);
+        }
+
+        self.expected
+            .insert(MessageId::generate_reply(id), (id, Relation::Reply));
+        self.expected
+            .insert(MessageId::generate_signal(id), (id, Relation::Signal));
+        for nonce in 0..self.max_outgoing {
+            self.expected.insert(
+                MessageId::generate_outgoing(id, nonce),
+                (id, Relation::Outgoing(nonce)),
+            );
+        }
+    }
+
+    /// Returns the node of the message, if it's a part of the tree.
+    fn node(&mut self, id: MessageId) -> Option<&mut Node> {
+        if let Some((parent, relation)) = self.expected.remove(&id) {
+            self.insert(id, Some(parent), relation);
+        }
+
+        self.nodes.get_mut(&id)
+    }
+
+    /// Applies the event to the tree, returning ids of updated messages.
+    fn apply(&mut self, event: Event) -> Vec<MessageId> {
+        let mut updated = Vec::new();
+        let mut update = |tree: &mut Self, id, route: Option<(ActorId, ActorId)>, status| {
+            if let Some(node) = tree.node(id) {
+                node.route = route.or(node.route);
+                node.status = status;
+                updated.push(id);
+            }
+        };
+
+        match event {
+            Event::MessageQueued {
+                id,
+                source,
+                destination,
+                entry,
+            } => {
+                if let MessageEntry::Reply(replied_to) = entry
+                    && self.nodes.contains_key(&replied_to)
+                    && !self.nodes.contains_key(&id)
+                {
+                    self.insert(id, Some(replied_to), Relation::UserReply);
+                }
+
+                let route = (ActorId::new(source.0), destination);
+                update(self, id, Some(route), Status::Queued);
+            }
+            Event::UserMessageSent {
+                message,
+                expiration,
+            } => {
+                let route = (message.source(), message.destination());
+                update(self, message.id(), Some(route), Status::Sent { expiration });
+            }
+            Event::UserMessageRead { id, reason } => {
+                update(self, id, None, Status::Read(format!("{reason:?}")));
+            }
+            Event::MessagesDispatched { statuses, .. } => {
+                for (id, status) in statuses {
+                    update(self, id, None, Status::Dispatched(status));
+                }
+            }
+            Event::MessageWaited { id, expiration, .. } => {
+                update(self, id, None, Status::Waiting { expiration });
+            }
+            Event::MessageWoken { id, .. } => {
+                update(self, id, None, Status::Woken);
+            }
+            _ => {}
+        }
+
+        updated
+    }
+
+    /// Whether any event of the message is received.
+    fn is_seen(&self, id: MessageId) -> bool {
+        self.nodes
+            .get(&id)
+            .is_some_and(|node| !matches!(node.status, Status::Unknown))
+    }
+
+    /// Whether all messages of the tree are processed.
+    ///
+    /// Messages left in the mailbox are considered processed.
+    fn is_settled(&self) -> bool {
+        self.nodes.values().all(|node| node.status.is_final())
+    }
+
+    fn render(&self, id: MessageId, prefix: &str, is_last: bool) {
+        let node = &self.nodes[&id];
+        let (branch, indent) = match node.relation {
+            Relation::Root => ("", ""),
+            _ if is_last => ("└─ ", "   "),
+            _ => ("├─ ", "│  "),
+        };
+
+        let route = node
+            .route
+            .map(|(source, destination)| format!(" ({source} → {destination})"))
+            .unwrap_or_default();
+        println!(
+            "{prefix}{branch}{} {id}{route}: {}",
+            node.relation, node.status
+        );
+
+        let prefix = format!("{prefix}{indent}");
+        for (i, child) in node.children.iter().enumerate() {
+            self.render(*child, &prefix, i + 1 == node.children.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gear_core::message::{ReplyCode, ReplyDetails, SuccessReplyReason, UserMessage};
+    use gsdk::ext::subxt::utils::AccountId32;
+
+    const USER: ActorId = ActorId::new([1; 32]);
+    const PROGRAM: ActorId = ActorId::new([2; 32]);
+
+    fn queued(id: MessageId, destination: ActorId, entry: MessageEntry) -> Event {
+        Event::MessageQueued {
+            id,
+            source: AccountId32(USER.into_bytes()),
+            destination,
+            entry,
+        }
+    }
+
+    fn dispatched(id: MessageId, status: DispatchStatus) -> Event {
+        Event::MessagesDispatched {
+            total: 1,
+            statuses: vec![(id, status)],
+            state_changes: vec![],
+        }
+    }
+
+    fn sent(id: MessageId, details: Option<ReplyDetails>, expiration: Option<u32>) -> Event {
+        Event::UserMessageSent {
+            message: UserMessage::new(id, PROGRAM, USER, Default::default(), 0, details),
+            expiration,
+        }
+    }
+
+    #[test]
+    fn reply_settles_tree() {
+        let root = MessageId::from(1);
+        let reply = MessageId::generate_reply(root);
+        let mut tree = MessageTree::new(root, 4);
+
+        assert!(!tree.is_seen(root));
+        assert_eq!(
+            tree.apply(queued(root, PROGRAM, MessageEntry::Handle)),
+            [root]
+        );
+        assert!(tree.is_seen(root));
+        assert!(!tree.is_settled());
+
+        assert_eq!(
+            tree.apply(dispatched(root, DispatchStatus::Success)),
+            [root]
+        );
+        let details = ReplyDetails::new(root, ReplyCode::Success(SuccessReplyReason::Manual));
+        assert_eq!(tree.apply(sent(reply, Some(details), None)), [reply]);
+        assert!(tree.is_settled());
+
+        assert_eq!(tree.nodes[&root].children, [reply]);
+        assert!(matches!(tree.nodes[&reply].relation, Relation::Reply));
+        assert_eq!(tree.nodes[&reply].route, Some((PROGRAM, USER)));
+    }
+
+    #[test]
+    fn outgoing_and_user_reply_are_tracked() {
+        let root = MessageId::from(1);
+        let outgoing = MessageId::generate_outgoing(root, 2);
+        let user_reply = MessageId::from(2);
+        let mut tree = MessageTree::new(root, 4);
+
+        tree.apply(queued(root, PROGRAM, MessageEntry::Handle));
+        tree.apply(dispatched(root, DispatchStatus::Success));
+        assert_eq!(tree.apply(sent(outgoing, None, Some(100))), [outgoing]);
+        assert!(matches!(
+            tree.nodes[&outgoing].relation,
+            Relation::Outgoing(2)
+        ));
+        // Message in the mailbox is considered processed.
+        assert!(tree.is_settled());
+
+        assert_eq!(
+            tree.apply(queued(user_reply, PROGRAM, MessageEntry::Reply(outgoing))),
+            [user_reply]
+        );
+        assert_eq!(tree.nodes[&outgoing].children, [user_reply]);
+        assert!(matches!(
+            tree.nodes[&user_reply].relation,
+            Relation::UserReply
+        ));
+        assert!(!tree.is_settled());
+
+        tree.apply(dispatched(user_reply, DispatchStatus::Failed));
+        assert!(tree.is_settled());
+    }
+
+    #[test]
+    fn unrelated_messages_are_ignored() {
+        let root = MessageId::from(1);
+        let mut tree = MessageTree::new(root, 4);
+
+        // Outgoing message beyond the `max_outgoing` limit.
+        let outgoing = MessageId::generate_outgoing(root, 4);
+        let unrelated = MessageId::from(3);
+
+        assert!(tree.apply(sent(outgoing, None, None)).is_empty());
+        assert!(
+            tree.apply(queued(unrelated, PROGRAM, MessageEntry::Handle))
+                .is_empty()
+        );
+        assert!(
+            tree.apply(dispatched(unrelated, DispatchStatus::Success))
+                .is_empty()
+        );
+
+        assert!(!tree.is_seen(root));
+        assert_eq!(tree.nodes.len(), 1);
+    }
+}
@@ -4,8 +4,10 @@
 //! command `upload_program`
 use crate::app::App;
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Args, Parser};
 use colored::Colorize;
+use gear_core::ids::ActorId;
+use gsdk::gear::runtime_types::pallet_gear_voucher::internal::VoucherId;
 use std::path::PathBuf;
 use tokio::{fs, io, io::AsyncReadExt};
 
@@ -14,8 +16,20 @@ use tokio::{fs, io, io::AsyncReadExt};
 /// The code can be then used to deploy programs
 /// from it.
 #[derive(Clone, Debug, Parser)]
-#[group(required = true, multiple = false)]
 pub struct UploadCode {
+    #[clap(flatten)]
+    code_args: CodeArgs,
+
+    /// Voucher to pay for the code uploading with, in SS58 or hex format.
+    ///
+    /// The voucher must allow code uploading.
+    #[arg(long)]
+    voucher: Option<ActorId>,
+}
+
+#[derive(Debug, Clone, Args)]
+#[group(required = true, multiple = false)]
+struct CodeArgs {
     /// Path to WASM binary.
     ///
     /// Mutually exclusive with `--stdin`.
@@ -28,11 +42,8 @@ pub struct UploadCode {
     stdin: bool,
 }
 
-impl UploadCode {
-    /// Exec command submit
-    pub async fn exec(self, app: &mut App) -> Result<()> {
-        let api = app.signed_api().await?;
-
+impl CodeArgs {
+    async fn into_code(self) -> Result<Vec<u8>> {
         let code = match self {
             Self {
                 path: Some(path),
@@ -54,7 +65,24 @@ impl UploadCode {
             _ => unreachable!(),
         };
 
-        let code_id = api.upload_code(code).await?.value;
+        Ok(code)
+    }
+}
+
+impl UploadCode {
+    /// Exec command submit
+    pub async fn exec(self, app: &mut App) -> Result<()> {
+        let api = app.signed_api().await?;
+
+        let code = self.code_args.into_code().await?;
+
+        let code_id = if let Some(voucher) = self.voucher {
+            api.upload_code_with_voucher(VoucherId(voucher.into_bytes()), code)
+                .await?
+                .value
+        } else {
+            api.upload_code(code).await?.value
+        };
 
         println!("Successfully uploaded the code");
         println!();
@@ -0,0 +1,238 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Command `voucher`
+use crate::app::App;
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use gear_core::ids::ActorId;
+use gsdk::{Api, GearVoucherInfo, gear::runtime_types::pallet_gear_voucher::internal::VoucherId};
+
+/// Manage vouchers paying for other accounts' transactions.
+#[derive(Clone, Debug, Parser)]
+pub enum Voucher {
+    /// Issue a new voucher.
+    Issue {
+        /// Spender address, in SS58 or hex format.
+        spender: ActorId,
+
+        /// Voucher balance, used to pay for transaction fees and gas.
+        balance: u128,
+
+        /// Amount of blocks the voucher is valid for.
+        #[arg(short, long)]
+        duration: u32,
+
+        /// Programs the voucher can be used to interact with.
+        ///
+        /// Defaults to any program.
+        #[arg(short, long = "program")]
+        programs: Vec<ActorId>,
+
+        /// Allow the voucher to pay for code uploading.
+        #[arg(short, long)]
+        code_uploading: bool,
+    },
+    /// Update an existing voucher.
+    ///
+    /// Can only be called by the voucher owner and only
+    /// extends the voucher rights.
+    Update {
+        /// Spender address, in SS58 or hex format.
+        spender: ActorId,
+
+        /// Voucher to update.
+        voucher_id: ActorId,
+
+        /// Move voucher ownership to the given account.
+        #[arg(long)]
+        move_ownership: Option<ActorId>,
+
+        /// Top up voucher balance from the owner balance.
+        #[arg(short, long)]
+        balance_top_up: Option<u128>,
+
+        /// Extend the pool of programs the voucher can be used to interact with.
+        #[arg(short, long = "program", conflicts_with = "any_program")]
+        programs: Vec<ActorId>,
+
+        /// Allow the voucher to interact with any program.
+        #[arg(long)]
+        any_program: bool,
+
+        /// Allow the voucher to pay for code uploading.
+        #[arg(short, long)]
+        code_uploading: bool,
+
+        /// Prolong voucher validity by the given amount of blocks.
+        #[arg(short = 'd', long)]
+        prolong_duration: Option<u32>,
+    },
+    /// Revoke an expired voucher, returning its balance to the owner.
+    Revoke {
+        /// Spender address, in SS58 or hex format.
+        spender: ActorId,
+
+        /// Voucher to revoke.
+        voucher_id: ActorId,
+    },
+    /// Decline a voucher issued for the current account.
+    Decline {
+        /// Voucher to decline.
+        voucher_id: ActorId,
+    },
+    /// List vouchers issued for the account.
+    List {
+        /// Spender address, defaults to the current account.
+        spender: Option<ActorId>,
+
+        /// Limit number of fetched vouchers.
+        #[arg(default_value = "10", short, long)]
+        count: usize,
+    },
+    /// Get voucher info.
+    Info {
+        /// Voucher to get info of.
+        voucher_id: ActorId,
+
+        /// Spender address, defaults to the current account.
+        #[arg(short, long)]
+        spender: Option<ActorId>,
+    },
+}
+
+impl Voucher {
+    /// Run the voucher command.
+    pub async fn exec(self, app: &mut App) -> Result<()> {
+        match self {
+            Voucher::Issue {
+                spender,
+                balance,
+                duration,
+                programs,
+                code_uploading,
+            } => {
+                let programs = (!programs.is_empty()).then_some(programs);
+                let voucher_id = app
+                    .signed_api()
+                    .await?
+                    .issue_voucher(spender, balance, programs, code_uploading, duration)
+                    .await?
+                    .value;
+
+                println!("Successfully issued the voucher");
+                println!();
+                println!("{} {}", "Voucher ID:".bold(), ActorId::from(voucher_id.0));
+            }
+            Voucher::Update {
+                spender,
+                voucher_id,
+                move_ownership,
+                balance_top_up,
+                programs,
+                any_program,
+                code_uploading,
+                prolong_duration,
+            } => {
+                let append_programs = if any_program {
+                    Some(None)
+                } else {
+                    (!programs.is_empty()).then_some(Some(programs))
+                };
+
+                app.signed_api()
+                    .await?
+                    .update_voucher(
+                        spender,
+                        VoucherId(voucher_id.into_bytes()),
+                        move_ownership,
+                        balance_top_up,
+                        append_programs,
+                        code_uploading.then_some(true),
+                        prolong_duration,
+                    )
+                    .await?;
+
+                println!("Successfully updated the voucher");
+            }
+            Voucher::Revoke {
+                spender,
+                voucher_id,
+            } => {
+                app.signed_api()
+                    .await?
+                    .revoke_voucher(spender, VoucherId(voucher_id.into_bytes()))
+                    .await?;
+
+                println!("Successfully revoked the voucher");
+            }
+            Voucher::Decline { voucher_id } => {
+                app.signed_api()
+                    .await?
+                    .decline_voucher(VoucherId(voucher_id.into_bytes()))
+                    .await?;
+
+                println!("Successfully declined the voucher");
+            }
+            Voucher::List { spender, count } => {
+                let spender = Self::spender_or_current(spender, app)?;
+                let api = app.api().await?;
+
+                let vouchers = api.vouchers(spender, count).await?;
+                if vouchers.is_empty() {
+                    println!("{}", "No vouchers found".dimmed());
+                }
+
+                for (voucher_id, info) in vouchers {
+                    Self::print_voucher(&api, voucher_id, info).await?;
+                    println!("{}", "---".dimmed());
+                }
+            }
+            Voucher::Info {
+                voucher_id,
+                spender,
+            } => {
+                let spender = Self::spender_or_current(spender, app)?;
+                let api = app.api().await?;
+
+                let voucher_id = VoucherId(voucher_id.into_bytes());
+                let info = api.voucher_info(spender, voucher_id.clone()).await?;
+                Self::print_voucher(&api, voucher_id, info).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the given spender or the current account.
+    fn spender_or_current(spender: Option<ActorId>, app: &App) -> Result<ActorId> {
+        match spender {
+            Some(spender) => Ok(spender),
+            None => Ok(app.ss58_address()?.parse()?),
+        }
+    }
+
+    /// Prints the voucher info along with its balance.
+    async fn print_voucher(api: &Api, voucher_id: VoucherId, info: GearVoucherInfo) -> Result<()> {
+        let voucher_address = ActorId::from(voucher_id.0);
+        let balance = api.free_balance(voucher_address).await?;
+
+        println!("{} {}", "id:".bold(), voucher_address);
+        println!("{} {}", "owner:".bold(), info.owner);
+        println!("{} {}", "balance:".bold(), balance);
+        match info.programs {
+            Some(programs) => {
+                println!("{}", "programs:".bold());
+                for program in programs {
+                    println!("  {program}");
+                }
+            }
+            None => println!("{} {}", "programs:".bold(), "any".dimmed()),
+        }
+        println!("{} {}", "code uploading:".bold(), info.code_uploading);
+        println!("{} {}", "expiry:".bold(), info.expiry);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Command `watch`
+use crate::{app::App, utils::HexBytes};
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
+use futures::prelude::*;
+use gear_core::ids::{ActorId, MessageId};
+use gsdk::gear::gear::Event;
+use std::pin::pin;
+
+/// Follow `Gear` pallet events.
+#[derive(Clone, Debug, Parser)]
+pub struct Watch {
+    /// Only show events related to the given programs.
+    #[arg(short, long = "program")]
+    programs: Vec<ActorId>,
+
+    /// Only show events related to the given messages.
+    #[arg(short, long = "message")]
+    messages: Vec<MessageId>,
+
+    /// Only show events of the given kinds.
+    #[arg(short, long = "kind")]
+    kinds: Vec<EventKind>,
+
+    /// Start from the given past block.
+    #[arg(long)]
+    from_block: Option<u32>,
+
+    /// Only follow finalized blocks.
+    #[arg(long)]
+    finalized: bool,
+}
+
+/// Kind of `Gear` pallet event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EventKind {
+    MessageQueued,
+    UserMessageSent,
+    UserMessageRead,
+    MessagesDispatched,
+    MessageWaited,
+    MessageWoken,
+    CodeChanged,
+    ProgramChanged,
+    QueueNotProcessed,
+}
+
+impl EventKind {
+    fn of(event: &Event) -> Self {
+        match event {
+            Event::MessageQueued { .. } => Self::MessageQueued,
+            Event::UserMessageSent { .. } => Self::UserMessageSent,
+            Event::UserMessageRead { .. } => Self::UserMessageRead,
+            Event::MessagesDispatched { .. } => Self::MessagesDispatched,
+            Event::MessageWaited { .. } => Self::MessageWaited,
+            Event::MessageWoken { .. } => Self::MessageWoken,
+            Event::CodeChanged { .. } => Self::CodeChanged,
+            Event::ProgramChanged { .. } => Self::ProgramChanged,
+            Event::QueueNotProcessed => Self::QueueNotProcessed,
+        }
+    }
+}
+
+impl Watch {
+    pub async fn exec(self, app: &mut App) -> Result<()> {
+        let api = app.api().await?;
+        let mut blocks = pin!(
+            api.subscribe_gear_events(self.from_block, self.finalized)
+                .await?
+        );
+
+        while let Some(block) = blocks.try_next().await? {
+            for event in block.events.iter().filter(|event| self.matches(event)) {
+                println!(
+                    "{} {}",
+                    format!("#{}", block.number).dimmed(),
+                    describe(event)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the event passes all the filters.
+    fn matches(&self, event: &Event) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&EventKind::of(event)))
+            && (self.programs.is_empty()
+                || self.programs.iter().any(|id| involves_program(event, *id)))
+            && (self.messages.is_empty()
+                || self.messages.iter().any(|id| involves_message(event, *id)))
+    }
+}
+
+fn involves_program(event: &Event, program_id: ActorId) -> bool {
+    match event {
+        Event::MessageQueued { destination, .. } => *destination == program_id,
+        Event::UserMessageSent { message, .. } => message.source() == program_id,
+        Event::MessagesDispatched { state_changes, .. } => state_changes.contains(&program_id),
+        Event::ProgramChanged { id, .. } => *id == program_id,
+        _ => false,
+    }
+}
+
+fn involves_message(event: &Event, message_id: MessageId) -> bool {
+    match event {
+        Event::MessageQueued { id, .. }
+        | Event::UserMessageRead { id, .. }
+        | Event::MessageWaited { id, .. }
+        | Event::MessageWoken { id, .. } => *id == message_id,
+        Event::UserMessageSent { message, .. } => {
+            message.id() == message_id
+                || message
+                    .details()
+                    .is_some_and(|details| details.to_message_id() == message_id)
+        }
+        Event::MessagesDispatched { statuses, .. } => {
+            statuses.iter().any(|(id, _)| *id == message_id)
+        }
+        _ => false,
+    }
+}
+
+/// Renders the event as a human-readable string.
+fn describe(event: &Event) -> String {
+    let kind = format!("{:?}", EventKind::of(event)).bold();
+
+    match event {
+        Event::MessageQueued {
+            id,
+            source,
+            destination,
+            entry,
+        } => format!("{kind} {id} from {source} to {destination}, entry: {entry:?}"),
+        Event::UserMessageSent {
+            message,
+            expiration,
+        } => {
+            let mut description = format!(
+                "{kind} {} from {} to {}, payload: {}, value: {}",
+                message.id(),
+                message.source(),
+                message.destination(),
+                HexBytes::from(message.payload_bytes().to_vec()),
+                message.value(),
+            );
+
+            if let Some(details) = message.details() {
+                description += &format!(
+                    ", reply to {} with code: {}",
+                    details.to_message_id(),
+                    details.to_reply_code()
+                );
+            }
+
+            if let Some(expiration) = expiration {
+                description += &format!(", in mailbox until #{expiration}");
+            }
+
+            description
+        }
+        Event::UserMessageRead { id, reason } => format!("{kind} {id}, reason: {reason:?}"),
+        Event::MessagesDispatched {
+            total, statuses, ..
+        } => {
+            let mut description = format!("{kind} {total} messages");
+            for (id, status) in statuses {
+                description += &format!("\n    {id}: {status:?}");
+            }
+
+            description
+        }
+        Event::MessageWaited {
+            id,
+            reason,
+            expiration,
+            ..
+        } => format!("{kind} {id} until #{expiration}, reason: {reason:?}"),
+        Event::MessageWoken { id, reason } => format!("{kind} {id}, reason: {reason:?}"),
+        Event::CodeChanged { id, change } => format!("{kind} {id}, change: {change:?}"),
+        Event::ProgramChanged { id, change } => format!("{kind} {id}, change: {change:?}"),
+        Event::QueueNotProcessed => kind.to_string(),
+    }
+}
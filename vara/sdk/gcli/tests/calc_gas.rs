@@ -0,0 +1,129 @@
+// Copyright (C) Gear Technologies Inc.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Tests for gas calculation and `--dry-run` modes of `gcli`.
+
+use anyhow::{Context, Result};
+use indoc::{formatdoc, indoc};
+use std::{thread, time::Duration};
+
+mod util;
+
+const GAS_INFO: &str = indoc!(
+    "
+    Min limit: [..]
+    Burned: [..]
+    Reserved: [..]
+    May be returned: [..]
+    "
+);
+
+/// Returns the value printed after `label` in the command output.
+fn field(stdout: &[u8], label: &str) -> Result<String> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix(label))
+        .map(|value| value.trim().to_string())
+        .with_context(|| format!("`{label}` is not found in the output"))
+}
+
+#[tokio::test]
+async fn calc_gas_and_dry_run() -> Result<()> {
+    let (_node, gcli) = util::init_node()?;
+
+    gcli().args(["wallet", "dev"]).assert().success();
+
+    // Deploying program.
+    gcli()
+        .args(["calc-gas", "create-program", "--stdin", "-i", "0x50494e47"])
+        .stdin(demo_ping::WASM_BINARY)
+        .assert()
+        .success()
+        .stdout_eq(GAS_INFO);
+    gcli()
+        .args(["create-program", "--stdin", "--dry-run"])
+        .stdin(demo_ping::WASM_BINARY)
+        .assert()
+        .success()
+        .stdout_eq(GAS_INFO);
+
+    let output = gcli()
+        .args(["create-program", "--stdin", "--gas-margin", "10"])
+        .stdin(demo_ping::WASM_BINARY)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let ping = field(&output, "Program ID:")?;
+
+    // Sending message.
+    let ping_reply = formatdoc!(
+        "
+        {GAS_INFO}
+        Reply code: [..]
+        Reply payload: 0x504f4e47
+        Reply payload (utf-8): \"PONG\"
+        Reply value: 0
+        "
+    );
+    gcli()
+        .args(["calc-gas", "send", &ping, "-p", "0x50494e47"])
+        .assert()
+        .success()
+        .stdout_eq(ping_reply.clone());
+    gcli()
+        .args(["send", &ping, "-p", "0x50494e47", "--dry-run"])
+        .assert()
+        .success()
+        .stdout_eq(ping_reply);
+
+    // Replying to message, which `demo_messenger` sends to the mailbox on init.
+    gcli()
+        .args(["create-program", "--stdin"])
+        .stdin(demo_messenger::WASM_BINARY)
+        .assert()
+        .success();
+
+    let mut mail = None;
+    for _ in 0..10 {
+        let output = gcli()
+            .args(["info", "mailbox"])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+
+        if let Ok(id) = field(&output, "id:") {
+            mail = Some(id);
+            break;
+        }
+
+        thread::sleep(Duration::from_secs(1));
+    }
+    let mail = mail.context("message is not found in the mailbox")?;
+
+    gcli()
+        .args(["calc-gas", "reply", &mail])
+        .assert()
+        .success()
+        .stdout_eq(GAS_INFO);
+    gcli()
+        .args(["reply", &mail, "--dry-run"])
+        .assert()
+        .success()
+        .stdout_eq(GAS_INFO);
+
+    // Dry run doesn't take the message out of the mailbox.
+    let output = gcli()
+        .args(["info", "mailbox"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(field(&output, "id:")?, mail);
+
+    Ok(())
+}
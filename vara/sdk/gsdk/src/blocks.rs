@@ -3,9 +3,10 @@
 
 //! This module provides useful functions for working with block stream.
 
-use crate::{Api, Error, GearConfig, Result};
+use crate::{Api, AsGear, BlockNumber, Error, Event, GearConfig, Result, gear::gear};
 use futures::prelude::*;
 use std::pin::pin;
+use subxt::utils::H256;
 
 /// Block retrieved from a node.
 pub type Block = subxt::blocks::Block<GearConfig, subxt::OnlineClient<GearConfig>>;
@@ -13,6 +14,17 @@ pub type Block = subxt::blocks::Block<GearConfig, subxt::OnlineClient<GearConfig
 /// Events from some block.
 pub type Events = subxt::events::Events<GearConfig>;
 
+/// `Gear` pallet events from some block.
+#[derive(Debug, Clone)]
+pub struct GearEvents {
+    /// Number of the block.
+    pub number: BlockNumber,
+    /// Hash of the block.
+    pub hash: H256,
+    /// `Gear` pallet events of the block, in order of their appearance.
+    pub events: Vec<gear::Event>,
+}
+
 /// Checks whether the blocks are progressing.
 pub async fn are_progressing<E>(blocks: impl Stream<Item = Result<Block, E>>) -> Result<bool>
 where
@@ -41,4 +53,81 @@ impl Api {
 
         are_progressing(blocks).await
     }
+
+    /// Returns `Gear` pallet events of the given block.
+    pub async fn block_gear_events(&self, block: Block) -> Result<GearEvents> {
+        let events = block
+            .events()
+            .await?
+            .iter()
+            .map(|event| event?.as_gear())
+            .filter_map(|event| match event {
+                Ok(Event::Gear(event)) => Some(Ok(event)),
+                Ok(_) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(GearEvents {
+            number: block.number(),
+            hash: block.hash(),
+            events,
+        })
+    }
+
+    /// Subscribes to `Gear` pallet events of new blocks.
+    ///
+    /// If `from` is specified, events of the past blocks starting
+    /// from the given block number are yielded first.
+    ///
+    /// If `finalized` is set, only finalized blocks are observed.
+    pub async fn subscribe_gear_events(
+        &self,
+        from: Option<BlockNumber>,
+        finalized: bool,
+    ) -> Result<impl Stream<Item = Result<GearEvents>> + '_> {
+        let live = if finalized {
+            self.blocks().subscribe_finalized().await?
+        } else {
+            self.blocks().subscribe_all().await?
+        };
+
+        let past = match from {
+            Some(from) => {
+                let latest = if finalized {
+                    let hash = self.legacy().chain_get_finalized_head().await?;
+                    self.blocks().at(hash).await?.number()
+                } else {
+                    self.blocks().at_latest().await?.number()
+                };
+
+                Some(from..=latest)
+            }
+            None => None,
+        };
+
+        let past = stream::iter(past.into_iter().flatten()).then(move |number| async move {
+            let hash = self
+                .legacy()
+                .chain_get_block_hash(Some(u64::from(number).into()))
+                .await?
+                .ok_or(Error::BlockNotFound(number))?;
+
+            Ok::<_, Error>(self.blocks().at(hash).await?)
+        });
+
+        let mut last = None;
+        Ok(past
+            .chain(live.map_err(Error::from))
+            // Skip blocks yielded twice on the edge of past and live ones.
+            .try_filter(move |block: &Block| {
+                let is_new = last.is_none_or(|last| block.number() > last);
+                if is_new {
+                    last = Some(block.number());
+                }
+
+                future::ready(is_new)
+            })
+            .and_then(move |block| self.block_gear_events(block)))
+    }
 }
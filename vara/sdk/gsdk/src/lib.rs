@@ -22,7 +22,7 @@ pub use crate::{
     convert::{AsGear, IntoAccountId32, IntoSubstrate, IntoSubxt},
     gear::{Event, runtime_types::vara_runtime::RuntimeError},
     result::{Error, Result},
-    signed::{PairSigner, SignedApi, Signer, TxOutput},
+    signed::{PairSigner, SignedApi, Signer, TxOutput, TxSigner},
     subscription::{
         PayloadFilter, ProgramStateChange, ProgramStateChanges, UserMessageSent,
         UserMessageSentFilter, UserMessageSentSubscription,
@@ -35,6 +35,7 @@ pub use subxt::dynamic::Value;
 use crate::gear::runtime_types::{
     gear_common::gas_provider::node::{GasNode, GasNodeId},
     gear_core::program::ActiveProgram,
+    pallet_gear_voucher::internal::VoucherInfo,
 };
 use gear_core::{
     ids::{MessageId, ReservationId},
@@ -85,6 +86,9 @@ pub type GearGasNodeId = GasNodeId<MessageId, ReservationId>;
 /// Gear gas node.
 pub type GearGasNode = GasNode<subxt::utils::AccountId32, GearGasNodeId, u64, u128>;
 
+/// Gear voucher info.
+pub type GearVoucherInfo = VoucherInfo<subxt::utils::AccountId32, BlockNumber>;
+
 /// Gear pages.
 pub type GearPages = HashMap<GearPage, PageBuf>;
 
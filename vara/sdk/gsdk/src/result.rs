@@ -24,6 +24,9 @@ pub enum Error {
     #[error("the queried storage entry not found")]
     StorageEntryNotFound,
 
+    #[error("block #{0} not found")]
+    BlockNotFound(u32),
+
     #[error("subscription has been died")]
     SubscriptionDied,
 
@@ -39,6 +42,9 @@ pub enum Error {
     #[error("invalid secret phrase or key material")]
     InvalidSecret,
 
+    #[error("failed to sign transaction: {0}")]
+    Signer(Box<dyn std::error::Error + Send + Sync>),
+
     #[error("{0} was not found in the storage")]
     PageNotFound(FailedPage),
 
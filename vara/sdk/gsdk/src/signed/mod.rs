@@ -8,7 +8,7 @@ pub use self::{pair_signer::PairSigner, tx_output::TxOutput};
 use crate::{Api, backtrace::Backtrace, config::GearConfig, result::Result};
 use sp_core::{Pair as PairT, sr25519::Pair};
 use sp_keyring::AccountKeyring;
-use sp_runtime::AccountId32;
+use sp_runtime::{AccountId32, MultiSignature};
 use std::sync::Arc;
 
 mod calls;
@@ -20,6 +20,26 @@ mod utils;
 
 pub type Signer = PairSigner<GearConfig, Pair>;
 
+/// Fallible signer of the transactions sent through [`SignedApi`],
+/// e.g. one keeping the keys out of the process.
+pub trait TxSigner: Send + Sync {
+    /// Returns the account id of the signer.
+    fn account_id(&self) -> AccountId32;
+
+    /// Signs the transaction payload.
+    fn sign(&self, signer_payload: &[u8]) -> Result<MultiSignature>;
+}
+
+impl TxSigner for Signer {
+    fn account_id(&self) -> AccountId32 {
+        Signer::account_id(self).clone()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> Result<MultiSignature> {
+        Ok(subxt::tx::Signer::<GearConfig>::sign(self, signer_payload))
+    }
+}
+
 /// Signed Gear API wrapper.
 #[derive(derive_more::Debug, Clone, derive_more::Into, derive_more::AsRef, derive_more::Deref)]
 pub struct SignedApi {
@@ -28,9 +48,13 @@ pub struct SignedApi {
     #[deref]
     api: Api,
 
-    /// Current signer.
+    /// Current signer, absent if the API signs with a custom [`TxSigner`].
+    #[debug("<signer>")]
+    signer: Option<Arc<Signer>>,
+    /// Signer of the transactions.
     #[debug("<signer>")]
-    signer: Arc<PairSigner<GearConfig, Pair>>,
+    tx_signer: Arc<dyn TxSigner>,
+    account_id: AccountId32,
 
     nonce: Option<u64>,
     backtrace: Backtrace,
@@ -55,9 +79,25 @@ impl Api {
 
 impl SignedApi {
     pub fn with_pair(api: Api, pair: Pair) -> Self {
+        let signer = Arc::new(PairSigner::new(pair));
         Self {
             api,
-            signer: PairSigner::new(pair).into(),
+            account_id: signer.account_id().clone(),
+            signer: Some(signer.clone()),
+            tx_signer: signer,
+            nonce: None,
+            backtrace: Backtrace::default(),
+        }
+    }
+
+    /// Constructs new signed API with a custom signer, e.g. one keeping
+    /// the keys out of the process.
+    pub fn with_tx_signer(api: Api, signer: impl TxSigner + 'static) -> Self {
+        Self {
+            api,
+            account_id: signer.account_id(),
+            signer: None,
+            tx_signer: Arc::new(signer),
             nonce: None,
             backtrace: Backtrace::default(),
         }
@@ -74,13 +114,24 @@ impl SignedApi {
     }
 
     /// Returns a reference to the inner signer.
+    ///
+    /// # Panics
+    ///
+    /// If the API was constructed with [`Self::with_tx_signer`].
     pub fn signer(&self) -> &Signer {
-        &self.signer
+        self.signer
+            .as_deref()
+            .expect("signed API has no key pair, use `tx_signer` instead")
+    }
+
+    /// Returns a reference to the signer of the transactions.
+    pub fn tx_signer(&self) -> &dyn TxSigner {
+        &*self.tx_signer
     }
 
     /// Returns the address of the current signer.
     pub fn account_id(&self) -> &AccountId32 {
-        self.signer.account_id()
+        &self.account_id
     }
 
     /// Returns the backtrace of the signed API.
@@ -190,26 +190,24 @@ impl SignedApi {
     }
 
     /// Wrapper for submit and watch with nonce.
-    #[allow(clippy::result_large_err)]
-    async fn sign_and_submit_then_watch<Call: Payload>(
-        &self,
-        call: &Call,
-    ) -> Result<TxProgress, subxt::Error> {
+    ///
+    /// The payload is signed separately from `subxt`, since the signer may fail.
+    async fn sign_and_submit_then_watch<Call: Payload>(&self, call: &Call) -> Result<TxProgress> {
+        let mut params = PolkadotExtrinsicParamsBuilder::new();
         if let Some(nonce) = self.nonce {
-            self.tx()
-                .create_signed(
-                    call,
-                    self.signer(),
-                    PolkadotExtrinsicParamsBuilder::new().nonce(nonce).build(),
-                )
-                .await?
-                .submit_and_watch()
-                .await
-        } else {
-            self.tx()
-                .sign_and_submit_then_watch_default(call, self.signer())
-                .await
+            params = params.nonce(nonce);
         }
+
+        let mut tx = self
+            .tx()
+            .create_partial(call, self.account_id(), params.build())
+            .await?;
+        let signature = self.tx_signer().sign(&tx.signer_payload())?;
+
+        Ok(tx
+            .sign_with_account_and_signature(self.account_id(), &signature)
+            .submit_and_watch()
+            .await?)
     }
 
     /// Get the next number used once (`nonce`) from the node.
@@ -4,7 +4,8 @@
 //! Gear storage apis
 
 use crate::{
-    Api, BlockNumber, GearGasNode, GearGasNodeId, GearPages, IntoAccountId32, IntoSubstrate,
+    Api, BlockNumber, GearGasNode, GearGasNodeId, GearPages, GearVoucherInfo, IntoAccountId32,
+    IntoSubstrate,
     gear::{
         self,
         runtime_types::{
@@ -16,6 +17,7 @@ use crate::{
             },
             pallet_balances::types::AccountData,
             pallet_gear_bank::pallet::BankAccount,
+            pallet_gear_voucher::internal::VoucherId,
             vara_runtime::RuntimeEvent,
         },
     },
@@ -483,6 +485,57 @@ impl Api {
     }
 }
 
+// pallet-gear-voucher
+impl Api {
+    /// Get voucher info by its spender and id at specified block.
+    #[at_block]
+    pub async fn voucher_info_at(
+        &self,
+        spender: impl IntoAccountId32,
+        voucher_id: VoucherId,
+        block_hash: Option<H256>,
+    ) -> Result<GearVoucherInfo> {
+        self.storage_fetch_at(
+            &gear::storage()
+                .gear_voucher()
+                .vouchers(spender.into_account_id(), voucher_id),
+            block_hash,
+        )
+        .await
+    }
+
+    /// Retrieves up to `count` vouchers issued for
+    /// the provided `spender` at specified block.
+    #[at_block]
+    pub async fn vouchers_at(
+        &self,
+        spender: impl IntoAccountId32,
+        count: usize,
+        block_hash: Option<H256>,
+    ) -> Result<Vec<(VoucherId, GearVoucherInfo)>> {
+        self.storage_at(block_hash)
+            .await?
+            .iter(
+                gear::storage()
+                    .gear_voucher()
+                    .vouchers_iter1(spender.into_account_id()),
+            )
+            .await?
+            .map_ok(|pair| {
+                // Voucher id is stored with `Identity` hasher,
+                // so it's the trailing 32 bytes of the key.
+                let (_, id) = pair.key_bytes.split_at(pair.key_bytes.len() - 32);
+                let id = id.try_into().expect("slice has exactly 32 bytes");
+
+                (VoucherId(id), pair.value)
+            })
+            .take(count)
+            .try_collect()
+            .await
+            .map_err(Error::from)
+    }
+}
+
 /// Get storage entry type id using `metadata` and storage entry `address`
 pub(crate) fn storage_type_id(
     metadata: &subxt::Metadata,
@@ -1,8 +1,8 @@
 // Copyright (C) Gear Technologies Inc.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
-use gear_core::ids::{CodeId, prelude::CodeIdExt};
-use gsdk::Result;
+use gear_core::ids::{ActorId, CodeId, prelude::CodeIdExt};
+use gsdk::{IntoAccountId32, Result};
 use sp_core::crypto::Ss58Codec;
 use sp_runtime::AccountId32;
 use utils::dev_node;
@@ -32,6 +32,42 @@ async fn test_issue_voucher() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_query_vouchers() -> Result<()> {
+    // arrange
+    let (_node, api) = dev_node().await;
+
+    let account_id = api.account_id();
+    let voucher_initial_balance = 100_000_000_000_000;
+    let program_id = ActorId::from(42);
+
+    // act
+    let voucher_id = api
+        .issue_voucher(
+            &account_id,
+            voucher_initial_balance,
+            Some(vec![program_id]),
+            true,
+            100,
+        )
+        .await?
+        .value;
+
+    let info = api
+        .unsigned()
+        .voucher_info(&account_id, voucher_id.clone())
+        .await?;
+    let vouchers = api.unsigned().vouchers(&account_id, 10).await?;
+
+    // assert
+    assert_eq!(info.owner, account_id.clone().into_account_id());
+    assert_eq!(info.programs, Some(vec![program_id]));
+    assert!(info.code_uploading);
+    assert_eq!(vouchers, vec![(voucher_id, info)]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_decline_revoke_voucher() -> Result<()> {
     // arrange
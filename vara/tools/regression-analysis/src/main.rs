@@ -307,6 +307,17 @@ fn weights(kind: WeightsKind, input_file: PathBuf, output_file: PathBuf) {
                     gr_block_height,
                     gr_block_timestamp,
                     gr_random,
+                    gr_blake2b_256,
+                    gr_blake2b_256_per_byte,
+                    gr_keccak256,
+                    gr_keccak256_per_byte,
+                    gr_sha256,
+                    gr_sha256_per_byte,
+                    gr_ed25519_verify,
+                    gr_ed25519_verify_per_byte,
+                    gr_sr25519_verify,
+                    gr_sr25519_verify_per_byte,
+                    gr_secp256k1_recover,
                     gr_send_init,
                     gr_send_push,
                     gr_send_push_per_byte,